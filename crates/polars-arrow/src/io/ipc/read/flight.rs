@@ -9,7 +9,7 @@ use polars_error::{polars_bail, polars_err, PolarsResult};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
 use crate::datatypes::ArrowSchema;
-use crate::io::ipc::read::common::read_record_batch;
+use crate::io::ipc::read::common::{read_dictionary, read_record_batch};
 use crate::io::ipc::read::file::{
     decode_footer_len, deserialize_schema_ref_from_footer, iter_dictionary_blocks_from_footer,
     iter_recordbatch_blocks_from_footer,
@@ -303,11 +303,30 @@ impl FlightConsumer {
             .map_err(|err| polars_err!(oos = OutOfSpecKind::InvalidFlatbufferHeader(err)))?
             .ok_or_else(|| polars_err!(oos = OutOfSpecKind::MissingMessageHeader))?;
 
+        // Memory mapping needs aligned buffers, which the allocation of a `Vec<u8>` does not
+        // guarantee; an empty one is not even allocated.
+        let aligned = msg.arrow_data.as_ptr().align_offset(16) == 0;
+
         // Either append to the dictionaries and return None or return Some(ArrowChunk)
         match header {
             MessageHeaderRef::Schema(_) => {
                 polars_bail!(ComputeError: "Unexpected schema message while parsing Stream");
             },
+            MessageHeaderRef::DictionaryBatch(batch) if !aligned => {
+                let data_size = msg.arrow_data.len() as u64;
+                let mut reader = std::io::Cursor::new(msg.arrow_data.as_slice());
+                read_dictionary(
+                    batch,
+                    &self.md.schema,
+                    &self.md.ipc_schema,
+                    &mut self.dictionaries,
+                    &mut reader,
+                    0,
+                    data_size,
+                    &mut self.scratch,
+                )
+                .map(|_| None)
+            },
             // Add to dictionary state and continue iteration
             MessageHeaderRef::DictionaryBatch(batch) => unsafe {
                 // Needed to memory map.
@@ -324,7 +343,7 @@ impl FlightConsumer {
             },
             // Return Batch
             MessageHeaderRef::RecordBatch(batch) => {
-                if batch.compression()?.is_some() {
+                if batch.compression()?.is_some() || !aligned {
                     let data_size = msg.arrow_data.len() as u64;
                     let mut reader = std::io::Cursor::new(msg.arrow_data.as_slice());
                     read_record_batch(
@@ -6,7 +6,7 @@ mod stream;
 pub(crate) mod writer;
 
 pub use common::{
-    commit_encoded_arrays, dictionaries_to_encode, encode_array, encode_dictionary,
+    commit_encoded_arrays, dictionaries_to_encode, encode_array, encode_chunk, encode_dictionary,
     encode_new_dictionaries, encode_record_batch, Compression, DictionaryTracker, EncodedData,
     Record, WriteOptions,
 };
@@ -125,6 +125,11 @@ impl<W: Write> StreamWriter<W> {
         Ok(())
     }
 
+    /// Returns a mutable reference to the inner writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
     /// Consumes itself, returning the inner writer.
     pub fn into_inner(self) -> W {
         self.writer
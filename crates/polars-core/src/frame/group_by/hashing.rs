@@ -1,4 +1,5 @@
 use hashbrown::hash_map::Entry;
+use polars_error::signals::{current_cancellation_token, try_raise_cancelled};
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
 use polars_utils::itertools::Itertools;
@@ -128,6 +129,7 @@ where
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
+    let cancel_token = current_cancellation_token();
     let out = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
@@ -136,6 +138,7 @@ where
 
                 let mut offset = 0;
                 for keys in &keys {
+                    try_raise_cancelled(cancel_token.as_ref());
                     let keys = keys.as_ref();
                     let len = keys.len() as IdxSize;
 
@@ -182,6 +185,7 @@ where
     // We will create a hashtable in every thread.
     // We use the hash to partition the keys to the matching hashtable.
     // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
+    let cancel_token = current_cancellation_token();
     let out = POOL.install(|| {
         (0..n_partitions)
             .into_par_iter()
@@ -191,6 +195,7 @@ where
 
                 let mut offset = 0;
                 for keys in keys {
+                    try_raise_cancelled(cancel_token.as_ref());
                     let keys = keys.clone().into_iter();
                     let len = keys.len() as IdxSize;
 
@@ -38,7 +38,7 @@ pub use crate::chunked_array::StructChunked;
 #[cfg(feature = "dtype-categorical")]
 pub use crate::datatypes::string_cache::StringCacheHolder;
 pub use crate::datatypes::{ArrayCollectIterExt, *};
-pub use crate::error::signals::try_raise_keyboard_interrupt;
+pub use crate::error::signals::{try_raise_keyboard_interrupt, CancellationToken};
 pub use crate::error::{
     polars_bail, polars_ensure, polars_err, polars_warn, PolarsError, PolarsResult,
 };
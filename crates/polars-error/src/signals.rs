@@ -1,6 +1,11 @@
 use std::any::Any;
-use std::panic::{catch_unwind, UnwindSafe};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe, UnwindSafe};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{polars_bail, PolarsError, PolarsResult};
 
 /// Python hooks SIGINT to instead generate a KeyboardInterrupt exception.
 /// So we do the same to try and abort long-running computations and return to
@@ -11,9 +16,20 @@ pub struct KeyboardInterrupt;
 static POLARS_KEYBOARD_INTERRUPT_STRING: &str = "__POLARS_KEYBOARD_INTERRUPT";
 
 // Bottom bit: interrupt flag.
-// Top 63 bits: number of alive interrupt catchers.
+// Bits 1-31: number of alive interrupt catchers.
+// Top 32 bits: number of cancellation tokens installed on threads.
 static INTERRUPT_STATE: AtomicU64 = AtomicU64::new(0);
 
+const INTERRUPT_FLAG: u64 = 1;
+const CATCHER_UNIT: u64 = 1 << 1;
+const CATCHER_MASK: u64 = TOKEN_UNIT - CATCHER_UNIT;
+const TOKEN_UNIT: u64 = 1 << 32;
+const TOKEN_MASK: u64 = !(TOKEN_UNIT - 1);
+
+fn num_catchers(state: u64) -> u64 {
+    (state & CATCHER_MASK) >> 1
+}
+
 fn is_keyboard_interrupt(p: &dyn Any) -> bool {
     if let Some(s) = p.downcast_ref::<&str>() {
         s.contains(POLARS_KEYBOARD_INTERRUPT_STRING)
@@ -29,7 +45,7 @@ pub fn register_polars_keyboard_interrupt_hook() {
     std::panic::set_hook(Box::new(move |p| {
         // Suppress output if there is an active catcher and the panic message
         // contains the keyboard interrupt string.
-        let num_catchers = INTERRUPT_STATE.load(Ordering::Relaxed) >> 1;
+        let num_catchers = num_catchers(INTERRUPT_STATE.load(Ordering::Relaxed));
         let suppress = num_catchers > 0 && is_keyboard_interrupt(p.payload());
         if !suppress {
             default_hook(p);
@@ -44,9 +60,8 @@ pub fn register_polars_keyboard_interrupt_hook() {
             // Set the interrupt flag, but only if there are active catchers.
             INTERRUPT_STATE
                 .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
-                    let num_catchers = state >> 1;
-                    if num_catchers > 0 {
-                        Some(state | 1)
+                    if num_catchers(state) > 0 {
+                        Some(state | INTERRUPT_FLAG)
                     } else {
                         None
                     }
@@ -59,9 +74,15 @@ pub fn register_polars_keyboard_interrupt_hook() {
 
 /// Checks if the keyboard interrupt flag is set, and if yes panics as a
 /// keyboard interrupt. This function is very cheap.
+///
+/// This also unwinds out of the query if the [`CancellationToken`] installed
+/// on the current thread is cancelled, see [`catch_cancellation`]. The token is
+/// only looked at while some thread has a token installed, and its timeout is
+/// only noticed once it was checked at a chunk boundary, see
+/// [`try_raise_cancelled`].
 #[inline(always)]
 pub fn try_raise_keyboard_interrupt() {
-    if INTERRUPT_STATE.load(Ordering::Relaxed) & 1 != 0 {
+    if INTERRUPT_STATE.load(Ordering::Relaxed) & (INTERRUPT_FLAG | TOKEN_MASK) != 0 {
         try_raise_keyboard_interrupt_slow()
     }
 }
@@ -69,7 +90,24 @@ pub fn try_raise_keyboard_interrupt() {
 #[inline(never)]
 #[cold]
 fn try_raise_keyboard_interrupt_slow() {
-    std::panic::panic_any(POLARS_KEYBOARD_INTERRUPT_STRING);
+    if INTERRUPT_STATE.load(Ordering::Relaxed) & INTERRUPT_FLAG != 0 {
+        std::panic::panic_any(POLARS_KEYBOARD_INTERRUPT_STRING);
+    }
+    CURRENT_TOKEN.with_borrow(|token| {
+        if let Some(token) = token.as_ref().filter(|t| t.is_stopped()) {
+            raise_cancelled(token.check().unwrap_err())
+        }
+    });
+}
+
+/// Like [`try_raise_keyboard_interrupt`], but also checks the timeout of the
+/// token installed on the current thread. This reads the clock, so it is meant
+/// for chunk boundaries.
+pub fn try_raise_keyboard_interrupt_or_timeout() {
+    try_raise_keyboard_interrupt();
+    if INTERRUPT_STATE.load(Ordering::Relaxed) & TOKEN_MASK != 0 {
+        CURRENT_TOKEN.with_borrow(|token| try_raise_cancelled(token.as_ref()));
+    }
 }
 
 /// Runs the passed function, catching any KeyboardInterrupts if they occur
@@ -92,8 +130,8 @@ pub fn catch_keyboard_interrupt<R, F: FnOnce() -> R + UnwindSafe>(
 }
 
 fn try_register_catcher() -> Result<(), KeyboardInterrupt> {
-    let old_state = INTERRUPT_STATE.fetch_add(2, Ordering::Relaxed);
-    if old_state & 1 != 0 {
+    let old_state = INTERRUPT_STATE.fetch_add(CATCHER_UNIT, Ordering::Relaxed);
+    if old_state & INTERRUPT_FLAG != 0 {
         unregister_catcher();
         return Err(KeyboardInterrupt);
     }
@@ -103,13 +141,163 @@ fn try_register_catcher() -> Result<(), KeyboardInterrupt> {
 fn unregister_catcher() {
     INTERRUPT_STATE
         .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |state| {
-            let num_catchers = state >> 1;
-            if num_catchers > 1 {
-                Some(state - 2)
+            if num_catchers(state) > 1 {
+                Some(state - CATCHER_UNIT)
             } else {
                 // Last catcher, clear interrupt flag.
-                Some(0)
+                Some(state & TOKEN_MASK)
             }
         })
         .ok();
 }
+
+/// A handle to cancel a single query, optionally once a timeout has passed.
+///
+/// Clones of a token share their state, so a query can be cancelled from any
+/// thread holding a clone. Queries check the token cooperatively: in the
+/// executors, at chunk boundaries of long-running kernels, in the tasks of the
+/// streaming engine and in the readers.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<TokenState>,
+}
+
+#[derive(Debug, Default)]
+struct TokenState {
+    /// One of `RUNNING`, `CANCELLED` or `TIMED_OUT`.
+    status: AtomicU8,
+    deadline: Option<Instant>,
+}
+
+const RUNNING: u8 = 0;
+const CANCELLED: u8 = 1;
+const TIMED_OUT: u8 = 2;
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token that is cancelled automatically once `timeout` has passed.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            inner: Arc::new(TokenState {
+                status: AtomicU8::new(RUNNING),
+                deadline: Instant::now().checked_add(timeout),
+            }),
+        }
+    }
+
+    /// Cancel the query at earliest convenience.
+    pub fn cancel(&self) {
+        self.stop(CANCELLED)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.is_stopped() || self.poll_deadline()
+    }
+
+    fn stop(&self, status: u8) {
+        let _ = self.inner.status.compare_exchange(
+            RUNNING,
+            status,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Whether the token was cancelled or was found to be timed out, without
+    /// reading the clock.
+    fn is_stopped(&self) -> bool {
+        self.inner.status.load(Ordering::Relaxed) != RUNNING
+    }
+
+    /// Marks the token as timed out if its deadline has passed, so that the
+    /// cheap checks of [`try_raise_keyboard_interrupt`] see it.
+    fn poll_deadline(&self) -> bool {
+        let timed_out = self.inner.deadline.is_some_and(|d| Instant::now() >= d);
+        if timed_out {
+            self.stop(TIMED_OUT);
+        }
+        timed_out
+    }
+
+    /// Returns an error if the token is cancelled or its timeout has passed.
+    pub fn check(&self) -> PolarsResult<()> {
+        if !self.is_stopped() {
+            self.poll_deadline();
+        }
+        match self.inner.status.load(Ordering::Relaxed) {
+            CANCELLED => polars_bail!(ComputeError: "query interrupted"),
+            TIMED_OUT => polars_bail!(ComputeError: "query timed out"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The panic payload a cancelled query unwinds with.
+struct QueryCancelled(PolarsError);
+
+thread_local! {
+    static CURRENT_TOKEN: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// The token installed on the current thread by [`with_cancellation_token`].
+pub fn current_cancellation_token() -> Option<CancellationToken> {
+    CURRENT_TOKEN.with_borrow(|token| token.clone())
+}
+
+/// Runs the passed function with `token` installed on the current thread, so
+/// that [`try_raise_keyboard_interrupt`] also checks it.
+///
+/// Tokens must not be installed on threads of a work-stealing pool: while such
+/// a thread waits on its own work it may run work of other queries. Work that
+/// such a pool runs for the query only sees the token if it is captured with
+/// [`current_cancellation_token`] and checked with [`try_raise_cancelled`].
+pub fn with_cancellation_token<R>(token: Option<CancellationToken>, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<CancellationToken>, bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_TOKEN.set(self.0.take());
+            if self.1 {
+                INTERRUPT_STATE.fetch_sub(TOKEN_UNIT, Ordering::Relaxed);
+            }
+        }
+    }
+
+    let installed = token.is_some();
+    if installed {
+        INTERRUPT_STATE.fetch_add(TOKEN_UNIT, Ordering::Relaxed);
+    }
+    let _restore = Restore(CURRENT_TOKEN.replace(token), installed);
+    f()
+}
+
+/// Unwinds out of the query if `token` is cancelled or timed out. Kernels that
+/// run on other threads than the one the token is installed on capture it with
+/// [`current_cancellation_token`] and check it with this function at chunk
+/// boundaries.
+#[inline]
+pub fn try_raise_cancelled(token: Option<&CancellationToken>) {
+    if let Some(token) = token.filter(|t| t.is_cancelled()) {
+        raise_cancelled(token.check().unwrap_err())
+    }
+}
+
+#[inline(never)]
+#[cold]
+fn raise_cancelled(err: PolarsError) -> ! {
+    // Unlike a panic this doesn't run the panic hook, so nothing is printed.
+    std::panic::resume_unwind(Box::new(QueryCancelled(err)))
+}
+
+/// Runs the passed function, turning an unwind of a cancelled query into the
+/// error that cancelled it.
+pub fn catch_cancellation<R>(try_fn: impl FnOnce() -> PolarsResult<R>) -> PolarsResult<R> {
+    catch_unwind(AssertUnwindSafe(try_fn)).unwrap_or_else(|p| {
+        match p.downcast::<QueryCancelled>() {
+            Ok(cancelled) => Err(cancelled.0),
+            Err(p) => std::panic::resume_unwind(p),
+        }
+    })
+}
@@ -1,5 +1,5 @@
 use std::borrow::Cow;
-use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU8, Ordering};
 use std::sync::{Mutex, RwLock};
 
 use bitflags::bitflags;
@@ -114,7 +114,7 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
-    stop: Arc<AtomicBool>,
+    stop: CancellationToken,
 }
 
 impl ExecutionState {
@@ -131,7 +131,7 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
-            stop: Arc::new(AtomicBool::new(false)),
+            stop: CancellationToken::new(),
         }
     }
 
@@ -150,14 +150,18 @@ impl ExecutionState {
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
         try_raise_keyboard_interrupt();
-        polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
-        Ok(())
+        self.stop.check()
     }
 
-    pub fn cancel_token(&self) -> Arc<AtomicBool> {
+    pub fn cancel_token(&self) -> CancellationToken {
         self.stop.clone()
     }
 
+    /// Stop the execution once `token` is cancelled or times out.
+    pub fn set_cancel_token(&mut self, token: CancellationToken) {
+        self.stop = token;
+    }
+
     pub fn record<T, F: FnOnce() -> T>(&self, func: F, name: Cow<'static, str>) -> T {
         match &self.node_timer {
             None => func(),
@@ -1,15 +1,22 @@
+use std::sync::Arc;
+
 use polars_core::prelude::PlHashMap;
 use polars_core::schema::Schema;
 use polars_error::{polars_bail, to_compute_err, PolarsResult};
 
-use super::models::{CatalogInfo, NamespaceInfo, TableCredentials, TableInfo};
+use super::models::{
+    CatalogInfo, NamespaceInfo, TableCredentials, TableCredentialsRefresh, TableInfo,
+};
 use super::schema::schema_to_column_info_list;
 use super::utils::{do_request, PageWalker};
 use crate::catalog::unity::models::{ColumnInfo, DataSourceFormat, TableType};
+use crate::cloud::CloudOptions;
 use crate::impl_page_walk;
+use crate::pl_async::get_runtime;
 use crate::utils::decode_json_response;
 
 /// Unity catalog client.
+#[derive(Clone)]
 pub struct CatalogClient {
     workspace_url: String,
     http_client: reqwest::Client,
@@ -106,6 +113,36 @@ impl CatalogClient {
         Ok(out)
     }
 
+    /// Get temporary credentials for a table and build the options to access its storage
+    /// location with them. New credentials are requested shortly before they expire.
+    pub async fn get_table_cloud_options(
+        &self,
+        table_info: &TableInfo,
+        write: bool,
+    ) -> PolarsResult<CloudOptions> {
+        let Some(storage_location) = table_info.storage_location.as_deref() else {
+            polars_bail!(
+                ComputeError:
+                "table '{}' has no storage_location", table_info.name
+            )
+        };
+
+        let client = self.clone();
+        let table_id = table_info.table_id.clone();
+        let refresh: TableCredentialsRefresh = Arc::new(move || {
+            let client = client.clone();
+            let table_id = table_id.clone();
+            // The request runs as a task, as the credential provider requires a `Sync` future.
+            let request = get_runtime()
+                .spawn(async move { client.get_table_credentials(&table_id, write).await });
+            Box::pin(async move { request.await.map_err(to_compute_err)? })
+        });
+
+        self.get_table_credentials(&table_info.table_id, write)
+            .await?
+            .into_cloud_options(storage_location, refresh)
+    }
+
     pub async fn create_catalog(
         &self,
         catalog_name: &str,
@@ -1,6 +1,23 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
 use polars_core::prelude::PlHashMap;
+use polars_error::{polars_bail, polars_ensure, PolarsResult};
+#[cfg(feature = "azure")]
+use polars_error::{polars_err, to_compute_err};
 use polars_utils::pl_str::PlSmallStr;
 
+#[cfg(feature = "aws")]
+use crate::cloud::credential_provider::AwsCredential;
+#[cfg(feature = "azure")]
+use crate::cloud::credential_provider::AzureCredential;
+#[cfg(feature = "gcp")]
+use crate::cloud::credential_provider::GcpCredential;
+use crate::cloud::credential_provider::{ObjectStoreCredential, PlCredentialProvider};
+use crate::cloud::{CloudOptions, CloudType};
+
 #[derive(Debug, serde::Deserialize)]
 pub struct CatalogInfo {
     pub name: String,
@@ -257,6 +274,13 @@ impl ColumnTypeJsonType {
     }
 }
 
+/// Fetches new temporary credentials of a table.
+pub type TableCredentialsRefresh = Arc<
+    dyn Fn() -> Pin<Box<dyn Future<Output = PolarsResult<TableCredentials>> + Send + Sync>>
+        + Send
+        + Sync,
+>;
+
 #[derive(Debug, serde::Deserialize)]
 pub struct TableCredentials {
     pub aws_temp_credentials: Option<TableCredentialsAws>,
@@ -277,6 +301,131 @@ impl TableCredentials {
             None
         }
     }
+
+    /// The cloud that these credentials are for.
+    fn cloud_type(&self) -> Option<CloudType> {
+        if self.aws_temp_credentials.is_some() {
+            Some(CloudType::Aws)
+        } else if self.azure_user_delegation_sas.is_some() {
+            Some(CloudType::Azure)
+        } else if self.gcp_oauth_token.is_some() {
+            Some(CloudType::Gcp)
+        } else {
+            None
+        }
+    }
+
+    /// Build the options to access `storage_location` with these credentials.
+    ///
+    /// The credentials are temporary, so they are passed by a credential provider that calls
+    /// `refresh` for new credentials shortly before they expire.
+    pub fn into_cloud_options(
+        self,
+        storage_location: &str,
+        refresh: TableCredentialsRefresh,
+    ) -> PolarsResult<CloudOptions> {
+        let options = CloudOptions::from_untyped_config(
+            storage_location,
+            std::iter::empty::<(&str, String)>(),
+        )?;
+        let location_type = CloudType::from_str(storage_location)?;
+        if matches!(
+            location_type,
+            CloudType::File | CloudType::Http | CloudType::Hf
+        ) {
+            return Ok(options);
+        }
+        let Some(cloud_type) = self.cloud_type() else {
+            return Ok(options);
+        };
+        polars_ensure!(
+            cloud_type == location_type,
+            ComputeError: "the catalog returned {:?} credentials for the storage location '{}'",
+            cloud_type, storage_location
+        );
+
+        // The first request is answered with the credentials that were already fetched.
+        let initial = Mutex::new(Some(self));
+        let provider = PlCredentialProvider::from_func(move || {
+            let initial = initial.lock().unwrap().take();
+            let refresh = refresh.clone();
+            Box::pin(async move {
+                let credentials = match initial {
+                    Some(credentials) => credentials,
+                    None => refresh().await?,
+                };
+                credentials.into_object_store_credential()
+            })
+        });
+        Ok(options.with_credential_provider(Some(provider)))
+    }
+
+    /// Convert to a credential for the object store and its expiry time in seconds since the
+    /// epoch.
+    #[cfg_attr(
+        not(any(feature = "aws", feature = "azure", feature = "gcp")),
+        allow(unused_variables, unreachable_code)
+    )]
+    fn into_object_store_credential(self) -> PolarsResult<(ObjectStoreCredential, u64)> {
+        #[allow(unused_imports)]
+        use TableCredentialsVariants::*;
+
+        // The expiration time is in milliseconds since the epoch. Credentials without one are
+        // never refreshed.
+        let expiry = match u64::try_from(self.expiration_time / 1000) {
+            Ok(expiry) if expiry > 0 => expiry,
+            _ => u64::MAX,
+        };
+
+        let credential = match self.into_enum() {
+            #[cfg(feature = "aws")]
+            Some(Aws(TableCredentialsAws {
+                access_key_id,
+                secret_access_key,
+                session_token,
+                access_point: _,
+            })) => ObjectStoreCredential::Aws(Arc::new(AwsCredential {
+                key_id: access_key_id,
+                secret_key: secret_access_key,
+                token: session_token,
+            })),
+            #[cfg(feature = "azure")]
+            Some(Azure(TableCredentialsAzure { sas_token })) => ObjectStoreCredential::Azure(
+                Arc::new(AzureCredential::SASToken(split_sas(&sas_token)?)),
+            ),
+            #[cfg(feature = "gcp")]
+            Some(Gcp(TableCredentialsGcp { oauth_token })) => {
+                ObjectStoreCredential::Gcp(Arc::new(GcpCredential {
+                    bearer: oauth_token,
+                }))
+            },
+            #[allow(unreachable_patterns)]
+            Some(_) => polars_bail!(
+                ComputeError: "the cloud of the table credentials is not enabled as a feature"
+            ),
+            None => polars_bail!(ComputeError: "the catalog returned no credentials for the table"),
+        };
+        Ok((credential, expiry))
+    }
+}
+
+/// Split a SAS token into its query pairs, in the same way as the `sas_token` configuration key.
+#[cfg(feature = "azure")]
+fn split_sas(sas_token: &str) -> PolarsResult<Vec<(String, String)>> {
+    let sas_token = percent_encoding::percent_decode_str(sas_token)
+        .decode_utf8()
+        .map_err(to_compute_err)?;
+    sas_token
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.trim().is_empty())
+        .map(|pair| {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| polars_err!(ComputeError: "invalid SAS token"))?;
+            Ok((key.to_string(), value.to_string()))
+        })
+        .collect()
 }
 
 pub enum TableCredentialsVariants {
@@ -314,3 +463,146 @@ where
     let opt_val = Option::<T>::deserialize(d)?;
     Ok(opt_val.unwrap_or_default())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::*;
+    use crate::pl_async::get_runtime;
+    use crate::utils::decode_json_response;
+
+    /// Credentials of the given kind that expire `seconds` from now.
+    fn credentials(kind: &str, value: &str, seconds: i64) -> TableCredentials {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        decode_json_response(
+            format!(
+                r#"{{"{kind}":{value},"expiration_time":{}}}"#,
+                now + seconds * 1000
+            )
+            .as_bytes(),
+        )
+        .unwrap()
+    }
+
+    /// A refresh function that counts its calls and returns the credentials for that count.
+    fn refresh_with(
+        make: impl Fn(usize) -> TableCredentials + Send + Sync + 'static,
+    ) -> (TableCredentialsRefresh, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let refresh: TableCredentialsRefresh = Arc::new(move || {
+            let credentials = make(counter.fetch_add(1, Ordering::Relaxed) + 1);
+            Box::pin(async move { Ok(credentials) })
+        });
+        (refresh, calls)
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_aws_credentials_are_refreshed() {
+        use crate::cloud::credential_provider::IntoCredentialProvider;
+
+        let aws = |key: usize| {
+            format!(
+                r#"{{"access_key_id":"key{key}","secret_access_key":"secret","session_token":"session"}}"#
+            )
+        };
+        let (refresh, calls) =
+            refresh_with(move |n| credentials("aws_temp_credentials", &aws(n), 3600));
+
+        // The first credentials expire within the request time buffer, so they are replaced on
+        // the second request.
+        let options = credentials("aws_temp_credentials", &aws(0), 5)
+            .into_cloud_options("s3://bucket/table", refresh)
+            .unwrap();
+        let provider = options.credential_provider.unwrap().into_aws_provider();
+
+        let keys = get_runtime().block_on(async {
+            let mut keys = vec![];
+            for _ in 0..3 {
+                keys.push(provider.get_credential().await.unwrap().key_id.clone());
+            }
+            keys
+        });
+        assert_eq!(keys, ["key0", "key1", "key1"]);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn test_azure_sas_token() {
+        use crate::cloud::credential_provider::IntoCredentialProvider;
+
+        let (refresh, calls) = refresh_with(|_| unreachable!());
+        let options = credentials(
+            "azure_user_delegation_sas",
+            r#"{"sas_token":"?sv=2022-11-02&sig=a%2Bb%3D"}"#,
+            3600,
+        )
+        .into_cloud_options(
+            "abfss://container@account.dfs.core.windows.net/table",
+            refresh,
+        )
+        .unwrap();
+        let provider = options.credential_provider.unwrap().into_azure_provider();
+
+        let credential = get_runtime().block_on(provider.get_credential()).unwrap();
+        let AzureCredential::SASToken(pairs) = credential.as_ref() else {
+            panic!("expected a SAS token, got {credential:?}")
+        };
+        assert_eq!(
+            pairs,
+            &[
+                ("sv".to_string(), "2022-11-02".to_string()),
+                ("sig".to_string(), "a+b=".to_string()),
+            ]
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+    }
+
+    #[cfg(feature = "gcp")]
+    #[test]
+    fn test_gcp_token_is_refreshed() {
+        use crate::cloud::credential_provider::IntoCredentialProvider;
+
+        let gcp = |n: usize| format!(r#"{{"oauth_token":"token{n}"}}"#);
+        let (refresh, calls) = refresh_with(move |n| credentials("gcp_oauth_token", &gcp(n), 5));
+        let options = credentials("gcp_oauth_token", &gcp(0), 5)
+            .into_cloud_options("gs://bucket/table", refresh)
+            .unwrap();
+        let provider = options.credential_provider.unwrap().into_gcp_provider();
+
+        let tokens = get_runtime().block_on(async {
+            let mut tokens = vec![];
+            for _ in 0..3 {
+                tokens.push(provider.get_credential().await.unwrap().bearer.clone());
+            }
+            tokens
+        });
+        assert_eq!(tokens, ["token0", "token1", "token2"]);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_credentials_for_location() {
+        let gcp = r#"{"oauth_token":"token"}"#;
+        let (refresh, _) = refresh_with(|_| unreachable!());
+
+        // Local tables do not need credentials.
+        let options = credentials("gcp_oauth_token", gcp, 3600)
+            .into_cloud_options("/tmp/table", refresh.clone())
+            .unwrap();
+        assert!(options.credential_provider.is_none());
+
+        #[cfg(feature = "aws")]
+        assert!(credentials("gcp_oauth_token", gcp, 3600)
+            .into_cloud_options("s3://bucket/table", refresh)
+            .is_err());
+    }
+}
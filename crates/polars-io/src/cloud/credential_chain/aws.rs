@@ -0,0 +1,795 @@
+use std::fmt::Write;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use object_store::aws::AwsCredential;
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use polars_utils::aliases::{PlHashMap, PlHashSet};
+use ring::{digest, hmac};
+use serde::Deserialize;
+
+use super::{now_secs, parse_rfc3339, run_shell_command, send, ChainEnv, HTTP_CLIENT};
+
+type Section = PlHashMap<String, String>;
+
+/// The profiles and SSO sessions of the AWS shared config and credentials files.
+#[derive(Debug, Default)]
+pub(crate) struct SharedConfig {
+    profiles: PlHashMap<String, Section>,
+    sso_sessions: PlHashMap<String, Section>,
+}
+
+impl SharedConfig {
+    pub(crate) fn load(env: &ChainEnv) -> Self {
+        let read = |var: &str, default: &str| {
+            let path = env
+                .var_os(var)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| env.resolve_homedir(&default));
+            std::fs::read_to_string(path).unwrap_or_default()
+        };
+        Self::parse(
+            &read("AWS_CONFIG_FILE", "~/.aws/config"),
+            &read("AWS_SHARED_CREDENTIALS_FILE", "~/.aws/credentials"),
+        )
+    }
+
+    fn parse(config: &str, credentials: &str) -> Self {
+        let mut this = Self::default();
+        for (name, section) in parse_ini(config) {
+            let (kind, name) = match name.split_once(char::is_whitespace) {
+                Some((kind, name)) => (kind, name.trim()),
+                None => ("profile", name.as_str()),
+            };
+            let sections = match kind {
+                "profile" => &mut this.profiles,
+                "sso-session" => &mut this.sso_sessions,
+                _ => continue,
+            };
+            sections.entry(name.into()).or_default().extend(section);
+        }
+        // The credentials file takes precedence over the config file.
+        for (name, section) in parse_ini(credentials) {
+            this.profiles.entry(name).or_default().extend(section);
+        }
+        this
+    }
+
+    /// Whether the selected profile needs more than static keys, such as SSO or assuming a role.
+    pub(crate) fn profile_requires_chain(&self, env: &ChainEnv) -> bool {
+        let Some(profile) = self.profiles.get(&profile_name(None, env)) else {
+            return false;
+        };
+        [
+            "role_arn",
+            "sso_session",
+            "sso_start_url",
+            "credential_process",
+        ]
+        .iter()
+        .any(|key| profile.contains_key(*key))
+    }
+
+    fn resolve(
+        &self,
+        name: &str,
+        visited: &mut PlHashSet<String>,
+        env: &ChainEnv,
+    ) -> PolarsResult<ProfileSource> {
+        polars_ensure!(
+            visited.insert(name.into()),
+            ComputeError: "AWS profile '{}' is part of a source_profile cycle", name
+        );
+        let profile = self.profiles.get(name).ok_or_else(
+            || polars_err!(ComputeError: "AWS profile '{}' was not found in the shared config", name),
+        )?;
+        let get = |key: &str| profile.get(key).cloned();
+
+        if let Some(role_arn) = get("role_arn") {
+            let session_name = get("role_session_name");
+            if let Some(token_file) = get("web_identity_token_file") {
+                return Ok(ProfileSource::WebIdentity(
+                    WebIdentity {
+                        role_arn,
+                        token_file: env.resolve_homedir(&token_file),
+                        session_name,
+                    },
+                    self.region(profile, env),
+                ));
+            }
+            let source = match (get("source_profile"), get("credential_source")) {
+                // A profile can use its own static keys to assume a role.
+                (Some(source), None) if source == name => static_credential(name, profile)?,
+                (Some(source), None) => self.resolve(&source, visited, env)?,
+                (None, Some(source)) if source == "Environment" => ProfileSource::Environment,
+                (None, Some(source)) => polars_bail!(
+                    ComputeError: "credential_source '{}' of AWS profile '{}' is not supported",
+                    source, name
+                ),
+                _ => polars_bail!(
+                    ComputeError: "AWS profile '{}' must set exactly one of source_profile and \
+                    credential_source", name
+                ),
+            };
+            return Ok(ProfileSource::AssumeRole {
+                role_arn,
+                session_name,
+                external_id: get("external_id"),
+                duration_seconds: get("duration_seconds"),
+                region: self.region(profile, env),
+                source: Box::new(source),
+            });
+        }
+
+        if profile.contains_key("sso_session") || profile.contains_key("sso_start_url") {
+            let (session, start_url, region) = match get("sso_session") {
+                Some(session) => {
+                    let section = self.sso_sessions.get(&session).ok_or_else(|| {
+                        polars_err!(
+                            ComputeError: "sso-session '{}' of AWS profile '{}' was not found",
+                            session, name
+                        )
+                    })?;
+                    (
+                        Some(session.clone()),
+                        section.get("sso_start_url").cloned(),
+                        section.get("sso_region").cloned(),
+                    )
+                },
+                None => (None, get("sso_start_url"), get("sso_region")),
+            };
+            let missing = |key: &str| polars_err!(ComputeError: "AWS profile '{}' does not set {}", name, key);
+            return Ok(ProfileSource::Sso {
+                session,
+                start_url: start_url.ok_or_else(|| missing("sso_start_url"))?,
+                region: region.ok_or_else(|| missing("sso_region"))?,
+                account_id: get("sso_account_id").ok_or_else(|| missing("sso_account_id"))?,
+                role_name: get("sso_role_name").ok_or_else(|| missing("sso_role_name"))?,
+            });
+        }
+
+        if let Some(command) = get("credential_process") {
+            return Ok(ProfileSource::Process(command));
+        }
+
+        static_credential(name, profile)
+    }
+
+    fn region(&self, profile: &Section, env: &ChainEnv) -> String {
+        profile
+            .get("region")
+            .cloned()
+            .unwrap_or_else(|| env_region(env))
+    }
+}
+
+/// Parse the sections of an INI file. Nested properties are skipped, as they only configure
+/// services.
+fn parse_ini(content: &str) -> Vec<(String, Section)> {
+    let mut sections: Vec<(String, Section)> = vec![];
+    let mut in_nested = false;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with(['#', ';']) {
+            continue;
+        }
+        if let Some(name) = trimmed.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            sections.push((name.trim().into(), Section::default()));
+            in_nested = false;
+            continue;
+        }
+        let Some((_, section)) = sections.last_mut() else {
+            continue;
+        };
+        let indented = line.starts_with(char::is_whitespace);
+        if indented && in_nested {
+            continue;
+        }
+        if let Some((key, value)) = trimmed.split_once('=') {
+            let (key, value) = (key.trim(), value.trim());
+            in_nested = value.is_empty();
+            if !indented && !in_nested {
+                section.insert(key.to_ascii_lowercase(), value.into());
+            }
+        }
+    }
+    sections
+}
+
+pub(crate) fn profile_name(profile: Option<&str>, env: &ChainEnv) -> String {
+    profile
+        .map(String::from)
+        .or_else(|| env.var("AWS_PROFILE"))
+        .unwrap_or_else(|| "default".into())
+}
+
+pub(super) fn env_region(env: &ChainEnv) -> String {
+    env.var("AWS_REGION")
+        .or_else(|| env.var("AWS_DEFAULT_REGION"))
+        .unwrap_or_else(|| "us-east-1".into())
+}
+
+fn static_credential(name: &str, profile: &Section) -> PolarsResult<ProfileSource> {
+    match (
+        profile.get("aws_access_key_id"),
+        profile.get("aws_secret_access_key"),
+    ) {
+        (Some(key_id), Some(secret_key)) => Ok(ProfileSource::Static(AwsCredential {
+            key_id: key_id.clone(),
+            secret_key: secret_key.clone(),
+            token: profile.get("aws_session_token").cloned(),
+        })),
+        _ => polars_bail!(ComputeError: "AWS profile '{}' does not configure credentials", name),
+    }
+}
+
+/// How the credentials of a profile are obtained.
+#[derive(Debug, PartialEq)]
+enum ProfileSource {
+    Static(AwsCredential),
+    Environment,
+    Process(String),
+    Sso {
+        session: Option<String>,
+        start_url: String,
+        region: String,
+        account_id: String,
+        role_name: String,
+    },
+    WebIdentity(WebIdentity, String),
+    AssumeRole {
+        role_arn: String,
+        session_name: Option<String>,
+        external_id: Option<String>,
+        duration_seconds: Option<String>,
+        region: String,
+        source: Box<ProfileSource>,
+    },
+}
+
+type CredentialFuture<'a> =
+    Pin<Box<dyn Future<Output = PolarsResult<(AwsCredential, u64)>> + Send + 'a>>;
+
+impl ProfileSource {
+    fn credential<'a>(&'a self, env: &'a ChainEnv) -> CredentialFuture<'a> {
+        Box::pin(async move {
+            match self {
+                Self::Static(credential) => Ok((
+                    AwsCredential {
+                        key_id: credential.key_id.clone(),
+                        secret_key: credential.secret_key.clone(),
+                        token: credential.token.clone(),
+                    },
+                    u64::MAX,
+                )),
+                Self::Environment => {
+                    let credential = AwsCredential {
+                        key_id: required_var("AWS_ACCESS_KEY_ID", env)?,
+                        secret_key: required_var("AWS_SECRET_ACCESS_KEY", env)?,
+                        token: env.var("AWS_SESSION_TOKEN"),
+                    };
+                    Ok((credential, u64::MAX))
+                },
+                Self::Process(command) => process_credential(command, env).await,
+                Self::Sso {
+                    session,
+                    start_url,
+                    region,
+                    account_id,
+                    role_name,
+                } => {
+                    let token = sso_token(session.as_deref().unwrap_or(start_url), env)?;
+                    sso_credential(&token, region, account_id, role_name).await
+                },
+                Self::WebIdentity(web_identity, region) => {
+                    web_identity.credential(region, env).await
+                },
+                Self::AssumeRole {
+                    role_arn,
+                    session_name,
+                    external_id,
+                    duration_seconds,
+                    region,
+                    source,
+                } => {
+                    let (source, _) = source.credential(env).await?;
+                    let session_name = session_name.clone().unwrap_or_else(default_session_name);
+                    let mut params = vec![
+                        ("Action", "AssumeRole"),
+                        ("Version", "2011-06-15"),
+                        ("RoleArn", role_arn.as_str()),
+                        ("RoleSessionName", session_name.as_str()),
+                    ];
+                    if let Some(external_id) = external_id {
+                        params.push(("ExternalId", external_id));
+                    }
+                    if let Some(duration_seconds) = duration_seconds {
+                        params.push(("DurationSeconds", duration_seconds));
+                    }
+                    sts_credential(region, &params, Some(&source), env).await
+                },
+            }
+        })
+    }
+}
+
+/// The credentials of a profile of the shared config.
+pub(super) async fn profile_credential(
+    profile: Option<&str>,
+    env: &ChainEnv,
+) -> PolarsResult<(AwsCredential, u64)> {
+    let name = profile_name(profile, env);
+    let source = SharedConfig::load(env).resolve(&name, &mut PlHashSet::default(), env)?;
+    source.credential(env).await
+}
+
+fn required_var(name: &str, env: &ChainEnv) -> PolarsResult<String> {
+    env.var(name)
+        .ok_or_else(|| polars_err!(ComputeError: "environment variable {} is not set", name))
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) struct WebIdentity {
+    role_arn: String,
+    token_file: PathBuf,
+    session_name: Option<String>,
+}
+
+impl WebIdentity {
+    pub(super) fn from_env(
+        role_arn: Option<String>,
+        token_file: Option<PathBuf>,
+        session_name: Option<String>,
+        env: &ChainEnv,
+    ) -> PolarsResult<Self> {
+        Ok(Self {
+            role_arn: role_arn.map_or_else(|| required_var("AWS_ROLE_ARN", env), Ok)?,
+            token_file: token_file.map_or_else(
+                || required_var("AWS_WEB_IDENTITY_TOKEN_FILE", env).map(PathBuf::from),
+                Ok,
+            )?,
+            session_name: session_name.or_else(|| env.var("AWS_ROLE_SESSION_NAME")),
+        })
+    }
+
+    pub(super) async fn credential(
+        &self,
+        region: &str,
+        env: &ChainEnv,
+    ) -> PolarsResult<(AwsCredential, u64)> {
+        // The token is read on every refresh, as it is rotated by the platform.
+        let token = tokio::fs::read_to_string(&self.token_file)
+            .await
+            .map_err(|e| {
+                polars_err!(
+                    ComputeError: "failed to read web identity token file {}: {}",
+                    self.token_file.display(), e
+                )
+            })?;
+        let session_name = self
+            .session_name
+            .clone()
+            .unwrap_or_else(default_session_name);
+        let params = [
+            ("Action", "AssumeRoleWithWebIdentity"),
+            ("Version", "2011-06-15"),
+            ("RoleArn", self.role_arn.as_str()),
+            ("RoleSessionName", session_name.as_str()),
+            ("WebIdentityToken", token.trim()),
+        ];
+        sts_credential(region, &params, None, env).await
+    }
+}
+
+fn default_session_name() -> String {
+    format!("polars-{}", now_secs())
+}
+
+/// The STS endpoint of `region`, which can be overridden with `AWS_ENDPOINT_URL_STS`.
+fn sts_endpoint(region: &str, env: &ChainEnv) -> String {
+    if let Some(endpoint) = env.var("AWS_ENDPOINT_URL_STS") {
+        return endpoint.trim_end_matches('/').into();
+    }
+    if region.starts_with("cn-") {
+        format!("https://sts.{region}.amazonaws.com.cn")
+    } else {
+        format!("https://sts.{region}.amazonaws.com")
+    }
+}
+
+/// Call an STS action, which is signed with `signer` if it is given.
+async fn sts_credential(
+    region: &str,
+    params: &[(&str, &str)],
+    signer: Option<&AwsCredential>,
+    env: &ChainEnv,
+) -> PolarsResult<(AwsCredential, u64)> {
+    #[derive(Deserialize)]
+    struct Response {
+        #[serde(rename = "AssumeRoleResult", alias = "AssumeRoleWithWebIdentityResult")]
+        result: ResponseResult,
+    }
+
+    #[derive(Deserialize)]
+    struct ResponseResult {
+        #[serde(rename = "Credentials")]
+        credentials: Credentials,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Credentials {
+        access_key_id: String,
+        secret_access_key: String,
+        session_token: String,
+        expiration: String,
+    }
+
+    let endpoint = sts_endpoint(region, env);
+    let host = endpoint
+        .split_once("://")
+        .map_or(endpoint.as_str(), |(_, host)| host)
+        .to_string();
+    let body = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish();
+    let mut headers = vec![
+        (
+            "content-type".to_string(),
+            "application/x-www-form-urlencoded".to_string(),
+        ),
+        ("host".to_string(), host.clone()),
+    ];
+    if let Some(signer) = signer {
+        let amz_date = amz_date(now_secs());
+        headers.push(("x-amz-date".into(), amz_date.clone()));
+        if let Some(token) = &signer.token {
+            headers.push(("x-amz-security-token".into(), token.clone()));
+        }
+        let authorization = sign_v4(
+            signer,
+            &SignedRequest {
+                method: "POST",
+                path: "/",
+                query: "",
+                headers: &headers,
+                body: body.as_bytes(),
+            },
+            &amz_date,
+            region,
+            "sts",
+        );
+        headers.push(("authorization".into(), authorization));
+    }
+
+    let mut request = HTTP_CLIENT.post(format!("{endpoint}/")).body(body);
+    for (name, value) in headers.iter().filter(|(name, _)| name != "host") {
+        request = request.header(name, value);
+    }
+    let response = send(request).await?;
+    let response = std::str::from_utf8(&response).map_err(to_compute_err)?;
+    let response: Response = quick_xml::de::from_str(response)
+        .map_err(|e| polars_err!(ComputeError: "failed to parse the AWS STS response: {}", e))?;
+    let credentials = response.result.credentials;
+    Ok((
+        AwsCredential {
+            key_id: credentials.access_key_id,
+            secret_key: credentials.secret_access_key,
+            token: Some(credentials.session_token),
+        },
+        parse_rfc3339(&credentials.expiration)?,
+    ))
+}
+
+/// Run a `credential_process` command.
+async fn process_credential(command: &str, env: &ChainEnv) -> PolarsResult<(AwsCredential, u64)> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Output {
+        version: u32,
+        access_key_id: String,
+        secret_access_key: String,
+        session_token: Option<String>,
+        expiration: Option<String>,
+    }
+
+    let output = run_shell_command(command, env).await?;
+    let output: Output = serde_json::from_slice(&output).map_err(
+        |e| polars_err!(ComputeError: "invalid output of credential_process '{}': {}", command, e),
+    )?;
+    polars_ensure!(
+        output.version == 1,
+        ComputeError: "unsupported credential_process output version {}", output.version
+    );
+    let expiry = match &output.expiration {
+        Some(expiration) => parse_rfc3339(expiration)?,
+        None => u64::MAX,
+    };
+    Ok((
+        AwsCredential {
+            key_id: output.access_key_id,
+            secret_key: output.secret_access_key,
+            token: output.session_token,
+        },
+        expiry,
+    ))
+}
+
+/// Read the access token that `aws sso login` cached for an SSO session or start URL.
+fn sso_token(cache_key: &str, env: &ChainEnv) -> PolarsResult<String> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct CachedToken {
+        access_token: String,
+        expires_at: String,
+    }
+
+    let hash = digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, cache_key.as_bytes());
+    let path = env
+        .resolve_homedir(&Path::new("~/.aws/sso/cache"))
+        .join(format!("{}.json", hex(hash.as_ref())));
+    let content = std::fs::read(&path).map_err(|_| {
+        polars_err!(
+            ComputeError: "no cached SSO token was found for '{}', run `aws sso login` first",
+            cache_key
+        )
+    })?;
+    let token: CachedToken = serde_json::from_slice(&content).map_err(to_compute_err)?;
+    // Older versions of the AWS CLI write a `UTC` suffix instead of `Z`.
+    let expires_at = parse_rfc3339(&token.expires_at.replace("UTC", "Z"))?;
+    polars_ensure!(
+        expires_at > now_secs(),
+        ComputeError: "the SSO token for '{}' has expired, run `aws sso login` to refresh it",
+        cache_key
+    );
+    Ok(token.access_token)
+}
+
+/// Exchange an SSO access token for the credentials of a role.
+async fn sso_credential(
+    token: &str,
+    region: &str,
+    account_id: &str,
+    role_name: &str,
+) -> PolarsResult<(AwsCredential, u64)> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Response {
+        role_credentials: RoleCredentials,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct RoleCredentials {
+        access_key_id: String,
+        secret_access_key: String,
+        session_token: String,
+        /// Milliseconds since the UNIX epoch.
+        expiration: u64,
+    }
+
+    let request = HTTP_CLIENT
+        .get(format!(
+            "https://portal.sso.{region}.amazonaws.com/federation/credentials"
+        ))
+        .query(&[("account_id", account_id), ("role_name", role_name)])
+        .header("x-amz-sso_bearer_token", token);
+    let response: Response =
+        serde_json::from_slice(&send(request).await?).map_err(to_compute_err)?;
+    let credentials = response.role_credentials;
+    Ok((
+        AwsCredential {
+            key_id: credentials.access_key_id,
+            secret_key: credentials.secret_access_key,
+            token: Some(credentials.session_token),
+        },
+        credentials.expiration / 1000,
+    ))
+}
+
+struct SignedRequest<'a> {
+    method: &'a str,
+    path: &'a str,
+    query: &'a str,
+    /// Lowercase header names, which must include `host` and `x-amz-date`.
+    headers: &'a [(String, String)],
+    body: &'a [u8],
+}
+
+/// The `Authorization` header of a request signed with AWS Signature Version 4.
+fn sign_v4(
+    credential: &AwsCredential,
+    request: &SignedRequest,
+    amz_date: &str,
+    region: &str,
+    service: &str,
+) -> String {
+    let mut headers = request.headers.iter().collect::<Vec<_>>();
+    headers.sort_unstable();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_headers = headers
+        .iter()
+        .fold(String::new(), |mut out, (name, value)| {
+            writeln!(out, "{name}:{}", value.trim()).unwrap();
+            out
+        });
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        request.path,
+        request.query,
+        canonical_headers,
+        signed_headers,
+        hex(digest::digest(&digest::SHA256, request.body).as_ref()),
+    );
+
+    let date = &amz_date[..8];
+    let scope = format!("{date}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex(digest::digest(&digest::SHA256, canonical_request.as_bytes()).as_ref())
+    );
+
+    let sign = |key: &[u8], data: &str| {
+        hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), data.as_bytes())
+    };
+    let key = sign(format!("AWS4{}", credential.secret_key).as_bytes(), date);
+    let key = sign(key.as_ref(), region);
+    let key = sign(key.as_ref(), service);
+    let key = sign(key.as_ref(), "aws4_request");
+    let signature = sign(key.as_ref(), &string_to_sign);
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={}",
+        credential.key_id,
+        hex(signature.as_ref())
+    )
+}
+
+/// Format seconds since the UNIX epoch as `YYYYMMDDTHHMMSSZ`.
+fn amz_date(secs: u64) -> String {
+    chrono::DateTime::from_timestamp(secs as i64, 0)
+        .unwrap()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut out, b| {
+        write!(out, "{b:02x}").unwrap();
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(config: &str, credentials: &str, name: &str) -> PolarsResult<ProfileSource> {
+        let env = ChainEnv::new(&[("AWS_REGION", "ap-south-1")], Path::new("/home"));
+        SharedConfig::parse(config, credentials).resolve(name, &mut PlHashSet::default(), &env)
+    }
+
+    fn static_source(key_id: &str) -> ProfileSource {
+        ProfileSource::Static(AwsCredential {
+            key_id: key_id.into(),
+            secret_key: "secret".into(),
+            token: None,
+        })
+    }
+
+    #[test]
+    fn test_resolve_profiles() {
+        let config = "
+[default]
+region = eu-west-1
+s3 =
+  max_concurrent_requests = 20
+
+# A comment
+[profile dev]
+role_arn = arn:aws:iam::123:role/dev
+source_profile = base
+external_id = abc
+
+[profile base]
+aws_access_key_id = ignored
+
+[profile sso]
+sso_session = corp
+sso_account_id = 123
+sso_role_name = reader
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+sso_region = us-west-2
+
+[profile cycle]
+role_arn = arn:aws:iam::123:role/cycle
+source_profile = cycle2
+
+[profile cycle2]
+role_arn = arn:aws:iam::123:role/cycle2
+source_profile = cycle
+";
+        let credentials = "
+[default]
+aws_access_key_id = default-key
+aws_secret_access_key = secret
+
+[base]
+aws_access_key_id = base-key
+aws_secret_access_key = secret
+";
+        assert_eq!(
+            resolve(config, credentials, "default").unwrap(),
+            static_source("default-key")
+        );
+        assert_eq!(
+            resolve(config, credentials, "dev").unwrap(),
+            ProfileSource::AssumeRole {
+                role_arn: "arn:aws:iam::123:role/dev".into(),
+                session_name: None,
+                external_id: Some("abc".into()),
+                duration_seconds: None,
+                region: "ap-south-1".into(),
+                source: Box::new(static_source("base-key")),
+            }
+        );
+        assert_eq!(
+            resolve(config, credentials, "sso").unwrap(),
+            ProfileSource::Sso {
+                session: Some("corp".into()),
+                start_url: "https://corp.awsapps.com/start".into(),
+                region: "us-west-2".into(),
+                account_id: "123".into(),
+                role_name: "reader".into(),
+            }
+        );
+        assert!(resolve(config, credentials, "cycle").is_err());
+        assert!(resolve(config, credentials, "missing").is_err());
+
+        let config = SharedConfig::parse(config, credentials);
+        assert_eq!(config.profiles["default"]["region"], "eu-west-1");
+        assert!(!config.profiles["default"].contains_key("max_concurrent_requests"));
+    }
+
+    #[test]
+    fn test_sign_v4() {
+        // The `get-vanilla` case of the AWS Signature Version 4 test suite.
+        let credential = AwsCredential {
+            key_id: "AKIDEXAMPLE".into(),
+            secret_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".into(),
+            token: None,
+        };
+        let headers = [
+            ("host".to_string(), "example.amazonaws.com".to_string()),
+            ("x-amz-date".to_string(), "20150830T123600Z".to_string()),
+        ];
+        let request = SignedRequest {
+            method: "GET",
+            path: "/",
+            query: "",
+            headers: &headers,
+            body: b"",
+        };
+        assert_eq!(amz_date(1440938160), "20150830T123600Z");
+        assert_eq!(
+            sign_v4(
+                &credential,
+                &request,
+                "20150830T123600Z",
+                "us-east-1",
+                "service"
+            ),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+}
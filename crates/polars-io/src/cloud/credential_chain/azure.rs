@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+
+use polars_error::{polars_err, to_compute_err, PolarsResult};
+use serde::Deserialize;
+
+use super::{now_secs, run_shell_command, send, ChainEnv, HTTP_CLIENT};
+
+const STORAGE_RESOURCE: &str = "https://storage.azure.com";
+
+/// Lifetime that is assumed for tokens of Azure CLI versions that do not report the expiry time
+/// in UTC.
+const DEFAULT_TOKEN_LIFETIME: u64 = 5 * 60;
+
+/// Whether an account is logged in to the Azure CLI, which stores its profile in
+/// `AZURE_CONFIG_DIR` or `~/.azure`.
+pub(crate) fn cli_logged_in(env: &ChainEnv) -> bool {
+    let config_dir = match env.var_os("AZURE_CONFIG_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => env.resolve_homedir(&Path::new("~/.azure")),
+    };
+    config_dir.join("azureProfile.json").exists()
+}
+
+/// Get a storage access token from the Azure CLI.
+pub(super) async fn cli_token(env: &ChainEnv) -> PolarsResult<(String, u64)> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Token {
+        access_token: String,
+        /// Seconds since the UNIX epoch, added in Azure CLI 2.54.
+        #[serde(rename = "expires_on")]
+        expires_on: Option<u64>,
+    }
+
+    let output = run_shell_command(
+        &format!("az account get-access-token --output json --resource {STORAGE_RESOURCE}"),
+        env,
+    )
+    .await
+    .map_err(|e| polars_err!(ComputeError: "failed to get a token from the Azure CLI: {}", e))?;
+    let token: Token = serde_json::from_slice(&output).map_err(to_compute_err)?;
+    let expiry = token
+        .expires_on
+        .unwrap_or_else(|| now_secs() + DEFAULT_TOKEN_LIFETIME);
+    Ok((token.access_token, expiry))
+}
+
+/// Get a storage access token for the managed identity of the environment.
+pub(super) async fn managed_identity_token(
+    client_id: Option<&str>,
+    env: &ChainEnv,
+) -> PolarsResult<(String, u64)> {
+    #[derive(Deserialize)]
+    struct Token {
+        access_token: String,
+        /// Seconds since the UNIX epoch, as a string or a number.
+        expires_on: serde_json::Value,
+    }
+
+    let client_id = client_id
+        .map(String::from)
+        .or_else(|| env.var("AZURE_CLIENT_ID"));
+    let mut query = vec![("resource", STORAGE_RESOURCE)];
+    if let Some(client_id) = &client_id {
+        query.push(("client_id", client_id));
+    }
+
+    // App Service and Functions expose their own endpoint, other resources use the instance
+    // metadata service.
+    let request = match (env.var("IDENTITY_ENDPOINT"), env.var("IDENTITY_HEADER")) {
+        (Some(endpoint), Some(header)) => {
+            query.push(("api-version", "2019-08-01"));
+            HTTP_CLIENT
+                .get(endpoint)
+                .header("X-IDENTITY-HEADER", header)
+        },
+        _ => {
+            query.push(("api-version", "2018-02-01"));
+            HTTP_CLIENT
+                .get("http://169.254.169.254/metadata/identity/oauth2/token")
+                .header("Metadata", "true")
+        },
+    };
+    let response = send(request.query(&query))
+        .await
+        .map_err(|e| polars_err!(ComputeError: "failed to get a managed identity token: {}", e))?;
+    let token: Token = serde_json::from_slice(&response).map_err(to_compute_err)?;
+    let expiry = match &token.expires_on {
+        serde_json::Value::Number(v) => v.as_u64(),
+        serde_json::Value::String(v) => v.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(
+        || polars_err!(ComputeError: "invalid managed identity token expiry {}", token.expires_on),
+    )?;
+    Ok((token.access_token, expiry))
+}
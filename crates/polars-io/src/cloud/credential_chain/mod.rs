@@ -0,0 +1,829 @@
+//! Credential providers that are implemented natively, so that Rust users do not need to supply
+//! a credential function.
+//!
+//! Every provider is wrapped in the same expiry-based cache as the function providers, so
+//! credentials are refreshed shortly before they expire.
+//!
+//! If no credentials are configured otherwise, [`CloudOptions`](super::CloudOptions) uses AWS
+//! profiles that need more than static keys, the account that is logged in to the Azure CLI, and
+//! the GCP application default credentials that are written by `gcloud`. Managed identities and
+//! the GCP metadata server are already used by `object_store` in that case.
+//!
+//! This changes which credentials are used in environments that previously fell back to
+//! anonymous access or to the credentials that `object_store` finds. Set
+//! `POLARS_NO_CREDENTIAL_CHAIN` to disable it.
+
+#[cfg(feature = "aws")]
+pub(super) mod aws;
+#[cfg(feature = "azure")]
+pub(super) mod azure;
+#[cfg(feature = "gcp")]
+pub(super) mod gcp;
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use polars_error::{polars_bail, polars_err, to_compute_err, PolarsResult};
+use polars_utils::aliases::PlHashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::credential_provider::{FetchedCredentialsCache, IntoCredentialProvider};
+use crate::path_utils::resolve_homedir;
+
+/// A natively implemented source of credentials.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CredentialChain {
+    /// A profile of the AWS shared config and credentials files, which are read from
+    /// `AWS_CONFIG_FILE` and `AWS_SHARED_CREDENTIALS_FILE` or `~/.aws`. If no profile is given,
+    /// `AWS_PROFILE` or the `default` profile is used.
+    ///
+    /// Profiles can use static keys, `credential_process`, IAM Identity Center (SSO) sessions
+    /// from `aws sso login`, `web_identity_token_file`, and `role_arn` with `source_profile` or
+    /// `credential_source = Environment`. Roles can be chained through source profiles.
+    #[cfg(feature = "aws")]
+    AwsProfile(Option<String>),
+    /// Assume a role with a web identity token file, as used by EKS service accounts. Fields
+    /// that are not given are read from `AWS_ROLE_ARN`, `AWS_WEB_IDENTITY_TOKEN_FILE` and
+    /// `AWS_ROLE_SESSION_NAME`.
+    #[cfg(feature = "aws")]
+    AwsWebIdentity {
+        role_arn: Option<String>,
+        token_file: Option<PathBuf>,
+        session_name: Option<String>,
+    },
+    /// The account that is logged in to the Azure CLI with `az login`.
+    #[cfg(feature = "azure")]
+    AzureCli,
+    /// The managed identity of an Azure VM, App Service or Function. Set `client_id` to use a
+    /// user-assigned identity.
+    #[cfg(feature = "azure")]
+    AzureManagedIdentity { client_id: Option<String> },
+    /// GCP application default credentials. These are read from the file at
+    /// `GOOGLE_APPLICATION_CREDENTIALS` or from `gcloud auth application-default login`, and
+    /// otherwise from the metadata server of the instance.
+    #[cfg(feature = "gcp")]
+    GcpApplicationDefault,
+}
+
+/// The environment variables and home directory that the credential chains read their
+/// configuration from.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ChainEnv {
+    /// The variables that replace the environment of the process.
+    vars: Option<Arc<PlHashMap<String, String>>>,
+    /// The directory that replaces the home directory of the user.
+    home: Option<PathBuf>,
+}
+
+impl ChainEnv {
+    /// The environment of the process and the home directory of the user.
+    pub(crate) fn process() -> Self {
+        Self::default()
+    }
+
+    /// An environment that only consists of `vars`, with `home` as the home directory.
+    #[cfg(test)]
+    pub(crate) fn new(vars: &[(&str, &str)], home: &Path) -> Self {
+        Self {
+            vars: Some(Arc::new(
+                vars.iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            )),
+            home: Some(home.into()),
+        }
+    }
+
+    pub(crate) fn var_os(&self, name: &str) -> Option<OsString> {
+        match &self.vars {
+            Some(vars) => vars.get(name).map(OsString::from),
+            None => std::env::var_os(name),
+        }
+    }
+
+    pub(crate) fn var(&self, name: &str) -> Option<String> {
+        self.var_os(name)?.into_string().ok()
+    }
+
+    /// Whether [`CloudOptions`](super::CloudOptions) may use a credential chain if no
+    /// credentials are configured, which is disabled by `POLARS_NO_CREDENTIAL_CHAIN`.
+    pub(crate) fn default_chain_enabled(&self) -> bool {
+        self.var_os("POLARS_NO_CREDENTIAL_CHAIN").is_none()
+    }
+
+    /// Replace a leading `~` of `path` with the home directory.
+    pub(crate) fn resolve_homedir(&self, path: &dyn AsRef<Path>) -> PathBuf {
+        let path = path.as_ref();
+        match (&self.home, path.strip_prefix("~")) {
+            (Some(home), Ok(rest)) => home.join(rest),
+            _ => resolve_homedir(&path),
+        }
+    }
+}
+
+impl CredentialChain {
+    #[cfg(feature = "aws")]
+    async fn fetch_aws(
+        &self,
+        env: &ChainEnv,
+    ) -> PolarsResult<(Arc<object_store::aws::AwsCredential>, u64)> {
+        #[allow(unreachable_patterns)]
+        let (credential, expiry) = match self {
+            Self::AwsProfile(profile) => aws::profile_credential(profile.as_deref(), env).await?,
+            Self::AwsWebIdentity {
+                role_arn,
+                token_file,
+                session_name,
+            } => {
+                let web_identity = aws::WebIdentity::from_env(
+                    role_arn.clone(),
+                    token_file.clone(),
+                    session_name.clone(),
+                    env,
+                )?;
+                web_identity.credential(&aws::env_region(env), env).await?
+            },
+            _ => return Err(self.err_cloud_mismatch("aws")),
+        };
+        Ok((Arc::new(credential), expiry))
+    }
+
+    #[cfg(feature = "azure")]
+    async fn fetch_azure(
+        &self,
+        env: &ChainEnv,
+    ) -> PolarsResult<(Arc<object_store::azure::AzureCredential>, u64)> {
+        #[allow(unreachable_patterns)]
+        let (token, expiry) = match self {
+            Self::AzureCli => azure::cli_token(env).await?,
+            Self::AzureManagedIdentity { client_id } => {
+                azure::managed_identity_token(client_id.as_deref(), env).await?
+            },
+            _ => return Err(self.err_cloud_mismatch("azure")),
+        };
+        Ok((
+            Arc::new(object_store::azure::AzureCredential::BearerToken(token)),
+            expiry,
+        ))
+    }
+
+    #[cfg(feature = "gcp")]
+    async fn fetch_gcp(
+        &self,
+        env: &ChainEnv,
+    ) -> PolarsResult<(Arc<object_store::gcp::GcpCredential>, u64)> {
+        #[allow(unreachable_patterns)]
+        let (bearer, expiry) = match self {
+            Self::GcpApplicationDefault => gcp::application_default_token(env).await?,
+            _ => return Err(self.err_cloud_mismatch("gcp")),
+        };
+        Ok((
+            Arc::new(object_store::gcp::GcpCredential { bearer }),
+            expiry,
+        ))
+    }
+
+    #[allow(dead_code)]
+    fn err_cloud_mismatch(&self, cloud: &str) -> polars_error::PolarsError {
+        polars_err!(
+            ComputeError: "credential chain {:?} cannot provide credentials for {}", self, cloud
+        )
+    }
+}
+
+fn to_object_store_err(
+    store: &'static str,
+) -> impl Fn(polars_error::PolarsError) -> object_store::Error {
+    move |e| object_store::Error::Generic {
+        store,
+        source: Box::new(e),
+    }
+}
+
+impl CredentialChain {
+    /// The provider of this chain, which reads its configuration from `env`.
+    #[cfg(feature = "aws")]
+    pub(crate) fn into_aws_provider_with_env(
+        self,
+        env: ChainEnv,
+    ) -> object_store::aws::AwsCredentialProvider {
+        #[derive(Debug)]
+        struct S(
+            CredentialChain,
+            ChainEnv,
+            FetchedCredentialsCache<Arc<object_store::aws::AwsCredential>>,
+        );
+
+        #[async_trait]
+        impl object_store::CredentialProvider for S {
+            type Credential = object_store::aws::AwsCredential;
+
+            async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+                self.2
+                    .get_maybe_update(self.0.fetch_aws(&self.1))
+                    .await
+                    .map_err(to_object_store_err("credential-chain-aws"))
+            }
+        }
+
+        Arc::new(S(
+            self,
+            env,
+            FetchedCredentialsCache::new(Arc::new(object_store::aws::AwsCredential {
+                key_id: String::new(),
+                secret_key: String::new(),
+                token: None,
+            })),
+        ))
+    }
+
+    /// The provider of this chain, which reads its configuration from `env`.
+    #[cfg(feature = "azure")]
+    pub(crate) fn into_azure_provider_with_env(
+        self,
+        env: ChainEnv,
+    ) -> object_store::azure::AzureCredentialProvider {
+        #[derive(Debug)]
+        struct S(
+            CredentialChain,
+            ChainEnv,
+            FetchedCredentialsCache<Arc<object_store::azure::AzureCredential>>,
+        );
+
+        #[async_trait]
+        impl object_store::CredentialProvider for S {
+            type Credential = object_store::azure::AzureCredential;
+
+            async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+                self.2
+                    .get_maybe_update(self.0.fetch_azure(&self.1))
+                    .await
+                    .map_err(to_object_store_err("credential-chain-azure"))
+            }
+        }
+
+        Arc::new(S(
+            self,
+            env,
+            FetchedCredentialsCache::new(Arc::new(
+                object_store::azure::AzureCredential::BearerToken(String::new()),
+            )),
+        ))
+    }
+
+    /// The provider of this chain, which reads its configuration from `env`.
+    #[cfg(feature = "gcp")]
+    pub(crate) fn into_gcp_provider_with_env(
+        self,
+        env: ChainEnv,
+    ) -> object_store::gcp::GcpCredentialProvider {
+        #[derive(Debug)]
+        struct S(
+            CredentialChain,
+            ChainEnv,
+            FetchedCredentialsCache<Arc<object_store::gcp::GcpCredential>>,
+        );
+
+        #[async_trait]
+        impl object_store::CredentialProvider for S {
+            type Credential = object_store::gcp::GcpCredential;
+
+            async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+                self.2
+                    .get_maybe_update(self.0.fetch_gcp(&self.1))
+                    .await
+                    .map_err(to_object_store_err("credential-chain-gcp"))
+            }
+        }
+
+        Arc::new(S(
+            self,
+            env,
+            FetchedCredentialsCache::new(Arc::new(object_store::gcp::GcpCredential {
+                bearer: String::new(),
+            })),
+        ))
+    }
+}
+
+impl IntoCredentialProvider for CredentialChain {
+    #[cfg(feature = "aws")]
+    fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider {
+        self.into_aws_provider_with_env(ChainEnv::process())
+    }
+
+    #[cfg(feature = "azure")]
+    fn into_azure_provider(self) -> object_store::azure::AzureCredentialProvider {
+        self.into_azure_provider_with_env(ChainEnv::process())
+    }
+
+    #[cfg(feature = "gcp")]
+    fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider {
+        self.into_gcp_provider_with_env(ChainEnv::process())
+    }
+}
+
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap()
+});
+
+/// Send `request` and return the body of a successful response.
+async fn send(request: reqwest::RequestBuilder) -> PolarsResult<bytes::Bytes> {
+    let response = request.send().await.map_err(to_compute_err)?;
+    let status = response.status();
+    let url = response.url().clone();
+    let body = response.bytes().await.map_err(to_compute_err)?;
+    if !status.is_success() {
+        polars_bail!(
+            ComputeError: "credential request to {} failed with status {}: {}",
+            url.as_str().split('?').next().unwrap(), status, String::from_utf8_lossy(&body)
+        )
+    }
+    Ok(body)
+}
+
+/// Run `program` in `env` and return its standard output.
+#[cfg(any(feature = "aws", feature = "azure"))]
+async fn run_command(program: &str, args: &[&str], env: &ChainEnv) -> PolarsResult<Vec<u8>> {
+    let mut command = std::process::Command::new(program);
+    command.args(args);
+    if let Some(vars) = &env.vars {
+        command.env_clear().envs(vars.iter());
+    }
+    let output = tokio::task::spawn_blocking(move || command.output())
+        .await
+        .map_err(to_compute_err)?
+        .map_err(|e| polars_err!(ComputeError: "failed to run `{}`: {}", program, e))?;
+    if !output.status.success() {
+        polars_bail!(
+            ComputeError: "`{}` failed with {}: {}",
+            program, output.status, String::from_utf8_lossy(&output.stderr).trim()
+        )
+    }
+    Ok(output.stdout)
+}
+
+/// Run `command` in `env` with the shell of the platform.
+#[cfg(any(feature = "aws", feature = "azure"))]
+async fn run_shell_command(command: &str, env: &ChainEnv) -> PolarsResult<Vec<u8>> {
+    if cfg!(windows) {
+        run_command("cmd", &["/C", command], env).await
+    } else {
+        run_command("sh", &["-c", command], env).await
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(feature = "aws")]
+fn parse_rfc3339(timestamp: &str) -> PolarsResult<u64> {
+    let t = chrono::DateTime::parse_from_rfc3339(timestamp).map_err(
+        |e| polars_err!(ComputeError: "invalid credential expiry time '{}': {}", timestamp, e),
+    )?;
+    Ok(t.timestamp().max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Mutex;
+
+    use object_store::CredentialProvider;
+
+    use super::*;
+    #[cfg(feature = "azure")]
+    use crate::cloud::AzureConfigKey;
+    use crate::cloud::CloudOptions;
+    use crate::pl_async;
+
+    #[derive(Debug)]
+    struct Request {
+        line: String,
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl Request {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// A token server that serves `body(n, expiry)` for the `n`-th request, where the expiry is
+    /// the current time plus the lifetime that is set by the test.
+    struct TokenServer {
+        url: String,
+        lifetime: Arc<AtomicI64>,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl TokenServer {
+        fn start(body: fn(usize, i64) -> String) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let lifetime = Arc::new(AtomicI64::new(3600));
+            let requests = Arc::new(Mutex::new(Vec::<Request>::new()));
+
+            let (server_lifetime, log) = (lifetime.clone(), requests.clone());
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(&mut stream);
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let mut headers = vec![];
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        match header.trim_end().split_once(':') {
+                            Some((name, value)) => {
+                                headers.push((name.to_string(), value.trim().to_string()))
+                            },
+                            None => break,
+                        }
+                    }
+                    let content_length = headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                        .map_or(0, |(_, value)| value.parse().unwrap());
+                    let mut content = vec![0; content_length];
+                    reader.read_exact(&mut content).unwrap();
+
+                    let mut log = log.lock().unwrap();
+                    log.push(Request {
+                        line: line.trim_end().to_string(),
+                        headers,
+                        body: String::from_utf8(content).unwrap(),
+                    });
+                    let expiry = now_secs() as i64 + server_lifetime.load(Ordering::Relaxed);
+                    let response = body(log.len(), expiry);
+                    drop(log);
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        response.len(),
+                        response
+                    )
+                    .unwrap();
+                }
+            });
+            Self {
+                url,
+                lifetime,
+                requests,
+            }
+        }
+
+        fn set_lifetime(&self, secs: i64) {
+            self.lifetime.store(secs, Ordering::Relaxed);
+        }
+
+        fn num_requests(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    fn get_credential<C: std::fmt::Debug>(
+        provider: &dyn CredentialProvider<Credential = C>,
+    ) -> object_store::Result<Arc<C>> {
+        pl_async::get_runtime().block_on(provider.get_credential())
+    }
+
+    /// Check that the credentials of `provider` are cached until shortly before they expire, and
+    /// that `make_provider` fails for credentials that have already expired.
+    fn check_refresh<C: std::fmt::Debug>(
+        server: &TokenServer,
+        make_provider: impl Fn() -> Arc<dyn CredentialProvider<Credential = C>>,
+        token: impl Fn(&C) -> String,
+        check_expired: bool,
+    ) {
+        let provider = make_provider();
+        server.set_lifetime(3);
+        assert_eq!(token(&get_credential(&*provider).unwrap()), "token-1");
+        // Credentials that expire within the request time buffer are fetched again.
+        assert_eq!(token(&get_credential(&*provider).unwrap()), "token-2");
+        server.set_lifetime(3600);
+        assert_eq!(token(&get_credential(&*provider).unwrap()), "token-3");
+        assert_eq!(token(&get_credential(&*provider).unwrap()), "token-3");
+        assert_eq!(server.num_requests(), 3);
+
+        if check_expired {
+            server.set_lifetime(-60);
+            let err = get_credential(&*make_provider()).unwrap_err();
+            assert!(err.to_string().contains("older than system time"), "{err}");
+            server.set_lifetime(3600);
+        }
+    }
+
+    #[cfg(feature = "aws")]
+    fn sts_response(n: usize, expiry: i64) -> String {
+        let expiration = chrono::DateTime::from_timestamp(expiry, 0)
+            .unwrap()
+            .to_rfc3339();
+        format!(
+            "<AssumeRoleResponse><AssumeRoleResult><Credentials>\
+             <AccessKeyId>token-{n}</AccessKeyId><SecretAccessKey>secret</SecretAccessKey>\
+             <SessionToken>session</SessionToken><Expiration>{expiration}</Expiration>\
+             </Credentials></AssumeRoleResult></AssumeRoleResponse>"
+        )
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_aws_web_identity() {
+        let server = TokenServer::start(sts_response);
+        let dir = tempfile::tempdir().unwrap();
+        let token_file = dir.path().join("token");
+        std::fs::write(&token_file, "web-identity-token\n").unwrap();
+
+        let chain = CredentialChain::AwsWebIdentity {
+            role_arn: Some("arn:aws:iam::123:role/reader".into()),
+            token_file: Some(token_file),
+            session_name: Some("session".into()),
+        };
+        let env = ChainEnv::new(&[("AWS_ENDPOINT_URL_STS", &server.url)], dir.path());
+        check_refresh(
+            &server,
+            || chain.clone().into_aws_provider_with_env(env.clone()),
+            |c| c.key_id.clone(),
+            true,
+        );
+
+        // The chain is used by the object store that `CloudOptions` builds.
+        let store = pl_async::get_runtime()
+            .block_on(
+                CloudOptions::default()
+                    .with_aws([(object_store::aws::AmazonS3ConfigKey::Region, "us-east-1")])
+                    .with_credential_provider(Some(
+                        crate::cloud::credential_provider::PlCredentialProvider::from_chain(
+                            chain.clone(),
+                        ),
+                    ))
+                    .build_aws_with_env("s3://bucket/file.parquet", &env),
+            )
+            .unwrap();
+        let credential = get_credential(&**store.credentials()).unwrap();
+        assert_eq!(credential.key_id, "token-5");
+        assert_eq!(credential.token.as_deref(), Some("session"));
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].line.starts_with("POST / "));
+        assert!(requests[0]
+            .body
+            .contains("Action=AssumeRoleWithWebIdentity"));
+        assert!(requests[0]
+            .body
+            .ends_with("WebIdentityToken=web-identity-token"));
+        assert!(requests[0].header("authorization").is_none());
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_aws_profile() {
+        let server = TokenServer::start(sts_response);
+        let home = tempfile::tempdir().unwrap();
+        std::fs::create_dir(home.path().join(".aws")).unwrap();
+        std::fs::write(
+            home.path().join(".aws/config"),
+            "[profile dev]\nrole_arn = arn:aws:iam::123:role/dev\nsource_profile = base\n\n\
+             [profile base]\naws_access_key_id = base-key\naws_secret_access_key = secret\n",
+        )
+        .unwrap();
+
+        let env = ChainEnv::new(
+            &[
+                ("AWS_ENDPOINT_URL_STS", &server.url),
+                ("AWS_PROFILE", "dev"),
+                ("AWS_REGION", "us-east-1"),
+            ],
+            home.path(),
+        );
+        check_refresh(
+            &server,
+            || CredentialChain::AwsProfile(None).into_aws_provider_with_env(env.clone()),
+            |c| c.key_id.clone(),
+            true,
+        );
+
+        // Profiles that assume a role are resolved natively without a credential provider.
+        let store = pl_async::get_runtime()
+            .block_on(
+                CloudOptions::default()
+                    .with_aws([(object_store::aws::AmazonS3ConfigKey::Region, "us-east-1")])
+                    .build_aws_with_env("s3://bucket/file.parquet", &env),
+            )
+            .unwrap();
+        let credential = get_credential(&**store.credentials()).unwrap();
+        assert_eq!(credential.key_id, "token-5");
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].body.contains("Action=AssumeRole&"));
+        assert!(requests[0]
+            .header("authorization")
+            .unwrap()
+            .starts_with("AWS4-HMAC-SHA256 Credential=base-key/"));
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn test_azure_managed_identity() {
+        let server = TokenServer::start(|n, expiry| {
+            format!(r#"{{"access_token": "token-{n}", "expires_on": "{expiry}"}}"#)
+        });
+        let token = |c: &object_store::azure::AzureCredential| match c {
+            object_store::azure::AzureCredential::BearerToken(token) => token.clone(),
+            _ => panic!("unexpected credential {c:?}"),
+        };
+        let chain = CredentialChain::AzureManagedIdentity {
+            client_id: Some("client".into()),
+        };
+
+        let home = tempfile::tempdir().unwrap();
+        let env = ChainEnv::new(
+            &[
+                ("IDENTITY_ENDPOINT", &server.url),
+                ("IDENTITY_HEADER", "secret"),
+            ],
+            home.path(),
+        );
+        check_refresh(
+            &server,
+            || chain.clone().into_azure_provider_with_env(env.clone()),
+            token,
+            true,
+        );
+
+        let store = CloudOptions::default()
+            .with_azure([(AzureConfigKey::AccountName, "account")])
+            .with_credential_provider(Some(
+                crate::cloud::credential_provider::PlCredentialProvider::from_chain(chain.clone()),
+            ))
+            .build_azure_with_env("az://container/file.parquet", &env)
+            .unwrap();
+        assert_eq!(
+            token(&get_credential(&**store.credentials()).unwrap()),
+            "token-5"
+        );
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].line.contains("client_id=client"));
+        assert!(requests[0].line.contains("api-version=2019-08-01"));
+        assert_eq!(requests[0].header("x-identity-header"), Some("secret"));
+    }
+
+    #[cfg(all(feature = "azure", unix))]
+    #[test]
+    fn test_azure_cli() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let home = tempfile::tempdir().unwrap();
+        std::fs::create_dir(home.path().join(".azure")).unwrap();
+        std::fs::write(home.path().join(".azure/azureProfile.json"), "{}").unwrap();
+        let az = home.path().join("az");
+        std::fs::write(
+            &az,
+            "#!/bin/sh\necho >> \"$(dirname \"$0\")/runs\"\n\
+             printf '{\"accessToken\": \"cli-token\", \"expires_on\": %s}' \
+             $(( $(date +%s) + 3600 ))\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&az, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let path = format!(
+            "{}:{}",
+            home.path().display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+        let env = ChainEnv::new(&[("PATH", &path)], home.path());
+
+        // The Azure CLI is used if no other credentials are configured.
+        let store = CloudOptions::default()
+            .with_azure([(AzureConfigKey::AccountName, "account")])
+            .build_azure_with_env("az://container/file.parquet", &env)
+            .unwrap();
+        for _ in 0..2 {
+            assert!(matches!(
+                &*get_credential(&**store.credentials()).unwrap(),
+                object_store::azure::AzureCredential::BearerToken(token) if token == "cli-token"
+            ));
+        }
+        let runs = std::fs::read_to_string(home.path().join("runs")).unwrap();
+        assert_eq!(runs.lines().count(), 1);
+        assert!(!format!("{store:?}").contains("ImdsManagedIdentityProvider"));
+
+        // `object_store` falls back to the managed identity if the default credential chains are
+        // disabled.
+        let opt_out = ChainEnv::new(
+            &[("PATH", &path), ("POLARS_NO_CREDENTIAL_CHAIN", "1")],
+            home.path(),
+        );
+        let store = CloudOptions::default()
+            .with_azure([(AzureConfigKey::AccountName, "account")])
+            .build_azure_with_env("az://container/file.parquet", &opt_out)
+            .unwrap();
+        assert!(format!("{store:?}").contains("ImdsManagedIdentityProvider"));
+
+        let store = CloudOptions::default()
+            .with_azure([
+                (AzureConfigKey::AccountName, "account"),
+                (AzureConfigKey::AccessKey, "a2V5"),
+            ])
+            .build_azure_with_env("az://container/file.parquet", &env)
+            .unwrap();
+        assert!(matches!(
+            &*get_credential(&**store.credentials()).unwrap(),
+            object_store::azure::AzureCredential::AccessKey(_)
+        ));
+    }
+
+    #[cfg(feature = "gcp")]
+    #[test]
+    fn test_gcp_application_default() {
+        let server = TokenServer::start(|n, expiry| {
+            format!(
+                r#"{{"access_token": "token-{n}", "expires_in": {}}}"#,
+                (expiry - now_secs() as i64).max(0)
+            )
+        });
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("application_default_credentials.json"),
+            format!(
+                r#"{{"type": "authorized_user", "client_id": "id", "client_secret": "secret",
+                "refresh_token": "refresh", "token_uri": "{}/token"}}"#,
+                server.url
+            ),
+        )
+        .unwrap();
+
+        let env = ChainEnv::new(
+            &[("CLOUDSDK_CONFIG", dir.path().to_str().unwrap())],
+            dir.path(),
+        );
+        // Tokens cannot be issued with an expiry in the past.
+        check_refresh(
+            &server,
+            || CredentialChain::GcpApplicationDefault.into_gcp_provider_with_env(env.clone()),
+            |c| c.bearer.clone(),
+            false,
+        );
+
+        // The credentials of `gcloud` are used if no other credentials are configured.
+        let store = CloudOptions::default()
+            .build_gcp_with_env("gs://bucket/file.parquet", &env)
+            .unwrap();
+        let credential = get_credential(&**store.credentials()).unwrap();
+        assert_eq!(credential.bearer, "token-4");
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0].line.starts_with("POST /token "));
+        assert!(requests[0].body.contains("grant_type=refresh_token"));
+        assert!(requests[0].body.contains("refresh_token=refresh"));
+    }
+
+    #[cfg(feature = "gcp")]
+    #[test]
+    fn test_gcp_metadata() {
+        let server = TokenServer::start(|n, expiry| {
+            format!(
+                r#"{{"access_token": "token-{n}", "expires_in": {}}}"#,
+                (expiry - now_secs() as i64).max(0)
+            )
+        });
+        let dir = tempfile::tempdir().unwrap();
+        let host = server.url.trim_start_matches("http://").to_string();
+
+        // The home directory does not contain credentials of `gcloud`.
+        let env = ChainEnv::new(&[("GCE_METADATA_HOST", &host)], dir.path());
+        check_refresh(
+            &server,
+            || CredentialChain::GcpApplicationDefault.into_gcp_provider_with_env(env.clone()),
+            |c| c.bearer.clone(),
+            false,
+        );
+
+        let requests = server.requests.lock().unwrap();
+        assert!(requests[0]
+            .line
+            .starts_with("GET /computeMetadata/v1/instance/service-accounts/default/token "));
+        assert_eq!(requests[0].header("metadata-flavor"), Some("Google"));
+    }
+}
@@ -19,10 +19,16 @@ use polars_utils::python_function::PythonObject;
 #[cfg(feature = "python")]
 use python_impl::PythonCredentialProvider;
 
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+use super::credential_chain::CredentialChain;
+
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
 pub enum PlCredentialProvider {
     /// Prefer using [`PlCredentialProvider::from_func`] instead of constructing this directly
     Function(CredentialProviderFunction),
+    /// Prefer using [`PlCredentialProvider::from_chain`] instead of constructing this directly
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    Chain(CredentialChain),
     #[cfg(feature = "python")]
     Python(python_impl::PythonCredentialProvider),
 }
@@ -43,6 +49,13 @@ impl PlCredentialProvider {
         Self::Function(CredentialProviderFunction(Arc::new(func)))
     }
 
+    /// Source credentials with a natively implemented [`CredentialChain`], such as an AWS profile
+    /// or the GCP application default credentials.
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    pub fn from_chain(chain: CredentialChain) -> Self {
+        Self::Chain(chain)
+    }
+
     /// Intended to be called with an internal `CredentialProviderBuilder` from
     /// py-polars.
     #[cfg(feature = "python")]
@@ -55,6 +68,15 @@ impl PlCredentialProvider {
     pub(super) fn func_addr(&self) -> usize {
         match self {
             Self::Function(CredentialProviderFunction(v)) => Arc::as_ptr(v) as *const () as usize,
+            // Chains are compared by value, so equal chains share their object stores.
+            #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+            Self::Chain(v) => {
+                use std::hash::Hasher;
+
+                let mut hasher = std::hash::DefaultHasher::new();
+                v.hash(&mut hasher);
+                hasher.finish() as usize
+            },
             #[cfg(feature = "python")]
             Self::Python(v) => v.func_addr(),
         }
@@ -67,6 +89,8 @@ impl PlCredentialProvider {
     pub(crate) fn try_into_initialized(self) -> PolarsResult<Option<Self>> {
         match self {
             Self::Function(_) => Ok(Some(self)),
+            #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+            Self::Chain(_) => Ok(Some(self)),
             #[cfg(feature = "python")]
             Self::Python(v) => Ok(v.try_into_initialized()?.map(Self::Python)),
         }
@@ -155,6 +179,7 @@ impl IntoCredentialProvider for PlCredentialProvider {
     fn into_aws_provider(self) -> object_store::aws::AwsCredentialProvider {
         match self {
             Self::Function(v) => v.into_aws_provider(),
+            Self::Chain(v) => v.into_aws_provider(),
             #[cfg(feature = "python")]
             Self::Python(v) => v.into_aws_provider(),
         }
@@ -164,6 +189,7 @@ impl IntoCredentialProvider for PlCredentialProvider {
     fn into_azure_provider(self) -> object_store::azure::AzureCredentialProvider {
         match self {
             Self::Function(v) => v.into_azure_provider(),
+            Self::Chain(v) => v.into_azure_provider(),
             #[cfg(feature = "python")]
             Self::Python(v) => v.into_azure_provider(),
         }
@@ -173,6 +199,7 @@ impl IntoCredentialProvider for PlCredentialProvider {
     fn into_gcp_provider(self) -> object_store::gcp::GcpCredentialProvider {
         match self {
             Self::Function(v) => v.into_gcp_provider(),
+            Self::Chain(v) => v.into_gcp_provider(),
             #[cfg(feature = "python")]
             Self::Python(v) => v.into_gcp_provider(),
         }
@@ -324,22 +351,46 @@ impl Hash for CredentialProviderFunction {
     }
 }
 
+/// The credential providers that can be serialized. Function providers cannot be serialized.
+///
+/// Python providers are encoded as the variants of [`PythonCredentialProvider`], as they were
+/// before chains were added, so that existing serialized plans can still be read.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+enum SerializableCredentialProvider {
+    #[cfg(feature = "python")]
+    #[serde(
+        serialize_with = "PythonObject::serialize_with_pyversion",
+        deserialize_with = "PythonObject::deserialize_with_pyversion"
+    )]
+    Builder(Arc<PythonObject>),
+    #[cfg(feature = "python")]
+    #[serde(
+        serialize_with = "PythonObject::serialize_with_pyversion",
+        deserialize_with = "PythonObject::deserialize_with_pyversion"
+    )]
+    Provider(Arc<PythonObject>),
+    #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+    Chain(CredentialChain),
+}
+
 #[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for PlCredentialProvider {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        #[cfg(feature = "python")]
-        {
-            Ok(Self::Python(PythonCredentialProvider::deserialize(
-                deserializer,
-            )?))
-        }
-        #[cfg(not(feature = "python"))]
-        {
-            use serde::de::Error;
-            Err(D::Error::custom("cannot deserialize PlCredentialProvider"))
+        match SerializableCredentialProvider::deserialize(deserializer)? {
+            #[cfg(feature = "python")]
+            SerializableCredentialProvider::Builder(v) => {
+                Ok(Self::Python(PythonCredentialProvider::Builder(v)))
+            },
+            #[cfg(feature = "python")]
+            SerializableCredentialProvider::Provider(v) => {
+                Ok(Self::Python(PythonCredentialProvider::Provider(v)))
+            },
+            #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+            SerializableCredentialProvider::Chain(v) => Ok(Self::Chain(v)),
         }
     }
 }
@@ -352,25 +403,35 @@ impl serde::Serialize for PlCredentialProvider {
     {
         use serde::ser::Error;
 
-        #[cfg(feature = "python")]
-        if let PlCredentialProvider::Python(v) = self {
-            return v.serialize(serializer);
-        }
-
-        Err(S::Error::custom(format!("cannot serialize {:?}", self)))
+        let v: SerializableCredentialProvider = match self {
+            Self::Function(_) => {
+                return Err(S::Error::custom(format!("cannot serialize {:?}", self)))
+            },
+            #[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+            Self::Chain(v) => SerializableCredentialProvider::Chain(v.clone()),
+            #[cfg(feature = "python")]
+            Self::Python(PythonCredentialProvider::Builder(v)) => {
+                SerializableCredentialProvider::Builder(v.clone())
+            },
+            #[cfg(feature = "python")]
+            Self::Python(PythonCredentialProvider::Provider(v)) => {
+                SerializableCredentialProvider::Provider(v.clone())
+            },
+        };
+        v.serialize(serializer)
     }
 }
 
 /// Avoids calling the credential provider function if we have not yet passed the expiry time.
 #[derive(Debug)]
-struct FetchedCredentialsCache<C>(tokio::sync::Mutex<(C, u64)>);
+pub(super) struct FetchedCredentialsCache<C>(tokio::sync::Mutex<(C, u64)>);
 
 impl<C: Clone> FetchedCredentialsCache<C> {
-    fn new(init_creds: C) -> Self {
+    pub(super) fn new(init_creds: C) -> Self {
         Self(tokio::sync::Mutex::new((init_creds, 0)))
     }
 
-    async fn get_maybe_update(
+    pub(super) async fn get_maybe_update(
         &self,
         // Taking an `impl Future` here allows us to potentially avoid a `Box::pin` allocation from
         // a `Fn() -> Pin<Box<dyn Future>>` by having it wrapped in an `async { f() }` block. We
@@ -794,5 +855,17 @@ mod tests {
             ),
             Ok(None)
         ));
+
+        #[cfg(feature = "aws")]
+        {
+            let provider =
+                PlCredentialProvider::from_chain(CredentialChain::AwsProfile(Some("dev".into())));
+            let json = serde_json::to_string(&provider).unwrap();
+            assert_eq!(json, r#"{"Chain":{"AwsProfile":"dev"}}"#);
+            assert_eq!(
+                serde_json::from_str::<PlCredentialProvider>(&json).unwrap(),
+                provider
+            );
+        }
     }
 }
@@ -213,6 +213,17 @@ impl PolarsObjectStoreBuilder {
     pub(crate) fn is_azure(&self) -> bool {
         matches!(&self.cloud_type, CloudType::Azure)
     }
+
+    /// The prefix of the keys of the objects of this store in the byte-range cache, `None` for
+    /// local files.
+    pub(crate) fn range_cache_prefix(&self) -> Option<&str> {
+        match &self.cloud_type {
+            CloudType::File => None,
+            // HTTP stores are built for the full URL.
+            CloudType::Http => Some(&self.url),
+            _ => Some(&self.parsed_url[url::Position::BeforeScheme..url::Position::AfterPort]),
+        }
+    }
 }
 
 /// Build an [`ObjectStore`] based on the URL and passed in url. Return the cloud location and an implementation of the object store.
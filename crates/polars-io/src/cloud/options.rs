@@ -33,6 +33,14 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "cloud")]
 use url::Url;
 
+#[cfg(feature = "aws")]
+use super::credential_chain::aws::SharedConfig;
+#[cfg(feature = "azure")]
+use super::credential_chain::azure;
+#[cfg(feature = "gcp")]
+use super::credential_chain::gcp;
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+use super::credential_chain::{ChainEnv, CredentialChain};
 #[cfg(feature = "cloud")]
 use super::credential_provider::PlCredentialProvider;
 #[cfg(feature = "file_cache")]
@@ -223,6 +231,35 @@ pub(super) fn get_client_options() -> ClientOptions {
         .with_allow_http(true)
 }
 
+/// Whether credentials other than a managed identity are configured for Azure.
+#[cfg(feature = "azure")]
+fn azure_credentials_configured(builder: &MicrosoftAzureBuilder) -> bool {
+    let is_set = |key| builder.get_config_value(&key).is_some();
+    let is_enabled = |key| {
+        builder
+            .get_config_value(&key)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+    };
+    [
+        AzureConfigKey::AccessKey,
+        AzureConfigKey::SasKey,
+        AzureConfigKey::Token,
+        AzureConfigKey::ClientSecret,
+        AzureConfigKey::FederatedTokenFile,
+        AzureConfigKey::MsiEndpoint,
+        AzureConfigKey::FabricSessionToken,
+    ]
+    .into_iter()
+    .any(is_set)
+        || [
+            AzureConfigKey::UseAzureCli,
+            AzureConfigKey::UseEmulator,
+            AzureConfigKey::SkipSignature,
+        ]
+        .into_iter()
+        .any(is_enabled)
+}
+
 #[cfg(feature = "aws")]
 fn read_config(
     builder: &mut AmazonS3Builder,
@@ -285,8 +322,22 @@ impl CloudOptions {
     }
 
     /// Build the [`object_store::ObjectStore`] implementation for AWS.
+    ///
+    /// If no credentials are configured, profiles that use SSO, roles or a credential process
+    /// are resolved natively. Set `POLARS_NO_CREDENTIAL_CHAIN` to disable this.
+    #[cfg(feature = "aws")]
+    pub async fn build_aws(&self, url: &str) -> PolarsResult<object_store::aws::AmazonS3> {
+        self.build_aws_with_env(url, &ChainEnv::process()).await
+    }
+
+    /// Build the AWS object store, with credential chains that read their configuration from
+    /// `env`.
     #[cfg(feature = "aws")]
-    pub async fn build_aws(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub(crate) async fn build_aws_with_env(
+        &self,
+        url: &str,
+        env: &ChainEnv,
+    ) -> PolarsResult<object_store::aws::AmazonS3> {
         use super::credential_provider::IntoCredentialProvider;
 
         let mut builder = AmazonS3Builder::from_env()
@@ -301,26 +352,43 @@ impl CloudOptions {
             )],
         );
 
-        read_config(
-            &mut builder,
-            &[(
-                Path::new("~/.aws/credentials"),
-                &[
-                    (
-                        "aws_access_key_id\\s*=\\s*([^\\r\\n]*)",
-                        AmazonS3ConfigKey::AccessKeyId,
-                    ),
-                    (
-                        "aws_secret_access_key\\s*=\\s*([^\\r\\n]*)",
-                        AmazonS3ConfigKey::SecretAccessKey,
-                    ),
-                    (
-                        "aws_session_token\\s*=\\s*([^\\r\\n]*)",
-                        AmazonS3ConfigKey::Token,
-                    ),
-                ],
-            )],
-        );
+        // Profiles that use SSO, roles or a credential process are resolved natively if no
+        // credentials are configured otherwise.
+        let use_profile_chain = env.default_chain_enabled()
+            && self.credential_provider.is_none()
+            && builder
+                .get_config_value(&AmazonS3ConfigKey::AccessKeyId)
+                .is_none()
+            && !matches!(
+                &self.config,
+                Some(CloudConfig::Aws(options))
+                    if options.iter().any(|(key, _)| *key == AmazonS3ConfigKey::AccessKeyId)
+            )
+            && env.var_os("AWS_WEB_IDENTITY_TOKEN_FILE").is_none()
+            && SharedConfig::load(env).profile_requires_chain(env);
+
+        if !use_profile_chain {
+            read_config(
+                &mut builder,
+                &[(
+                    Path::new("~/.aws/credentials"),
+                    &[
+                        (
+                            "aws_access_key_id\\s*=\\s*([^\\r\\n]*)",
+                            AmazonS3ConfigKey::AccessKeyId,
+                        ),
+                        (
+                            "aws_secret_access_key\\s*=\\s*([^\\r\\n]*)",
+                            AmazonS3ConfigKey::SecretAccessKey,
+                        ),
+                        (
+                            "aws_session_token\\s*=\\s*([^\\r\\n]*)",
+                            AmazonS3ConfigKey::Token,
+                        ),
+                    ],
+                )],
+            );
+        }
 
         if let Some(options) = &self.config {
             let CloudConfig::Aws(options) = options else {
@@ -382,10 +450,20 @@ impl CloudOptions {
 
         let builder = builder.with_retry(get_retry_config(self.max_retries));
 
-        let builder = if let Some(v) = self.initialized_credential_provider()? {
-            builder.with_credentials(v.into_aws_provider())
-        } else {
-            builder
+        let builder = match self.initialized_credential_provider()? {
+            Some(PlCredentialProvider::Chain(chain)) => {
+                builder.with_credentials(chain.into_aws_provider_with_env(env.clone()))
+            },
+            Some(v) => builder.with_credentials(v.into_aws_provider()),
+            None if use_profile_chain => {
+                if polars_core::config::verbose() {
+                    eprintln!("[CloudOptions::build_aws]: Using credentials of the AWS profile");
+                }
+                builder.with_credentials(
+                    CredentialChain::AwsProfile(None).into_aws_provider_with_env(env.clone()),
+                )
+            },
+            None => builder,
         };
 
         builder.build().map_err(to_compute_err)
@@ -404,8 +482,22 @@ impl CloudOptions {
     }
 
     /// Build the [`object_store::ObjectStore`] implementation for Azure.
+    ///
+    /// If no credentials are configured, the account that is logged in to the Azure CLI is
+    /// used. Set `POLARS_NO_CREDENTIAL_CHAIN` to disable this.
+    #[cfg(feature = "azure")]
+    pub fn build_azure(&self, url: &str) -> PolarsResult<object_store::azure::MicrosoftAzure> {
+        self.build_azure_with_env(url, &ChainEnv::process())
+    }
+
+    /// Build the Azure object store, with credential chains that read their configuration from
+    /// `env`.
     #[cfg(feature = "azure")]
-    pub fn build_azure(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub(crate) fn build_azure_with_env(
+        &self,
+        url: &str,
+        env: &ChainEnv,
+    ) -> PolarsResult<object_store::azure::MicrosoftAzure> {
         use super::credential_provider::IntoCredentialProvider;
 
         let verbose = polars_core::config::verbose();
@@ -435,7 +527,23 @@ impl CloudOptions {
                     &v
                 );
             }
-            builder.with_credentials(v.into_azure_provider())
+            match v {
+                PlCredentialProvider::Chain(chain) => {
+                    builder.with_credentials(chain.into_azure_provider_with_env(env.clone()))
+                },
+                v => builder.with_credentials(v.into_azure_provider()),
+            }
+        } else if env.default_chain_enabled()
+            && !azure_credentials_configured(&builder)
+            && azure::cli_logged_in(env)
+        {
+            // `object_store` only uses the Azure CLI if it is enabled explicitly.
+            if verbose {
+                eprintln!("[CloudOptions::build_azure]: Using credentials of the Azure CLI");
+            }
+            builder.with_credentials(
+                CredentialChain::AzureCli.into_azure_provider_with_env(env.clone()),
+            )
         } else {
             builder
         };
@@ -456,8 +564,22 @@ impl CloudOptions {
     }
 
     /// Build the [`object_store::ObjectStore`] implementation for GCP.
+    ///
+    /// If no credentials are configured, the application default credentials of `gcloud` are
+    /// used. Set `POLARS_NO_CREDENTIAL_CHAIN` to disable this.
+    #[cfg(feature = "gcp")]
+    pub fn build_gcp(&self, url: &str) -> PolarsResult<object_store::gcp::GoogleCloudStorage> {
+        self.build_gcp_with_env(url, &ChainEnv::process())
+    }
+
+    /// Build the GCP object store, with credential chains that read their configuration from
+    /// `env`.
     #[cfg(feature = "gcp")]
-    pub fn build_gcp(&self, url: &str) -> PolarsResult<impl object_store::ObjectStore> {
+    pub(crate) fn build_gcp_with_env(
+        &self,
+        url: &str,
+        env: &ChainEnv,
+    ) -> PolarsResult<object_store::gcp::GoogleCloudStorage> {
         use super::credential_provider::IntoCredentialProvider;
 
         let credential_provider = self.initialized_credential_provider()?;
@@ -483,8 +605,30 @@ impl CloudOptions {
             .with_url(url)
             .with_retry(get_retry_config(self.max_retries));
 
-        let builder = if let Some(v) = credential_provider.clone() {
-            builder.with_credentials(v.into_gcp_provider())
+        let builder = if let Some(v) = credential_provider {
+            match v {
+                PlCredentialProvider::Chain(chain) => {
+                    builder.with_credentials(chain.into_gcp_provider_with_env(env.clone()))
+                },
+                v => builder.with_credentials(v.into_gcp_provider()),
+            }
+        } else if env.default_chain_enabled()
+            && [
+                GoogleConfigKey::ServiceAccount,
+                GoogleConfigKey::ServiceAccountKey,
+                GoogleConfigKey::ApplicationCredentials,
+            ]
+            .iter()
+            .all(|key| builder.get_config_value(key).is_none())
+            && gcp::credentials_path(env).is_some()
+        {
+            // The credentials file of `gcloud` is also found in `CLOUDSDK_CONFIG` and on Windows.
+            if polars_core::config::verbose() {
+                eprintln!("[CloudOptions::build_gcp]: Using GCP application default credentials");
+            }
+            builder.with_credentials(
+                CredentialChain::GcpApplicationDefault.into_gcp_provider_with_env(env.clone()),
+            )
         } else {
             builder
         };
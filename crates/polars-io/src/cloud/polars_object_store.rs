@@ -8,6 +8,7 @@ use polars_core::prelude::{InitHashMaps, PlHashMap};
 use polars_error::{to_compute_err, PolarsError, PolarsResult};
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
+use crate::file_cache::{CachedObject, RangeCache, RANGE_CACHE};
 use crate::pl_async::{
     self, get_concurrency_limit, get_download_chunk_size, tune_with_concurrency_budget,
     with_concurrency_budget, MAX_BUDGET_PER_REQUEST,
@@ -52,6 +53,13 @@ mod inner {
     }
 
     impl PolarsObjectStore {
+        /// The key of the object at `path` in the byte-range cache, `None` if the objects of
+        /// this store are not cached.
+        pub(crate) fn range_cache_key(&self, path: &object_store::path::Path) -> Option<String> {
+            let prefix = self.inner.builder.range_cache_prefix()?;
+            Some(format!("{}\0{}", prefix, path))
+        }
+
         pub(crate) fn new_from_inner(
             store: Arc<dyn ObjectStore>,
             builder: PolarsObjectStoreBuilder,
@@ -165,7 +173,49 @@ impl PolarsObjectStore {
         .buffered(get_concurrency_limit() as usize)
     }
 
+    /// The byte-range cache and the object at `path` in it, if the cache is enabled.
+    async fn range_cache_object(
+        &self,
+        path: &Path,
+    ) -> PolarsResult<Option<(&'static RangeCache, CachedObject)>> {
+        let (Some(cache), Some(key)) = (RANGE_CACHE.as_ref(), self.range_cache_key(path)) else {
+            return Ok(None);
+        };
+        let object = match cache.get_object(&key) {
+            Some(object) => object,
+            None => cache.update_object(key, &self.head(path).await?),
+        };
+        Ok(Some((cache, object)))
+    }
+
+    /// Fetch `ranges`, which must have distinct starts, bypassing the byte-range cache.
+    async fn fetch_ranges(
+        &self,
+        path: &Path,
+        mut ranges: Vec<Range<usize>>,
+    ) -> PolarsResult<Vec<Bytes>> {
+        let starts = ranges.iter().map(|range| range.start).collect::<Vec<_>>();
+        let mut parts: PlHashMap<usize, Bytes> =
+            self.get_ranges_sort_uncached(path, &mut ranges).await?;
+        Ok(starts
+            .iter()
+            .map(|start| parts.remove(start).unwrap())
+            .collect())
+    }
+
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        if let Some((cache, object)) = self.range_cache_object(path).await? {
+            let mut out = cache
+                .get_ranges(&object, std::slice::from_ref(&range), |ranges| {
+                    self.fetch_ranges(path, ranges)
+                })
+                .await?;
+            return Ok(out.pop().unwrap());
+        }
+        self.get_range_uncached(path, range).await
+    }
+
+    async fn get_range_uncached(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
             let st = store.clone();
@@ -220,6 +270,32 @@ impl PolarsObjectStore {
             return Ok(Default::default());
         }
 
+        if let Some((cache, object)) = self.range_cache_object(path).await? {
+            ranges.sort_unstable_by_key(|x| x.start);
+            let parts = cache
+                .get_ranges(&object, ranges, |ranges| self.fetch_ranges(path, ranges))
+                .await?;
+            return Ok(ranges
+                .iter()
+                .zip(parts)
+                .map(|(range, bytes)| (K::try_from(range.start).unwrap(), T::from(bytes)))
+                .collect());
+        }
+        self.get_ranges_sort_uncached(path, ranges).await
+    }
+
+    async fn get_ranges_sort_uncached<
+        K: TryFrom<usize, Error = impl std::fmt::Debug> + std::hash::Hash + Eq,
+        T: From<Bytes>,
+    >(
+        &self,
+        path: &Path,
+        ranges: &mut [Range<usize>],
+    ) -> PolarsResult<PlHashMap<K, T>> {
+        if ranges.is_empty() {
+            return Ok(Default::default());
+        }
+
         ranges.sort_unstable_by_key(|x| x.start);
 
         let ranges_len = ranges.len();
@@ -362,41 +438,48 @@ impl PolarsObjectStore {
         .await
     }
 
-    /// Fetch the metadata of the parquet file, do not memoize it.
+    /// Fetch the metadata of the parquet file, do not memoize it. This refreshes the version of
+    /// the object in the byte-range cache.
     pub async fn head(&self, path: &Path) -> PolarsResult<ObjectMeta> {
-        self.try_exec_rebuild_on_err(|store| {
-            let st = store.clone();
-
-            async {
-                with_concurrency_budget(1, || async {
-                    let store = st;
-                    let head_result = store.head(path).await;
-
-                    if head_result.is_err() {
-                        // Pre-signed URLs forbid the HEAD method, but we can still retrieve the header
-                        // information with a range 0-0 request.
-                        let get_range_0_0_result = store
-                            .get_opts(
-                                path,
-                                object_store::GetOptions {
-                                    range: Some((0..1).into()),
-                                    ..Default::default()
-                                },
-                            )
-                            .await;
-
-                        if let Ok(v) = get_range_0_0_result {
-                            return Ok(v.meta);
+        let meta = self
+            .try_exec_rebuild_on_err(|store| {
+                let st = store.clone();
+
+                async {
+                    with_concurrency_budget(1, || async {
+                        let store = st;
+                        let head_result = store.head(path).await;
+
+                        if head_result.is_err() {
+                            // Pre-signed URLs forbid the HEAD method, but we can still retrieve the header
+                            // information with a range 0-0 request.
+                            let get_range_0_0_result = store
+                                .get_opts(
+                                    path,
+                                    object_store::GetOptions {
+                                        range: Some((0..1).into()),
+                                        ..Default::default()
+                                    },
+                                )
+                                .await;
+
+                            if let Ok(v) = get_range_0_0_result {
+                                return Ok(v.meta);
+                            }
                         }
-                    }
 
-                    head_result
-                })
-                .await
-                .map_err(to_compute_err)
-            }
-        })
-        .await
+                        head_result
+                    })
+                    .await
+                    .map_err(to_compute_err)
+                }
+            })
+            .await?;
+
+        if let (Some(cache), Some(key)) = (RANGE_CACHE.as_ref(), self.range_cache_key(path)) {
+            cache.update_object(key, &meta);
+        }
+        Ok(meta)
     }
 }
 
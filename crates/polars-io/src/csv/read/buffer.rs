@@ -158,6 +158,7 @@ pub struct Utf8Field {
     mutable: MutableBinaryViewArray<[u8]>,
     scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
 }
 
@@ -166,6 +167,7 @@ impl Utf8Field {
         name: PlSmallStr,
         capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         encoding: CsvEncoding,
     ) -> Self {
         Self {
@@ -173,6 +175,7 @@ impl Utf8Field {
             mutable: MutableBinaryViewArray::with_capacity(capacity),
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             encoding,
         }
     }
@@ -211,8 +214,12 @@ impl ParsedBuffer for Utf8Field {
             // SAFETY:
             // we just allocated enough capacity and data_len is correct.
             unsafe {
-                let n_written =
-                    escape_field(bytes, self.quote_char, self.scratch.spare_capacity_mut());
+                let n_written = escape_field(
+                    bytes,
+                    self.quote_char,
+                    self.escape_char,
+                    self.scratch.spare_capacity_mut(),
+                );
                 self.scratch.set_len(n_written);
             }
 
@@ -265,6 +272,7 @@ pub struct CategoricalField {
 pub struct CategoricalField {
     escape_scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     builder: CategoricalChunkedBuilder,
     is_enum: bool,
 }
@@ -275,6 +283,7 @@ impl CategoricalField {
         name: PlSmallStr,
         capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         ordering: CategoricalOrdering,
     ) -> Self {
         let builder = CategoricalChunkedBuilder::new(name, capacity, ordering);
@@ -282,15 +291,21 @@ impl CategoricalField {
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             builder,
             is_enum: false,
         }
     }
 
-    fn new_enum(quote_char: Option<u8>, builder: CategoricalChunkedBuilder) -> Self {
+    fn new_enum(
+        quote_char: Option<u8>,
+        escape_char: Option<u8>,
+        builder: CategoricalChunkedBuilder,
+    ) -> Self {
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             builder,
             is_enum: true,
         }
@@ -321,6 +336,7 @@ impl CategoricalField {
                     let n_written = escape_field(
                         bytes,
                         self.quote_char,
+                        self.escape_char,
                         self.escape_scratch.spare_capacity_mut(),
                     );
                     self.escape_scratch.set_len(n_written);
@@ -513,6 +529,7 @@ pub fn init_buffers(
     capacity: usize,
     schema: &Schema,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
     decimal_comma: bool,
 ) -> PolarsResult<Vec<Buffer>> {
@@ -557,9 +574,13 @@ pub fn init_buffers(
                         Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity))
                     }
                 },
-                &DataType::String => {
-                    Buffer::Utf8(Utf8Field::new(name, capacity, quote_char, encoding))
-                },
+                &DataType::String => Buffer::Utf8(Utf8Field::new(
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    encoding,
+                )),
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
                     buf: DatetimeField::new(name, capacity),
@@ -570,7 +591,11 @@ pub fn init_buffers(
                 &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
-                    name, capacity, quote_char, *ordering,
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    *ordering,
                 )),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Enum(rev_map, _) => {
@@ -583,7 +608,11 @@ pub fn init_buffers(
                     for cat in cats.values_iter() {
                         builder.register_value(cat);
                     }
-                    Buffer::Categorical(CategoricalField::new_enum(quote_char, builder))
+                    Buffer::Categorical(CategoricalField::new_enum(
+                        quote_char,
+                        escape_char,
+                        builder,
+                    ))
                 },
                 dt => polars_bail!(
                     ComputeError: "unsupported data type when reading CSV: {} when reading CSV", dt,
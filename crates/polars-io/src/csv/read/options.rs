@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use polars_core::datatypes::{DataType, Field};
 use polars_core::schema::{Schema, SchemaRef};
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -46,7 +46,10 @@ pub struct CsvReadOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParseOptions {
     pub separator: u8,
+    /// Separator consisting of more than one byte. If set, `separator` holds its first byte.
+    pub multi_char_separator: Option<PlSmallStr>,
     pub quote_char: Option<u8>,
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
@@ -94,7 +97,9 @@ impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
             separator: b',',
+            multi_char_separator: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
             encoding: Default::default(),
             null_values: None,
@@ -258,9 +263,40 @@ impl CsvParseOptions {
     /// is most often a comma ','.
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.separator = separator;
+        self.multi_char_separator = None;
         self
     }
 
+    /// Set a separator that may consist of multiple characters, e.g. `||`.
+    /// A single byte separator is stored as if set by [`with_separator`][Self::with_separator].
+    ///
+    /// An empty separator is rejected with an error when the file is read.
+    pub fn with_multi_char_separator(mut self, separator: &str) -> Self {
+        if let Some(first) = separator.as_bytes().first() {
+            self.separator = *first;
+        }
+        self.multi_char_separator = (separator.len() != 1).then(|| PlSmallStr::from_str(separator));
+        self
+    }
+
+    /// The bytes of the separator that follow its first byte. This is empty
+    /// for single byte separators.
+    pub fn separator_tail(&self) -> &[u8] {
+        self.multi_char_separator
+            .as_ref()
+            .and_then(|s| s.as_bytes().get(1..))
+            .unwrap_or_default()
+    }
+
+    /// Check that the separator is not empty.
+    pub fn check_separator(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.multi_char_separator.as_ref().is_none_or(|s| !s.is_empty()),
+            InvalidOperation: "CSV separator cannot be empty"
+        );
+        Ok(())
+    }
+
     /// Set the character used for field quoting. This is most often double
     /// quotes '"'. Set this to [None] to disable quote parsing.
     pub fn with_quote_char(mut self, quote_char: Option<u8>) -> Self {
@@ -268,6 +304,14 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set the character used to escape the quote character (and itself)
+    /// within quoted fields, e.g. `\` for `"say \"hi\""`. By default quotes
+    /// are escaped by doubling them.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the character used to indicate an end-of-line (eol).
     pub fn with_eol_char(mut self, eol_char: u8) -> Self {
         self.eol_char = eol_char;
@@ -415,7 +459,7 @@ impl NullValuesCompiled {
     /// # Safety
     ///
     /// The caller must ensure that `index` is in bounds
-    pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
+    pub(crate) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
         use NullValuesCompiled::*;
         match self {
             AllColumnsSingle(v) => v.as_bytes() == field,
@@ -23,6 +23,7 @@ pub fn count_rows(
     path: &Path,
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -47,6 +48,7 @@ pub fn count_rows(
         reader_bytes,
         separator,
         quote_char,
+        escape_char,
         comment_prefix,
         eol_char,
         has_header,
@@ -59,6 +61,7 @@ pub fn count_rows_from_slice(
     mut bytes: &[u8],
     separator: u8,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
@@ -81,7 +84,11 @@ pub fn count_rows_from_slice(
         eol_char,
         None,
         separator,
+        // Fields are not counted without `expected_fields`, so the tail of a
+        // multi-character separator is not needed.
+        &[],
         quote_char,
+        escape_char,
     )
     .map(|(mean, std)| {
         let n_rows = (bytes.len() as f32 / (mean - 0.01 * std)) as usize;
@@ -89,12 +96,26 @@ pub fn count_rows_from_slice(
     })
     .unwrap_or(1);
 
-    let file_chunks: Vec<(usize, usize)> =
-        get_file_chunks(bytes, n_threads, None, separator, quote_char, eol_char);
+    let file_chunks: Vec<(usize, usize)> = get_file_chunks(
+        bytes,
+        n_threads,
+        None,
+        separator,
+        &[],
+        quote_char,
+        escape_char,
+        eol_char,
+    );
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let local_bytes = &bytes[start..stop];
-        let row_iterator = SplitLines::new(local_bytes, quote_char, eol_char, comment_prefix);
+        let row_iterator = SplitLines::new(
+            local_bytes,
+            quote_char,
+            escape_char,
+            eol_char,
+            comment_prefix,
+        );
         if comment_prefix.is_some() {
             Ok(row_iterator
                 .filter(|line| !line.is_empty() && !is_comment_line(line, comment_prefix))
@@ -160,19 +181,28 @@ pub(super) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
         separator: u8,
+        separator_tail: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
-            if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
+        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char)
+            .with_extended_syntax(separator_tail, escape_char)
+        {
+            let n_splits = memchr2_iter(separator, eol_char, field)
+                .filter(|&i| field[i] == eol_char || field[i + 1..].starts_with(separator_tail))
+                .count();
+            if n_splits >= expected_fields {
                 return false;
             }
             count += 1;
@@ -218,16 +248,32 @@ pub(super) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char, eol_char, None);
+        let mut lines = SplitLines::new(new_input, quote_char, escape_char, eol_char, None);
         let line = lines.next();
 
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    separator,
+                    separator_tail,
+                    eol_char,
+                    quote_char,
+                    escape_char,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            separator,
+                            separator_tail,
+                            eol_char,
+                            quote_char,
+                            escape_char,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -290,13 +336,16 @@ pub(super) fn skip_line_ending(input: &[u8], eol_char: u8) -> &[u8] {
 }
 
 /// Get the mean and standard deviation of length of lines in bytes
+#[allow(clippy::too_many_arguments)]
 pub(super) fn get_line_stats(
     bytes: &[u8],
     n_lines: usize,
     eol_char: u8,
     expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
 
@@ -312,7 +361,9 @@ pub(super) fn get_line_stats(
             bytes_trunc,
             expected_fields,
             separator,
+            separator_tail,
             quote_char,
+            escape_char,
             eol_char,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
@@ -348,6 +399,7 @@ pub(super) fn get_line_stats(
 pub(super) struct SplitLines<'a> {
     v: &'a [u8],
     quote_char: u8,
+    escape_char: Option<u8>,
     eol_char: u8,
     #[cfg(feature = "simd")]
     simd_eol_char: SimdVec,
@@ -375,6 +427,7 @@ impl<'a> SplitLines<'a> {
     pub(super) fn new(
         slice: &'a [u8],
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         comment_prefix: Option<&'a CommentPrefix>,
     ) -> Self {
@@ -387,6 +440,7 @@ impl<'a> SplitLines<'a> {
         Self {
             v: slice,
             quote_char,
+            escape_char,
             eol_char,
             #[cfg(feature = "simd")]
             simd_eol_char,
@@ -419,7 +473,12 @@ impl<'a> SplitLines<'a> {
                     Some(&c) => {
                         pos += 1;
 
-                        if self.quoting && c == self.quote_char {
+                        if in_field && Some(c) == self.escape_char {
+                            // the escaped character cannot end the field
+                            if iter.next().is_some() {
+                                pos += 1;
+                            }
+                        } else if self.quoting && c == self.quote_char {
                             // toggle between string field enclosure
                             //      if we encounter a starting '"' -> in_field = true;
                             //      if we encounter a closing '"' -> in_field = false;
@@ -499,7 +558,7 @@ impl<'a> Iterator for SplitLines<'a> {
         if self.v.is_empty() {
             return None;
         }
-        if self.comment_prefix.is_some() {
+        if self.comment_prefix.is_some() || self.escape_char.is_some() {
             return self.next_scalar();
         }
 
@@ -600,6 +659,7 @@ impl<'a> Iterator for SplitLines<'a> {
 
 pub struct CountLines {
     quote_char: u8,
+    escape_char: Option<u8>,
     eol_char: u8,
     #[cfg(feature = "simd")]
     simd_eol_char: SimdVec,
@@ -616,7 +676,7 @@ pub struct LineStats {
 }
 
 impl CountLines {
-    pub fn new(quote_char: Option<u8>, eol_char: u8) -> Self {
+    pub fn new(quote_char: Option<u8>, escape_char: Option<u8>, eol_char: u8) -> Self {
         let quoting = quote_char.is_some();
         let quote_char = quote_char.unwrap_or(b'\"');
         #[cfg(feature = "simd")]
@@ -625,6 +685,7 @@ impl CountLines {
         let simd_quote_char = SimdVec::splat(quote_char);
         Self {
             quote_char,
+            escape_char,
             eol_char,
             #[cfg(feature = "simd")]
             simd_eol_char,
@@ -639,6 +700,8 @@ impl CountLines {
     /// Returns (newline_count, last_newline_offset, end_inside_string) twice,
     /// the first is assuming the start of the chunk is *not* inside a string,
     /// the second assuming the start is inside a string.
+    ///
+    /// Escape characters are not taken into account.
     pub fn analyze_chunk(&self, bytes: &[u8]) -> [LineStats; 2] {
         let mut scan_offset = 0;
         let mut states = [
@@ -743,6 +806,9 @@ impl CountLines {
     // Returns count and offset in slice
     #[cfg(feature = "simd")]
     pub fn count(&self, bytes: &[u8]) -> (usize, usize) {
+        if self.escape_char.is_some() {
+            return self.count_no_simd(bytes, false);
+        }
         let mut total_idx = 0;
         let original_bytes = bytes;
         let mut count = 0;
@@ -805,14 +871,17 @@ impl CountLines {
     }
 
     fn count_no_simd(&self, bytes: &[u8], in_field: bool) -> (usize, usize) {
-        let iter = bytes.iter();
+        let mut iter = bytes.iter();
         let mut in_field = in_field;
         let mut count = 0;
         let mut position = 0;
 
-        for b in iter {
+        while let Some(b) = iter.next() {
             let c = *b;
-            if self.quoting && c == self.quote_char {
+            if in_field && Some(c) == self.escape_char {
+                // the escaped character cannot end the field
+                iter.next();
+            } else if self.quoting && c == self.quote_char {
                 // toggle between string field enclosure
                 //      if we encounter a starting '"' -> in_field = true;
                 //      if we encounter a closing '"' -> in_field = false;
@@ -831,14 +900,19 @@ impl CountLines {
 }
 
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
+fn find_quoted(bytes: &[u8], quote_char: u8, escape_char: Option<u8>, needle: u8) -> Option<usize> {
     let mut in_field = false;
 
     let mut idx = 0u32;
+    let mut escaped = false;
     // micro optimizations
     #[allow(clippy::explicit_counter_loop)]
     for &c in bytes.iter() {
-        if c == quote_char {
+        if escaped {
+            escaped = false;
+        } else if in_field && Some(c) == escape_char {
+            escaped = true;
+        } else if c == quote_char {
             // toggle between string field enclosure
             //      if we encounter a starting '"' -> in_field = true;
             //      if we encounter a closing '"' -> in_field = false;
@@ -854,9 +928,14 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
+pub(super) fn skip_this_line(
+    bytes: &[u8],
+    quote: Option<u8>,
+    escape_char: Option<u8>,
+    eol_char: u8,
+) -> &[u8] {
     let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
+        Some(quote) => find_quoted(bytes, quote, escape_char, eol_char),
         None => bytes.iter().position(|x| *x == eol_char),
     };
     match pos {
@@ -902,6 +981,7 @@ pub(super) fn parse_lines(
         "at least one column should be projected"
     );
     let mut truncate_ragged_lines = parse_options.truncate_ragged_lines;
+    let separator_tail = parse_options.separator_tail();
     // During projection pushdown we are not checking other csv fields.
     // This would be very expensive and we don't care as we only want
     // the projected columns.
@@ -942,7 +1022,8 @@ pub(super) fn parse_lines(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_extended_syntax(separator_tail, parse_options.escape_char);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
@@ -957,6 +1038,12 @@ pub(super) fn parse_lines(
 
                     // +1 is the split character that is consumed by the iterator.
                     read_sol += field_len + 1;
+                    // Multi-byte separators consume more than a single split character.
+                    if !separator_tail.is_empty()
+                        && bytes.get(read_sol - 1) == Some(&parse_options.separator)
+                    {
+                        read_sol += separator_tail.len();
+                    }
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -1032,6 +1119,7 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked(read_sol - 1..) },
                                         parse_options.quote_char,
+                                        parse_options.escape_char,
                                         parse_options.eol_char,
                                     );
                                     bytes = bytes_rem;
@@ -1068,15 +1156,21 @@ mod test {
     #[test]
     fn test_splitlines() {
         let input = "1,\"foo\n\"\n2,\"foo\n\"\n";
-        let mut lines = SplitLines::new(input.as_bytes(), Some(b'"'), b'\n', None);
+        let mut lines = SplitLines::new(input.as_bytes(), Some(b'"'), None, b'\n', None);
         assert_eq!(lines.next(), Some("1,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), Some("2,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), None);
 
         let input2 = "1,'foo\n'\n2,'foo\n'\n";
-        let mut lines2 = SplitLines::new(input2.as_bytes(), Some(b'\''), b'\n', None);
+        let mut lines2 = SplitLines::new(input2.as_bytes(), Some(b'\''), None, b'\n', None);
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
+
+        let input3 = "1,\"foo\\\"\n\"\n2,\"foo\"\n";
+        let mut lines3 = SplitLines::new(input3.as_bytes(), Some(b'"'), Some(b'\\'), b'\n', None);
+        assert_eq!(lines3.next(), Some("1,\"foo\\\"\n\"".as_bytes()));
+        assert_eq!(lines3.next(), Some("2,\"foo\"".as_bytes()));
+        assert_eq!(lines3.next(), None);
     }
 }
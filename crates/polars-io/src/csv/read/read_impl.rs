@@ -7,6 +7,7 @@ use std::sync::Mutex;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, handle_casting_failures};
 use polars_core::POOL;
+use polars_error::signals::current_cancellation_token;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::*;
 use rayon::prelude::*;
@@ -159,6 +160,7 @@ impl<'a> CoreReader<'a> {
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
+        parse_options.check_separator()?;
         check_decimal_comma(parse_options.decimal_comma, separator)?;
         #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
@@ -180,7 +182,9 @@ impl<'a> CoreReader<'a> {
                 &reader_bytes,
                 total_n_rows,
                 separator,
+                parse_options.separator_tail(),
                 parse_options.quote_char,
+                parse_options.escape_char,
                 parse_options.eol_char,
             ) {
                 reader_bytes = ReaderBytes::Owned(b.into());
@@ -265,6 +269,7 @@ impl<'a> CoreReader<'a> {
         let i = find_starting_point(
             bytes,
             quote_char,
+            self.parse_options.escape_char,
             eol_char,
             self.schema.len(),
             self.skip_lines,
@@ -387,15 +392,21 @@ impl<'a> CoreReader<'a> {
         #[cfg(target_family = "wasm")]
         let pool = &POOL;
 
-        let counter = CountLines::new(self.parse_options.quote_char, self.parse_options.eol_char);
+        let counter = CountLines::new(
+            self.parse_options.quote_char,
+            self.parse_options.escape_char,
+            self.parse_options.eol_char,
+        );
         let mut total_offset = 0;
         let check_utf8 = matches!(self.parse_options.encoding, CsvEncoding::Utf8)
             && self.schema.iter_fields().any(|f| f.dtype().is_string());
 
+        // Stop spawning chunks once the query is cancelled.
+        let cancel_token = current_cancellation_token();
         pool.scope(|s| {
             loop {
                 let b = unsafe { bytes.get_unchecked(total_offset..) };
-                if b.is_empty() {
+                if b.is_empty() || cancel_token.as_ref().is_some_and(|t| t.is_cancelled()) {
                     break;
                 }
                 debug_assert!(
@@ -479,6 +490,9 @@ impl<'a> CoreReader<'a> {
                 total_bytes_offset += b.len();
             }
         });
+        if let Some(token) = &cancel_token {
+            token.check()?;
+        }
         let mut results = std::mem::take(&mut *results.lock().unwrap());
         results.sort_unstable_by_key(|k| k.0);
         let mut dfs = results
@@ -541,6 +555,7 @@ pub fn read_chunk(
         capacity + 1,
         schema,
         parse_options.quote_char,
+        parse_options.escape_char,
         parse_options.encoding,
         parse_options.decimal_comma,
     )?;
@@ -581,6 +596,7 @@ pub fn read_chunk(
 pub fn find_starting_point(
     mut bytes: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     schema_len: usize,
     skip_lines: usize,
@@ -609,7 +625,8 @@ pub fn find_starting_point(
 
     // skip 'n' leading rows
     if skip_rows_before_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines =
+            SplitLines::new(bytes, quote_char, escape_char, eol_char, comment_prefix);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_before_header {
@@ -631,11 +648,12 @@ pub fn find_starting_point(
 
     // skip header row
     if has_header {
-        bytes = skip_this_line(bytes, quote_char, eol_char);
+        bytes = skip_this_line(bytes, quote_char, escape_char, eol_char);
     }
     // skip 'n' rows following the header
     if skip_rows_after_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines =
+            SplitLines::new(bytes, quote_char, escape_char, eol_char, comment_prefix);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_after_header {
@@ -210,6 +210,7 @@ fn infer_file_schema_inner(
     let mut lines = SplitLines::new(
         bytes,
         parse_options.quote_char,
+        parse_options.escape_char,
         parse_options.eol_char,
         parse_options.comment_prefix.as_ref(),
     )
@@ -249,7 +250,8 @@ fn infer_file_schema_inner(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_extended_syntax(parse_options.separator_tail(), parse_options.escape_char);
         if has_header {
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
@@ -312,6 +314,7 @@ fn infer_file_schema_inner(
         lines = SplitLines::new(
             bytes,
             parse_options.quote_char,
+            parse_options.escape_char,
             parse_options.eol_char,
             parse_options.comment_prefix.as_ref(),
         )
@@ -375,7 +378,8 @@ fn infer_file_schema_inner(
             parse_options.separator,
             parse_options.quote_char,
             parse_options.eol_char,
-        );
+        )
+        .with_extended_syntax(parse_options.separator_tail(), parse_options.escape_char);
 
         for i in 0..header_length {
             if let Some((slice, needs_escaping)) = record.next() {
@@ -551,6 +555,7 @@ pub fn infer_file_schema(
     raise_if_empty: bool,
     n_threads: &mut Option<usize>,
 ) -> PolarsResult<(Schema, usize, usize)> {
+    parse_options.check_separator()?;
     check_decimal_comma(parse_options.decimal_comma, parse_options.separator)?;
 
     if skip_lines > 0 {
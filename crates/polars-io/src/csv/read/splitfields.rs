@@ -5,9 +5,11 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         v: &'a [u8],
         separator: u8,
+        separator_tail: &'a [u8],
         finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: Option<u8>,
         eol_char: u8,
     }
 
@@ -21,13 +23,52 @@ mod inner {
             Self {
                 v: slice,
                 separator,
+                separator_tail: &[],
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
+                escape_char: None,
                 eol_char,
             }
         }
 
+        /// Support separators of multiple bytes and escape characters in quoted fields.
+        /// `separator_tail` are the bytes of the separator following its first byte.
+        pub(crate) fn with_extended_syntax(
+            mut self,
+            separator_tail: &'a [u8],
+            escape_char: Option<u8>,
+        ) -> Self {
+            self.separator_tail = separator_tail;
+            self.escape_char = escape_char;
+            self
+        }
+
+        fn is_extended(&self) -> bool {
+            !self.separator_tail.is_empty() || self.escape_char.is_some()
+        }
+
+        fn next_extended(&mut self) -> Option<(&'a [u8], bool)> {
+            let needs_escaping = self.quoting && self.v.first() == Some(&self.quote_char);
+            match super::find_field_end(
+                self.v,
+                self.separator,
+                self.separator_tail,
+                self.quoting.then_some(self.quote_char),
+                self.escape_char,
+                self.eol_char,
+            ) {
+                super::FieldEnd::Eof => self.finish(needs_escaping),
+                // SAFETY: `find_field_end` returns in bounds positions.
+                super::FieldEnd::Eol(pos) => unsafe { self.finish_eol(needs_escaping, pos) },
+                super::FieldEnd::Separator(pos) => {
+                    let ret = Some((&self.v[..pos], needs_escaping));
+                    self.v = &self.v[pos + 1 + self.separator_tail.len()..];
+                    ret
+                },
+            }
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -58,6 +99,8 @@ mod inner {
                 return None;
             } else if self.v.is_empty() {
                 return self.finish(false);
+            } else if self.is_extended() {
+                return self.next_extended();
             }
 
             let mut needs_escaping = false;
@@ -148,9 +191,11 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         pub v: &'a [u8],
         separator: u8,
+        separator_tail: &'a [u8],
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: Option<u8>,
         eol_char: u8,
         simd_separator: SimdVec,
         simd_eol_char: SimdVec,
@@ -174,9 +219,11 @@ mod inner {
             Self {
                 v: slice,
                 separator,
+                separator_tail: &[],
                 finished: false,
                 quote_char,
                 quoting,
+                escape_char: None,
                 eol_char,
                 simd_separator,
                 simd_eol_char,
@@ -185,6 +232,43 @@ mod inner {
             }
         }
 
+        /// Support separators of multiple bytes and escape characters in quoted fields.
+        /// `separator_tail` are the bytes of the separator following its first byte.
+        pub(crate) fn with_extended_syntax(
+            mut self,
+            separator_tail: &'a [u8],
+            escape_char: Option<u8>,
+        ) -> Self {
+            self.separator_tail = separator_tail;
+            self.escape_char = escape_char;
+            self
+        }
+
+        fn is_extended(&self) -> bool {
+            !self.separator_tail.is_empty() || self.escape_char.is_some()
+        }
+
+        fn next_extended(&mut self) -> Option<(&'a [u8], bool)> {
+            let needs_escaping = self.quoting && self.v.first() == Some(&self.quote_char);
+            match super::find_field_end(
+                self.v,
+                self.separator,
+                self.separator_tail,
+                self.quoting.then_some(self.quote_char),
+                self.escape_char,
+                self.eol_char,
+            ) {
+                super::FieldEnd::Eof => self.finish(needs_escaping),
+                // SAFETY: `find_field_end` returns in bounds positions.
+                super::FieldEnd::Eol(pos) => unsafe { self.finish_eol(needs_escaping, pos) },
+                super::FieldEnd::Separator(pos) => {
+                    let ret = Some((&self.v[..pos], needs_escaping));
+                    self.v = &self.v[pos + 1 + self.separator_tail.len()..];
+                    ret
+                },
+            }
+        }
+
         unsafe fn finish_eol(
             &mut self,
             need_escaping: bool,
@@ -246,6 +330,9 @@ mod inner {
             if self.v.is_empty() {
                 return self.finish(false);
             }
+            if self.is_extended() {
+                return self.next_extended();
+            }
 
             let mut needs_escaping = false;
             // There can be strings with separators:
@@ -407,6 +494,51 @@ mod inner {
 
 pub(crate) use inner::SplitFields;
 
+enum FieldEnd {
+    /// The field ends at the start of a separator.
+    Separator(usize),
+    /// The field ends at an end-of-line character.
+    Eol(usize),
+    /// The field runs until the end of the input.
+    Eof,
+}
+
+/// Scalar search for the end of the field at the start of `v`, used for the syntax that
+/// the SIMD splitter doesn't handle: separators of multiple bytes and escaped quotes.
+fn find_field_end(
+    v: &[u8],
+    separator: u8,
+    separator_tail: &[u8],
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    eol_char: u8,
+) -> FieldEnd {
+    // Only fields starting with a quote are considered quoted.
+    let quoted = quote_char.is_some() && v.first() == quote_char.as_ref();
+    let mut in_field = false;
+    let mut idx = 0;
+    while idx < v.len() {
+        let c = v[idx];
+        if quoted && in_field && Some(c) == escape_char {
+            // Skip the escaped character.
+            idx += 2;
+            continue;
+        }
+        if quoted && Some(c) == quote_char {
+            in_field = !in_field;
+        } else if !in_field {
+            if c == eol_char {
+                return FieldEnd::Eol(idx);
+            }
+            if c == separator && v[idx + 1..].starts_with(separator_tail) {
+                return FieldEnd::Separator(idx);
+            }
+        }
+        idx += 1;
+    }
+    FieldEnd::Eof
+}
+
 #[cfg(test)]
 mod test {
     use super::SplitFields;
@@ -428,4 +560,16 @@ mod test {
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_extended_syntax() {
+        let input = "a||\"b|\\\"c\"||d\n";
+        let mut fields = SplitFields::new(input.as_bytes(), b'|', Some(b'"'), b'\n')
+            .with_extended_syntax(b"|", Some(b'\\'));
+
+        assert_eq!(fields.next(), Some(("a".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"b|\\\"c\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("d".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+    }
 }
@@ -7,12 +7,15 @@ use super::parser::next_line_position;
 use super::parser::next_line_position_naive;
 use super::splitfields::SplitFields;
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
@@ -30,7 +33,9 @@ pub(crate) fn get_file_chunks(
             &bytes[search_pos..],
             expected_fields,
             separator,
+            separator_tail,
             quote_char,
+            escape_char,
             eol_char,
         ) {
             Some(pos) => search_pos + pos,
@@ -50,7 +55,9 @@ fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     let chunk_size = 4096;
@@ -80,8 +87,9 @@ fn decompress_impl<R: Read>(
                         break;
                     }
                     // now that we have enough, we compute the number of fields (also takes embedding into account)
-                    expected_fields =
-                        SplitFields::new(&out, separator, quote_char, eol_char).count();
+                    expected_fields = SplitFields::new(&out, separator, quote_char, eol_char)
+                        .with_extended_syntax(separator_tail, escape_char)
+                        .count();
                     break;
                 }
             }
@@ -95,7 +103,9 @@ fn decompress_impl<R: Read>(
                     &out[buf_pos + 1..],
                     Some(expected_fields),
                     separator,
+                    separator_tail,
                     quote_char,
+                    escape_char,
                     eol_char,
                 ) {
                     Some(pos) => {
@@ -126,7 +136,9 @@ pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
     separator: u8,
+    separator_tail: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     use crate::utils::compression::SupportedCompression;
@@ -135,15 +147,39 @@ pub(crate) fn decompress(
         match algo {
             SupportedCompression::GZIP => {
                 let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+                decompress_impl(
+                    &mut decoder,
+                    n_rows,
+                    separator,
+                    separator_tail,
+                    quote_char,
+                    escape_char,
+                    eol_char,
+                )
             },
             SupportedCompression::ZLIB => {
                 let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+                decompress_impl(
+                    &mut decoder,
+                    n_rows,
+                    separator,
+                    separator_tail,
+                    quote_char,
+                    escape_char,
+                    eol_char,
+                )
             },
             SupportedCompression::ZSTD => {
                 let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
+                decompress_impl(
+                    &mut decoder,
+                    n_rows,
+                    separator,
+                    separator_tail,
+                    quote_char,
+                    escape_char,
+                    eol_char,
+                )
             },
         }
     } else {
@@ -151,7 +187,7 @@ pub(crate) fn decompress(
     }
 }
 
-/// replace double quotes by single ones
+/// replace double quotes by single ones and remove escape characters
 ///
 /// This function assumes that bytes is wrapped in the quoting character.
 ///
@@ -161,13 +197,25 @@ pub(crate) fn decompress(
 ///     - Output buffer must have enough capacity to hold `bytes.len()`
 ///     - bytes ends with the quote character e.g.: `"`
 ///     - bytes length > 1.
-pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUninit<u8>]) -> usize {
+pub(super) unsafe fn escape_field(
+    bytes: &[u8],
+    quote: u8,
+    escape_char: Option<u8>,
+    buf: &mut [MaybeUninit<u8>],
+) -> usize {
     debug_assert!(bytes.len() > 1);
     let mut prev_quote = false;
+    let mut prev_escape = false;
 
     let mut count = 0;
     for c in bytes.get_unchecked(1..bytes.len() - 1) {
-        if *c == quote {
+        if prev_escape {
+            prev_escape = false;
+            buf.get_unchecked_mut(count).write(*c);
+            count += 1;
+        } else if Some(*c) == escape_char {
+            prev_escape = true;
+        } else if *c == quote {
             if prev_quote {
                 prev_quote = false;
                 buf.get_unchecked_mut(count).write(*c);
@@ -186,7 +234,16 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
 
 #[cfg(test)]
 mod test {
-    use super::get_file_chunks;
+    use super::{escape_field, get_file_chunks};
+
+    #[test]
+    fn test_escape_field() {
+        let bytes = b"\"a\\\"b\\\\c\"\"d\"";
+        let mut buf = Vec::with_capacity(bytes.len());
+        let n_written = unsafe { escape_field(bytes, b'"', Some(b'\\'), buf.spare_capacity_mut()) };
+        unsafe { buf.set_len(n_written) };
+        assert_eq!(buf, b"a\"b\\c\"d");
+    }
 
     #[test]
     fn test_get_file_chunks() {
@@ -195,10 +252,29 @@ mod test {
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b',', &[], None, None, b'\n').len() as i32 - 10)
+                .abs()
+                <= 1
         );
         assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 8, Some(4), b',', &[], None, None, b'\n').len() as i32 - 8)
+                .abs()
+                <= 1
         );
     }
+
+    #[test]
+    fn test_get_file_chunks_multi_char_separator() {
+        let mut s = String::from("a||b||c\n");
+        for i in 0..1000 {
+            s.push_str(&format!("{i}||x|y||\"q||{i}\"\n"));
+        }
+        let bytes = s.as_bytes();
+        let chunks = get_file_chunks(bytes, 8, Some(3), b'|', b"|", Some(b'"'), None, b'\n');
+        assert!((chunks.len() as i32 - 8).abs() <= 1);
+        for (start, stop) in chunks {
+            assert!(start == 0 || bytes[start - 1] == b'\n');
+            assert!(bytes[stop - 1] == b'\n');
+        }
+    }
 }
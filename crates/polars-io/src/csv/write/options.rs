@@ -44,8 +44,12 @@ pub struct SerializeOptions {
     pub float_precision: Option<usize>,
     /// Used as separator.
     pub separator: u8,
+    /// Separator consisting of more than one byte. If set, it is used instead of `separator`.
+    pub multi_char_separator: Option<String>,
     /// Quoting character.
     pub quote_char: u8,
+    /// Character used to escape quotes within quoted fields. If not set, quotes are doubled.
+    pub escape_char: Option<u8>,
     /// Null value representation.
     pub null: String,
     /// String appended after every row.
@@ -63,7 +67,9 @@ impl Default for SerializeOptions {
             float_scientific: None,
             float_precision: None,
             separator: b',',
+            multi_char_separator: None,
             quote_char: b'"',
+            escape_char: None,
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
@@ -71,6 +77,16 @@ impl Default for SerializeOptions {
     }
 }
 
+impl SerializeOptions {
+    /// The bytes written between two fields.
+    pub fn separator_bytes(&self) -> &[u8] {
+        match &self.multi_char_separator {
+            Some(separator) => separator.as_bytes(),
+            None => std::slice::from_ref(&self.separator),
+        }
+    }
+}
+
 /// Quote style indicating when to insert quotes around a field.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -43,6 +43,10 @@ pub(crate) fn write<W: Write>(
         std::str::from_utf8(&[options.quote_char, options.quote_char]).is_ok(),
         ComputeError: "quote char results in invalid utf-8",
     );
+    polars_ensure!(
+        std::str::from_utf8(options.separator_bytes()).is_ok(),
+        ComputeError: "separator results in invalid utf-8",
+    );
 
     let (datetime_formats, time_zones): (Vec<&str>, Vec<Option<Tz>>) = df
         .get_columns()
@@ -169,7 +173,7 @@ pub(crate) fn write<W: Write>(
             for _ in 0..len {
                 serializers[0].serialize(write_buffer, options);
                 for serializer in &mut serializers[1..] {
-                    write_buffer.push(options.separator);
+                    write_buffer.extend_from_slice(options.separator_bytes());
                     serializer.serialize(write_buffer, options);
                 }
 
@@ -221,7 +225,7 @@ pub(crate) fn write_header<W: Write>(
     for i in 0..names.len() {
         names_serializer.serialize(&mut header, options);
         if i != names.len() - 1 {
-            header.push(options.separator);
+            header.extend_from_slice(options.separator_bytes());
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
@@ -38,7 +38,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr3, memchr_iter};
+use memchr::{memchr, memchr2_iter, memchr3, memchr_iter};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 
@@ -375,7 +375,35 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
-    fn serialize_str_escaped(buf: &mut Vec<u8>, s: &[u8], quote_char: u8, quoted: bool) {
+    fn serialize_str_escaped(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        quote_char: u8,
+        escape_char: Option<u8>,
+        quoted: bool,
+    ) {
+        if let Some(escape_char) = escape_char {
+            // Escape characters only have a meaning within quoted fields.
+            if !quoted && memchr(quote_char, s).is_none() {
+                buf.extend_from_slice(s);
+                return;
+            }
+            if !quoted {
+                buf.push(quote_char);
+            }
+            let mut start_pos = 0;
+            for pos in memchr2_iter(quote_char, escape_char, s) {
+                buf.extend_from_slice(&s[start_pos..pos]);
+                buf.extend_from_slice(&[escape_char, s[pos]]);
+                start_pos = pos + 1;
+            }
+            buf.extend_from_slice(&s[start_pos..]);
+            if !quoted {
+                buf.push(quote_char);
+            }
+            return;
+        }
+
         let mut iter = memchr_iter(quote_char, s);
         let first_quote = iter.next();
         match first_quote {
@@ -418,7 +446,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.push(quote_char);
                         return;
                     };
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, options.escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -436,7 +464,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     };
                     let quote_char = options.quote_char;
                     buf.push(quote_char);
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, options.escape_char, true);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -462,7 +490,13 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     if needs_quote {
                         buf.push(quote_char);
                     }
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, needs_quote);
+                    serialize_str_escaped(
+                        buf,
+                        s.as_bytes(),
+                        quote_char,
+                        options.escape_char,
+                        needs_quote,
+                    );
                     if needs_quote {
                         buf.push(quote_char);
                     }
@@ -86,6 +86,16 @@ where
     /// Set the CSV file's column separator as a byte character.
     pub fn with_separator(mut self, separator: u8) -> Self {
         self.options.separator = separator;
+        self.options.multi_char_separator = None;
+        self
+    }
+
+    /// Set a column separator that may consist of multiple characters, e.g. `||`.
+    pub fn with_multi_char_separator(mut self, separator: Option<String>) -> Self {
+        if let Some(first) = separator.as_ref().and_then(|s| s.as_bytes().first()) {
+            self.options.separator = *first;
+        }
+        self.options.multi_char_separator = separator.filter(|s| s.len() > 1);
         self
     }
 
@@ -141,6 +151,13 @@ where
         self
     }
 
+    /// Set the single byte character used to escape quotes within quoted fields.
+    /// If [None], quotes are escaped by doubling them.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.options.escape_char = escape_char;
+        self
+    }
+
     /// Set the CSV file's null value representation.
     pub fn with_null_value(mut self, null_value: String) -> Self {
         self.options.null = null_value;
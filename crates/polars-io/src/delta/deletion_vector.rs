@@ -0,0 +1,291 @@
+use std::fmt::Write;
+
+use polars_error::{polars_bail, polars_ensure, polars_err, PolarsResult};
+use serde::{Deserialize, Serialize};
+
+use super::store::TableStore;
+
+/// Magic number at the start of a serialized `RoaringBitmapArray`.
+const ROARING_BITMAP_ARRAY_MAGIC: u32 = 1681511377;
+
+/// Describes where the deletion vector of a data file is stored.
+///
+/// A deletion vector is a set of row indices, relative to the start of the data file, of rows
+/// that have been deleted but not yet physically removed from the file.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// `u` for a file relative to the table root identified by a UUID, `p` for an absolute
+    /// path and `i` for a deletion vector stored inline in the log.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// Byte offset of the deletion vector within its file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// Number of deleted rows.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifies the deletion vector. Together with the path, this identifies a logical file
+    /// of the table.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Read the sorted indices of the deleted rows.
+    pub(super) fn read(&self, store: &TableStore) -> PolarsResult<Vec<u64>> {
+        let bytes = match self.storage_type.as_str() {
+            "i" => {
+                // The encoded data is padded to a multiple of 4 bytes.
+                let mut bytes = z85_decode(&self.path_or_inline_dv)?;
+                bytes.truncate(self.size_in_bytes as usize);
+                bytes
+            },
+            "u" | "p" => {
+                let file = if self.storage_type == "u" {
+                    store.read(&self.relative_path()?)?
+                } else {
+                    TableStore::read_absolute(&self.path_or_inline_dv, store)?
+                };
+                let offset = self.offset.unwrap_or(1) as usize;
+                let data = file.get(offset..offset + 4).ok_or_else(
+                    || polars_err!(ComputeError: "deletion vector file is too short"),
+                )?;
+                let size = u32::from_be_bytes(data.try_into().unwrap()) as usize;
+                file.get(offset + 4..offset + 4 + size)
+                    .ok_or_else(|| polars_err!(ComputeError: "deletion vector file is too short"))?
+                    .to_vec()
+            },
+            st => polars_bail!(
+                ComputeError: "unknown deletion vector storage type '{}'", st
+            ),
+        };
+
+        let mut rows = decode_roaring_bitmap_array(&bytes)?;
+        polars_ensure!(
+            rows.len() as i64 == self.cardinality,
+            ComputeError: "deletion vector contains {} rows, but its cardinality is {}",
+            rows.len(), self.cardinality
+        );
+        rows.sort_unstable();
+        Ok(rows)
+    }
+
+    /// The path of a `u` deletion vector relative to the table root.
+    fn relative_path(&self) -> PolarsResult<String> {
+        let dv = &self.path_or_inline_dv;
+        polars_ensure!(
+            dv.len() >= 20,
+            ComputeError: "invalid deletion vector path '{}'", dv
+        );
+        let (prefix, encoded_uuid) = dv.split_at(dv.len() - 20);
+        let uuid = z85_decode(encoded_uuid)?;
+        let hex = uuid.iter().fold(String::new(), |mut acc, b| {
+            write!(acc, "{b:02x}").unwrap();
+            acc
+        });
+        let file_name = format!(
+            "deletion_vector_{}-{}-{}-{}-{}.bin",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        );
+        Ok(if prefix.is_empty() {
+            file_name
+        } else {
+            format!("{prefix}/{file_name}")
+        })
+    }
+}
+
+/// Decode a [Z85](https://rfc.zeromq.org/spec/32/) string.
+fn z85_decode(encoded: &str) -> PolarsResult<Vec<u8>> {
+    const ALPHABET: &[u8; 85] =
+        b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+    let encoded = encoded.as_bytes();
+    polars_ensure!(
+        encoded.len() % 5 == 0,
+        ComputeError: "z85 encoded data must have a length that is a multiple of 5"
+    );
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks_exact(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = ALPHABET.iter().position(|a| a == c).ok_or_else(
+                || polars_err!(ComputeError: "invalid z85 character '{}'", *c as char),
+            )?;
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid z85 encoded data"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(out)
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.bytes.len(),
+            ComputeError: "unexpected end of deletion vector data"
+        );
+        let (out, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+}
+
+/// Decode a `RoaringBitmapArray`: a 64-bit roaring bitmap stored as 32-bit roaring bitmaps,
+/// one per distinct value of the high 32 bits.
+fn decode_roaring_bitmap_array(bytes: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut reader = ByteReader { bytes };
+    polars_ensure!(
+        reader.u32()? == ROARING_BITMAP_ARRAY_MAGIC,
+        ComputeError: "deletion vector does not start with the expected magic number"
+    );
+
+    let mut out = vec![];
+    let n_bitmaps = reader.u64()?;
+    for _ in 0..n_bitmaps {
+        let high = reader.u32()? as u64;
+        decode_portable_roaring_bitmap(&mut reader, high << 32, &mut out)?;
+    }
+    Ok(out)
+}
+
+/// Decode a 32-bit roaring bitmap in the portable serialization format, see
+/// <https://github.com/RoaringBitmap/RoaringFormatSpec>.
+fn decode_portable_roaring_bitmap(
+    reader: &mut ByteReader,
+    base: u64,
+    out: &mut Vec<u64>,
+) -> PolarsResult<()> {
+    const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+    const SERIAL_COOKIE: u32 = 12347;
+    const NO_OFFSET_THRESHOLD: usize = 4;
+
+    let cookie = reader.u32()?;
+    let (n_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let n = (cookie >> 16) as usize + 1;
+        (n, Some(reader.take(n.div_ceil(8))?))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        polars_bail!(ComputeError: "invalid roaring bitmap cookie {}", cookie)
+    };
+
+    let headers = (0..n_containers)
+        .map(|_| Ok((reader.u16()?, reader.u16()? as usize + 1)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if run_flags.is_none() || n_containers >= NO_OFFSET_THRESHOLD {
+        reader.take(4 * n_containers)?;
+    }
+
+    for (i, (key, cardinality)) in headers.into_iter().enumerate() {
+        let base = base | ((key as u64) << 16);
+        let is_run = run_flags.is_some_and(|flags| (flags[i / 8] >> (i % 8)) & 1 == 1);
+
+        if is_run {
+            let n_runs = reader.u16()?;
+            for _ in 0..n_runs {
+                let start = reader.u16()? as u64;
+                let length = reader.u16()? as u64;
+                out.extend((start..=start + length).map(|v| base | v));
+            }
+        } else if cardinality <= 4096 {
+            for _ in 0..cardinality {
+                out.push(base | reader.u16()? as u64);
+            }
+        } else {
+            for word_idx in 0..1024u64 {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros() as u64;
+                    out.push(base | (word_idx * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() {
+        // Example from the Z85 specification.
+        let decoded = z85_decode("HelloWorld").unwrap();
+        assert_eq!(decoded, [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]);
+    }
+
+    #[test]
+    fn test_decode_roaring_bitmap_array() {
+        let mut bytes = vec![];
+        bytes.extend(ROARING_BITMAP_ARRAY_MAGIC.to_le_bytes());
+        bytes.extend(2u64.to_le_bytes());
+
+        // Bitmap for the high bits 0, with an array container and a run container.
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend((12347u32 | (1 << 16)).to_le_bytes());
+        bytes.push(0b10);
+        for (key, cardinality) in [(0u16, 2u16), (1, 3)] {
+            bytes.extend(key.to_le_bytes());
+            bytes.extend((cardinality - 1).to_le_bytes());
+        }
+        for v in [3u16, 7] {
+            bytes.extend(v.to_le_bytes());
+        }
+        for v in [1u16, 10, 2] {
+            bytes.extend(v.to_le_bytes());
+        }
+
+        // Bitmap for the high bits 1, without run containers.
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(12346u32.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend([0, 0, 0, 0]);
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend(5u16.to_le_bytes());
+
+        let rows = decode_roaring_bitmap_array(&bytes).unwrap();
+        assert_eq!(
+            rows,
+            [
+                3,
+                7,
+                (1 << 16) | 10,
+                (1 << 16) | 11,
+                (1 << 16) | 12,
+                (1 << 32) | 5
+            ]
+        );
+    }
+}
@@ -0,0 +1,785 @@
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use polars_utils::format_pl_smallstr;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use super::deletion_vector::DeletionVectorDescriptor;
+use super::schema::delta_schema_to_schema;
+use super::store::{LogFile, TableStore, LOG_DIR};
+use crate::cloud::CloudOptions;
+use crate::json::{JsonFormat, JsonWriter};
+use crate::parquet::read::ParquetReader;
+use crate::shared::{SerReader, SerWriter};
+
+/// Reader features that do not change how the data files have to be read.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// Which version of a Delta table to read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DeltaVersion {
+    #[default]
+    Latest,
+    Version(i64),
+    /// The latest version that was committed at or before this time, in milliseconds since
+    /// the epoch. The commit time is the modification time of the commit file.
+    Timestamp(i64),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaProtocol {
+    pub min_reader_version: i32,
+    pub min_writer_version: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reader_features: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writer_features: Option<Vec<String>>,
+}
+
+/// The metadata of a Delta table at a given version.
+#[derive(Clone, Debug)]
+pub struct DeltaMetadata {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    pub configuration: BTreeMap<String, String>,
+    pub created_time: Option<i64>,
+}
+
+/// Statistics of a data file, as written by the writer of the file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DeltaFileStats {
+    pub num_records: Option<i64>,
+    pub min_values: serde_json::Map<String, Value>,
+    pub max_values: serde_json::Map<String, Value>,
+    pub null_count: serde_json::Map<String, Value>,
+}
+
+/// A data file that is part of a snapshot of a Delta table.
+#[derive(Clone, Debug)]
+pub struct DeltaFile {
+    /// The path relative to the table root, or an absolute URI.
+    pub path: String,
+    /// The values of the partition columns, in the order of
+    /// [`DeltaMetadata::partition_columns`]. These are serialized as strings.
+    pub partition_values: Vec<Option<String>>,
+    pub size: i64,
+    pub modification_time: i64,
+    pub stats: Option<DeltaFileStats>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// The state of a Delta table at a given version.
+#[derive(Clone)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    pub protocol: DeltaProtocol,
+    pub metadata: DeltaMetadata,
+    pub files: Vec<DeltaFile>,
+    store: TableStore,
+}
+
+impl DeltaSnapshot {
+    /// The full path or URI of a data file.
+    pub fn file_uri(&self, file: &DeltaFile) -> String {
+        if file.path.contains("://") {
+            file.path.clone()
+        } else {
+            self.store.join(&file.path)
+        }
+    }
+
+    /// The sorted indices of the rows of `file` that have been deleted.
+    pub fn deleted_rows(&self, file: &DeltaFile) -> PolarsResult<Option<Vec<u64>>> {
+        file.deletion_vector
+            .as_ref()
+            .map(|dv| dv.read(&self.store))
+            .transpose()
+    }
+
+    /// The number of rows in a data file, including deleted rows. This is taken from the
+    /// statistics in the log, or else from the parquet footer of the file.
+    pub fn num_rows(&self, file: &DeltaFile) -> PolarsResult<usize> {
+        if let Some(num_records) = file.stats.as_ref().and_then(|stats| stats.num_records) {
+            return Ok(num_records as usize);
+        }
+        ParquetReader::new(Cursor::new(self.read_bytes(file)?)).num_rows()
+    }
+
+    fn read_bytes(&self, file: &DeltaFile) -> PolarsResult<Vec<u8>> {
+        if file.path.contains("://") {
+            TableStore::read_absolute(&file.path, &self.store)
+        } else {
+            self.store.read(&file.path)
+        }
+    }
+
+    /// Read the rows of a data file that have not been deleted, in the schema of the table.
+    pub fn read_file(&self, file: &DeltaFile) -> PolarsResult<DataFrame> {
+        let mut df = ParquetReader::new(Cursor::new(self.read_bytes(file)?)).finish()?;
+        if let Some(deleted_rows) = self.deleted_rows(file)? {
+            let mut keep = vec![true; df.height()];
+            for row in deleted_rows {
+                if let Some(keep) = keep.get_mut(row as usize) {
+                    *keep = false;
+                }
+            }
+            df = df.filter(&BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep))?;
+        }
+
+        let height = df.height();
+        let columns = self
+            .metadata
+            .schema
+            .iter()
+            .map(|(name, dtype)| {
+                let partition_idx = self
+                    .metadata
+                    .partition_columns
+                    .iter()
+                    .position(|c| c == name);
+                let column = match partition_idx {
+                    Some(idx) => {
+                        let value = file.partition_values[idx].as_deref();
+                        Series::new(name.clone(), [value])
+                            .strict_cast(dtype)?
+                            .new_from_index(0, height)
+                            .into()
+                    },
+                    // Columns that were added to the table are missing from older files.
+                    None => match df.column(name) {
+                        Ok(column) => column.cast(dtype)?,
+                        Err(_) => Column::full_null(name.clone(), height, dtype),
+                    },
+                };
+                Ok(column)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    /// The partition values of the files as a [`DataFrame`] with a row per file.
+    pub fn partition_values(&self) -> PolarsResult<DataFrame> {
+        let columns = self
+            .partition_schema()?
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, dtype))| {
+                let values = self
+                    .files
+                    .iter()
+                    .map(|f| f.partition_values[idx].as_deref())
+                    .collect::<Vec<_>>();
+                Ok(Series::new(name.clone(), values).strict_cast(dtype)?.into())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new_with_height(self.files.len(), columns)
+    }
+
+    /// The statistics of the files, derived from the statistics in the log and the partition
+    /// values.
+    ///
+    /// The frame has one row per file, with a `len` column and `{column}_min`, `{column}_max`
+    /// and `{column}_nc` columns for every column of the table, which is the layout that batch
+    /// skipping predicates are evaluated on. Unknown statistics are null.
+    pub fn file_statistics(&self) -> PolarsResult<DataFrame> {
+        let height = self.files.len();
+        let lengths = self
+            .files
+            .iter()
+            .map(|f| f.stats.as_ref()?.num_records.map(|n| n as IdxSize))
+            .collect::<Vec<_>>();
+        let mut columns = Vec::with_capacity(1 + 3 * self.metadata.schema.len());
+        columns.push(
+            IdxCa::from_iter_options(PlSmallStr::from_static("len"), lengths.iter().copied())
+                .into_column(),
+        );
+
+        for (name, dtype) in self.metadata.schema.iter() {
+            let partition_idx = self
+                .metadata
+                .partition_columns
+                .iter()
+                .position(|c| c == name);
+            let (min, max, null_counts) = match partition_idx {
+                Some(idx) => {
+                    let values = self
+                        .files
+                        .iter()
+                        .map(|f| f.partition_values[idx].as_deref())
+                        .collect::<Vec<_>>();
+                    let null_counts = values
+                        .iter()
+                        .zip(&lengths)
+                        .map(|(value, len)| if value.is_some() { Some(0) } else { *len })
+                        .collect::<Vec<_>>();
+                    let values = Series::new(PlSmallStr::EMPTY, values).strict_cast(dtype)?;
+                    (values.clone(), values, null_counts)
+                },
+                None => {
+                    let null_counts = self
+                        .files
+                        .iter()
+                        .map(|f| {
+                            let nc = f.stats.as_ref()?.null_count.get(name.as_str())?;
+                            nc.as_u64().map(|nc| nc as IdxSize)
+                        })
+                        .collect::<Vec<_>>();
+                    (
+                        stats_values(&self.files, name, dtype, |s| &s.min_values)?,
+                        stats_values(&self.files, name, dtype, |s| &s.max_values)?,
+                        null_counts,
+                    )
+                },
+            };
+            columns.push(
+                min.with_name(format_pl_smallstr!("{name}_min"))
+                    .into_column(),
+            );
+            columns.push(
+                max.with_name(format_pl_smallstr!("{name}_max"))
+                    .into_column(),
+            );
+            columns.push(
+                IdxCa::from_iter_options(format_pl_smallstr!("{name}_nc"), null_counts.into_iter())
+                    .into_column(),
+            );
+        }
+        DataFrame::new_with_height(height, columns)
+    }
+
+    /// The schema of the partition columns.
+    pub fn partition_schema(&self) -> PolarsResult<Schema> {
+        self.metadata
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let dtype = self.metadata.schema.try_get(name)?;
+                Ok(Field::new(name.clone(), dtype.clone()))
+            })
+            .collect()
+    }
+}
+
+/// The minimum or maximum values of a column in the statistics of `files`.
+///
+/// Only numeric, string and temporal values are read; other values, and values that cannot be
+/// parsed, are unknown.
+fn stats_values(
+    files: &[DeltaFile],
+    name: &str,
+    dtype: &DataType,
+    values: fn(&DeltaFileStats) -> &Map<String, Value>,
+) -> PolarsResult<Series> {
+    let supported = dtype.is_primitive_numeric()
+        || matches!(
+            dtype,
+            DataType::String | DataType::Date | DataType::Datetime(_, _)
+        );
+    if !supported {
+        return Ok(Series::full_null(PlSmallStr::EMPTY, files.len(), dtype));
+    }
+    let values = files
+        .iter()
+        .map(|f| match values(f.stats.as_ref()?).get(name)? {
+            Value::String(s) => Some(s.clone()),
+            value @ Value::Number(_) => Some(value.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    Series::new(PlSmallStr::EMPTY, values).cast(dtype)
+}
+
+/// A Delta Lake table, identified by the directory that contains its `_delta_log`.
+#[derive(Clone)]
+pub struct DeltaTable {
+    pub(super) store: TableStore,
+}
+
+impl DeltaTable {
+    pub fn open(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        Ok(Self {
+            store: TableStore::new(uri, cloud_options)?,
+        })
+    }
+
+    /// Replay the transaction log up to `version`.
+    pub fn snapshot(&self, version: DeltaVersion) -> PolarsResult<DeltaSnapshot> {
+        self.try_snapshot(version)?.ok_or_else(|| {
+            polars_err!(
+                ComputeError: "'{}' is not a Delta table: the Delta log does not contain any commits",
+                self.store.uri()
+            )
+        })
+    }
+
+    /// Replay the transaction log up to `version`, returns `None` if the table does not exist.
+    pub fn try_snapshot(&self, version: DeltaVersion) -> PolarsResult<Option<DeltaSnapshot>> {
+        let log = LogListing::new(self.store.list_log()?);
+
+        let Some(latest) = log
+            .commits
+            .keys()
+            .chain(log.checkpoints.keys())
+            .max()
+            .copied()
+        else {
+            return Ok(None);
+        };
+        let version = match version {
+            DeltaVersion::Latest => latest,
+            DeltaVersion::Version(version) => {
+                polars_ensure!(
+                    (0..=latest).contains(&version),
+                    ComputeError: "Delta table version {} does not exist, the latest version is {}",
+                    version, latest
+                );
+                version
+            },
+            DeltaVersion::Timestamp(timestamp) => log
+                .commits
+                .iter()
+                .rev()
+                .find(|(_, file)| file.last_modified <= timestamp)
+                .map(|(version, _)| *version)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "no Delta table version was committed at or before timestamp {}",
+                        timestamp
+                    )
+                })?,
+        };
+
+        let mut replay = Replay::default();
+        let checkpoint = log.checkpoints.range(..=version).next_back();
+        if let Some((_, parts)) = checkpoint {
+            for part in parts {
+                let bytes = self.store.read(&format!("{LOG_DIR}/{part}"))?;
+                for action in read_checkpoint_actions(bytes)? {
+                    replay.apply(action, true)?;
+                }
+            }
+        }
+
+        let first_commit = checkpoint.map_or(0, |(v, _)| v + 1);
+        for v in first_commit..=version {
+            let commit = log.commits.get(&v).ok_or_else(
+                || polars_err!(ComputeError: "commit {} is missing from the Delta log", v),
+            )?;
+            let bytes = self.store.read(&format!("{LOG_DIR}/{}", commit.name))?;
+            for action in parse_json_actions(&bytes)? {
+                replay.apply(action, false)?;
+            }
+        }
+
+        replay.finish(version, self.store.clone()).map(Some)
+    }
+}
+
+/// The commits and complete checkpoints in the `_delta_log` directory, by version.
+struct LogListing {
+    commits: BTreeMap<i64, LogFile>,
+    checkpoints: BTreeMap<i64, Vec<String>>,
+}
+
+impl LogListing {
+    fn new(files: Vec<LogFile>) -> Self {
+        let mut commits = BTreeMap::new();
+        // Checkpoint parts by version, with the total number of parts.
+        let mut checkpoint_parts: BTreeMap<i64, (usize, Vec<(usize, String)>)> = BTreeMap::new();
+
+        for file in files {
+            let Some(version) = file
+                .name
+                .get(..20)
+                .filter(|v| v.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|v| v.parse::<i64>().ok())
+            else {
+                continue;
+            };
+
+            match &file.name[20..] {
+                ".json" => {
+                    commits.insert(version, file);
+                },
+                ".checkpoint.parquet" => {
+                    checkpoint_parts.insert(version, (1, vec![(1, file.name)]));
+                },
+                rest => {
+                    // Multi-part checkpoints: `<version>.checkpoint.<part>.<parts>.parquet`.
+                    let parts = rest
+                        .strip_prefix(".checkpoint.")
+                        .and_then(|v| v.strip_suffix(".parquet"))
+                        .and_then(|v| v.split_once('.'))
+                        .and_then(|(part, n_parts)| {
+                            Some((part.parse::<usize>().ok()?, n_parts.parse::<usize>().ok()?))
+                        });
+                    if let Some((part, n_parts)) = parts {
+                        let entry = checkpoint_parts
+                            .entry(version)
+                            .or_insert_with(|| (n_parts, vec![]));
+                        if entry.0 == n_parts {
+                            entry.1.push((part, file.name));
+                        }
+                    }
+                },
+            }
+        }
+
+        let checkpoints = checkpoint_parts
+            .into_iter()
+            .filter(|(_, (n_parts, parts))| parts.len() == *n_parts)
+            .map(|(version, (_, mut parts))| {
+                parts.sort_unstable();
+                (version, parts.into_iter().map(|(_, name)| name).collect())
+            })
+            .collect();
+
+        Self {
+            commits,
+            checkpoints,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LogAction {
+    pub add: Option<AddAction>,
+    pub remove: Option<RemoveAction>,
+    pub meta_data: Option<MetadataAction>,
+    pub protocol: Option<DeltaProtocol>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AddAction {
+    pub path: String,
+    #[serde(default, deserialize_with = "deserialize_partition_values")]
+    partition_values: PlHashMap<String, Option<String>>,
+    size: i64,
+    #[serde(default)]
+    modification_time: i64,
+    stats: Option<String>,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RemoveAction {
+    pub path: String,
+    deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct MetadataAction {
+    id: String,
+    name: Option<String>,
+    description: Option<String>,
+    schema_string: String,
+    #[serde(default)]
+    partition_columns: Vec<String>,
+    #[serde(default, deserialize_with = "deserialize_partition_values")]
+    configuration: PlHashMap<String, Option<String>>,
+    created_time: Option<i64>,
+}
+
+/// Maps are serialized as JSON objects in commits, but as a list of key/value structs in
+/// checkpoints.
+fn deserialize_partition_values<'de, D>(
+    deserializer: D,
+) -> Result<PlHashMap<String, Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct KeyValue {
+        key: String,
+        value: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringMap {
+        Object(PlHashMap<String, Option<String>>),
+        List(Vec<KeyValue>),
+    }
+
+    Ok(match Option::<StringMap>::deserialize(deserializer)? {
+        None => PlHashMap::new(),
+        Some(StringMap::Object(map)) => map,
+        Some(StringMap::List(list)) => list.into_iter().map(|kv| (kv.key, kv.value)).collect(),
+    })
+}
+
+pub(super) fn parse_json_actions(bytes: &[u8]) -> PolarsResult<Vec<LogAction>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(|line| serde_json::from_slice(line).map_err(to_compute_err))
+        .collect()
+}
+
+fn read_checkpoint_actions(bytes: Vec<u8>) -> PolarsResult<Vec<LogAction>> {
+    let mut reader = ParquetReader::new(Cursor::new(bytes));
+    let schema = reader.schema()?;
+    let columns = ["add", "remove", "metaData", "protocol"]
+        .into_iter()
+        .filter(|name| schema.contains(name))
+        .map(String::from)
+        .collect();
+    let mut df = reader.with_columns(Some(columns)).finish()?;
+
+    // Go through JSON, so that checkpoints and commits share the same deserialization.
+    let mut json = vec![];
+    JsonWriter::new(&mut json)
+        .with_json_format(JsonFormat::JsonLines)
+        .finish(&mut df)?;
+    parse_json_actions(&json)
+}
+
+#[derive(Default)]
+struct Replay {
+    protocol: Option<DeltaProtocol>,
+    metadata: Option<MetadataAction>,
+    /// Live files by `(path, deletion vector id)`, with the order in which they were added.
+    files: PlHashMap<(String, Option<String>), (usize, AddAction)>,
+    n_added: usize,
+}
+
+impl Replay {
+    fn apply(&mut self, action: LogAction, is_checkpoint: bool) -> PolarsResult<()> {
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(add) = action.add {
+            let key = (
+                decode_path(&add.path)?,
+                add.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+            );
+            self.files.insert(key, (self.n_added, add));
+            self.n_added += 1;
+        }
+        // The removes in a checkpoint are tombstones of files that are no longer part of the
+        // table.
+        if let Some(remove) = action.remove.filter(|_| !is_checkpoint) {
+            let key = (
+                decode_path(&remove.path)?,
+                remove.deletion_vector.as_ref().map(|dv| dv.unique_id()),
+            );
+            self.files.remove(&key);
+        }
+        Ok(())
+    }
+
+    fn finish(self, version: i64, store: TableStore) -> PolarsResult<DeltaSnapshot> {
+        let protocol = self.protocol.ok_or_else(
+            || polars_err!(ComputeError: "the Delta log does not contain a protocol action"),
+        )?;
+        let metadata = self.metadata.ok_or_else(
+            || polars_err!(ComputeError: "the Delta log does not contain a metaData action"),
+        )?;
+
+        polars_ensure!(
+            protocol.min_reader_version <= 3,
+            ComputeError: "Delta reader version {} is not supported", protocol.min_reader_version
+        );
+        for feature in protocol.reader_features.iter().flatten() {
+            polars_ensure!(
+                SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+                ComputeError: "Delta reader feature '{}' is not supported", feature
+            );
+        }
+        let configuration = metadata
+            .configuration
+            .into_iter()
+            .filter_map(|(k, v)| Some((k, v?)))
+            .collect::<BTreeMap<_, _>>();
+        if let Some(mode) = configuration.get("delta.columnMapping.mode") {
+            polars_ensure!(
+                mode == "none",
+                ComputeError: "Delta column mapping mode '{}' is not supported", mode
+            );
+        }
+
+        let schema = delta_schema_to_schema(&metadata.schema_string)?;
+        let partition_columns = metadata
+            .partition_columns
+            .into_iter()
+            .map(PlSmallStr::from)
+            .collect::<Vec<_>>();
+        for name in &partition_columns {
+            if !schema.contains(name) {
+                polars_bail!(ColumnNotFound: "partition column '{}' is not in the table schema", name)
+            }
+        }
+
+        let mut files = self.files.into_iter().collect::<Vec<_>>();
+        files.sort_unstable_by_key(|(_, (order, _))| *order);
+        let files = files
+            .into_iter()
+            .map(|((path, _), (_, mut add))| {
+                let partition_values = partition_columns
+                    .iter()
+                    .map(|name| add.partition_values.remove(name.as_str()).flatten())
+                    .collect();
+                let stats = add
+                    .stats
+                    .as_deref()
+                    .map(|stats| serde_json::from_str(stats).map_err(to_compute_err))
+                    .transpose()?;
+                Ok(DeltaFile {
+                    path,
+                    partition_values,
+                    size: add.size,
+                    modification_time: add.modification_time,
+                    stats,
+                    deletion_vector: add.deletion_vector,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(DeltaSnapshot {
+            version,
+            protocol,
+            metadata: DeltaMetadata {
+                id: metadata.id,
+                name: metadata.name,
+                description: metadata.description,
+                schema: Arc::new(schema),
+                partition_columns,
+                configuration,
+                created_time: metadata.created_time,
+            },
+            files,
+            store,
+        })
+    }
+}
+
+pub(super) fn decode_path(path: &str) -> PolarsResult<String> {
+    percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map(|p| p.into_owned())
+        .map_err(to_compute_err)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write_commit(root: &std::path::Path, version: i64, actions: &[&str]) {
+        let dir = root.join(LOG_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{version:020}.json")), actions.join("\n")).unwrap();
+    }
+
+    #[test]
+    fn test_log_listing() {
+        let files = [
+            "00000000000000000000.json",
+            "00000000000000000001.json",
+            "00000000000000000001.checkpoint.parquet",
+            "00000000000000000002.checkpoint.0000000002.0000000002.parquet",
+            "00000000000000000003.checkpoint.0000000002.0000000002.parquet",
+            "00000000000000000003.checkpoint.0000000001.0000000002.parquet",
+            "_last_checkpoint",
+        ]
+        .map(|name| LogFile {
+            name: name.to_string(),
+            last_modified: 0,
+        });
+        let listing = LogListing::new(files.into());
+
+        assert_eq!(listing.commits.keys().copied().collect::<Vec<_>>(), [0, 1]);
+        assert_eq!(
+            listing.checkpoints.into_iter().collect::<Vec<_>>(),
+            [
+                (
+                    1,
+                    vec!["00000000000000000001.checkpoint.parquet".to_string()]
+                ),
+                (
+                    3,
+                    vec![
+                        "00000000000000000003.checkpoint.0000000001.0000000002.parquet".to_string(),
+                        "00000000000000000003.checkpoint.0000000002.0000000002.parquet".to_string(),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let schema_string = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}"#;
+        write_commit(
+            dir.path(),
+            0,
+            &[
+                r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#,
+                &format!(
+                    r#"{{"metaData":{{"id":"id","format":{{"provider":"parquet","options":{{}}}},"schemaString":"{schema_string}","partitionColumns":["p"],"configuration":{{}}}}}}"#
+                ),
+                r#"{"add":{"path":"p=x/a.parquet","partitionValues":{"p":"x"},"size":1,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":3}"}}"#,
+                r#"{"add":{"path":"p=y%20z/b.parquet","partitionValues":{"p":"y z"},"size":1,"modificationTime":0,"dataChange":true}}"#,
+            ],
+        );
+        write_commit(
+            dir.path(),
+            1,
+            &[
+                r#"{"commitInfo":{"timestamp":0}}"#,
+                r#"{"remove":{"path":"p=x/a.parquet","dataChange":true}}"#,
+                r#"{"add":{"path":"c.parquet","partitionValues":{"p":null},"size":1,"modificationTime":0,"dataChange":true}}"#,
+            ],
+        );
+
+        let table = DeltaTable::open(dir.path().to_str().unwrap(), None).unwrap();
+
+        let snapshot = table.snapshot(DeltaVersion::Latest).unwrap();
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.metadata.partition_columns, ["p"]);
+        let files = snapshot
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.partition_values.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            [
+                ("p=y z/b.parquet", vec![Some("y z".to_string())]),
+                ("c.parquet", vec![None]),
+            ]
+        );
+
+        let snapshot = table.snapshot(DeltaVersion::Version(0)).unwrap();
+        assert_eq!(snapshot.files.len(), 2);
+        assert_eq!(snapshot.files[0].path, "p=x/a.parquet");
+        assert_eq!(
+            snapshot.files[0].stats.as_ref().unwrap().num_records,
+            Some(3)
+        );
+
+        assert!(table.snapshot(DeltaVersion::Version(2)).is_err());
+        assert!(table.snapshot(DeltaVersion::Timestamp(0)).is_err());
+    }
+}
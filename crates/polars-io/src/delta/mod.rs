@@ -0,0 +1,20 @@
+//! Reading and writing [Delta Lake](https://delta.io) tables.
+//!
+//! A snapshot of a table is reconstructed by replaying its `_delta_log`, starting from the
+//! latest checkpoint. The data files of the snapshot are regular parquet files that can be
+//! read with the parquet reader, after filtering out the rows in their deletion vectors.
+//!
+//! Writes add new parquet files and then atomically create the next commit in the log.
+mod deletion_vector;
+mod log;
+mod schema;
+mod store;
+mod write;
+
+pub use deletion_vector::DeletionVectorDescriptor;
+pub use log::{
+    DeltaFile, DeltaFileStats, DeltaMetadata, DeltaProtocol, DeltaSnapshot, DeltaTable,
+    DeltaVersion,
+};
+pub use schema::delta_schema_to_schema;
+pub use write::{DeltaOperation, DeltaTransaction};
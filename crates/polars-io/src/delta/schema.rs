@@ -0,0 +1,223 @@
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_err, to_compute_err, PolarsResult};
+use serde_json::Value;
+
+/// Convert the `schemaString` of a Delta table to a polars [`Schema`].
+///
+/// Delta `timestamp` columns are adjusted to UTC and map to a `Datetime` in UTC, while
+/// `timestamp_ntz` maps to a `Datetime` without a time zone. Maps are read as a list of
+/// `key`/`value` structs.
+pub fn delta_schema_to_schema(schema_string: &str) -> PolarsResult<Schema> {
+    let value: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+    match parse_type(&value)? {
+        DataType::Struct(fields) => Ok(fields.into_iter().collect()),
+        _ => polars_bail!(ComputeError: "the schema of a Delta table must be a struct"),
+    }
+}
+
+fn parse_type(value: &Value) -> PolarsResult<DataType> {
+    let get = |key: &str| {
+        value
+            .get(key)
+            .ok_or_else(|| polars_err!(ComputeError: "Delta type is missing the '{}' key", key))
+    };
+
+    let dtype = match value {
+        Value::String(name) => parse_primitive_type(name)?,
+        Value::Object(_) => match get("type")?.as_str() {
+            Some("array") => DataType::List(Box::new(parse_type(get("elementType")?)?)),
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("key"), parse_type(get("keyType")?)?),
+                Field::new(
+                    PlSmallStr::from_static("value"),
+                    parse_type(get("valueType")?)?,
+                ),
+            ]))),
+            Some("struct") => {
+                let fields = get("fields")?
+                    .as_array()
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "Delta struct fields must be an array"),
+                    )?
+                    .iter()
+                    .map(|field| {
+                        let name = field.get("name").and_then(Value::as_str).ok_or_else(
+                            || polars_err!(ComputeError: "Delta struct field is missing a name"),
+                        )?;
+                        let dtype = parse_type(field.get("type").unwrap_or(&Value::Null))?;
+                        Ok(Field::new(name.into(), dtype))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataType::Struct(fields)
+            },
+            _ => polars_bail!(ComputeError: "unsupported Delta type: {}", value),
+        },
+        _ => polars_bail!(ComputeError: "unsupported Delta type: {}", value),
+    };
+    Ok(dtype)
+}
+
+fn parse_primitive_type(name: &str) -> PolarsResult<DataType> {
+    let dtype = match name {
+        "boolean" => DataType::Boolean,
+        "byte" => DataType::Int8,
+        "short" => DataType::Int16,
+        "integer" => DataType::Int32,
+        "long" => DataType::Int64,
+        "float" => DataType::Float32,
+        "double" => DataType::Float64,
+        "string" => DataType::String,
+        "binary" => DataType::Binary,
+        "date" => DataType::Date,
+        "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+        "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+        name if name.starts_with("decimal") => {
+            let parsed = name
+                .strip_prefix("decimal(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| v.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)));
+            let Some((precision, scale)) = parsed else {
+                polars_bail!(ComputeError: "invalid Delta decimal type '{}'", name)
+            };
+            #[cfg(feature = "dtype-decimal")]
+            {
+                DataType::Decimal(Some(precision), Some(scale))
+            }
+            #[cfg(not(feature = "dtype-decimal"))]
+            {
+                let _: (usize, usize) = (precision, scale);
+                polars_bail!(ComputeError: "reading Delta decimal columns requires the 'dtype-decimal' feature")
+            }
+        },
+        name => polars_bail!(ComputeError: "unsupported Delta type '{}'", name),
+    };
+    Ok(dtype)
+}
+
+/// The data type in which a column of type `dtype` is stored in a Delta table.
+///
+/// Delta has no unsigned integers, so these are widened to the next signed integer type, and
+/// timestamps are always stored in microseconds.
+pub(super) fn to_delta_dtype(name: &str, dtype: &DataType) -> PolarsResult<DataType> {
+    let dtype = match dtype {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::String
+        | DataType::Binary
+        | DataType::Date => dtype.clone(),
+        DataType::UInt8 => DataType::Int16,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 => DataType::Int64,
+        DataType::Datetime(_, tz) => DataType::Datetime(
+            TimeUnit::Microseconds,
+            tz.as_ref().map(|_| PlSmallStr::from_static("UTC")),
+        ),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(precision, scale) => {
+            DataType::Decimal(Some(precision.unwrap_or(38)), Some(scale.unwrap_or(0)))
+        },
+        DataType::List(inner) => DataType::List(Box::new(to_delta_dtype(name, inner)?)),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name.clone(), to_delta_dtype(name, &f.dtype)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        dtype => polars_bail!(
+            ComputeError: "column '{}' has type {}, which cannot be written to a Delta table",
+            name, dtype
+        ),
+    };
+    Ok(dtype)
+}
+
+/// Convert a polars [`Schema`] to the `schemaString` of a Delta table.
+///
+/// The schema must only contain types returned by [`to_delta_dtype`].
+pub(super) fn schema_to_delta_schema(schema: &Schema) -> PolarsResult<String> {
+    let value = struct_type(schema.iter().map(|(name, dtype)| (name.as_str(), dtype)))?;
+    serde_json::to_string(&value).map_err(to_compute_err)
+}
+
+fn struct_type<'a>(fields: impl Iterator<Item = (&'a str, &'a DataType)>) -> PolarsResult<Value> {
+    let fields = fields
+        .map(|(name, dtype)| {
+            Ok(serde_json::json!({
+                "name": name,
+                "type": to_type_value(dtype)?,
+                "nullable": true,
+                "metadata": {},
+            }))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(serde_json::json!({"type": "struct", "fields": fields}))
+}
+
+fn to_type_value(dtype: &DataType) -> PolarsResult<Value> {
+    let name = match dtype {
+        DataType::Boolean => "boolean",
+        DataType::Int8 => "byte",
+        DataType::Int16 => "short",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "long",
+        DataType::Float32 => "float",
+        DataType::Float64 => "double",
+        DataType::String => "string",
+        DataType::Binary => "binary",
+        DataType::Date => "date",
+        DataType::Datetime(TimeUnit::Microseconds, Some(_)) => "timestamp",
+        DataType::Datetime(TimeUnit::Microseconds, None) => "timestamp_ntz",
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(Some(precision), Some(scale)) => {
+            return Ok(Value::String(format!("decimal({precision},{scale})")))
+        },
+        DataType::List(inner) => {
+            return Ok(serde_json::json!({
+                "type": "array",
+                "elementType": to_type_value(inner)?,
+                "containsNull": true,
+            }))
+        },
+        DataType::Struct(fields) => {
+            return struct_type(fields.iter().map(|f| (f.name.as_str(), &f.dtype)))
+        },
+        dtype => polars_bail!(ComputeError: "cannot convert type {} to a Delta type", dtype),
+    };
+    Ok(Value::String(name.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_delta_schema_to_schema() {
+        let schema_string = r#"{"type":"struct","fields":[
+            {"name":"id","type":"long","nullable":true,"metadata":{}},
+            {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+            {"name":"ts","type":"timestamp_ntz","nullable":true,"metadata":{}}
+        ]}"#;
+        let schema = delta_schema_to_schema(schema_string).unwrap();
+
+        let expected = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+            Field::new(
+                "ts".into(),
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ),
+        ]);
+        assert_eq!(schema, expected);
+
+        let roundtrip = delta_schema_to_schema(&schema_to_delta_schema(&schema).unwrap()).unwrap();
+        assert_eq!(roundtrip, expected);
+    }
+}
@@ -0,0 +1,243 @@
+use std::path::PathBuf;
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+use crate::cloud::CloudOptions;
+#[cfg(feature = "cloud")]
+use crate::cloud::{
+    build_object_store, object_path_from_str, CloudLocation, ObjectStorePath, PolarsObjectStore,
+};
+use crate::path_utils::{is_cloud_url, resolve_homedir};
+
+pub(super) const LOG_DIR: &str = "_delta_log";
+
+/// A file in the `_delta_log` directory.
+pub(super) struct LogFile {
+    pub name: String,
+    /// Milliseconds since the epoch.
+    pub last_modified: i64,
+}
+
+/// Access to the files of a Delta table, on the local filesystem or in an object store.
+#[derive(Clone)]
+pub(super) enum TableStore {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        uri: String,
+        store: PolarsObjectStore,
+        prefix: String,
+        cloud_options: Option<CloudOptions>,
+    },
+}
+
+impl TableStore {
+    pub(super) fn new(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let uri = uri.trim_end_matches('/');
+        if is_cloud_url(uri) {
+            polars_core::error::feature_gated!("cloud", {
+                let (CloudLocation { prefix, .. }, store) = crate::pl_async::get_runtime()
+                    .block_on_potential_spawn(build_object_store(uri, cloud_options, false))?;
+                Ok(Self::Cloud {
+                    uri: uri.to_string(),
+                    store,
+                    prefix: prefix.trim_end_matches('/').to_string(),
+                    cloud_options: cloud_options.cloned(),
+                })
+            })
+        } else {
+            let _ = cloud_options;
+            Ok(Self::Local(resolve_homedir(&uri)))
+        }
+    }
+
+    /// The path or URI of the table root.
+    pub(super) fn uri(&self) -> String {
+        match self {
+            Self::Local(root) => root.to_string_lossy().into_owned(),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { uri, .. } => uri.clone(),
+        }
+    }
+
+    /// The full path or URI of a file in the table.
+    pub(super) fn join(&self, relative_path: &str) -> String {
+        match self {
+            Self::Local(root) => root.join(relative_path).to_string_lossy().into_owned(),
+            #[cfg(feature = "cloud")]
+            Self::Cloud { uri, .. } => format!("{uri}/{relative_path}"),
+        }
+    }
+
+    #[cfg(feature = "cloud")]
+    fn object_path(prefix: &str, relative_path: &str) -> PolarsResult<ObjectStorePath> {
+        if prefix.is_empty() {
+            object_path_from_str(relative_path)
+        } else {
+            object_path_from_str(&format!("{prefix}/{relative_path}"))
+        }
+    }
+
+    /// List the files in the `_delta_log` directory.
+    pub(super) fn list_log(&self) -> PolarsResult<Vec<LogFile>> {
+        match self {
+            Self::Local(root) => {
+                let dir = root.join(LOG_DIR);
+                let entries = match std::fs::read_dir(&dir) {
+                    Ok(entries) => entries,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                    Err(e) => polars_bail!(
+                        ComputeError: "cannot read '{}': {}", dir.display(), e
+                    ),
+                };
+
+                let mut files = vec![];
+                for entry in entries {
+                    let entry = entry?;
+                    let metadata = entry.metadata()?;
+                    if !metadata.is_file() {
+                        continue;
+                    }
+                    let last_modified = metadata
+                        .modified()?
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |d| d.as_millis() as i64);
+                    files.push(LogFile {
+                        name: entry.file_name().to_string_lossy().into_owned(),
+                        last_modified,
+                    });
+                }
+                Ok(files)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                use futures::TryStreamExt;
+
+                let log_prefix = Self::object_path(prefix, LOG_DIR)?;
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    let store = store.to_dyn_object_store().await;
+                    let objects = store
+                        .list(Some(&log_prefix))
+                        .try_collect::<Vec<_>>()
+                        .await?;
+                    Ok(objects
+                        .into_iter()
+                        .filter_map(|meta| {
+                            Some(LogFile {
+                                name: meta.location.filename()?.to_string(),
+                                last_modified: meta.last_modified.timestamp_millis(),
+                            })
+                        })
+                        .collect())
+                })
+            },
+        }
+    }
+
+    /// Read a file relative to the table root.
+    pub(super) fn read(&self, relative_path: &str) -> PolarsResult<Vec<u8>> {
+        match self {
+            Self::Local(root) => {
+                let path = root.join(relative_path);
+                std::fs::read(&path).map_err(
+                    |e| polars_err!(ComputeError: "cannot read '{}': {}", path.display(), e),
+                )
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                let path = Self::object_path(prefix, relative_path)?;
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    let store = store.to_dyn_object_store().await;
+                    let bytes = store.get(&path).await?.bytes().await?;
+                    Ok(bytes.to_vec())
+                })
+            },
+        }
+    }
+
+    /// Write a file relative to the table root, replacing an existing file.
+    pub(super) fn write(&self, relative_path: &str, bytes: Vec<u8>) -> PolarsResult<()> {
+        match self {
+            Self::Local(root) => {
+                let path = root.join(relative_path);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, bytes).map_err(
+                    |e| polars_err!(ComputeError: "cannot write '{}': {}", path.display(), e),
+                )
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                let path = Self::object_path(prefix, relative_path)?;
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    let store = store.to_dyn_object_store().await;
+                    store.put(&path, bytes::Bytes::from(bytes).into()).await?;
+                    Ok(())
+                })
+            },
+        }
+    }
+
+    /// Atomically create a file relative to the table root. Returns `false` without writing
+    /// anything if the file already exists.
+    pub(super) fn create_new(&self, relative_path: &str, bytes: Vec<u8>) -> PolarsResult<bool> {
+        match self {
+            Self::Local(root) => {
+                // Write to a temporary file first and hard link it into place, so that the file
+                // never becomes visible with partial contents.
+                let path = root.join(relative_path);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let tmp_path = path.with_file_name(format!(".{}.tmp", uuid::Uuid::new_v4()));
+                std::fs::write(&tmp_path, bytes).map_err(
+                    |e| polars_err!(ComputeError: "cannot write '{}': {}", tmp_path.display(), e),
+                )?;
+                let linked = std::fs::hard_link(&tmp_path, &path);
+                let _ = std::fs::remove_file(&tmp_path);
+                match linked {
+                    Ok(()) => Ok(true),
+                    Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+                    Err(e) => polars_bail!(
+                        ComputeError: "cannot create '{}': {}", path.display(), e
+                    ),
+                }
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix, .. } => {
+                use object_store::{PutMode, PutOptions};
+
+                let path = Self::object_path(prefix, relative_path)?;
+                crate::pl_async::get_runtime().block_on_potential_spawn(async {
+                    let store = store.to_dyn_object_store().await;
+                    let options = PutOptions {
+                        mode: PutMode::Create,
+                        ..Default::default()
+                    };
+                    match store
+                        .put_opts(&path, bytes::Bytes::from(bytes).into(), options)
+                        .await
+                    {
+                        Ok(_) => Ok(true),
+                        Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+                        Err(e) => Err(e.into()),
+                    }
+                })
+            },
+        }
+    }
+
+    /// Read a file given by an absolute path or URI, using the credentials of `self`.
+    pub(super) fn read_absolute(uri: &str, store: &TableStore) -> PolarsResult<Vec<u8>> {
+        let (parent, name) = uri
+            .rsplit_once('/')
+            .ok_or_else(|| polars_err!(ComputeError: "invalid absolute path '{}'", uri))?;
+        let cloud_options = match store {
+            Self::Local(_) => None,
+            #[cfg(feature = "cloud")]
+            Self::Cloud { cloud_options, .. } => cloud_options.as_ref(),
+        };
+        TableStore::new(parent, cloud_options)?.read(name)
+    }
+}
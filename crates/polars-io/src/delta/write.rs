@@ -0,0 +1,684 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use percent_encoding::{AsciiSet, CONTROLS};
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, to_compute_err, PolarsResult};
+use serde_json::{json, Map, Value};
+
+use super::log::{
+    decode_path, parse_json_actions, DeltaFile, DeltaFileStats, DeltaProtocol, DeltaSnapshot,
+    DeltaTable, DeltaVersion,
+};
+use super::schema::{schema_to_delta_schema, to_delta_dtype};
+use super::store::LOG_DIR;
+use crate::parquet::write::ParquetWriteOptions;
+
+/// Writer features that are either implemented here or that do not affect writers.
+///
+/// `columnMapping` only affects writers if a mapping mode other than `none` is set, and the
+/// snapshots of such tables fail to load, so they are never written to.
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// How many versions a commit tries before giving up when other writers keep committing first.
+const MAX_COMMIT_ATTEMPTS: usize = 100;
+
+/// The directory name of a partition with a null value, as used by Hive.
+const NULL_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Characters that are escaped in the directory names of partitions.
+const PARTITION_ESCAPE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'\'')
+    .add(b'*')
+    .add(b'/')
+    .add(b':')
+    .add(b'=')
+    .add(b'?')
+    .add(b'\\')
+    .add(b'[')
+    .add(b']')
+    .add(b'^')
+    .add(b'{')
+    .add(b'}');
+
+/// Characters that are escaped in the paths of add and remove actions, which are URIs.
+const PATH_ESCAPE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The kind of write to a Delta table. This determines with which concurrent commits it
+/// conflicts.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeltaOperation {
+    /// Add data to the table.
+    Append,
+    /// Replace all data of the table. This may also change the schema and the partitioning.
+    Overwrite,
+    /// Replace the rows that match a predicate. The predicate is only recorded in the commit.
+    OverwriteWhere { predicate: String },
+}
+
+impl DeltaOperation {
+    /// Whether the result of the operation depends on the data in the table.
+    fn reads_table(&self) -> bool {
+        !matches!(self, Self::Append)
+    }
+}
+
+/// A write to a Delta table that is committed atomically.
+///
+/// Data files are written as soon as data is added to the transaction, but they only become
+/// part of the table when the transaction is committed. If another writer commits first, the
+/// commit is retried on top of the new version unless the two commits conflict.
+pub struct DeltaTransaction {
+    table: DeltaTable,
+    snapshot: Option<DeltaSnapshot>,
+    operation: DeltaOperation,
+    /// The schema of the table after the transaction, including the partition columns.
+    schema: SchemaRef,
+    partition_columns: Vec<PlSmallStr>,
+    parquet_options: ParquetWriteOptions,
+    protocol: Option<DeltaProtocol>,
+    metadata: Option<Value>,
+    add: Vec<DeltaFile>,
+    remove: Vec<DeltaFile>,
+}
+
+impl DeltaTransaction {
+    /// Start a transaction that writes data with the given schema to the latest version of
+    /// `table`.
+    ///
+    /// A table that does not exist yet is created, partitioned by `partition_by`. For an existing
+    /// table `partition_by` must be empty or match the partitioning of the table, and the schema
+    /// must match the schema of the table, unless the table is overwritten.
+    pub fn new(
+        table: &DeltaTable,
+        operation: DeltaOperation,
+        schema: &Schema,
+        partition_by: &[PlSmallStr],
+        parquet_options: ParquetWriteOptions,
+    ) -> PolarsResult<Self> {
+        let schema = schema
+            .iter()
+            .map(|(name, dtype)| Ok(Field::new(name.clone(), to_delta_dtype(name, dtype)?)))
+            .collect::<PolarsResult<Schema>>()?;
+        for name in partition_by {
+            let dtype = schema.try_get(name)?;
+            polars_ensure!(
+                !dtype.is_nested(),
+                ComputeError: "cannot partition a Delta table by column '{}' of type {}", name, dtype
+            );
+        }
+
+        let mut txn = Self {
+            table: table.clone(),
+            snapshot: None,
+            operation,
+            schema: Arc::new(schema),
+            partition_columns: partition_by.to_vec(),
+            parquet_options,
+            protocol: None,
+            metadata: None,
+            add: vec![],
+            remove: vec![],
+        };
+
+        let Some(snapshot) = table.try_snapshot(DeltaVersion::Latest)? else {
+            txn.set_metadata(None);
+            return Ok(txn);
+        };
+
+        check_writer_protocol(&snapshot.protocol)?;
+        let metadata = &snapshot.metadata;
+        if txn.operation.reads_table() {
+            polars_ensure!(
+                metadata.configuration.get("delta.appendOnly").map(String::as_str) != Some("true"),
+                ComputeError: "cannot overwrite data of the append-only Delta table '{}'",
+                table.store.uri()
+            );
+        }
+        if txn.partition_columns.is_empty() {
+            txn.partition_columns = metadata.partition_columns.clone();
+        }
+
+        let same_schema = txn.schema.len() == metadata.schema.len()
+            && txn
+                .schema
+                .iter()
+                .all(|(name, dtype)| metadata.schema.get(name) == Some(dtype));
+        let same_partitioning = txn.partition_columns == metadata.partition_columns;
+        if same_schema && same_partitioning {
+            // Keep the column order of the table.
+            txn.schema = metadata.schema.clone();
+        } else if txn.operation == DeltaOperation::Overwrite {
+            txn.set_metadata(Some(&snapshot));
+        } else {
+            polars_ensure!(
+                same_schema,
+                SchemaMismatch: "the schema of the data does not match the schema of the Delta table: expected {:?}, got {:?}",
+                metadata.schema, txn.schema
+            );
+            polars_bail!(
+                ComputeError: "the data is partitioned by {:?}, but the Delta table is partitioned by {:?}",
+                txn.partition_columns, metadata.partition_columns
+            );
+        }
+
+        if txn.operation == DeltaOperation::Overwrite {
+            txn.remove = snapshot.files.clone();
+        }
+        txn.snapshot = Some(snapshot);
+        Ok(txn)
+    }
+
+    /// The snapshot that the transaction is based on, `None` if the table is created.
+    pub fn snapshot(&self) -> Option<&DeltaSnapshot> {
+        self.snapshot.as_ref()
+    }
+
+    /// The schema of the table after the transaction, including the partition columns.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Write `df` to new data files, one per partition.
+    pub fn write(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let df = self.conform(df)?;
+        if self.partition_columns.is_empty() {
+            return self.write_file(df, vec![]);
+        }
+
+        for partition in df.partition_by_stable(self.partition_columns.clone(), true)? {
+            let partition_values = self
+                .partition_columns
+                .iter()
+                .map(|name| partition_value(partition.column(name)?))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let data = partition.drop_many(self.partition_columns.clone());
+            self.write_file(data, partition_values)?;
+        }
+        Ok(())
+    }
+
+    /// Remove a data file of the snapshot from the table.
+    pub fn remove(&mut self, file: &DeltaFile) {
+        self.remove.push(file.clone());
+    }
+
+    /// Commit the transaction, returns the version of the table that it created.
+    pub fn commit(self) -> PolarsResult<i64> {
+        let commit = self.commit_actions()?;
+        let mut version = self.snapshot.as_ref().map_or(0, |s| s.version + 1);
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            let path = format!("{LOG_DIR}/{version:020}.json");
+            if self.table.store.create_new(&path, commit.clone())? {
+                return Ok(version);
+            }
+            // Another writer committed this version first.
+            self.check_conflicts(&path)?;
+            version += 1;
+        }
+        polars_bail!(
+            ComputeError: "failed to commit to Delta table '{}' after {} attempts",
+            self.table.store.uri(), MAX_COMMIT_ATTEMPTS
+        )
+    }
+
+    /// Set a new metaData action and upgrade the protocol if the schema requires it.
+    fn set_metadata(&mut self, existing: Option<&DeltaSnapshot>) {
+        let needs_timestamp_ntz = self.schema.iter_values().any(contains_timestamp_ntz);
+        self.protocol = match existing {
+            None if needs_timestamp_ntz => Some(DeltaProtocol {
+                min_reader_version: 3,
+                min_writer_version: 7,
+                reader_features: Some(vec!["timestampNtz".to_string()]),
+                writer_features: Some(vec!["timestampNtz".to_string()]),
+            }),
+            None => Some(DeltaProtocol {
+                min_reader_version: 1,
+                min_writer_version: 2,
+                reader_features: None,
+                writer_features: None,
+            }),
+            Some(snapshot) if needs_timestamp_ntz => with_timestamp_ntz(&snapshot.protocol),
+            Some(_) => None,
+        };
+
+        let existing = existing.map(|s| &s.metadata);
+        let mut metadata = Map::new();
+        metadata.insert(
+            "id".into(),
+            json!(existing.map_or_else(|| uuid::Uuid::new_v4().to_string(), |m| m.id.clone())),
+        );
+        if let Some(name) = existing.and_then(|m| m.name.as_ref()) {
+            metadata.insert("name".into(), json!(name));
+        }
+        if let Some(description) = existing.and_then(|m| m.description.as_ref()) {
+            metadata.insert("description".into(), json!(description));
+        }
+        metadata.insert(
+            "format".into(),
+            json!({"provider": "parquet", "options": {}}),
+        );
+        // Infallible, the schema only contains types returned by `to_delta_dtype`.
+        metadata.insert(
+            "schemaString".into(),
+            json!(schema_to_delta_schema(&self.schema).unwrap()),
+        );
+        metadata.insert(
+            "partitionColumns".into(),
+            json!(self
+                .partition_columns
+                .iter()
+                .map(PlSmallStr::as_str)
+                .collect::<Vec<_>>()),
+        );
+        metadata.insert(
+            "configuration".into(),
+            json!(existing
+                .map(|m| m.configuration.clone())
+                .unwrap_or_default()),
+        );
+        metadata.insert(
+            "createdTime".into(),
+            json!(existing.and_then(|m| m.created_time).unwrap_or_else(now)),
+        );
+        self.metadata = Some(Value::Object(metadata));
+    }
+
+    /// Cast `df` to the schema of the table.
+    fn conform(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            df.width() == self.schema.len(),
+            SchemaMismatch: "the data has {} columns, but the Delta table has {}",
+            df.width(), self.schema.len()
+        );
+        let columns = self
+            .schema
+            .iter()
+            .map(|(name, dtype)| df.column(name)?.cast(dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(columns)
+    }
+
+    fn write_file(
+        &mut self,
+        mut df: DataFrame,
+        partition_values: Vec<Option<String>>,
+    ) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let mut path = String::new();
+        for (name, value) in self.partition_columns.iter().zip(&partition_values) {
+            let value = value.as_deref().map_or_else(
+                || NULL_PARTITION.into(),
+                |v| percent_encoding::utf8_percent_encode(v, PARTITION_ESCAPE).to_string(),
+            );
+            path.push_str(&format!(
+                "{}={}/",
+                percent_encoding::utf8_percent_encode(name, PARTITION_ESCAPE),
+                value
+            ));
+        }
+        path.push_str(&format!(
+            "part-{:05}-{}-c000.parquet",
+            self.add.len(),
+            uuid::Uuid::new_v4()
+        ));
+
+        let stats = file_stats(&df)?;
+        let mut bytes = vec![];
+        self.parquet_options.to_writer(&mut bytes).finish(&mut df)?;
+        let size = bytes.len() as i64;
+        self.table.store.write(&path, bytes)?;
+
+        self.add.push(DeltaFile {
+            path,
+            partition_values,
+            size,
+            modification_time: now(),
+            stats: Some(stats),
+            deletion_vector: None,
+        });
+        Ok(())
+    }
+
+    /// The newline-delimited JSON actions of the commit.
+    fn commit_actions(&self) -> PolarsResult<Vec<u8>> {
+        let timestamp = now();
+        let partition_columns = self
+            .partition_columns
+            .iter()
+            .map(PlSmallStr::as_str)
+            .collect::<Vec<_>>();
+        let mut operation_parameters = json!({
+            "mode": if self.operation == DeltaOperation::Append { "Append" } else { "Overwrite" },
+            "partitionBy": serde_json::to_string(&partition_columns).map_err(to_compute_err)?,
+        });
+        if let DeltaOperation::OverwriteWhere { predicate } = &self.operation {
+            operation_parameters["predicate"] = json!(predicate);
+        }
+
+        let mut actions = vec![json!({"commitInfo": {
+            "timestamp": timestamp,
+            "operation": "WRITE",
+            "operationParameters": operation_parameters,
+            "engineInfo": concat!("polars/", env!("CARGO_PKG_VERSION")),
+            "isBlindAppend": !self.operation.reads_table(),
+        }})];
+        if let Some(protocol) = &self.protocol {
+            actions.push(json!({ "protocol": protocol }));
+        }
+        if let Some(metadata) = &self.metadata {
+            actions.push(json!({ "metaData": metadata }));
+        }
+
+        let removed_partition_columns = self
+            .snapshot
+            .as_ref()
+            .map_or(&[][..], |s| &s.metadata.partition_columns);
+        for file in &self.remove {
+            let mut remove = json!({
+                "path": encode_path(&file.path),
+                "deletionTimestamp": timestamp,
+                "dataChange": true,
+                "extendedFileMetadata": true,
+                "partitionValues": partition_values_map(removed_partition_columns, &file.partition_values),
+                "size": file.size,
+            });
+            if let Some(dv) = &file.deletion_vector {
+                remove["deletionVector"] = json!(dv);
+            }
+            actions.push(json!({ "remove": remove }));
+        }
+        for file in &self.add {
+            let stats = file
+                .stats
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+                .map_err(to_compute_err)?;
+            actions.push(json!({"add": {
+                "path": encode_path(&file.path),
+                "partitionValues": partition_values_map(&self.partition_columns, &file.partition_values),
+                "size": file.size,
+                "modificationTime": file.modification_time,
+                "dataChange": true,
+                "stats": stats,
+            }}));
+        }
+
+        let mut out = vec![];
+        for action in actions {
+            serde_json::to_writer(&mut out, &action).map_err(to_compute_err)?;
+            out.push(b'\n');
+        }
+        Ok(out)
+    }
+
+    /// Check whether the commit at `path`, which was committed after our snapshot, conflicts
+    /// with this transaction.
+    fn check_conflicts(&self, path: &str) -> PolarsResult<()> {
+        for action in parse_json_actions(&self.table.store.read(path)?)? {
+            polars_ensure!(
+                action.meta_data.is_none() && action.protocol.is_none(),
+                ComputeError: "conflicting commit to Delta table '{}': the metadata of the table was changed concurrently",
+                self.table.store.uri()
+            );
+            if let Some(remove) = &action.remove {
+                let path = decode_path(&remove.path)?;
+                polars_ensure!(
+                    !self.operation.reads_table() && !self.remove.iter().any(|f| f.path == path),
+                    ComputeError: "conflicting commit to Delta table '{}': file '{}' was removed concurrently",
+                    self.table.store.uri(), path
+                );
+            }
+            if let Some(add) = &action.add {
+                polars_ensure!(
+                    !self.operation.reads_table(),
+                    ComputeError: "conflicting commit to Delta table '{}': file '{}' was added concurrently",
+                    self.table.store.uri(), add.path
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_writer_protocol(protocol: &DeltaProtocol) -> PolarsResult<()> {
+    match protocol.min_writer_version {
+        1 | 2 => {},
+        7 => {
+            for feature in protocol.writer_features.iter().flatten() {
+                polars_ensure!(
+                    SUPPORTED_WRITER_FEATURES.contains(&feature.as_str()),
+                    ComputeError: "Delta writer feature '{}' is not supported", feature
+                );
+            }
+        },
+        version => polars_bail!(ComputeError: "Delta writer version {} is not supported", version),
+    }
+    Ok(())
+}
+
+/// The protocol with the `timestampNtz` feature, or `None` if `protocol` already supports it.
+fn with_timestamp_ntz(protocol: &DeltaProtocol) -> Option<DeltaProtocol> {
+    let feature = "timestampNtz".to_string();
+    if protocol
+        .reader_features
+        .as_ref()
+        .is_some_and(|f| f.contains(&feature))
+    {
+        return None;
+    }
+
+    // Table features replace the features that are implied by the legacy protocol versions.
+    let mut reader_features = protocol.reader_features.clone().unwrap_or_else(|| {
+        if protocol.min_reader_version >= 2 {
+            vec!["columnMapping".to_string()]
+        } else {
+            vec![]
+        }
+    });
+    let mut writer_features = protocol.writer_features.clone().unwrap_or_else(|| {
+        if protocol.min_writer_version >= 2 {
+            // Column invariants are not enforced, so they are not declared either.
+            vec!["appendOnly".to_string()]
+        } else {
+            vec![]
+        }
+    });
+    reader_features.push(feature.clone());
+    writer_features.push(feature);
+    Some(DeltaProtocol {
+        min_reader_version: 3,
+        min_writer_version: 7,
+        reader_features: Some(reader_features),
+        writer_features: Some(writer_features),
+    })
+}
+
+fn contains_timestamp_ntz(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Datetime(_, None) => true,
+        DataType::List(inner) => contains_timestamp_ntz(inner),
+        DataType::Struct(fields) => fields.iter().any(|f| contains_timestamp_ntz(&f.dtype)),
+        _ => false,
+    }
+}
+
+/// The statistics of a data file. Nested columns have no statistics.
+fn file_stats(df: &DataFrame) -> PolarsResult<DeltaFileStats> {
+    let mut stats = DeltaFileStats {
+        num_records: Some(df.height() as i64),
+        ..Default::default()
+    };
+    for column in df.get_columns() {
+        let dtype = column.dtype();
+        if dtype.is_nested() {
+            continue;
+        }
+        let name = column.name().to_string();
+        stats
+            .null_count
+            .insert(name.clone(), json!(column.null_count()));
+
+        let series = column.as_materialized_series().to_physical_repr();
+        let min = series.min_reduce()?;
+        let max = series.max_reduce()?;
+        if let Some(min) = stat_value(dtype, min.value()) {
+            stats.min_values.insert(name.clone(), min);
+        }
+        if let Some(max) = stat_value(dtype, max.value()) {
+            stats.max_values.insert(name, max);
+        }
+    }
+    Ok(stats)
+}
+
+/// Convert a physical value of type `dtype` to its JSON representation in the statistics.
+fn stat_value(dtype: &DataType, value: &AnyValue) -> Option<Value> {
+    match dtype {
+        DataType::Date => format_date(value.extract::<i32>()?).map(Value::from),
+        DataType::Datetime(_, tz) => {
+            let datetime = chrono::DateTime::from_timestamp_micros(value.extract::<i64>()?)?;
+            let format = if tz.is_some() {
+                "%Y-%m-%dT%H:%M:%S%.6fZ"
+            } else {
+                "%Y-%m-%dT%H:%M:%S%.6f"
+            };
+            Some(json!(datetime.format(format).to_string()))
+        },
+        DataType::Float32 | DataType::Float64 => {
+            let value = value.extract::<f64>()?;
+            value.is_finite().then(|| json!(value))
+        },
+        dtype if dtype.is_integer() => Some(json!(value.extract::<i64>()?)),
+        DataType::String => Some(json!(value.get_str()?)),
+        _ => None,
+    }
+}
+
+/// The serialized partition value of a column with a single distinct value.
+fn partition_value(column: &Column) -> PolarsResult<Option<String>> {
+    let series = column.as_materialized_series().slice(0, 1);
+    if series.null_count() == 1 {
+        return Ok(None);
+    }
+    let value = match series.dtype() {
+        DataType::Date => format_date(series.to_physical_repr().i32()?.get(0).unwrap()),
+        DataType::Datetime(_, _) => {
+            let micros = series.to_physical_repr().i64()?.get(0).unwrap();
+            chrono::DateTime::from_timestamp_micros(micros)
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+        },
+        _ => series
+            .cast(&DataType::String)?
+            .str()?
+            .get(0)
+            .map(String::from),
+    };
+    Ok(value)
+}
+
+fn partition_values_map(columns: &[PlSmallStr], values: &[Option<String>]) -> Value {
+    Value::Object(
+        columns
+            .iter()
+            .zip(values)
+            .map(|(name, value)| (name.to_string(), json!(value)))
+            .collect(),
+    )
+}
+
+fn format_date(days: i32) -> Option<String> {
+    // Days between 0001-01-01 and 1970-01-01.
+    const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+    let date = chrono::NaiveDate::from_num_days_from_ce_opt(days + UNIX_EPOCH_DAYS_FROM_CE)?;
+    Some(date.format("%Y-%m-%d").to_string())
+}
+
+fn encode_path(path: &str) -> String {
+    percent_encoding::utf8_percent_encode(path, PATH_ESCAPE).to_string()
+}
+
+/// Milliseconds since the epoch.
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write(table: &DeltaTable, operation: DeltaOperation, df: &DataFrame) -> DeltaTransaction {
+        let mut txn = DeltaTransaction::new(
+            table,
+            operation,
+            df.schema(),
+            &["p".into()],
+            Default::default(),
+        )
+        .unwrap();
+        txn.write(df).unwrap();
+        txn
+    }
+
+    #[test]
+    fn test_commit_and_conflicts() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = DeltaTable::open(dir.path().to_str().unwrap(), None).unwrap();
+        let df = df!(
+            "x" => [1u32, 2, 3],
+            "p" => [Some("a"), None, Some("a")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            write(&table, DeltaOperation::Append, &df).commit().unwrap(),
+            0
+        );
+        let snapshot = table.snapshot(DeltaVersion::Latest).unwrap();
+        assert_eq!(snapshot.metadata.schema.get("x"), Some(&DataType::Int64));
+        assert_eq!(snapshot.files.len(), 2);
+        let stats = snapshot.files[0].stats.as_ref().unwrap();
+        assert_eq!(stats.num_records, Some(2));
+        assert_eq!(stats.max_values["x"], json!(3));
+        assert_eq!(snapshot.files[1].partition_values, [None]);
+        assert_eq!(snapshot.read_file(&snapshot.files[1]).unwrap().height(), 1);
+
+        // Concurrent appends do not conflict.
+        let append_1 = write(&table, DeltaOperation::Append, &df);
+        let append_2 = write(&table, DeltaOperation::Append, &df);
+        let overwrite = write(&table, DeltaOperation::Overwrite, &df);
+        assert_eq!(append_1.commit().unwrap(), 1);
+        assert_eq!(append_2.commit().unwrap(), 2);
+
+        // The overwrite did not see the appended files.
+        assert!(overwrite.commit().is_err());
+        let overwrite = write(&table, DeltaOperation::Overwrite, &df);
+        assert_eq!(overwrite.commit().unwrap(), 3);
+        assert_eq!(table.snapshot(DeltaVersion::Latest).unwrap().files.len(), 2);
+    }
+}
@@ -330,3 +330,34 @@ impl EvictionManager {
         }
     }
 }
+
+/// Removes the least recently accessed files in `dir` until their total size is at most
+/// `max_size`. Returns the total size of the remaining files.
+pub(super) fn evict_lru(dir: &Path, max_size: u64) -> std::io::Result<u64> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        // Files may be removed concurrently by other processes.
+        let Ok((path, metadata)) = entry.and_then(|entry| Ok((entry.path(), entry.metadata()?)))
+        else {
+            continue;
+        };
+        let last_accessed = metadata
+            .accessed()
+            .or_else(|_| metadata.modified())
+            .unwrap_or(UNIX_EPOCH);
+        files.push((last_accessed, metadata.len(), path));
+    }
+
+    let mut size = files.iter().map(|(_, len, _)| len).sum::<u64>();
+    files.sort_unstable_by_key(|(last_accessed, _, _)| *last_accessed);
+
+    for (_, len, path) in files {
+        if size <= max_size {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            size -= len;
+        }
+    }
+    Ok(size)
+}
@@ -0,0 +1,501 @@
+//! A cache of byte ranges of remote objects.
+//!
+//! Ranged reads of a [`PolarsObjectStore`](crate::cloud::PolarsObjectStore) are split into
+//! blocks that are stored in the file cache directory, so that reading the same columns or
+//! footers of a remote file again only downloads the blocks that are not cached. Blocks are keyed
+//! by the object and its version (the ETag, or the last modified time and size), which is
+//! refreshed whenever the metadata of the object is fetched. The least recently accessed blocks
+//! are evicted when the cache grows over its size limit.
+//!
+//! The cache is enabled by setting `POLARS_FILE_CACHE_RANGE_LIMIT` to its size limit in bytes.
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io::Read;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
+
+use bytes::Bytes;
+use object_store::ObjectMeta;
+use once_cell::sync::Lazy;
+use polars_core::config;
+use polars_error::{polars_ensure, polars_warn, to_compute_err, PolarsResult};
+use polars_utils::aliases::PlHashMap;
+
+use super::eviction::evict_lru;
+use super::utils::FILE_CACHE_PREFIX;
+use crate::path_utils::ensure_directory_init;
+use crate::pl_async;
+
+pub(super) const RANGE_PREFIX: u8 = b'r';
+const BLOCK_SIZE: usize = 1024 * 1024;
+
+pub(crate) static RANGE_CACHE: Lazy<Option<RangeCache>> = Lazy::new(|| {
+    let max_size = std::env::var("POLARS_FILE_CACHE_RANGE_LIMIT").ok()?;
+    let Ok(max_size) = max_size.parse::<u64>() else {
+        polars_warn!(
+            "POLARS_FILE_CACHE_RANGE_LIMIT must be a size in bytes, got '{}'; the range cache is disabled",
+            max_size
+        );
+        return None;
+    };
+    if max_size == 0 {
+        return None;
+    }
+
+    let dir = FILE_CACHE_PREFIX
+        .join(std::str::from_utf8(&[RANGE_PREFIX]).unwrap())
+        .into_boxed_path();
+    if let Err(err) = ensure_directory_init(&dir) {
+        polars_warn!(
+            "failed to create file cache range directory: path = {}, err = {}; the range cache is disabled",
+            dir.to_str().unwrap(),
+            err
+        );
+        return None;
+    }
+
+    if config::verbose() {
+        eprintln!(
+            "[RangeCache] caching byte ranges in {}, max_size = {}",
+            dir.to_str().unwrap(),
+            max_size
+        );
+    }
+
+    Some(RangeCache::new(dir.into(), max_size, BLOCK_SIZE))
+});
+
+/// An object of which blocks are cached.
+#[derive(Debug, Clone)]
+pub(crate) struct CachedObject {
+    /// Hash of the key and version of the object, the prefix of the names of its block files.
+    id: Arc<str>,
+    size: usize,
+}
+
+pub(crate) struct RangeCache {
+    dir: Arc<Path>,
+    max_size: u64,
+    block_size: usize,
+    /// The size of the cached blocks, updated by eviction.
+    size: Arc<AtomicU64>,
+    evicting: Arc<AtomicBool>,
+    objects: RwLock<PlHashMap<String, CachedObject>>,
+}
+
+impl RangeCache {
+    fn new(dir: Arc<Path>, max_size: u64, block_size: usize) -> Self {
+        let size = evict_lru(&dir, max_size).unwrap_or(0);
+        Self {
+            dir,
+            max_size,
+            block_size,
+            size: Arc::new(AtomicU64::new(size)),
+            evicting: Default::default(),
+            objects: Default::default(),
+        }
+    }
+
+    /// Record the current version of the object with `key`.
+    pub(crate) fn update_object(&self, key: String, meta: &ObjectMeta) -> CachedObject {
+        let version = match (&meta.e_tag, &meta.version) {
+            (Some(e_tag), _) => e_tag.clone(),
+            (None, Some(version)) => version.clone(),
+            (None, None) => meta.last_modified.timestamp_millis().to_string(),
+        };
+        let hash = blake3::hash(format!("{}\0{}\0{}", key, version, meta.size).as_bytes());
+        let object = CachedObject {
+            id: hash.to_hex()[..32].into(),
+            size: meta.size,
+        };
+        self.objects.write().unwrap().insert(key, object.clone());
+        object
+    }
+
+    pub(crate) fn get_object(&self, key: &str) -> Option<CachedObject> {
+        self.objects.read().unwrap().get(key).cloned()
+    }
+
+    /// Get `ranges` of `object`, calling `fetch` to download the blocks that are not cached.
+    ///
+    /// `fetch` is called at most once, with the byte ranges of runs of adjacent missing blocks,
+    /// and must return their bytes in the same order.
+    pub(crate) async fn get_ranges<F, Fut>(
+        &self,
+        object: &CachedObject,
+        ranges: &[Range<usize>],
+        fetch: F,
+    ) -> PolarsResult<Vec<Bytes>>
+    where
+        F: FnOnce(Vec<Range<usize>>) -> Fut,
+        Fut: Future<Output = PolarsResult<Vec<Bytes>>>,
+    {
+        if ranges.iter().any(|range| range.end > object.size) {
+            // Let the object store raise the error.
+            return fetch(ranges.to_vec()).await;
+        }
+
+        let block_size = self.block_size;
+        let mut blocks: BTreeMap<usize, Option<Bytes>> = ranges
+            .iter()
+            .flat_map(|range| range.start / block_size..range.end.div_ceil(block_size))
+            .map(|i| (i, None))
+            .collect();
+        // The file system is accessed on a blocking thread, to not block the async runtime.
+        let reads = blocks
+            .keys()
+            .map(|&i| (self.block_path(object, i), self.block_len(object, i)))
+            .collect::<Vec<_>>();
+        let read = pl_async::get_runtime()
+            .spawn_blocking(move || {
+                reads
+                    .iter()
+                    .map(|(path, len)| read_block(path, *len))
+                    .collect::<Vec<_>>()
+            })
+            .await
+            .map_err(to_compute_err)?;
+        for (block, read) in blocks.values_mut().zip(read) {
+            *block = read;
+        }
+
+        // Download the missing blocks, merging adjacent blocks into one range.
+        let mut runs: Vec<Range<usize>> = vec![];
+        for (&i, _) in blocks.iter().filter(|(_, block)| block.is_none()) {
+            match runs.last_mut() {
+                Some(run) if run.end == i => run.end += 1,
+                _ => runs.push(i..i + 1),
+            }
+        }
+        if !runs.is_empty() {
+            let byte_ranges = runs
+                .iter()
+                .map(|run| run.start * block_size..(run.end * block_size).min(object.size))
+                .collect::<Vec<_>>();
+            let fetched = fetch(byte_ranges.clone()).await?;
+
+            let mut writes = vec![];
+            for ((run, byte_range), bytes) in runs.iter().zip(byte_ranges).zip(fetched) {
+                polars_ensure!(
+                    bytes.len() == byte_range.len(),
+                    ComputeError: "expected {} bytes for range {:?}, got {}",
+                    byte_range.len(), byte_range, bytes.len()
+                );
+                for i in run.clone() {
+                    let offset = (i - run.start) * block_size;
+                    let block = bytes.slice(offset..(offset + block_size).min(bytes.len()));
+                    writes.push((self.block_path(object, i), block.clone()));
+                    blocks.insert(i, Some(block));
+                }
+            }
+            let written = pl_async::get_runtime()
+                .spawn_blocking(move || {
+                    writes
+                        .iter()
+                        .filter(|(path, block)| write_block(path, block))
+                        .map(|(_, block)| block.len() as u64)
+                        .sum::<u64>()
+                })
+                .await
+                .map_err(to_compute_err)?;
+            self.size.fetch_add(written, Ordering::Relaxed);
+            self.evict_if_full();
+        }
+
+        let block = |i: usize| blocks[&i].as_ref().unwrap();
+        let out = ranges
+            .iter()
+            .map(|range| {
+                if range.is_empty() {
+                    return Bytes::new();
+                }
+                let first = range.start / block_size;
+                let last = (range.end - 1) / block_size;
+                if first == last {
+                    let offset = first * block_size;
+                    return block(first).slice(range.start - offset..range.end - offset);
+                }
+                let mut out = Vec::with_capacity(range.len());
+                for i in first..=last {
+                    let offset = i * block_size;
+                    let start = range.start.saturating_sub(offset);
+                    let end = (range.end - offset).min(block(i).len());
+                    out.extend_from_slice(&block(i)[start..end]);
+                }
+                Bytes::from(out)
+            })
+            .collect();
+        Ok(out)
+    }
+
+    fn block_path(&self, object: &CachedObject, i: usize) -> PathBuf {
+        self.dir.join(format!("{}_{}", object.id, i))
+    }
+
+    /// The length of block `i` of `object`, which is shorter than the block size for the last
+    /// block.
+    fn block_len(&self, object: &CachedObject, i: usize) -> usize {
+        self.block_size.min(object.size - i * self.block_size)
+    }
+
+    fn evict_if_full(&self) {
+        if self.size.load(Ordering::Relaxed) <= self.max_size
+            || self.evicting.swap(true, Ordering::Relaxed)
+        {
+            return;
+        }
+
+        let dir = self.dir.clone();
+        let size = self.size.clone();
+        let evicting = self.evicting.clone();
+        // Make room for some more blocks before evicting again.
+        let target_size = self.max_size - self.max_size / 8;
+        pl_async::get_runtime().spawn_blocking(move || {
+            match evict_lru(&dir, target_size) {
+                Ok(remaining) => size.store(remaining, Ordering::Relaxed),
+                Err(err) => {
+                    if config::verbose() {
+                        eprintln!("[RangeCache] error evicting blocks: {}", err);
+                    }
+                },
+            }
+            evicting.store(false, Ordering::Relaxed);
+        });
+    }
+}
+
+fn read_block(path: &Path, expected_len: usize) -> Option<Bytes> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut buf = Vec::with_capacity(expected_len);
+    file.read_to_end(&mut buf).ok()?;
+    // Blocks are written to a temporary file and renamed, so this only fails if the file
+    // was changed outside of polars.
+    if buf.len() != expected_len {
+        return None;
+    }
+    let _ = file.set_times(std::fs::FileTimes::new().set_accessed(SystemTime::now()));
+    Some(Bytes::from(buf))
+}
+
+/// Write a block, returning whether it was written.
+fn write_block(path: &Path, block: &[u8]) -> bool {
+    static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let tmp_path = path.with_extension(format!(
+        "tmp-{}-{}",
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let result = std::fs::write(&tmp_path, block).and_then(|_| std::fs::rename(&tmp_path, path));
+    if let Err(err) = result {
+        let _ = std::fs::remove_file(&tmp_path);
+        if config::verbose() {
+            eprintln!(
+                "[RangeCache] failed to write block to {}: {}",
+                path.to_str().unwrap(),
+                err
+            );
+        }
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    fn object_meta(e_tag: &str, size: usize) -> ObjectMeta {
+        ObjectMeta {
+            location: "a/b.parquet".into(),
+            last_modified: Default::default(),
+            size,
+            e_tag: Some(e_tag.into()),
+            version: None,
+        }
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_range_cache_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = RangeCache::new(dir.path().into(), 1 << 20, 10);
+        let data = Bytes::from((0..95u8).collect::<Vec<_>>());
+        let fetched = Mutex::new(vec![]);
+        let fetch = |ranges: Vec<Range<usize>>| {
+            fetched.lock().unwrap().extend(ranges.iter().cloned());
+            let out = ranges.into_iter().map(|r| data.slice(r)).collect();
+            async { PolarsResult::Ok(out) }
+        };
+        let get = |object: &CachedObject, ranges: &[Range<usize>]| {
+            pl_async::get_runtime()
+                .block_on(cache.get_ranges(object, ranges, fetch))
+                .unwrap()
+        };
+
+        let object = cache.update_object("s3://bucket\0a/b.parquet".into(), &object_meta("1", 95));
+        let ranges = [85..95, 12..14, 25..47, 30..30];
+        let out = get(&object, &ranges);
+        for (range, bytes) in ranges.iter().zip(out) {
+            assert_eq!(bytes, data.slice(range.clone()));
+        }
+        assert_eq!(
+            fetched.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            [10..50, 80..95]
+        );
+
+        // Only the blocks that are not cached are downloaded.
+        let out = get(&object, &[5..35, 93..95]);
+        assert_eq!(out[0], data.slice(5..35));
+        assert_eq!(out[1], data.slice(93..95));
+        assert_eq!(
+            fetched.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            [0..10]
+        );
+
+        // A new version of the object is downloaded again.
+        let object = cache.update_object("s3://bucket\0a/b.parquet".into(), &object_meta("2", 95));
+        get(&object, &[0..5]);
+        assert_eq!(
+            fetched.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            [0..10]
+        );
+        assert_eq!(cache.size.load(Ordering::Relaxed), 75);
+    }
+
+    /// Serve `data` over HTTP with support for range requests, recording the `Range` header of
+    /// every `GET` request.
+    #[cfg(feature = "http")]
+    fn serve(data: Bytes, e_tag: String) -> (String, Arc<Mutex<Vec<String>>>) {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/data.bin", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let log = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&mut stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut range = None;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("range") {
+                            range = Some(value.trim().to_string());
+                        }
+                    }
+                }
+
+                let head = request_line.starts_with("HEAD");
+                let (status, content_range, body) = match &range {
+                    Some(range) => {
+                        log.lock().unwrap().push(range.clone());
+                        let (start, end) =
+                            range.trim_start_matches("bytes=").split_once('-').unwrap();
+                        let (start, end) = (start.parse().unwrap(), end.parse::<usize>().unwrap());
+                        (
+                            "206 Partial Content",
+                            format!("Content-Range: bytes {start}-{end}/{}\r\n", data.len()),
+                            data.slice(start..end + 1),
+                        )
+                    },
+                    None => ("200 OK", String::new(), data.clone()),
+                };
+                let header = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nETag: \"{e_tag}\"\r\n{content_range}Connection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(header.as_bytes()).unwrap();
+                if !head {
+                    stream.write_all(&body).unwrap();
+                }
+            }
+        });
+        (url, requests)
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_range_cache_object_store() {
+        use object_store::path::Path;
+
+        use crate::cloud::build_object_store;
+
+        std::env::set_var("POLARS_FILE_CACHE_RANGE_LIMIT", (1u64 << 30).to_string());
+        let data = Bytes::from(
+            (0..2 * BLOCK_SIZE + 100)
+                .map(|i| (i % 251) as u8)
+                .collect::<Vec<_>>(),
+        );
+        // A new version of the object for every run, so that no blocks of earlier runs are used.
+        let e_tag = format!(
+            "{}-{}",
+            std::process::id(),
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        );
+        let (url, requests) = serve(data.clone(), e_tag);
+        let take_requests = || std::mem::take(&mut *requests.lock().unwrap());
+
+        pl_async::get_runtime().block_on(async {
+            let (_, store) = build_object_store(&url, None, false).await.unwrap();
+            let path = Path::from("");
+            let range = BLOCK_SIZE - 10..BLOCK_SIZE + 10;
+
+            let bytes = store.get_range(&path, range.clone()).await.unwrap();
+            assert_eq!(bytes, data.slice(range.clone()));
+            assert_eq!(take_requests(), [format!("bytes=0-{}", 2 * BLOCK_SIZE - 1)]);
+
+            // Cached blocks are read from the cache directory.
+            let bytes = store.get_range(&path, range.clone()).await.unwrap();
+            assert_eq!(bytes, data.slice(range));
+            let mut ranges = [5..10, 2 * BLOCK_SIZE + 50..2 * BLOCK_SIZE + 100];
+            let parts = store
+                .get_ranges_sort::<usize, Bytes>(&path, &mut ranges)
+                .await
+                .unwrap();
+            for range in ranges {
+                assert_eq!(parts[&range.start], data.slice(range));
+            }
+            assert_eq!(
+                take_requests(),
+                [format!("bytes={}-{}", 2 * BLOCK_SIZE, 2 * BLOCK_SIZE + 99)]
+            );
+        });
+    }
+
+    #[test]
+    fn test_evict_lru() {
+        let dir = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        for (name, age) in [("a", 30), ("b", 10), ("c", 20)] {
+            let path = dir.path().join(name);
+            std::fs::write(&path, [0u8; 10]).unwrap();
+            let file = std::fs::File::options().write(true).open(&path).unwrap();
+            let accessed = now - std::time::Duration::from_secs(age);
+            file.set_times(std::fs::FileTimes::new().set_accessed(accessed))
+                .unwrap();
+        }
+
+        assert_eq!(evict_lru(dir.path(), 25).unwrap(), 20);
+        assert!(!dir.path().join("a").exists());
+        assert_eq!(evict_lru(dir.path(), 10).unwrap(), 10);
+        assert!(dir.path().join("b").exists());
+    }
+}
@@ -0,0 +1,28 @@
+//! Functionality for reading fixed-width text files.
+//!
+//! In a fixed-width file every column occupies the same byte range on each line.
+//! The layout is not self-describing, so the caller supplies the columns.
+//!
+//! # Examples
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::prelude::*;
+//!
+//! fn example() -> PolarsResult<DataFrame> {
+//!     let columns = [
+//!         FixedWidthColumn::new("id".into(), 0, 4, DataType::Int64),
+//!         FixedWidthColumn::new("name".into(), 4, 10, DataType::String),
+//!     ];
+//!     FixedWidthReadOptions::new(columns)
+//!         .with_skip_rows(1)
+//!         .try_into_reader_with_file_path(Some("example.txt".into()))?
+//!         .finish()
+//! }
+//! ```
+
+mod options;
+mod reader;
+
+pub use options::{FixedWidthColumn, FixedWidthReadOptions, FixedWidthTrim};
+pub use reader::FixedWidthReader;
@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::{CsvEncoding, NullValues};
+
+/// A column in a fixed-width file, described by its byte range on each line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWidthColumn {
+    pub name: PlSmallStr,
+    /// Byte offset of the first byte of the column.
+    pub start: usize,
+    /// Width of the column in bytes.
+    pub width: usize,
+    pub dtype: DataType,
+}
+
+impl FixedWidthColumn {
+    pub fn new(name: PlSmallStr, start: usize, width: usize, dtype: DataType) -> Self {
+        Self {
+            name,
+            start,
+            width,
+            dtype,
+        }
+    }
+
+    pub(super) fn end(&self) -> usize {
+        self.start + self.width
+    }
+}
+
+/// Which padding to strip from a field before it is parsed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FixedWidthTrim {
+    None,
+    Start,
+    End,
+    #[default]
+    Both,
+}
+
+impl FixedWidthTrim {
+    pub(super) fn apply<'a>(&self, mut field: &'a [u8]) -> &'a [u8] {
+        let is_pad = |b: &u8| *b == b' ' || *b == b'\t';
+        if matches!(self, Self::Start | Self::Both) {
+            let n = field.iter().take_while(|b| is_pad(b)).count();
+            field = &field[n..];
+        }
+        if matches!(self, Self::End | Self::Both) {
+            let n = field.iter().rev().take_while(|b| is_pad(b)).count();
+            field = &field[..field.len() - n];
+        }
+        field
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWidthReadOptions {
+    pub path: Option<PathBuf>,
+    pub n_threads: Option<usize>,
+    pub n_rows: Option<usize>,
+    pub columns: Arc<[FixedWidthColumn]>,
+    pub projection: Option<Arc<Vec<usize>>>,
+    /// Skip this many lines at the start of the file, e.g. a header line.
+    pub skip_rows: usize,
+    pub trim: FixedWidthTrim,
+    pub null_values: Option<NullValues>,
+    /// Read fields that are empty after trimming as null.
+    pub blank_is_null: bool,
+    pub eol_char: u8,
+    pub encoding: CsvEncoding,
+    pub ignore_errors: bool,
+}
+
+impl FixedWidthReadOptions {
+    pub fn new(columns: impl Into<Arc<[FixedWidthColumn]>>) -> Self {
+        Self {
+            path: None,
+            n_threads: None,
+            n_rows: None,
+            columns: columns.into(),
+            projection: None,
+            skip_rows: 0,
+            trim: FixedWidthTrim::default(),
+            null_values: None,
+            blank_is_null: true,
+            eol_char: b'\n',
+            encoding: CsvEncoding::Utf8,
+            ignore_errors: false,
+        }
+    }
+
+    /// The schema of the file, i.e. all columns in the order they were given.
+    pub fn schema(&self) -> Schema {
+        self.columns
+            .iter()
+            .map(|c| Field::new(c.name.clone(), c.dtype.clone()))
+            .collect()
+    }
+
+    pub(super) fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.columns.is_empty(),
+            InvalidOperation: "fixed-width read requires at least one column"
+        );
+        let mut names = PlHashSet::with_capacity(self.columns.len());
+        for c in self.columns.iter() {
+            polars_ensure!(
+                c.width > 0,
+                InvalidOperation: "fixed-width column '{}' has a width of 0", c.name
+            );
+            polars_ensure!(
+                names.insert(&c.name),
+                Duplicate: "column '{}' is specified more than once", c.name
+            );
+        }
+        if let Some(projection) = &self.projection {
+            for &i in projection.iter() {
+                polars_ensure!(
+                    i < self.columns.len(),
+                    OutOfBounds: "projection index {} is out of bounds for {} columns",
+                    i, self.columns.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: Option<P>) -> Self {
+        self.path = path.map(|p| p.into());
+        self
+    }
+
+    /// Number of threads to use for reading. Defaults to the size of the polars
+    /// thread pool.
+    pub fn with_n_threads(mut self, n_threads: Option<usize>) -> Self {
+        self.n_threads = n_threads;
+        self
+    }
+
+    /// Limits the number of rows to read.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Which columns to select denoted by their index in `columns`.
+    pub fn with_projection(mut self, projection: Option<Arc<Vec<usize>>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Number of lines to skip before reading data.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Which padding to strip from fields. Spaces and tabs are padding.
+    pub fn with_trim(mut self, trim: FixedWidthTrim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null. Values are compared
+    /// after trimming.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Read blank fields as null instead of as empty strings.
+    pub fn with_blank_is_null(mut self, blank_is_null: bool) -> Self {
+        self.blank_is_null = blank_is_null;
+        self
+    }
+
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set fields that fail to parse to null instead of raising an error.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+}
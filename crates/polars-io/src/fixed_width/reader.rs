@@ -0,0 +1,291 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use super::options::FixedWidthReadOptions;
+use crate::csv::read::_csv_read_internal::{cast_columns, prepare_csv_schema, NullValuesCompiled};
+use crate::csv::read::buffer::init_buffers;
+use crate::mmap::MmapBytesReader;
+use crate::path_utils::resolve_homedir;
+use crate::shared::SerReader;
+use crate::utils::get_reader_bytes;
+
+/// Create a new DataFrame by reading a fixed-width text file.
+///
+/// Lines are split on the `eol_char`; a trailing carriage return is ignored and
+/// empty lines are skipped. Lines that are shorter than a column's byte range
+/// yield the (possibly empty) part that is present.
+#[must_use]
+pub struct FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    reader: R,
+    options: FixedWidthReadOptions,
+}
+
+impl FixedWidthReadOptions {
+    /// Creates a fixed-width reader using a file path.
+    ///
+    /// # Panics
+    /// If both self.path and the path parameter are non-null. Only one of them is
+    /// to be non-null.
+    pub fn try_into_reader_with_file_path(
+        mut self,
+        path: Option<PathBuf>,
+    ) -> PolarsResult<FixedWidthReader<File>> {
+        if self.path.is_some() {
+            assert!(
+                path.is_none(),
+                "impl error: only 1 of self.path or the path parameter is to be non-null"
+            );
+        } else {
+            self.path = path;
+        };
+
+        assert!(
+            self.path.is_some(),
+            "impl error: either one of self.path or the path parameter is to be non-null"
+        );
+
+        let path = resolve_homedir(self.path.as_ref().unwrap());
+        let reader = polars_utils::open_file(&path)?;
+
+        Ok(FixedWidthReader {
+            reader,
+            options: self,
+        })
+    }
+
+    /// Creates a fixed-width reader using a file handle.
+    pub fn into_reader_with_file_handle<R: MmapBytesReader>(
+        self,
+        reader: R,
+    ) -> FixedWidthReader<R> {
+        FixedWidthReader {
+            reader,
+            options: self,
+        }
+    }
+}
+
+impl<R: MmapBytesReader> FixedWidthReader<R> {
+    pub fn with_options(mut self, options: FixedWidthReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<R> SerReader<R> for FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// Create a new FixedWidthReader without any columns. Set the columns with
+    /// [`FixedWidthReader::with_options`].
+    fn new(reader: R) -> Self {
+        FixedWidthReader {
+            reader,
+            options: FixedWidthReadOptions::new([]),
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let options = &self.options;
+        options.validate()?;
+
+        let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        let bytes = reader_bytes.as_ref();
+        let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+
+        let lines = split_lines(bytes, options.eol_char, options.skip_rows, options.n_rows);
+
+        let projection = match &options.projection {
+            Some(projection) => projection.as_ref().clone(),
+            None => (0..options.columns.len()).collect(),
+        };
+        let columns = projection
+            .iter()
+            .map(|&i| options.columns[i].clone())
+            .collect::<Vec<_>>();
+
+        let mut to_cast = vec![];
+        let mut schema: SchemaRef = Arc::new(
+            columns
+                .iter()
+                .map(|c| Field::new(c.name.clone(), c.dtype.clone()))
+                .collect(),
+        );
+        let _has_categorical = prepare_csv_schema(&mut schema, &mut to_cast)?;
+        #[cfg(feature = "dtype-categorical")]
+        let _cat_lock = _has_categorical.then(polars_core::StringCacheHolder::hold);
+
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&schema))
+            .transpose()?;
+
+        let n_threads = options
+            .n_threads
+            .unwrap_or_else(|| POOL.current_num_threads())
+            .max(1);
+        let chunk_size = lines.len().div_ceil(n_threads).max(1 << 12);
+
+        let dfs = POOL.install(|| {
+            lines
+                .par_chunks(chunk_size)
+                .map(|lines| {
+                    parse_lines(
+                        lines,
+                        &columns,
+                        &schema,
+                        null_values.as_ref(),
+                        &self.options,
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_schema(&schema)
+        } else {
+            accumulate_dataframes_vertical(dfs)?
+        };
+        cast_columns(&mut df, &to_cast, false, options.ignore_errors)?;
+
+        if df.first_col_n_chunks() > 1 {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+/// Splits `bytes` into non-empty lines, without their line terminator.
+fn split_lines(bytes: &[u8], eol_char: u8, skip_rows: usize, n_rows: Option<usize>) -> Vec<&[u8]> {
+    bytes
+        .split(|b| *b == eol_char)
+        .skip(skip_rows)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .take(n_rows.unwrap_or(usize::MAX))
+        .collect()
+}
+
+fn parse_lines(
+    lines: &[&[u8]],
+    columns: &[super::FixedWidthColumn],
+    schema: &Schema,
+    null_values: Option<&NullValuesCompiled>,
+    options: &FixedWidthReadOptions,
+) -> PolarsResult<DataFrame> {
+    let projection = (0..columns.len()).collect::<Vec<_>>();
+    let mut buffers = init_buffers(
+        &projection,
+        lines.len(),
+        schema,
+        None,
+        None,
+        options.encoding,
+        false,
+    )?;
+
+    for line in lines {
+        for (idx, (column, buf)) in columns.iter().zip(buffers.iter_mut()).enumerate() {
+            let start = column.start.min(line.len());
+            let end = column.end().min(line.len());
+            let field = options.trim.apply(&line[start..end]);
+
+            // SAFETY: `idx` is in bounds of the projected schema.
+            let is_null = null_values.is_some_and(|nv| unsafe { nv.is_null(field, idx) })
+                || (options.blank_is_null && field.is_empty());
+            if is_null {
+                buf.add_null(!options.blank_is_null && field.is_empty())
+            } else {
+                buf.add(field, options.ignore_errors, false, options.blank_is_null)
+                    .map_err(|e| {
+                        polars_err!(
+                            ComputeError: "could not parse `{}` as dtype `{}` at column '{}' (bytes {}..{})\n\n\
+                            Original error: ```{}```",
+                            String::from_utf8_lossy(field),
+                            column.dtype,
+                            column.name,
+                            column.start,
+                            column.end(),
+                            e
+                        )
+                    })?;
+            }
+        }
+    }
+
+    let columns = buffers
+        .into_iter()
+        .map(|buf| buf.into_series().map(Column::from))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(unsafe { DataFrame::new_no_checks(lines.len(), columns) })
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::csv::read::NullValues;
+    use crate::fixed_width::{FixedWidthColumn, FixedWidthTrim};
+
+    const DATA: &str = "\
+id  name      score
+1   alice     1.5
+2   bob
+  3 carol     -2.25
+";
+
+    fn columns() -> Vec<FixedWidthColumn> {
+        vec![
+            FixedWidthColumn::new("id".into(), 0, 4, DataType::Int64),
+            FixedWidthColumn::new("name".into(), 4, 10, DataType::String),
+            FixedWidthColumn::new("score".into(), 14, 6, DataType::Float64),
+        ]
+    }
+
+    #[test]
+    fn test_read_fixed_width() -> PolarsResult<()> {
+        let df = FixedWidthReadOptions::new(columns())
+            .with_skip_rows(1)
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish()?;
+
+        let expected = df![
+            "id" => [1i64, 2, 3],
+            "name" => ["alice", "bob", "carol"],
+            "score" => [Some(1.5), None, Some(-2.25)],
+        ]?;
+        assert!(df.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_fixed_width_options() -> PolarsResult<()> {
+        let df = FixedWidthReadOptions::new(columns())
+            .with_skip_rows(1)
+            .with_n_rows(Some(2))
+            .with_projection(Some(Arc::new(vec![1])))
+            .with_trim(FixedWidthTrim::None)
+            .with_null_values(Some(NullValues::AllColumnsSingle("bob".into())))
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish()?;
+
+        let expected = df!["name" => [Some("alice     "), None]]?;
+        assert!(df.equals_missing(&expected));
+
+        let err = FixedWidthReadOptions::new(columns())
+            .into_reader_with_file_handle(Cursor::new(DATA))
+            .finish();
+        assert!(err.is_err());
+        Ok(())
+    }
+}
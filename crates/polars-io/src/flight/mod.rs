@@ -0,0 +1,16 @@
+//! Exchanging data with [Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) services.
+//!
+//! [`FlightReader`] issues a `DoGet` call for a ticket and decodes the stream of record batches
+//! into a [`DataFrame`](polars_core::frame::DataFrame). [`FlightServer`] answers `DoGet` calls
+//! with the stream that a [`FlightHandler`] writes, so polars processes can exchange data
+//! without files.
+//!
+//! Both sides use the generated `FlightService` client and server of `arrow-format` over
+//! `tonic`. Only `DoGet` and `GetSchema` are supported, over plaintext HTTP/2 (`grpc://` or
+//! `grpc+tcp://` locations). The schema of a ticket is requested with a descriptor that has the
+//! ticket as its command.
+mod read;
+mod server;
+
+pub use read::FlightReader;
+pub use server::{FlightHandler, FlightServer};
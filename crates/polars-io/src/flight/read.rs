@@ -0,0 +1,136 @@
+use arrow::io::ipc::read::{read_stream_metadata, FlightConsumer};
+use arrow::io::ipc::write::EncodedData;
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use arrow_format::flight::data::{FlightData, FlightDescriptor, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{polars_bail, polars_err, PolarsResult};
+use tonic::transport::Channel;
+use tonic::Status;
+
+use crate::pl_async;
+
+/// Reads the record batches that a Flight service returns for a ticket into a [`DataFrame`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct FlightReader {
+    location: String,
+    ticket: Vec<u8>,
+    n_rows: Option<usize>,
+}
+
+impl FlightReader {
+    /// `location` is the address of the service, such as `grpc://localhost:8815`.
+    pub fn new(location: impl Into<String>, ticket: impl Into<Vec<u8>>) -> Self {
+        Self {
+            location: location.into(),
+            ticket: ticket.into(),
+            n_rows: None,
+        }
+    }
+
+    /// Stop reading after `n_rows` rows, which cancels the rest of the stream.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// The schema of the stream.
+    ///
+    /// This issues a `GetSchema` call for a descriptor with the ticket as its command, so the
+    /// service doesn't have to compute the stream.
+    pub fn schema(&self) -> PolarsResult<SchemaRef> {
+        pl_async::get_runtime().block_on_potential_spawn(async {
+            let descriptor = FlightDescriptor {
+                r#type: DescriptorType::Cmd as i32,
+                cmd: self.ticket.clone(),
+                ..Default::default()
+            };
+            let result = connect(&self.location)
+                .await?
+                .get_schema(descriptor)
+                .await
+                .map_err(call_failed)?
+                .into_inner();
+            let metadata = read_stream_metadata(&mut result.schema.as_slice())?;
+            Ok(Arc::new(Schema::from_arrow_schema(&metadata.schema)))
+        })
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        // Every batch can come with its own dictionaries, so categoricals of different batches
+        // need a string cache to be combined.
+        #[cfg(feature = "dtype-categorical")]
+        let _sc = polars_core::StringCacheHolder::hold();
+        pl_async::get_runtime().block_on_potential_spawn(self.read())
+    }
+
+    async fn read(self) -> PolarsResult<DataFrame> {
+        let ticket = Ticket {
+            ticket: self.ticket.clone(),
+        };
+        // Dropping the stream cancels the call.
+        let mut stream = connect(&self.location)
+            .await?
+            .do_get(ticket)
+            .await
+            .map_err(call_failed)?
+            .into_inner();
+        let Some(first) = stream.message().await.map_err(call_failed)? else {
+            polars_bail!(ComputeError: "Flight stream ended before its schema")
+        };
+        let mut consumer = FlightConsumer::new(encoded_data(first))?;
+        let arrow_schema = consumer.schema().clone();
+
+        let mut dfs = vec![];
+        let mut rows_read = 0;
+        while self.n_rows.is_none_or(|n| rows_read < n) {
+            let Some(data) = stream.message().await.map_err(call_failed)? else {
+                break;
+            };
+            let Some(batch) = consumer.consume(encoded_data(data))? else {
+                continue;
+            };
+            let mut df = DataFrame::try_from((batch, &arrow_schema))?;
+            if let Some(n) = self.n_rows {
+                df = df.slice(0, n - rows_read);
+            }
+            rows_read += df.height();
+            dfs.push(df);
+        }
+        if dfs.is_empty() {
+            return Ok(DataFrame::empty_with_arrow_schema(&arrow_schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(dfs))
+    }
+}
+
+async fn connect(location: &str) -> PolarsResult<FlightServiceClient<Channel>> {
+    let authority = match location.split_once("://") {
+        Some(("grpc" | "grpc+tcp", authority)) => authority.trim_end_matches('/'),
+        Some((scheme, _)) => {
+            polars_bail!(ComputeError: "unsupported Flight location scheme '{}'", scheme)
+        },
+        None => location,
+    };
+    FlightServiceClient::connect(format!("http://{authority}"))
+        .await
+        .map_err(|e| {
+            polars_err!(ComputeError: "failed to connect to Flight service at '{}': {}", location, e)
+        })
+}
+
+fn call_failed(status: Status) -> PolarsError {
+    polars_err!(
+        ComputeError: "Flight call failed with gRPC status {:?}: {}",
+        status.code(), status.message()
+    )
+}
+
+fn encoded_data(data: FlightData) -> EncodedData {
+    EncodedData {
+        ipc_message: data.data_header,
+        arrow_data: data.data_body,
+    }
+}
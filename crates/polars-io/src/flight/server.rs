@@ -0,0 +1,329 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::time::Duration;
+
+use arrow::io::ipc::read::{read_stream_metadata, StreamReader, StreamState};
+use arrow::io::ipc::write::{
+    encode_chunk, schema_to_bytes, DictionaryTracker, EncodedData, StreamWriter, WriteOptions,
+};
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use polars_core::prelude::*;
+use polars_error::{polars_err, PolarsResult};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::ipc::IpcStreamWriter;
+use crate::shared::schema_to_arrow_checked;
+use crate::{pl_async, SerWriter};
+
+/// Answers the calls of a [`FlightServer`] for a ticket.
+pub trait FlightHandler: Send + Sync {
+    /// The schema of the stream for `ticket`, which answers `GetSchema` calls.
+    fn schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef>;
+
+    /// Write the stream for `ticket` as an Arrow IPC stream to `writer`. Every record batch is
+    /// sent to the client as soon as it is written.
+    fn write(&self, ticket: &[u8], writer: Box<dyn Write + Send>) -> PolarsResult<()>;
+}
+
+/// Serves the [`DataFrame`] that the function returns for a ticket. `GetSchema` calls compute
+/// the frame as well.
+impl<F> FlightHandler for F
+where
+    F: Fn(&[u8]) -> PolarsResult<DataFrame> + Send + Sync,
+{
+    fn schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef> {
+        Ok(self(ticket)?.schema().clone())
+    }
+
+    fn write(&self, ticket: &[u8], writer: Box<dyn Write + Send>) -> PolarsResult<()> {
+        IpcStreamWriter::new(writer)
+            .with_compat_level(CompatLevel::newest())
+            .finish(&mut self(ticket)?)
+    }
+}
+
+/// A Flight service that answers `DoGet` and `GetSchema` calls with a [`FlightHandler`].
+///
+/// The handler runs on a blocking thread for every call, and its error is returned to the
+/// client. The service stops accepting connections when it is dropped.
+pub struct FlightServer {
+    addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl FlightServer {
+    /// Serve calls on `addr`. Use port 0 to bind to a free port, which is part of
+    /// [`FlightServer::location`].
+    pub fn bind(addr: impl ToSocketAddrs, handler: Arc<dyn FlightHandler>) -> PolarsResult<Self> {
+        let listener = std::net::TcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        let runtime = pl_async::get_runtime();
+        let listener =
+            runtime.block_on_potential_spawn(async { TcpListener::from_std(listener) })?;
+        let incoming = futures::stream::unfold(listener, |listener| async move {
+            loop {
+                match listener.accept().await {
+                    Ok((socket, _)) => {
+                        let _ = socket.set_nodelay(true);
+                        return Some((Ok::<_, std::io::Error>(socket), listener));
+                    },
+                    // Back off when we are out of file descriptors.
+                    Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+                }
+            }
+        });
+        let service = FlightServiceServer::new(Service { handler });
+        let task = runtime.spawn(async move {
+            let _ = Server::builder()
+                .add_service(service)
+                .serve_with_incoming(incoming)
+                .await;
+        });
+        Ok(Self { addr, task })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The location of the service for a [`FlightReader`](super::FlightReader).
+    pub fn location(&self) -> String {
+        format!("grpc://{}", self.addr)
+    }
+}
+
+impl Drop for FlightServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct Service {
+    handler: Arc<dyn FlightHandler>,
+}
+
+type CallStream<T> = BoxStream<'static, Result<T, Status>>;
+
+#[tonic::async_trait]
+impl FlightService for Service {
+    type HandshakeStream = CallStream<HandshakeResponse>;
+    type ListFlightsStream = CallStream<FlightInfo>;
+    type DoGetStream = CallStream<FlightData>;
+    type DoPutStream = CallStream<PutResult>;
+    type DoExchangeStream = CallStream<FlightData>;
+    type DoActionStream = CallStream<arrow_format::flight::data::Result>;
+    type ListActionsStream = CallStream<ActionType>;
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let handler = self.handler.clone();
+        let descriptor = request.into_inner();
+        let schema =
+            tokio::task::spawn_blocking(move || encode_schema(&handler.schema(&descriptor.cmd)?))
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(SchemaResult { schema }))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let handler = self.handler.clone();
+        let ticket = request.into_inner().ticket;
+
+        // The handler blocks when the client doesn't keep up, instead of buffering the stream.
+        let (sender, mut receiver) = mpsc::channel(2);
+        tokio::spawn(async move {
+            let (writer, reader) = pipe();
+            let write = tokio::task::spawn_blocking(move || handler.write(&ticket, writer));
+            let forward_sender = sender.clone();
+            let forward = tokio::task::spawn_blocking(move || forward(reader, &forward_sender));
+            // The error of the handler is the cause of an error of the forwarding.
+            let result = match write.await {
+                Ok(Ok(())) => forward.await.map_err(to_polars_err).and_then(|r| r),
+                Ok(Err(e)) => Err(e),
+                Err(e) => Err(to_polars_err(e)),
+            };
+            if let Err(e) = result {
+                let _ = sender.send(Err(Status::internal(e.to_string()))).await;
+            }
+        });
+        let stream = futures::stream::poll_fn(move |cx| receiver.poll_recv(cx));
+        Ok(Response::new(stream.boxed()))
+    }
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("Handshake is not supported"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("ListFlights is not supported"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(Status::unimplemented("GetFlightInfo is not supported"))
+    }
+
+    async fn do_put(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        Err(Status::unimplemented("DoPut is not supported"))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("DoExchange is not supported"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("DoAction is not supported"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Err(Status::unimplemented("ListActions is not supported"))
+    }
+}
+
+fn to_polars_err(e: tokio::task::JoinError) -> PolarsError {
+    polars_err!(ComputeError: "Flight handler failed: {}", e)
+}
+
+/// The IPC message of `schema`, preceded by the continuation marker and its length as in a
+/// stream.
+fn encode_schema(schema: &SchemaRef) -> PolarsResult<Vec<u8>> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "flight")?;
+    let mut buf = vec![];
+    StreamWriter::new(&mut buf, WriteOptions { compression: None }).start(&schema, None)?;
+    Ok(buf)
+}
+
+/// Decode the Arrow IPC stream that a [`FlightHandler`] writes, and send every record batch to
+/// the client as `FlightData` messages as soon as it is complete.
+fn forward(
+    mut reader: PipeReader,
+    sender: &mpsc::Sender<Result<FlightData, Status>>,
+) -> PolarsResult<()> {
+    let send = |data: EncodedData| {
+        let data = FlightData {
+            data_header: data.ipc_message,
+            data_body: data.arrow_data,
+            ..Default::default()
+        };
+        sender
+            .blocking_send(Ok(data))
+            .map_err(|_| polars_err!(ComputeError: "the Flight client closed the stream"))
+    };
+
+    let metadata = read_stream_metadata(&mut reader)?;
+    let fields = metadata.ipc_schema.fields.clone();
+    send(EncodedData {
+        ipc_message: schema_to_bytes(&metadata.schema, &fields, None),
+        arrow_data: vec![],
+    })?;
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+    };
+    let options = WriteOptions { compression: None };
+    for state in StreamReader::new(reader, metadata, None) {
+        let batch = match state? {
+            StreamState::Some(batch) => batch,
+            // The writer is gone without ending the stream.
+            StreamState::Waiting => break,
+        };
+        let (dictionaries, data) =
+            encode_chunk(&batch, &fields, &mut dictionary_tracker, &options)?;
+        for data in dictionaries.into_iter().chain([data]) {
+            send(data)?;
+        }
+    }
+    Ok(())
+}
+
+/// An in-memory pipe from the thread of a [`FlightHandler`] to the thread that forwards its
+/// stream. Writes fail once the reader is dropped, which cancels the handler.
+fn pipe() -> (Box<dyn Write + Send>, PipeReader) {
+    let (sender, receiver) = sync_channel(16);
+    let reader = PipeReader {
+        receiver,
+        buf: vec![],
+        pos: 0,
+    };
+    (Box::new(PipeWriter(sender)), reader)
+}
+
+struct PipeWriter(SyncSender<Vec<u8>>);
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.send(buf.to_vec()).map_err(|_| {
+            std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "the Flight client closed the stream",
+            )
+        })?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+struct PipeReader {
+    receiver: Receiver<Vec<u8>>,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        while self.pos == self.buf.len() {
+            // The writer is dropped at the end of the stream.
+            let Ok(buf) = self.receiver.recv() else {
+                return Ok(0);
+            };
+            self.buf = buf;
+            self.pos = 0;
+        }
+        let n = out.len().min(self.buf.len() - self.pos);
+        out[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
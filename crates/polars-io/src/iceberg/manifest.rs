@@ -0,0 +1,280 @@
+use std::fs::File;
+use std::path::Path;
+
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+use super::metadata::local_path;
+use crate::avro::AvroReader;
+use crate::shared::SerReader;
+
+/// What an entry of a manifest tracks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IcebergContent {
+    Data,
+    /// Deletes rows by their position in a data file.
+    PositionDeletes,
+    /// Deletes rows that are equal to a row of the file in the columns of `equality_ids`.
+    EqualityDeletes,
+}
+
+/// A summary of the values of a partition field in a manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IcebergFieldSummary {
+    pub contains_null: bool,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+/// An entry of the manifest list of a snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergManifest {
+    pub path: String,
+    pub spec_id: i32,
+    /// Whether the manifest tracks delete files instead of data files.
+    pub contains_deletes: bool,
+    pub sequence_number: i64,
+    /// Summaries of the partition fields of the spec, in order.
+    pub partitions: Vec<IcebergFieldSummary>,
+}
+
+/// A live data or delete file of a snapshot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IcebergDataFile {
+    pub content: IcebergContent,
+    pub path: String,
+    pub format: String,
+    pub spec_id: i32,
+    /// The values of the partition fields of the spec, in order.
+    pub partition: Vec<AnyValue<'static>>,
+    pub record_count: i64,
+    /// The data sequence number, which orders the file relative to delete files.
+    pub sequence_number: i64,
+    /// The following statistics are keyed by field ID.
+    pub null_value_counts: PlHashMap<i32, i64>,
+    pub lower_bounds: PlHashMap<i32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<i32, Vec<u8>>,
+    pub equality_ids: Vec<i32>,
+}
+
+impl IcebergDataFile {
+    /// Whether this delete file deletes rows of the data file `data`.
+    pub fn applies_to(&self, data: &IcebergDataFile, unpartitioned: bool) -> bool {
+        let same_partition = self.spec_id == data.spec_id && self.partition == data.partition;
+        match self.content {
+            IcebergContent::Data => false,
+            IcebergContent::PositionDeletes => {
+                self.sequence_number >= data.sequence_number && same_partition
+            },
+            IcebergContent::EqualityDeletes => {
+                self.sequence_number > data.sequence_number && (unpartitioned || same_partition)
+            },
+        }
+    }
+}
+
+fn read_avro(path: &Path) -> PolarsResult<DataFrame> {
+    let file = File::open(path).map_err(
+        |e| polars_err!(ComputeError: "failed to open Iceberg file '{}': {}", path.display(), e),
+    )?;
+    AvroReader::new(file).finish()
+}
+
+fn opt_column<'a>(df: &'a DataFrame, name: &str) -> Option<&'a Column> {
+    df.column(name).ok()
+}
+
+fn i64_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<i64>>> {
+    Ok(match opt_column(df, name) {
+        Some(c) => c.cast(&DataType::Int64)?.i64()?.iter().collect(),
+        None => vec![None; df.height()],
+    })
+}
+
+fn str_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<String>> {
+    let c = df.column(name)?;
+    c.str()?
+        .iter()
+        .map(|v| {
+            v.map(str::to_string)
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg manifest is missing '{}'", name))
+        })
+        .collect()
+}
+
+/// The entries of a list of `key`/`value` structs, which is how Iceberg stores maps in Avro.
+fn map_entries(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<DataFrame>>> {
+    let Some(c) = opt_column(df, name) else {
+        return Ok(vec![None; df.height()]);
+    };
+    c.list()?
+        .into_iter()
+        .map(|entries| {
+            entries
+                .map(|s| Ok(s.struct_()?.clone().unnest()))
+                .transpose()
+        })
+        .collect()
+}
+
+fn int_map(entries: Option<DataFrame>) -> PolarsResult<PlHashMap<i32, i64>> {
+    let Some(entries) = entries else {
+        return Ok(PlHashMap::new());
+    };
+    let keys = entries.column("key")?.cast(&DataType::Int32)?;
+    let values = entries.column("value")?.cast(&DataType::Int64)?;
+    Ok(keys
+        .i32()?
+        .iter()
+        .zip(values.i64()?.iter())
+        .filter_map(|(k, v)| Some((k?, v?)))
+        .collect())
+}
+
+fn bytes_map(entries: Option<DataFrame>) -> PolarsResult<PlHashMap<i32, Vec<u8>>> {
+    let Some(entries) = entries else {
+        return Ok(PlHashMap::new());
+    };
+    let keys = entries.column("key")?.cast(&DataType::Int32)?;
+    let values = entries.column("value")?;
+    Ok(keys
+        .i32()?
+        .iter()
+        .zip(values.binary()?.iter())
+        .filter_map(|(k, v)| Some((k?, v?.to_vec())))
+        .collect())
+}
+
+/// Read the manifest list of a snapshot.
+pub(super) fn read_manifest_list(path: &Path) -> PolarsResult<Vec<IcebergManifest>> {
+    let df = read_avro(path)?;
+    let paths = str_values(&df, "manifest_path")?;
+    let spec_ids = i64_values(&df, "partition_spec_id")?;
+    let content = i64_values(&df, "content")?;
+    let sequence_numbers = i64_values(&df, "sequence_number")?;
+    let partitions = match opt_column(&df, "partitions") {
+        Some(c) => c
+            .list()?
+            .into_iter()
+            .map(|summaries| {
+                let Some(summaries) = summaries else {
+                    return Ok(vec![]);
+                };
+                let summaries = summaries.struct_()?.clone().unnest();
+                let contains_null = summaries.column("contains_null")?.bool()?.clone();
+                let bounds = |name| -> PolarsResult<Vec<Option<Vec<u8>>>> {
+                    Ok(match opt_column(&summaries, name) {
+                        Some(c) => c.binary()?.iter().map(|v| v.map(<[u8]>::to_vec)).collect(),
+                        None => vec![None; summaries.height()],
+                    })
+                };
+                Ok(contains_null
+                    .iter()
+                    .zip(bounds("lower_bound")?)
+                    .zip(bounds("upper_bound")?)
+                    .map(
+                        |((contains_null, lower_bound), upper_bound)| IcebergFieldSummary {
+                            // A missing value means that it is unknown.
+                            contains_null: contains_null.unwrap_or(true),
+                            lower_bound,
+                            upper_bound,
+                        },
+                    )
+                    .collect())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?,
+        None => vec![vec![]; df.height()],
+    };
+
+    Ok(paths
+        .into_iter()
+        .zip(spec_ids)
+        .zip(content)
+        .zip(sequence_numbers)
+        .zip(partitions)
+        .map(
+            |((((path, spec_id), content), sequence_number), partitions)| IcebergManifest {
+                path,
+                spec_id: spec_id.unwrap_or_default() as i32,
+                contains_deletes: content == Some(1),
+                sequence_number: sequence_number.unwrap_or_default(),
+                partitions,
+            },
+        )
+        .collect())
+}
+
+/// Read the live files of a manifest.
+pub(super) fn read_manifest(manifest: &IcebergManifest) -> PolarsResult<Vec<IcebergDataFile>> {
+    let entries = read_avro(&local_path(&manifest.path)?)?;
+    let status = i64_values(&entries, "status")?;
+    let sequence_numbers = i64_values(&entries, "sequence_number")?;
+    let data_file = entries.column("data_file")?.struct_()?;
+    let partition = data_file
+        .field_by_name("partition")?
+        .struct_()?
+        .fields_as_series();
+    let data_file = data_file.clone().unnest();
+
+    let content = i64_values(&data_file, "content")?;
+    let paths = str_values(&data_file, "file_path")?;
+    let formats = str_values(&data_file, "file_format")?;
+    let record_counts = i64_values(&data_file, "record_count")?;
+    let null_value_counts = map_entries(&data_file, "null_value_counts")?;
+    let lower_bounds = map_entries(&data_file, "lower_bounds")?;
+    let upper_bounds = map_entries(&data_file, "upper_bounds")?;
+    let equality_ids = match opt_column(&data_file, "equality_ids") {
+        Some(c) => c
+            .list()?
+            .into_iter()
+            .map(|ids| {
+                Ok(match ids {
+                    Some(ids) => ids
+                        .cast(&DataType::Int32)?
+                        .i32()?
+                        .into_no_null_iter()
+                        .collect(),
+                    None => vec![],
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?,
+        None => vec![vec![]; data_file.height()],
+    };
+
+    let mut files = Vec::with_capacity(entries.height());
+    for (i, ((null_value_counts, lower_bounds), upper_bounds)) in null_value_counts
+        .into_iter()
+        .zip(lower_bounds)
+        .zip(upper_bounds)
+        .enumerate()
+    {
+        // Status 2 marks files that were deleted in the snapshot of the manifest.
+        if status[i] == Some(2) {
+            continue;
+        }
+        let content = match content[i].unwrap_or_default() {
+            0 => IcebergContent::Data,
+            1 => IcebergContent::PositionDeletes,
+            2 => IcebergContent::EqualityDeletes,
+            content => polars_bail!(ComputeError: "invalid Iceberg file content {}", content),
+        };
+        files.push(IcebergDataFile {
+            content,
+            path: paths[i].clone(),
+            format: formats[i].clone(),
+            spec_id: manifest.spec_id,
+            partition: partition
+                .iter()
+                .map(|s| Ok(s.get(i)?.into_static()))
+                .collect::<PolarsResult<_>>()?,
+            record_count: record_counts[i].unwrap_or_default(),
+            // Files that were added by the snapshot of the manifest inherit its sequence number.
+            sequence_number: sequence_numbers[i].unwrap_or(manifest.sequence_number),
+            null_value_counts: int_map(null_value_counts)?,
+            lower_bounds: bytes_map(lower_bounds)?,
+            upper_bounds: bytes_map(upper_bounds)?,
+            equality_ids: equality_ids[i].clone(),
+        });
+    }
+    Ok(files)
+}
@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::manifest::{read_manifest_list, IcebergManifest};
+use super::schema::IcebergSchema;
+use crate::path_utils::{is_cloud_url, resolve_homedir};
+
+/// The table property with the mapping from field IDs to the column names of data files that
+/// were written without field IDs.
+pub(super) const NAME_MAPPING_PROPERTY: &str = "schema.name-mapping.default";
+
+/// A partition transform, which derives the value of a partition field from a source column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IcebergTransform {
+    Identity,
+    Year,
+    Month,
+    Day,
+    Hour,
+    Bucket(u32),
+    Truncate(u32),
+    Void,
+    Unknown(String),
+}
+
+impl IcebergTransform {
+    fn parse(transform: &str) -> Self {
+        let with_arg = |prefix: &str| {
+            transform
+                .strip_prefix(prefix)?
+                .strip_prefix('[')?
+                .strip_suffix(']')?
+                .parse()
+                .ok()
+        };
+        match transform {
+            "identity" => Self::Identity,
+            "year" => Self::Year,
+            "month" => Self::Month,
+            "day" => Self::Day,
+            "hour" => Self::Hour,
+            "void" => Self::Void,
+            _ => {
+                if let Some(n) = with_arg("bucket") {
+                    Self::Bucket(n)
+                } else if let Some(width) = with_arg("truncate") {
+                    Self::Truncate(width)
+                } else {
+                    Self::Unknown(transform.to_string())
+                }
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergPartitionField {
+    /// The field ID of the source column.
+    pub source_id: i32,
+    pub name: PlSmallStr,
+    pub transform: IcebergTransform,
+}
+
+/// How the data files of a table are partitioned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IcebergPartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<IcebergPartitionField>,
+}
+
+impl IcebergPartitionSpec {
+    pub fn is_unpartitioned(&self) -> bool {
+        self.fields
+            .iter()
+            .all(|f| f.transform == IcebergTransform::Void)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionFieldJson {
+    source_id: i32,
+    name: String,
+    transform: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartitionSpecJson {
+    spec_id: i32,
+    #[serde(default)]
+    fields: Vec<PartitionFieldJson>,
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct SnapshotJson {
+    snapshot_id: i64,
+    #[serde(default)]
+    sequence_number: i64,
+    manifest_list: Option<String>,
+    schema_id: Option<i32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct TableMetadataJson {
+    format_version: i32,
+    #[serde(default)]
+    schemas: Vec<Value>,
+    current_schema_id: Option<i32>,
+    /// The only schema of a v1 table that does not track `schemas`.
+    schema: Option<Value>,
+    #[serde(default)]
+    partition_specs: Vec<PartitionSpecJson>,
+    /// The only partition spec of a v1 table that does not track `partition-specs`.
+    partition_spec: Option<Vec<PartitionFieldJson>>,
+    #[serde(default)]
+    properties: BTreeMap<String, String>,
+    current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    snapshots: Vec<SnapshotJson>,
+}
+
+/// The state of an Iceberg table at a snapshot.
+#[derive(Clone, Debug)]
+pub struct IcebergSnapshot {
+    /// `None` if the table has no snapshots yet.
+    pub snapshot_id: Option<i64>,
+    pub sequence_number: i64,
+    pub format_version: i32,
+    pub schema: IcebergSchema,
+    pub partition_specs: PlHashMap<i32, IcebergPartitionSpec>,
+    pub properties: BTreeMap<String, String>,
+    /// The manifests of the snapshot, from its manifest list.
+    pub manifests: Vec<IcebergManifest>,
+}
+
+/// An Iceberg table on the local filesystem, identified by a metadata file or by the table
+/// directory that contains a `metadata` directory.
+#[derive(Clone)]
+pub struct IcebergTable {
+    metadata_location: PathBuf,
+}
+
+impl IcebergTable {
+    pub fn open(path: &str) -> PolarsResult<Self> {
+        let path = local_path(path)?;
+        let metadata_location = if path.is_file() {
+            path
+        } else {
+            find_metadata_file(&path.join("metadata"))?
+        };
+        Ok(Self { metadata_location })
+    }
+
+    /// The metadata file that describes the current state of the table.
+    pub fn metadata_location(&self) -> &Path {
+        &self.metadata_location
+    }
+
+    /// Load the snapshot with `snapshot_id`, or the current snapshot.
+    pub fn snapshot(&self, snapshot_id: Option<i64>) -> PolarsResult<IcebergSnapshot> {
+        let bytes = std::fs::read(&self.metadata_location)?;
+        let metadata: TableMetadataJson = serde_json::from_slice(&bytes).map_err(to_compute_err)?;
+        polars_ensure!(
+            (1..=2).contains(&metadata.format_version),
+            ComputeError: "unsupported Iceberg format version {}", metadata.format_version
+        );
+
+        let snapshot = match snapshot_id.or(metadata.current_snapshot_id) {
+            None | Some(-1) => None,
+            Some(id) => Some(
+                metadata
+                    .snapshots
+                    .iter()
+                    .find(|s| s.snapshot_id == id)
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "Iceberg snapshot {} does not exist", id),
+                    )?,
+            ),
+        };
+
+        // Time travel reads a snapshot with the schema it was written with.
+        let schema_id = match snapshot_id.and(snapshot) {
+            Some(snapshot) => snapshot.schema_id.or(metadata.current_schema_id),
+            None => metadata.current_schema_id,
+        };
+        let schema = match (&metadata.schema, schema_id) {
+            (Some(schema), _) if metadata.schemas.is_empty() => schema,
+            (_, schema_id) => metadata
+                .schemas
+                .iter()
+                .find(|s| {
+                    s.get("schema-id")
+                        .and_then(Value::as_i64)
+                        .map(|id| id as i32)
+                        == schema_id
+                })
+                .or(metadata.schemas.last())
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg table has no schema"))?,
+        };
+        let schema = IcebergSchema::parse(schema)?;
+
+        let mut partition_specs = metadata
+            .partition_specs
+            .into_iter()
+            .map(|spec| (spec.spec_id, spec.fields))
+            .collect::<Vec<_>>();
+        if partition_specs.is_empty() {
+            partition_specs.push((0, metadata.partition_spec.unwrap_or_default()));
+        }
+        let partition_specs = partition_specs
+            .into_iter()
+            .map(|(spec_id, fields)| {
+                let fields = fields
+                    .into_iter()
+                    .map(|f| IcebergPartitionField {
+                        source_id: f.source_id,
+                        name: f.name.into(),
+                        transform: IcebergTransform::parse(&f.transform),
+                    })
+                    .collect();
+                (spec_id, IcebergPartitionSpec { spec_id, fields })
+            })
+            .collect();
+
+        let manifests = match snapshot {
+            None => vec![],
+            Some(snapshot) => {
+                let Some(manifest_list) = &snapshot.manifest_list else {
+                    polars_bail!(
+                        ComputeError: "Iceberg snapshots without a manifest list are not supported"
+                    )
+                };
+                read_manifest_list(&local_path(manifest_list)?)?
+            },
+        };
+
+        Ok(IcebergSnapshot {
+            snapshot_id: snapshot.map(|s| s.snapshot_id),
+            sequence_number: snapshot.map_or(0, |s| s.sequence_number),
+            format_version: metadata.format_version,
+            schema,
+            partition_specs,
+            properties: metadata.properties,
+            manifests,
+        })
+    }
+}
+
+/// The local path of a file referenced by Iceberg metadata.
+pub(super) fn local_path(uri: &str) -> PolarsResult<PathBuf> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        Ok(PathBuf::from(path))
+    } else if let Some(path) = uri.strip_prefix("file:") {
+        Ok(PathBuf::from(path))
+    } else if is_cloud_url(uri) {
+        polars_bail!(ComputeError: "only Iceberg tables on the local filesystem are supported, got '{}'", uri)
+    } else {
+        Ok(resolve_homedir(&uri))
+    }
+}
+
+/// Find the latest metadata file of a table: the one named by `version-hint.text`, or else the
+/// one with the highest version.
+fn find_metadata_file(dir: &Path) -> PolarsResult<PathBuf> {
+    if let Ok(hint) = std::fs::read_to_string(dir.join("version-hint.text")) {
+        let path = dir.join(format!("v{}.metadata.json", hint.trim()));
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+
+    let version = |name: &str| -> Option<u64> {
+        let name = name.strip_suffix(".metadata.json")?;
+        let name = name.strip_prefix('v').unwrap_or(name);
+        let digits = name.split(|c: char| !c.is_ascii_digit()).next()?;
+        digits.parse().ok()
+    };
+    let mut latest = None;
+    let entries = std::fs::read_dir(dir).map_err(
+        |e| polars_err!(ComputeError: "'{}' is not an Iceberg table: {}", dir.display(), e),
+    )?;
+    for entry in entries {
+        let name = entry?.file_name();
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        if let Some(v) = version(name) {
+            if latest.as_ref().is_none_or(|(latest, _)| v > *latest) {
+                latest = Some((v, name.to_string()));
+            }
+        }
+    }
+    let (_, name) = latest.ok_or_else(
+        || polars_err!(ComputeError: "'{}' does not contain Iceberg metadata files", dir.display()),
+    )?;
+    Ok(dir.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_transform() {
+        assert_eq!(
+            IcebergTransform::parse("bucket[16]"),
+            IcebergTransform::Bucket(16)
+        );
+        assert_eq!(
+            IcebergTransform::parse("truncate[4]"),
+            IcebergTransform::Truncate(4)
+        );
+        assert_eq!(IcebergTransform::parse("day"), IcebergTransform::Day);
+        assert_eq!(
+            IcebergTransform::parse("zorder"),
+            IcebergTransform::Unknown("zorder".into())
+        );
+    }
+
+    #[test]
+    fn test_find_metadata_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        for name in [
+            "00001-a.metadata.json",
+            "00010-b.metadata.json",
+            "00002-c.metadata.json",
+            "snap-1.avro",
+        ] {
+            std::fs::write(dir.join(name), "{}").unwrap();
+        }
+        assert_eq!(
+            find_metadata_file(dir).unwrap(),
+            dir.join("00010-b.metadata.json")
+        );
+
+        std::fs::write(dir.join("v3.metadata.json"), "{}").unwrap();
+        std::fs::write(dir.join("version-hint.text"), "3\n").unwrap();
+        assert_eq!(
+            find_metadata_file(dir).unwrap(),
+            dir.join("v3.metadata.json")
+        );
+    }
+}
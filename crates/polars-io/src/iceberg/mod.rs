@@ -0,0 +1,19 @@
+//! Reading [Apache Iceberg](https://iceberg.apache.org) tables.
+//!
+//! A snapshot of a table is loaded from the table metadata file and the manifest list of the
+//! snapshot. Manifests, which are Avro files, list the parquet data files and delete files of
+//! the snapshot together with their partition values and column statistics. These statistics
+//! are exposed in the layout of batch skipping predicates, so that files can be pruned before
+//! they are read.
+mod manifest;
+mod metadata;
+mod read;
+mod schema;
+mod stats;
+
+pub use manifest::{IcebergContent, IcebergDataFile, IcebergFieldSummary, IcebergManifest};
+pub use metadata::{
+    IcebergPartitionField, IcebergPartitionSpec, IcebergSnapshot, IcebergTable, IcebergTransform,
+};
+pub use read::IcebergFileColumn;
+pub use schema::{IcebergField, IcebergSchema};
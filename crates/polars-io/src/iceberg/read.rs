@@ -0,0 +1,101 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_error::{polars_ensure, to_compute_err, PolarsResult};
+use serde::Deserialize;
+
+use super::manifest::{read_manifest, IcebergDataFile};
+use super::metadata::{local_path, IcebergSnapshot, NAME_MAPPING_PROPERTY};
+use crate::parquet::read::ParquetReader;
+use crate::shared::SerReader;
+
+/// A column of a data file and the column of the table that it stores.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IcebergFileColumn {
+    /// The name of the column in the data file.
+    pub file_name: PlSmallStr,
+    /// The type of the column in the data file, which can be narrower than the type in the
+    /// table after a type promotion.
+    pub dtype: DataType,
+    pub table_name: PlSmallStr,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct MappedField {
+    field_id: Option<i32>,
+    #[serde(default)]
+    names: Vec<String>,
+}
+
+impl IcebergSnapshot {
+    /// Read the live files of the manifests, skipping the data manifests for which `skip` is
+    /// `true`. Delete manifests are always read.
+    pub fn read_manifests(&self, skip: Option<&[bool]>) -> PolarsResult<Vec<IcebergDataFile>> {
+        let mut files = vec![];
+        for (i, manifest) in self.manifests.iter().enumerate() {
+            if !manifest.contains_deletes && skip.is_some_and(|skip| skip[i]) {
+                continue;
+            }
+            files.extend(read_manifest(manifest)?);
+        }
+        Ok(files)
+    }
+
+    /// The local path of a data or delete file.
+    pub fn file_path(&self, file: &IcebergDataFile) -> PolarsResult<PathBuf> {
+        polars_ensure!(
+            file.format.eq_ignore_ascii_case("parquet"),
+            ComputeError: "unsupported Iceberg file format '{}' of '{}'", file.format, file.path
+        );
+        local_path(&file.path)
+    }
+
+    /// Map the columns of a parquet data or delete file onto the columns of the table.
+    ///
+    /// Columns are matched by field ID. Files that were written without field IDs are matched
+    /// through the name mapping of the table if it has one, and by name otherwise. Columns
+    /// that were dropped from the table are not returned.
+    pub fn file_columns(&self, file: &IcebergDataFile) -> PolarsResult<Vec<IcebergFileColumn>> {
+        let mut reader = ParquetReader::new(File::open(self.file_path(file)?)?);
+        let schema = Schema::from_arrow_schema(reader.schema()?.as_ref());
+        let field_ids = reader
+            .get_metadata()?
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.get_field_info().id)
+            .collect::<Vec<_>>();
+
+        let name_mapping = match self.properties.get(NAME_MAPPING_PROPERTY) {
+            Some(mapping) if field_ids.iter().all(Option::is_none) => {
+                Some(serde_json::from_str::<Vec<MappedField>>(mapping).map_err(to_compute_err)?)
+            },
+            _ => None,
+        };
+
+        Ok(schema
+            .iter()
+            .zip(field_ids)
+            .filter_map(|((name, dtype), id)| {
+                let field = match (id, &name_mapping) {
+                    (Some(id), _) => self.schema.field_by_id(id),
+                    (None, Some(mapping)) => {
+                        let id = mapping
+                            .iter()
+                            .find(|f| f.names.iter().any(|n| n == name.as_str()))?
+                            .field_id?;
+                        self.schema.field_by_id(id)
+                    },
+                    (None, None) => self.schema.field_by_name(name),
+                }?;
+                Some(IcebergFileColumn {
+                    file_name: name.clone(),
+                    dtype: dtype.clone(),
+                    table_name: field.name.clone(),
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,263 @@
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_err, PolarsResult};
+use serde_json::Value;
+
+/// A top-level field of an Iceberg schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IcebergField {
+    /// The field ID, which identifies the column across renames and in the data files.
+    pub id: i32,
+    pub name: PlSmallStr,
+    pub required: bool,
+    pub dtype: DataType,
+}
+
+/// A schema of an Iceberg table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IcebergSchema {
+    pub schema_id: i32,
+    pub fields: Vec<IcebergField>,
+}
+
+impl IcebergSchema {
+    pub(super) fn parse(value: &Value) -> PolarsResult<Self> {
+        let schema_id = value
+            .get("schema-id")
+            .and_then(Value::as_i64)
+            .unwrap_or_default() as i32;
+        let fields = parse_fields(value)?
+            .into_iter()
+            .map(|(id, name, required, dtype)| IcebergField {
+                id,
+                name,
+                required,
+                dtype,
+            })
+            .collect();
+        Ok(Self { schema_id, fields })
+    }
+
+    /// The polars [`Schema`] of the table.
+    pub fn to_schema(&self) -> Schema {
+        self.fields
+            .iter()
+            .map(|f| Field::new(f.name.clone(), f.dtype.clone()))
+            .collect()
+    }
+
+    pub fn field_by_id(&self, id: i32) -> Option<&IcebergField> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+
+    pub fn field_by_name(&self, name: &str) -> Option<&IcebergField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+fn get<'a>(value: &'a Value, key: &str) -> PolarsResult<&'a Value> {
+    value
+        .get(key)
+        .ok_or_else(|| polars_err!(ComputeError: "Iceberg type is missing the '{}' key", key))
+}
+
+fn parse_fields(value: &Value) -> PolarsResult<Vec<(i32, PlSmallStr, bool, DataType)>> {
+    get(value, "fields")?
+        .as_array()
+        .ok_or_else(|| polars_err!(ComputeError: "Iceberg struct fields must be an array"))?
+        .iter()
+        .map(|field| {
+            let id = get(field, "id")?
+                .as_i64()
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg field IDs must be integers"))?;
+            let name = get(field, "name")?
+                .as_str()
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg field names must be strings"))?;
+            let required = field
+                .get("required")
+                .and_then(Value::as_bool)
+                .unwrap_or_default();
+            let dtype = parse_type(get(field, "type")?)?;
+            Ok((id as i32, name.into(), required, dtype))
+        })
+        .collect()
+}
+
+fn parse_type(value: &Value) -> PolarsResult<DataType> {
+    let dtype = match value {
+        Value::String(name) => parse_primitive_type(name)?,
+        Value::Object(_) => match get(value, "type")?.as_str() {
+            Some("struct") => DataType::Struct(
+                parse_fields(value)?
+                    .into_iter()
+                    .map(|(_, name, _, dtype)| Field::new(name, dtype))
+                    .collect(),
+            ),
+            Some("list") => DataType::List(Box::new(parse_type(get(value, "element")?)?)),
+            Some("map") => DataType::List(Box::new(DataType::Struct(vec![
+                Field::new(
+                    PlSmallStr::from_static("key"),
+                    parse_type(get(value, "key")?)?,
+                ),
+                Field::new(
+                    PlSmallStr::from_static("value"),
+                    parse_type(get(value, "value")?)?,
+                ),
+            ]))),
+            _ => polars_bail!(ComputeError: "unsupported Iceberg type: {}", value),
+        },
+        _ => polars_bail!(ComputeError: "unsupported Iceberg type: {}", value),
+    };
+    Ok(dtype)
+}
+
+fn parse_primitive_type(name: &str) -> PolarsResult<DataType> {
+    let dtype = match name {
+        "boolean" => DataType::Boolean,
+        "int" => DataType::Int32,
+        "long" => DataType::Int64,
+        "float" => DataType::Float32,
+        "double" => DataType::Float64,
+        "date" => DataType::Date,
+        #[cfg(feature = "dtype-time")]
+        "time" => DataType::Time,
+        "timestamp" => DataType::Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+        "timestamp_ns" => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into())),
+        "string" => DataType::String,
+        "uuid" | "binary" => DataType::Binary,
+        name if name.starts_with("fixed[") => DataType::Binary,
+        name if name.starts_with("decimal") => {
+            let parsed = name
+                .strip_prefix("decimal(")
+                .and_then(|v| v.strip_suffix(')'))
+                .and_then(|v| v.split_once(','))
+                .and_then(|(p, s)| Some((p.trim().parse().ok()?, s.trim().parse().ok()?)));
+            let Some((precision, scale)) = parsed else {
+                polars_bail!(ComputeError: "invalid Iceberg decimal type '{}'", name)
+            };
+            #[cfg(feature = "dtype-decimal")]
+            {
+                DataType::Decimal(Some(precision), Some(scale))
+            }
+            #[cfg(not(feature = "dtype-decimal"))]
+            {
+                let _: (usize, usize) = (precision, scale);
+                polars_bail!(ComputeError: "reading Iceberg decimal columns requires the 'dtype-decimal' feature")
+            }
+        },
+        name => polars_bail!(ComputeError: "unsupported Iceberg type '{}'", name),
+    };
+    Ok(dtype)
+}
+
+/// Decode a lower or upper bound of a column, which uses Iceberg's single-value binary
+/// serialization, into the physical representation of `dtype`.
+///
+/// Returns `None` for types that have no ordered statistics in polars.
+pub(super) fn decode_bound(bytes: &[u8], dtype: &DataType) -> Option<AnyValue<'static>> {
+    fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+        bytes.try_into().ok()
+    }
+
+    let value = match dtype {
+        DataType::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+        DataType::Int32 | DataType::Date => AnyValue::Int32(i32::from_le_bytes(le(bytes)?)),
+        // Columns that were promoted from `int` keep 4-byte bounds in older files.
+        DataType::Int64 | DataType::Datetime(_, _) => match bytes.len() {
+            4 => AnyValue::Int64(i32::from_le_bytes(le(bytes)?) as i64),
+            _ => AnyValue::Int64(i64::from_le_bytes(le(bytes)?)),
+        },
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => AnyValue::Int64(i64::from_le_bytes(le(bytes)?).checked_mul(1000)?),
+        DataType::Float32 => AnyValue::Float32(f32::from_le_bytes(le(bytes)?)),
+        DataType::Float64 => match bytes.len() {
+            4 => AnyValue::Float64(f32::from_le_bytes(le(bytes)?) as f64),
+            _ => AnyValue::Float64(f64::from_le_bytes(le(bytes)?)),
+        },
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => {
+            // Big-endian two's complement of the unscaled value.
+            if bytes.is_empty() || bytes.len() > 16 {
+                return None;
+            }
+            let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+            let mut buf = [fill; 16];
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            AnyValue::Int128(i128::from_be_bytes(buf))
+        },
+        DataType::String => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+        DataType::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Build a column of `dtype` from values in its physical representation.
+pub(super) fn physical_values_to_column(
+    name: PlSmallStr,
+    values: &[AnyValue],
+    dtype: &DataType,
+) -> PolarsResult<Column> {
+    let physical = dtype.to_physical();
+    let s = Series::from_any_values_and_dtype(name, values, &physical, false)?;
+    // SAFETY: the values are in the physical representation of `dtype`.
+    Ok(unsafe { s.from_physical_unchecked(dtype) }?.into_column())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let value: Value = serde_json::from_str(
+            r#"{"type":"struct","schema-id":1,"fields":[
+                {"id":1,"name":"id","required":true,"type":"long"},
+                {"id":2,"name":"ts","required":false,"type":"timestamptz"},
+                {"id":3,"name":"tags","required":false,"type":{"type":"list","element-id":5,"element":"string","element-required":false}},
+                {"id":4,"name":"props","required":false,"type":{"type":"map","key-id":6,"key":"string","value-id":7,"value":"int","value-required":false}}
+            ]}"#,
+        )
+        .unwrap();
+        let schema = IcebergSchema::parse(&value).unwrap();
+        assert_eq!(schema.schema_id, 1);
+        assert!(schema.fields[0].required);
+        assert_eq!(schema.field_by_id(2).unwrap().name, "ts");
+        assert_eq!(
+            schema.to_schema(),
+            Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into()))
+                ),
+                Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+                Field::new(
+                    "props".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_decode_bound() {
+        assert_eq!(
+            decode_bound(&5i32.to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(5))
+        );
+        assert_eq!(
+            decode_bound(&(-3i64).to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(-3))
+        );
+        assert_eq!(
+            decode_bound(b"abc", &DataType::String),
+            Some(AnyValue::StringOwned("abc".into()))
+        );
+        assert_eq!(decode_bound(&[1, 2], &DataType::Int32), None);
+    }
+}
@@ -0,0 +1,335 @@
+use chrono::NaiveDate;
+use polars_core::prelude::*;
+use polars_error::PolarsResult;
+use polars_utils::format_pl_smallstr;
+
+use super::manifest::IcebergDataFile;
+use super::metadata::{IcebergPartitionField, IcebergSnapshot, IcebergTransform};
+use super::schema::{decode_bound, physical_values_to_column, IcebergField};
+
+/// The statistics of a column in a file or manifest, in the physical representation of the
+/// column.
+struct ColumnStats {
+    min: AnyValue<'static>,
+    max: AnyValue<'static>,
+    null_count: Option<IdxSize>,
+}
+
+impl Default for ColumnStats {
+    fn default() -> Self {
+        Self {
+            min: AnyValue::Null,
+            max: AnyValue::Null,
+            null_count: None,
+        }
+    }
+}
+
+impl IcebergSnapshot {
+    /// The statistics of the manifests of the snapshot, derived from their partition summaries.
+    ///
+    /// The frame has one row per manifest, with a `len` column and `{column}_min`,
+    /// `{column}_max` and `{column}_nc` columns for every column of the table, which is the
+    /// layout that batch skipping predicates are evaluated on. Unknown statistics are null.
+    pub fn manifest_statistics(&self) -> PolarsResult<DataFrame> {
+        let stats = self
+            .manifests
+            .iter()
+            .map(|manifest| {
+                let spec = self.partition_specs.get(&manifest.spec_id);
+                self.schema
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let mut stats = ColumnStats::default();
+                        let Some(spec) = spec else {
+                            return stats;
+                        };
+                        for (partition_field, summary) in
+                            spec.fields.iter().zip(&manifest.partitions)
+                        {
+                            if partition_field.source_id != field.id
+                                || partition_field.transform == IcebergTransform::Void
+                            {
+                                continue;
+                            }
+                            // Every transform maps nulls to null.
+                            if !summary.contains_null {
+                                stats.null_count = Some(0);
+                            }
+                            let result_dtype = transform_result_dtype(partition_field, field);
+                            let bound = |bound: &Option<Vec<u8>>| {
+                                let value = decode_bound(bound.as_deref()?, &result_dtype)?;
+                                partition_range(&partition_field.transform, &value, &field.dtype)
+                            };
+                            if let Some((min, _)) = bound(&summary.lower_bound) {
+                                stats.min = min;
+                            }
+                            if let Some((_, Some(max))) = bound(&summary.upper_bound) {
+                                stats.max = max;
+                            }
+                        }
+                        stats
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        self.statistics_frame(vec![None; self.manifests.len()], stats)
+    }
+
+    /// The statistics of data files, derived from their column bounds and partition values.
+    ///
+    /// See [`IcebergSnapshot::manifest_statistics`] for the layout of the frame.
+    pub fn file_statistics(&self, files: &[IcebergDataFile]) -> PolarsResult<DataFrame> {
+        let stats = files
+            .iter()
+            .map(|file| {
+                let spec = self.partition_specs.get(&file.spec_id);
+                self.schema
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let mut stats = ColumnStats {
+                            min: file
+                                .lower_bounds
+                                .get(&field.id)
+                                .and_then(|b| decode_bound(b, &field.dtype))
+                                .unwrap_or(AnyValue::Null),
+                            max: file
+                                .upper_bounds
+                                .get(&field.id)
+                                .and_then(|b| decode_bound(b, &field.dtype))
+                                .unwrap_or(AnyValue::Null),
+                            null_count: file
+                                .null_value_counts
+                                .get(&field.id)
+                                .map(|&nc| nc as IdxSize),
+                        };
+                        let Some(spec) = spec else {
+                            return stats;
+                        };
+                        // Fall back to the partition values for statistics that are missing.
+                        for (partition_field, value) in spec.fields.iter().zip(&file.partition) {
+                            if partition_field.source_id != field.id {
+                                continue;
+                            }
+                            if value.is_null() {
+                                if partition_field.transform == IcebergTransform::Identity {
+                                    stats.null_count.get_or_insert(file.record_count as IdxSize);
+                                }
+                                continue;
+                            }
+                            let value = to_physical(value);
+                            let Some((min, max)) =
+                                partition_range(&partition_field.transform, &value, &field.dtype)
+                            else {
+                                continue;
+                            };
+                            if stats.min.is_null() {
+                                stats.min = min;
+                            }
+                            if let (true, Some(max)) = (stats.max.is_null(), max) {
+                                stats.max = max;
+                            }
+                        }
+                        stats
+                    })
+                    .collect()
+            })
+            .collect::<Vec<Vec<_>>>();
+        let lengths = files
+            .iter()
+            .map(|f| Some(f.record_count as IdxSize))
+            .collect();
+        self.statistics_frame(lengths, stats)
+    }
+
+    fn statistics_frame(
+        &self,
+        lengths: Vec<Option<IdxSize>>,
+        mut stats: Vec<Vec<ColumnStats>>,
+    ) -> PolarsResult<DataFrame> {
+        let height = lengths.len();
+        let mut columns = Vec::with_capacity(1 + 3 * self.schema.fields.len());
+        columns.push(
+            IdxCa::from_iter_options(PlSmallStr::from_static("len"), lengths.into_iter())
+                .into_column(),
+        );
+        for (i, field) in self.schema.fields.iter().enumerate() {
+            let mut mins = Vec::with_capacity(height);
+            let mut maxs = Vec::with_capacity(height);
+            let mut null_counts = Vec::with_capacity(height);
+            for row in stats.iter_mut() {
+                let stats = std::mem::take(&mut row[i]);
+                mins.push(stats.min);
+                maxs.push(stats.max);
+                null_counts.push(stats.null_count);
+            }
+            let name = &field.name;
+            let has_order = !field.dtype.is_nested();
+            for (suffix, values) in [("min", mins), ("max", maxs)] {
+                let name = format_pl_smallstr!("{name}_{suffix}");
+                columns.push(if has_order {
+                    physical_values_to_column(name, &values, &field.dtype)?
+                } else {
+                    Column::full_null(name, height, &field.dtype)
+                });
+            }
+            columns.push(
+                IdxCa::from_iter_options(format_pl_smallstr!("{name}_nc"), null_counts.into_iter())
+                    .into_column(),
+            );
+        }
+        DataFrame::new_with_height(height, columns)
+    }
+}
+
+/// The type of the values of a partition field.
+fn transform_result_dtype(
+    partition_field: &IcebergPartitionField,
+    source: &IcebergField,
+) -> DataType {
+    match partition_field.transform {
+        IcebergTransform::Identity | IcebergTransform::Truncate(_) | IcebergTransform::Void => {
+            source.dtype.clone()
+        },
+        _ => DataType::Int32,
+    }
+}
+
+/// Partition values are read from Avro with logical types, the statistics use physical ones.
+fn to_physical(value: &AnyValue) -> AnyValue<'static> {
+    match value {
+        AnyValue::Date(v) => AnyValue::Int32(*v),
+        AnyValue::Datetime(v, _, _) | AnyValue::DatetimeOwned(v, _, _) => AnyValue::Int64(*v),
+        #[cfg(feature = "dtype-decimal")]
+        AnyValue::Decimal(v, _) => AnyValue::Int128(*v),
+        value => value.clone().into_static(),
+    }
+}
+
+/// The range of values of the source column of a partition field with the given value.
+///
+/// Returns `None` if the transform does not preserve order, and no upper bound if only the
+/// lower bound is known.
+fn partition_range(
+    transform: &IcebergTransform,
+    value: &AnyValue,
+    dtype: &DataType,
+) -> Option<(AnyValue<'static>, Option<AnyValue<'static>>)> {
+    let range = match transform {
+        IcebergTransform::Identity => (
+            value.clone().into_static(),
+            Some(value.clone().into_static()),
+        ),
+        IcebergTransform::Truncate(width) => match value {
+            AnyValue::Int32(v) => (
+                AnyValue::Int32(*v),
+                Some(AnyValue::Int32(v.checked_add(*width as i32 - 1)?)),
+            ),
+            AnyValue::Int64(v) => (
+                AnyValue::Int64(*v),
+                Some(AnyValue::Int64(v.checked_add(*width as i64 - 1)?)),
+            ),
+            // A truncated string is a prefix of all values of the partition.
+            AnyValue::String(_) | AnyValue::StringOwned(_) => (value.clone().into_static(), None),
+            _ => return None,
+        },
+        IcebergTransform::Year
+        | IcebergTransform::Month
+        | IcebergTransform::Day
+        | IcebergTransform::Hour => {
+            let v = value.extract::<i64>()?;
+            // The start and end of the period in seconds since the epoch.
+            let days = |date: NaiveDate| {
+                date.signed_duration_since(NaiveDate::default())
+                    .num_days()
+                    .checked_mul(86400)
+            };
+            let (start, end) = match transform {
+                IcebergTransform::Year => {
+                    let year = i32::try_from(1970 + v).ok()?;
+                    (
+                        days(NaiveDate::from_ymd_opt(year, 1, 1)?)?,
+                        days(NaiveDate::from_ymd_opt(year + 1, 1, 1)?)?,
+                    )
+                },
+                IcebergTransform::Month => {
+                    let start = NaiveDate::from_ymd_opt(
+                        i32::try_from(1970 + v.div_euclid(12)).ok()?,
+                        v.rem_euclid(12) as u32 + 1,
+                        1,
+                    )?;
+                    (
+                        days(start)?,
+                        days(start.checked_add_months(chrono::Months::new(1))?)?,
+                    )
+                },
+                IcebergTransform::Day => (v.checked_mul(86400)?, (v + 1).checked_mul(86400)?),
+                _ => (v.checked_mul(3600)?, (v + 1).checked_mul(3600)?),
+            };
+            match dtype {
+                DataType::Date if *transform != IcebergTransform::Hour => (
+                    AnyValue::Int32((start / 86400) as i32),
+                    Some(AnyValue::Int32((end / 86400) as i32 - 1)),
+                ),
+                DataType::Datetime(tu, _) => {
+                    let per_second = match tu {
+                        TimeUnit::Nanoseconds => 1_000_000_000,
+                        TimeUnit::Microseconds => 1_000_000,
+                        TimeUnit::Milliseconds => 1_000,
+                    };
+                    (
+                        AnyValue::Int64(start.checked_mul(per_second)?),
+                        Some(AnyValue::Int64(end.checked_mul(per_second)? - 1)),
+                    )
+                },
+                _ => return None,
+            }
+        },
+        IcebergTransform::Bucket(_) | IcebergTransform::Void | IcebergTransform::Unknown(_) => {
+            return None
+        },
+    };
+    Some(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_range() {
+        let datetime = DataType::Datetime(TimeUnit::Microseconds, None);
+        // 2024-02 is month 649 since the epoch.
+        let (min, max) = partition_range(
+            &IcebergTransform::Month,
+            &AnyValue::Int32(649),
+            &DataType::Date,
+        )
+        .unwrap();
+        assert_eq!(min, AnyValue::Int32(19754));
+        assert_eq!(max, Some(AnyValue::Int32(19782)));
+
+        let (min, max) =
+            partition_range(&IcebergTransform::Day, &AnyValue::Int32(1), &datetime).unwrap();
+        assert_eq!(min, AnyValue::Int64(86_400_000_000));
+        assert_eq!(max, Some(AnyValue::Int64(2 * 86_400_000_000 - 1)));
+
+        let (min, max) = partition_range(
+            &IcebergTransform::Truncate(10),
+            &AnyValue::Int64(20),
+            &DataType::Int64,
+        )
+        .unwrap();
+        assert_eq!(min, AnyValue::Int64(20));
+        assert_eq!(max, Some(AnyValue::Int64(29)));
+
+        assert!(partition_range(
+            &IcebergTransform::Bucket(4),
+            &AnyValue::Int32(1),
+            &DataType::Int64
+        )
+        .is_none());
+    }
+}
@@ -123,7 +123,7 @@ impl IpcReaderAsync {
 
         arrow::io::ipc::read::deserialize_footer(
             footer.as_ref(),
-            footer_size.try_into().map_err(to_compute_err)?,
+            file_size.try_into().map_err(to_compute_err)?,
         )
     }
 
@@ -44,7 +44,7 @@ use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 
 use crate::prelude::*;
-use crate::shared::{finish_reader, ArrowReader};
+use crate::shared::{finish_reader, schema_to_arrow_checked, ArrowReader};
 
 /// Read Arrows Stream IPC format into a DataFrame
 ///
@@ -116,6 +116,28 @@ impl<R: Read> IpcStreamReader<R> {
         self
     }
 
+    /// Turn the reader into a [`BatchedIpcStreamReader`] that yields the record batches of the
+    /// stream one at a time, as they arrive.
+    pub fn batched(mut self) -> PolarsResult<BatchedIpcStreamReader<R>> {
+        let metadata = self.metadata()?;
+
+        if let Some(columns) = &self.columns {
+            self.projection = Some(columns_to_projection(columns, &metadata.schema)?);
+        }
+        let schema = match &self.projection {
+            Some(projection) => apply_projection(&metadata.schema, projection),
+            None => metadata.schema.clone(),
+        };
+
+        Ok(BatchedIpcStreamReader {
+            reader: read::StreamReader::new(self.reader, metadata, self.projection),
+            schema,
+            n_rows: self.n_rows,
+            rows_read: 0,
+            row_index: self.row_index,
+        })
+    }
+
     fn metadata(&mut self) -> PolarsResult<StreamMetadata> {
         match &self.metadata {
             None => {
@@ -143,6 +165,45 @@ where
     }
 }
 
+/// Reads an Arrow IPC stream batch by batch. Created with [`IpcStreamReader::batched`].
+pub struct BatchedIpcStreamReader<R: Read> {
+    reader: read::StreamReader<R>,
+    schema: ArrowSchema,
+    n_rows: Option<usize>,
+    rows_read: usize,
+    row_index: Option<RowIndex>,
+}
+
+impl<R: Read> BatchedIpcStreamReader<R> {
+    /// Schema of the batches that are returned, without the row index.
+    pub fn schema(&self) -> Schema {
+        Schema::from_arrow_schema(&self.schema)
+    }
+
+    /// Read the next record batch of the stream. Returns `None` when the stream is finished
+    /// or `n_rows` rows have been read.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.n_rows.is_some_and(|n| self.rows_read >= n) {
+            return Ok(None);
+        }
+        let Some(batch) = self.reader.next_record_batch()? else {
+            return Ok(None);
+        };
+
+        let offset = self.rows_read;
+        let mut df = DataFrame::try_from((batch, &self.schema))?;
+        if let Some(n) = self.n_rows {
+            df = df.slice(0, n - offset);
+        }
+        self.rows_read += df.height();
+
+        if let Some(rc) = &self.row_index {
+            df.with_row_index_mut(rc.name.clone(), Some(offset as IdxSize + rc.offset));
+        }
+        Ok(Some(df))
+    }
+}
+
 impl<R> SerReader<R> for IpcStreamReader<R>
 where
     R: Read,
@@ -251,6 +312,57 @@ impl<W> IpcStreamWriter<W> {
     }
 }
 
+impl<W: Write> IpcStreamWriter<W> {
+    /// Start the stream and return a [`BatchedIpcStreamWriter`] that writes each
+    /// [`DataFrame`] as soon as it is given.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedIpcStreamWriter<W>> {
+        let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
+        let mut writer = write::StreamWriter::new(
+            self.writer,
+            WriteOptions {
+                compression: self.compression.map(|c| c.into()),
+            },
+        );
+        if let Some(custom_metadata) = self.custom_schema_metadata {
+            writer.set_custom_schema_metadata(custom_metadata);
+        }
+        writer.start(&schema, None)?;
+
+        Ok(BatchedIpcStreamWriter {
+            writer,
+            compat_level: self.compat_level,
+        })
+    }
+}
+
+/// Writes an Arrow IPC stream batch by batch. Created with [`IpcStreamWriter::batched`].
+pub struct BatchedIpcStreamWriter<W: Write> {
+    writer: write::StreamWriter<W>,
+    compat_level: CompatLevel,
+}
+
+impl<W: Write> BatchedIpcStreamWriter<W> {
+    /// Write a batch to the stream and flush the underlying writer, so that the reader on
+    /// the other side of a pipe or socket receives it immediately.
+    ///
+    /// # Panics
+    /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        for batch in df.iter_chunks(self.compat_level, true) {
+            self.writer.write(&batch, None)?
+        }
+        self.writer.get_mut().flush()?;
+        Ok(())
+    }
+
+    /// Write the end-of-stream marker.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()?;
+        self.writer.get_mut().flush()?;
+        Ok(())
+    }
+}
+
 impl<W> SerWriter<W> for IpcStreamWriter<W>
 where
     W: Write,
@@ -0,0 +1,441 @@
+//! Conversion between [JSON Schema](https://json-schema.org) documents and polars [`Schema`]s.
+//!
+//! Only the structural subset of JSON Schema that maps to polars data types is supported:
+//!
+//! | JSON Schema                               | polars                          |
+//! |-------------------------------------------|---------------------------------|
+//! | `boolean`                                 | `Boolean`                       |
+//! | `integer`                                 | `Int64`                         |
+//! | `number`                                  | `Float64`                       |
+//! | `string`                                  | `String`                        |
+//! | `string` with `format: date-time`         | `Datetime(Microseconds, "UTC")` |
+//! | `string` with `format: date` / `time`     | `Date` / `Time`                 |
+//! | `enum` of strings                         | `Enum`                          |
+//! | `array` with `items`                      | `List`                          |
+//! | `object` with `properties`                | `Struct`                        |
+//! | `null`                                    | `Null`                          |
+//!
+//! Unions (`"type": [...]`, `anyOf`, `oneOf`) are resolved to the supertype of their
+//! non-null members, and local references (`#/$defs/...`, `#/definitions/...`) are followed.
+//! Validation keywords such as `minimum` or `pattern` are ignored. RFC 3339 date-times always
+//! carry an offset, so they are read as UTC.
+#[cfg(feature = "dtype-categorical")]
+use arrow::array::Utf8ViewArray;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_error::to_compute_err;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// References are followed at most this deep, which guards against recursive schemas.
+const MAX_REF_DEPTH: usize = 64;
+
+/// Convert a JSON Schema document describing a JSON object into a [`Schema`].
+///
+/// The properties of the top-level object become the columns, in document order.
+pub fn schema_from_json_schema(json_schema: &str) -> PolarsResult<Schema> {
+    let root: Json = serde_json::from_str(json_schema).map_err(to_compute_err)?;
+    let converter = Converter { root: &root };
+    match converter.to_dtype(&root, "<root>", 0)? {
+        DataType::Struct(fields) => Ok(Schema::from_iter(fields)),
+        dt => polars_bail!(
+            SchemaMismatch: "JSON Schema must describe an object to be used as a schema, got {}", dt
+        ),
+    }
+}
+
+/// Convert a [`Schema`] into a JSON Schema document describing a JSON object with one
+/// property per column.
+pub fn schema_to_json_schema(schema: &Schema) -> PolarsResult<String> {
+    let doc = object([
+        (
+            "$schema",
+            string("https://json-schema.org/draft/2020-12/schema"),
+        ),
+        ("type", string("object")),
+        ("properties", properties_to_json(schema.iter())?),
+    ]);
+    serde_json::to_string_pretty(&doc).map_err(to_compute_err)
+}
+
+/// A JSON value whose objects keep the order of their keys, so that properties map to columns
+/// in document order. serde_json's `preserve_order` feature would change `serde_json::Map` for
+/// every crate in the build instead.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+enum Json {
+    Object(PlIndexMap<String, Json>),
+    Array(Vec<Json>),
+    Scalar(Value),
+}
+
+impl Json {
+    fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(obj) => obj.get(key),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::Scalar(Value::String(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        matches!(self, Json::Scalar(Value::Null))
+    }
+
+    /// Look up a value by a JSON Pointer (RFC 6901), e.g. `/$defs/point`.
+    fn pointer(&self, pointer: &str) -> Option<&Json> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .try_fold(self, |value, token| match value {
+                Json::Object(obj) => obj.get(&token),
+                Json::Array(values) => values.get(token.parse::<usize>().ok()?),
+                Json::Scalar(_) => None,
+            })
+    }
+}
+
+impl std::fmt::Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+        f.write_str(&s)
+    }
+}
+
+fn object<const N: usize>(entries: [(&str, Json); N]) -> Json {
+    Json::Object(
+        entries
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn string(s: &str) -> Json {
+    Json::Scalar(Value::String(s.to_string()))
+}
+
+struct Converter<'a> {
+    root: &'a Json,
+}
+
+impl<'a> Converter<'a> {
+    fn to_dtype(&self, value: &'a Json, path: &str, depth: usize) -> PolarsResult<DataType> {
+        let obj = match value {
+            // `true` accepts any value, the closest we can get is a string.
+            Json::Scalar(Value::Bool(true)) => return Ok(DataType::String),
+            Json::Object(_) => value,
+            _ => {
+                polars_bail!(ComputeError: "invalid JSON Schema at '{}': expected an object", path)
+            },
+        };
+
+        if let Some(reference) = obj.get("$ref") {
+            polars_ensure!(
+                depth < MAX_REF_DEPTH,
+                ComputeError: "JSON Schema reference at '{}' is nested too deep; recursive schemas are not supported",
+                path
+            );
+            let target = self.resolve_ref(reference, path)?;
+            return self.to_dtype(target, path, depth + 1);
+        }
+
+        if let Some(variants) = obj.get("enum") {
+            let Json::Array(variants) = variants else {
+                polars_bail!(ComputeError: "invalid JSON Schema at '{}': 'enum' must be an array", path)
+            };
+            return enum_to_dtype(variants.iter(), path);
+        }
+        if let Some(constant) = obj.get("const") {
+            return enum_to_dtype(std::iter::once(constant), path);
+        }
+
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(variants) = obj.get(keyword) {
+                let Json::Array(variants) = variants else {
+                    polars_bail!(ComputeError: "invalid JSON Schema at '{}': '{}' must be an array", path, keyword)
+                };
+                let dtypes = variants
+                    .iter()
+                    .map(|v| self.to_dtype(v, path, depth))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                return union_to_dtype(dtypes, path);
+            }
+        }
+
+        match obj.get("type") {
+            Some(Json::Scalar(Value::String(tp))) => self.type_to_dtype(tp, obj, path, depth),
+            Some(Json::Array(types)) => {
+                let dtypes = types
+                    .iter()
+                    .map(|tp| match tp.as_str() {
+                        Some(tp) => self.type_to_dtype(tp, obj, path, depth),
+                        None => polars_bail!(ComputeError: "invalid JSON Schema at '{}': 'type' must contain strings", path),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                union_to_dtype(dtypes, path)
+            },
+            Some(_) => {
+                polars_bail!(ComputeError: "invalid JSON Schema at '{}': 'type' must be a string or an array", path)
+            },
+            None if obj.get("properties").is_some() => {
+                self.type_to_dtype("object", obj, path, depth)
+            },
+            None if obj.get("items").is_some() => self.type_to_dtype("array", obj, path, depth),
+            None => polars_bail!(
+                ComputeError: "cannot determine the data type of JSON Schema at '{}'; set 'type'", path
+            ),
+        }
+    }
+
+    fn type_to_dtype(
+        &self,
+        tp: &str,
+        obj: &'a Json,
+        path: &str,
+        depth: usize,
+    ) -> PolarsResult<DataType> {
+        Ok(match tp {
+            "null" => DataType::Null,
+            "boolean" => DataType::Boolean,
+            "integer" => DataType::Int64,
+            "number" => DataType::Float64,
+            "string" => match obj.get("format").and_then(|f| f.as_str()) {
+                #[cfg(feature = "dtype-datetime")]
+                Some("date-time") => {
+                    DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+                },
+                #[cfg(feature = "dtype-date")]
+                Some("date") => DataType::Date,
+                #[cfg(feature = "dtype-time")]
+                Some("time") => DataType::Time,
+                _ => DataType::String,
+            },
+            "array" => {
+                let inner = match obj.get("items") {
+                    Some(items) => self.to_dtype(items, &format!("{path}[]"), depth)?,
+                    None => DataType::Null,
+                };
+                DataType::List(Box::new(inner))
+            },
+            "object" => {
+                let Some(properties) = obj.get("properties") else {
+                    polars_bail!(
+                        ComputeError: "cannot convert JSON Schema object at '{}' without 'properties'", path
+                    )
+                };
+                let Json::Object(properties) = properties else {
+                    polars_bail!(ComputeError: "invalid JSON Schema at '{}': 'properties' must be an object", path)
+                };
+                let fields = properties
+                    .iter()
+                    .map(|(name, value)| {
+                        let dtype = self.to_dtype(value, &format!("{path}.{name}"), depth)?;
+                        Ok(Field::new(name.as_str().into(), dtype))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                DataType::Struct(fields)
+            },
+            tp => polars_bail!(ComputeError: "unsupported JSON Schema type '{}' at '{}'", tp, path),
+        })
+    }
+
+    fn resolve_ref(&self, reference: &Json, path: &str) -> PolarsResult<&'a Json> {
+        let pointer = reference.as_str().and_then(|r| r.strip_prefix('#'));
+        let target = pointer.and_then(|p| self.root.pointer(p));
+        target.ok_or_else(|| {
+            polars_err!(
+                ComputeError: "cannot resolve JSON Schema reference {} at '{}'; only local references are supported",
+                reference, path
+            )
+        })
+    }
+}
+
+fn enum_to_dtype<'a>(
+    variants: impl Iterator<Item = &'a Json>,
+    path: &str,
+) -> PolarsResult<DataType> {
+    let categories = variants
+        .filter(|v| !v.is_null())
+        .map(|v| v.as_str())
+        .collect::<Option<Vec<_>>>();
+    let Some(_categories) = categories else {
+        polars_bail!(ComputeError: "cannot convert JSON Schema enum at '{}': only string variants are supported", path)
+    };
+    #[cfg(feature = "dtype-categorical")]
+    {
+        let categories = Utf8ViewArray::from_slice_values(_categories);
+        Ok(create_enum_dtype(categories))
+    }
+    #[cfg(not(feature = "dtype-categorical"))]
+    {
+        Ok(DataType::String)
+    }
+}
+
+fn union_to_dtype(dtypes: Vec<DataType>, path: &str) -> PolarsResult<DataType> {
+    dtypes
+        .into_iter()
+        .filter(|dt| !dt.is_null())
+        .map(Ok)
+        .reduce(|l, r| {
+            let (l, r) = (l?, r?);
+            try_get_supertype(&l, &r).map_err(|_| {
+                polars_err!(
+                    SchemaMismatch: "cannot combine the types {} and {} in the JSON Schema union at '{}'",
+                    l, r, path
+                )
+            })
+        })
+        .unwrap_or(Ok(DataType::Null))
+}
+
+fn properties_to_json<'a>(
+    fields: impl Iterator<Item = (&'a PlSmallStr, &'a DataType)>,
+) -> PolarsResult<Json> {
+    fields
+        .map(|(name, dtype)| Ok((name.to_string(), dtype_to_json(name, dtype)?)))
+        .collect::<PolarsResult<PlIndexMap<_, _>>>()
+        .map(Json::Object)
+}
+
+fn dtype_to_json(name: &str, dtype: &DataType) -> PolarsResult<Json> {
+    use DataType::*;
+    let typed = |tp: &str| object([("type", string(tp))]);
+    let string_format =
+        |format: &str| object([("type", string("string")), ("format", string(format))]);
+    Ok(match dtype {
+        Null => typed("null"),
+        Boolean => typed("boolean"),
+        dt if dt.is_integer() => typed("integer"),
+        dt if dt.is_float() => typed("number"),
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => typed("number"),
+        String => typed("string"),
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(_, _) => string_format("date-time"),
+        #[cfg(feature = "dtype-date")]
+        Date => string_format("date"),
+        #[cfg(feature = "dtype-time")]
+        Time => string_format("time"),
+        #[cfg(feature = "dtype-categorical")]
+        Enum(Some(rev_map), _) => object([
+            ("type", string("string")),
+            (
+                "enum",
+                Json::Array(rev_map.get_categories().values_iter().map(string).collect()),
+            ),
+        ]),
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(None, _) => typed("string"),
+        List(inner) => object([
+            ("type", string("array")),
+            ("items", dtype_to_json(name, inner)?),
+        ]),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => object([
+            ("type", string("object")),
+            (
+                "properties",
+                properties_to_json(fields.iter().map(|f| (f.name(), f.dtype())))?,
+            ),
+        ]),
+        dt => polars_bail!(
+            InvalidOperation: "cannot convert column '{}' of type {} to JSON Schema", name, dt
+        ),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "dtype-categorical", feature = "dtype-datetime"))]
+    fn test_json_schema_roundtrip() -> PolarsResult<()> {
+        let doc = r##"{
+            "$defs": {"point": {"type": "object", "properties": {"x": {"type": "number"}, "y": {"type": "number"}}}},
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "name": {"type": ["string", "null"]},
+                "score": {"anyOf": [{"type": "integer"}, {"type": "number"}]},
+                "created": {"type": "string", "format": "date-time"},
+                "color": {"enum": ["red", "green", null]},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "location": {"$ref": "#/$defs/point"}
+            }
+        }"##;
+        let schema = schema_from_json_schema(doc)?;
+
+        let point = DataType::Struct(vec![
+            Field::new("x".into(), DataType::Float64),
+            Field::new("y".into(), DataType::Float64),
+        ]);
+        let color = create_enum_dtype(Utf8ViewArray::from_slice_values(["red", "green"]));
+        let expected = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+            Field::new("score".into(), DataType::Float64),
+            Field::new(
+                "created".into(),
+                DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            ),
+            Field::new("color".into(), color),
+            Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+            Field::new("location".into(), point),
+        ]);
+        assert_eq!(schema, expected);
+
+        let out = schema_to_json_schema(&schema)?;
+        assert_eq!(schema_from_json_schema(&out)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_schema_property_order() -> PolarsResult<()> {
+        let doc = r#"{"properties": {"z": {"type": "integer"}, "a": {"type": "string"}, "m": {"type": "boolean"}, "a": {"type": "number"}}}"#;
+        let schema = schema_from_json_schema(doc)?;
+        let expected = Schema::from_iter([
+            Field::new("z".into(), DataType::Int64),
+            Field::new("a".into(), DataType::Float64),
+            Field::new("m".into(), DataType::Boolean),
+        ]);
+        assert_eq!(schema, expected);
+
+        let out = schema_to_json_schema(&schema)?;
+        let positions = ["\"z\"", "\"a\"", "\"m\""].map(|key| out.find(key).unwrap());
+        assert!(positions.is_sorted());
+        assert_eq!(schema_from_json_schema(&out)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_schema_errors() {
+        let err = schema_from_json_schema(r#"{"type": "array", "items": {"type": "integer"}}"#);
+        assert!(err.is_err());
+        let err = schema_from_json_schema(
+            r##"{"type": "object", "properties": {"a": {"$ref": "#/$defs/missing"}}}"##,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("#/$defs/missing"));
+        let err = schema_from_json_schema(
+            r#"{"type": "object", "properties": {"a": {"type": ["array", "object"], "items": {"type": "integer"}, "properties": {"b": {"type": "integer"}}}}}"#,
+        );
+        assert!(err.is_err());
+
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Binary)]);
+        assert!(schema_to_json_schema(&schema).is_err());
+    }
+}
@@ -63,12 +63,14 @@
 //! ```
 //!
 pub(crate) mod infer;
+pub mod json_schema;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 
 use arrow::legacy::conversion::chunk_to_struct;
+pub use json_schema::{schema_from_json_schema, schema_to_json_schema};
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 use polars_error::{polars_bail, PolarsResult};
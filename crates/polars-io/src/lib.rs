@@ -10,8 +10,16 @@ pub mod catalog;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "fixed_width")]
+pub mod fixed_width;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -20,6 +28,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "parquet")]
@@ -30,6 +40,10 @@ pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
 mod shared;
+#[cfg(feature = "spreadsheet")]
+pub mod spreadsheet;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod utils;
 
 #[cfg(feature = "cloud")]
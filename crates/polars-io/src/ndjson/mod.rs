@@ -3,6 +3,7 @@ use std::num::NonZeroUsize;
 
 use arrow::array::StructArray;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
 use polars_core::POOL;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
@@ -23,6 +24,36 @@ pub fn infer_schema<R: std::io::BufRead>(
     Ok(schema)
 }
 
+/// Merge a schema inferred from another source into `schema`.
+///
+/// Fields that are not yet in `schema` are appended, fields that exist in both are
+/// promoted to their supertype. Struct fields are merged recursively, so fields that
+/// only appear in some sources are retained. `source` is used for error messages.
+pub fn merge_inferred_schema(
+    schema: &mut Schema,
+    other: &Schema,
+    source: &str,
+) -> PolarsResult<()> {
+    for (name, dtype) in other.iter() {
+        match schema.get_mut(name) {
+            Some(current) if current != dtype => {
+                *current = try_get_supertype(current, dtype).map_err(|_| {
+                    polars_err!(
+                        SchemaMismatch: "failed to merge the schema inferred from '{}': \
+                        field '{}' has type {} but was inferred as {} from earlier sources",
+                        source, name, dtype, current
+                    )
+                })?;
+            },
+            Some(_) => {},
+            None => {
+                schema.with_column(name.clone(), dtype.clone());
+            },
+        }
+    }
+    Ok(())
+}
+
 /// Count the number of rows. The slice passed must represent the entire file. This will
 /// potentially parallelize using rayon.
 ///
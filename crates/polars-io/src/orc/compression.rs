@@ -0,0 +1,142 @@
+//! Compressed ORC streams are a sequence of chunks with a 3 byte header. The header holds the
+//! length of the chunk and whether the chunk is stored without compression, which writers do
+//! if compression does not make it smaller.
+use std::io::{Read, Write};
+
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+use super::proto::CompressionKind;
+use super::OrcCompression;
+
+const HEADER_SIZE: usize = 3;
+
+pub(super) fn decompress(
+    kind: CompressionKind,
+    block_size: usize,
+    data: &[u8],
+) -> PolarsResult<Vec<u8>> {
+    if kind == CompressionKind::None {
+        return Ok(data.to_vec());
+    }
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut data = data;
+    while !data.is_empty() {
+        ensure_len(data, HEADER_SIZE)?;
+        let header = data[0] as usize | ((data[1] as usize) << 8) | ((data[2] as usize) << 16);
+        let is_original = header & 1 == 1;
+        let len = header >> 1;
+        data = &data[HEADER_SIZE..];
+        ensure_len(data, len)?;
+        let (chunk, rest) = data.split_at(len);
+        data = rest;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match kind {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(|e| polars_err!(ComputeError: "invalid zlib data in ORC file: {e}"))?;
+            },
+            CompressionKind::Snappy => {
+                let decompressed = snap::raw::Decoder::new().decompress_vec(chunk).map_err(
+                    |e| polars_err!(ComputeError: "invalid snappy data in ORC file: {e}"),
+                )?;
+                out.extend_from_slice(&decompressed);
+            },
+            CompressionKind::Lz4 => {
+                let start = out.len();
+                out.resize(start + block_size, 0);
+                let n = lz4_flex::block::decompress_into(chunk, &mut out[start..])
+                    .map_err(|e| polars_err!(ComputeError: "invalid lz4 data in ORC file: {e}"))?;
+                out.truncate(start + n);
+            },
+            CompressionKind::Zstd => {
+                zstd::stream::read::Decoder::new(chunk)
+                    .and_then(|mut decoder| decoder.read_to_end(&mut out))
+                    .map_err(|e| polars_err!(ComputeError: "invalid zstd data in ORC file: {e}"))?;
+            },
+            CompressionKind::None | CompressionKind::Lzo => {
+                polars_bail!(ComputeError: "ORC compression {:?} is not supported", kind)
+            },
+        }
+    }
+    Ok(out)
+}
+
+fn ensure_len(data: &[u8], len: usize) -> PolarsResult<()> {
+    if data.len() < len {
+        polars_bail!(ComputeError: "unexpected end of compressed stream in ORC file");
+    }
+    Ok(())
+}
+
+/// Compress `data` in chunks of at most `block_size` bytes.
+pub(super) fn compress(
+    compression: OrcCompression,
+    block_size: usize,
+    data: &[u8],
+) -> PolarsResult<Vec<u8>> {
+    if compression == OrcCompression::Uncompressed {
+        return Ok(data.to_vec());
+    }
+    let mut out = Vec::with_capacity(data.len() / 2);
+    let mut compressed = vec![];
+    for chunk in data.chunks(block_size) {
+        compressed.clear();
+        match compression {
+            OrcCompression::Zlib => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    &mut compressed,
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(chunk)?;
+                encoder.finish()?;
+            },
+            OrcCompression::Snappy => {
+                compressed = snap::raw::Encoder::new()
+                    .compress_vec(chunk)
+                    .map_err(|e| polars_err!(ComputeError: "snappy compression failed: {e}"))?;
+            },
+            OrcCompression::Lz4 => compressed = lz4_flex::block::compress(chunk),
+            OrcCompression::Zstd => compressed = zstd::bulk::compress(chunk, 0)?,
+            OrcCompression::Uncompressed => unreachable!(),
+        }
+        let (chunk, is_original) = if compressed.len() < chunk.len() {
+            (compressed.as_slice(), 0)
+        } else {
+            (chunk, 1)
+        };
+        let header = (chunk.len() << 1) | is_original;
+        out.extend_from_slice(&header.to_le_bytes()[..HEADER_SIZE]);
+        out.extend_from_slice(chunk);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let data = (0..100_000u32)
+            .flat_map(|i| (i % 1000).to_le_bytes())
+            .collect::<Vec<_>>();
+        for (compression, kind) in [
+            (OrcCompression::Uncompressed, CompressionKind::None),
+            (OrcCompression::Zlib, CompressionKind::Zlib),
+            (OrcCompression::Snappy, CompressionKind::Snappy),
+            (OrcCompression::Lz4, CompressionKind::Lz4),
+            (OrcCompression::Zstd, CompressionKind::Zstd),
+        ] {
+            let compressed = compress(compression, 1 << 16, &data).unwrap();
+            assert_eq!(decompress(kind, 1 << 16, &compressed).unwrap(), data);
+        }
+        // Chunks that do not get smaller are stored as is.
+        let compressed = compress(OrcCompression::Zstd, 1 << 16, &[1, 2, 3]).unwrap();
+        assert_eq!(compressed, [7, 0, 0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,55 @@
+//! Reading and writing of ORC files.
+//!
+//! Files are read stripe by stripe, and only the streams of the projected columns are read.
+//! Nested structs, lists and maps are decoded into polars-arrow arrays; maps are read as lists
+//! of `key`/`value` structs, timestamps with nanosecond precision and decimals with the
+//! precision and scale of the file. The stripe statistics of a file are exposed in the layout
+//! of batch skipping predicates, so scans can skip stripes that cannot match a predicate.
+//!
+//! The writer stores one stripe per `stripe_size` rows, with integer RLE version 2 and
+//! statistics for every column and stripe.
+mod compression;
+mod proto;
+mod read;
+mod rle;
+mod write;
+
+pub use read::{OrcMetadata, OrcReader};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use write::OrcWriter;
+
+/// The offset of the ORC timestamp epoch, 2015-01-01, from the unix epoch in seconds.
+const ORC_EPOCH_OFFSET: i64 = 1_420_070_400;
+const NANOSECONDS: i64 = 1_000_000_000;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrcCompression {
+    Uncompressed,
+    Zlib,
+    Snappy,
+    Lz4,
+    #[default]
+    Zstd,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrcWriterOptions {
+    pub compression: OrcCompression,
+    /// The maximum number of rows of a stripe.
+    pub stripe_size: usize,
+    /// The number of bytes that are compressed at once.
+    pub compression_block_size: usize,
+}
+
+impl Default for OrcWriterOptions {
+    fn default() -> Self {
+        Self {
+            compression: OrcCompression::default(),
+            stripe_size: 128 * 1024,
+            compression_block_size: 256 * 1024,
+        }
+    }
+}
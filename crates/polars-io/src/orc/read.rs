@@ -0,0 +1,897 @@
+use std::io::{Read, Seek, SeekFrom};
+
+use arrow::array::{
+    ArrayRef, BooleanArray, ListArray, MutableBinaryViewArray, PrimitiveArray, StructArray,
+};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::ArrowDataType;
+use arrow::offset::Offsets;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::format_pl_smallstr;
+use prost::Message;
+
+use super::compression::decompress;
+use super::proto::{
+    ColumnEncodingKind, ColumnStatistics, CompressionKind, Footer, PostScript, StreamKind,
+    StripeFooter, StripeInformation, Type, TypeKind,
+};
+use super::rle::{decode_booleans, decode_bytes, decode_ints, decode_varints, RleVersion};
+use super::{NANOSECONDS, ORC_EPOCH_OFFSET};
+use crate::prelude::*;
+
+/// The number of bytes at the end of the file that are read to get the file tail at once.
+const TAIL_READ_SIZE: u64 = 16 * 1024;
+
+/// The metadata of an ORC file: the schema and the stripes with their statistics.
+#[derive(Debug)]
+pub struct OrcMetadata {
+    compression: CompressionKind,
+    block_size: usize,
+    types: Vec<Type>,
+    stripes: Vec<StripeInformation>,
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    num_rows: usize,
+    schema: SchemaRef,
+    /// The polars data type of every ORC type, indexed by column id.
+    dtypes: Vec<DataType>,
+}
+
+impl OrcMetadata {
+    /// Read the file tail of an ORC file.
+    pub fn read<R: Read + Seek>(reader: &mut R) -> PolarsResult<Self> {
+        let file_len = reader.seek(SeekFrom::End(0))?;
+        let tail_len = file_len.min(TAIL_READ_SIZE);
+        let mut tail = vec![0; tail_len as usize];
+        reader.seek(SeekFrom::Start(file_len - tail_len))?;
+        reader.read_exact(&mut tail)?;
+
+        let invalid = || polars_err!(ComputeError: "invalid ORC file: the file tail is corrupt");
+        let ps_len = *tail.last().ok_or_else(invalid)? as usize;
+        polars_ensure!(ps_len < tail.len(), ComputeError: "invalid ORC file: the file tail is corrupt");
+        let ps_start = tail.len() - 1 - ps_len;
+        let postscript =
+            PostScript::decode(&tail[ps_start..tail.len() - 1]).map_err(|_| invalid())?;
+        polars_ensure!(
+            postscript.magic.as_deref() == Some("ORC"),
+            ComputeError: "invalid ORC file: missing magic bytes"
+        );
+        let compression =
+            CompressionKind::from_i32(postscript.compression.unwrap_or(0)).ok_or_else(invalid)?;
+        let block_size = postscript.compression_block_size.unwrap_or(256 * 1024) as usize;
+        let footer_len = postscript.footer_length.unwrap_or(0);
+        let metadata_len = postscript.metadata_length.unwrap_or(0);
+
+        // The metadata section with the stripe statistics is followed by the footer.
+        let needed = footer_len + metadata_len + ps_len as u64 + 1;
+        polars_ensure!(needed <= file_len, ComputeError: "invalid ORC file: the file tail is corrupt");
+        if needed > tail_len {
+            tail = vec![0; needed as usize];
+            reader.seek(SeekFrom::Start(file_len - needed))?;
+            reader.read_exact(&mut tail)?;
+        }
+        let footer_end = tail.len() - 1 - ps_len;
+        let footer_start = footer_end - footer_len as usize;
+        let metadata_start = footer_start - metadata_len as usize;
+
+        let footer = decompress(compression, block_size, &tail[footer_start..footer_end])?;
+        let footer = Footer::decode(footer.as_slice()).map_err(|_| invalid())?;
+        let metadata = decompress(compression, block_size, &tail[metadata_start..footer_start])?;
+        let metadata =
+            super::proto::Metadata::decode(metadata.as_slice()).map_err(|_| invalid())?;
+
+        let mut dtypes = vec![DataType::Null; footer.types.len()];
+        polars_ensure!(
+            footer.types.first().and_then(|t| t.kind) == Some(TypeKind::Struct as i32),
+            ComputeError: "invalid ORC file: the root type must be a struct"
+        );
+        dtypes[0] = to_dtype(&footer.types, 0, &mut dtypes)?;
+        let DataType::Struct(fields) = &dtypes[0] else {
+            unreachable!()
+        };
+        let schema = Arc::new(Schema::from_iter(fields.iter().cloned()));
+
+        Ok(Self {
+            compression,
+            block_size,
+            num_rows: footer.number_of_rows.unwrap_or(0) as usize,
+            types: footer.types,
+            stripes: footer.stripes,
+            stripe_statistics: metadata
+                .stripe_stats
+                .into_iter()
+                .map(|s| s.col_stats)
+                .collect(),
+            schema,
+            dtypes,
+        })
+    }
+
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.stripes.len()
+    }
+
+    /// The column id of the top-level column at `index`.
+    fn column_id(&self, index: usize) -> u32 {
+        self.types[0].subtypes[index]
+    }
+
+    /// The statistics of the stripes of the file.
+    ///
+    /// The frame has one row per stripe, with a `len` column and `{column}_min`,
+    /// `{column}_max` and `{column}_nc` columns for every top-level column, which is the layout
+    /// that batch skipping predicates are evaluated on. Unknown statistics are null.
+    pub fn stripe_statistics(&self) -> PolarsResult<DataFrame> {
+        let height = self.stripes.len();
+        let mut columns = Vec::with_capacity(1 + 3 * self.schema.len());
+        columns.push(
+            IdxCa::from_iter_options(
+                PlSmallStr::from_static("len"),
+                self.stripes
+                    .iter()
+                    .map(|s| s.number_of_rows.map(|n| n as IdxSize)),
+            )
+            .into_column(),
+        );
+        for (index, (name, dtype)) in self.schema.iter().enumerate() {
+            let id = self.column_id(index) as usize;
+            let mut mins = Vec::with_capacity(height);
+            let mut maxs = Vec::with_capacity(height);
+            let mut null_counts = Vec::with_capacity(height);
+            for (i, stripe) in self.stripes.iter().enumerate() {
+                let stats = self.stripe_statistics.get(i).and_then(|s| s.get(id));
+                let (min, max) = stats
+                    .and_then(|stats| min_max(stats, dtype))
+                    .unwrap_or((AnyValue::Null, AnyValue::Null));
+                mins.push(min);
+                maxs.push(max);
+                null_counts.push(stats.and_then(|stats| {
+                    let rows = stripe.number_of_rows?;
+                    Some((rows - stats.number_of_values?) as IdxSize)
+                }));
+            }
+            for (suffix, values) in [("min", mins), ("max", maxs)] {
+                let name = format_pl_smallstr!("{name}_{suffix}");
+                let physical = dtype.to_physical();
+                let s = Series::from_any_values_and_dtype(name, &values, &physical, false)?;
+                // SAFETY: the values are in the physical representation of `dtype`.
+                columns.push(unsafe { s.from_physical_unchecked(dtype) }?.into_column());
+            }
+            columns.push(
+                IdxCa::from_iter_options(format_pl_smallstr!("{name}_nc"), null_counts.into_iter())
+                    .into_column(),
+            );
+        }
+        DataFrame::new_with_height(height, columns)
+    }
+}
+
+/// The polars data type of the ORC type with id `id`. The types of all nested types are
+/// stored in `dtypes`.
+fn to_dtype(types: &[Type], id: u32, dtypes: &mut [DataType]) -> PolarsResult<DataType> {
+    let ty = types
+        .get(id as usize)
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: unknown type {}", id))?;
+    let kind = TypeKind::from_i32(ty.kind.unwrap_or(0))
+        .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: unknown type kind"))?;
+    let mut child = |i: usize| -> PolarsResult<DataType> {
+        let child_id = *ty.subtypes.get(i).ok_or_else(
+            || polars_err!(ComputeError: "invalid ORC file: missing child of type {}", id),
+        )?;
+        polars_ensure!(child_id > id, ComputeError: "invalid ORC file: types are not in pre-order");
+        let dtype = to_dtype(types, child_id, dtypes)?;
+        dtypes[child_id as usize] = dtype.clone();
+        Ok(dtype)
+    };
+    let dtype = match kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::String,
+        TypeKind::Binary => DataType::Binary,
+        TypeKind::Date => DataType::Date,
+        TypeKind::Timestamp => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        TypeKind::TimestampInstant => {
+            DataType::Datetime(TimeUnit::Nanoseconds, Some(PlSmallStr::from_static("UTC")))
+        },
+        TypeKind::Decimal => DataType::Decimal(
+            Some(ty.precision.filter(|p| *p > 0).unwrap_or(38) as usize),
+            Some(ty.scale.unwrap_or(10) as usize),
+        ),
+        TypeKind::List => DataType::List(Box::new(child(0)?)),
+        TypeKind::Map => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), child(0)?),
+            Field::new(PlSmallStr::from_static("value"), child(1)?),
+        ]))),
+        TypeKind::Struct => DataType::Struct(
+            ty.field_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Ok(Field::new(name.into(), child(i)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        TypeKind::Union => polars_bail!(ComputeError: "ORC union types are not supported"),
+    };
+    Ok(dtype)
+}
+
+/// The minimum and maximum of a column in its physical representation.
+fn min_max(
+    stats: &ColumnStatistics,
+    dtype: &DataType,
+) -> Option<(AnyValue<'static>, AnyValue<'static>)> {
+    Some(match dtype {
+        DataType::Boolean => {
+            let values = stats.number_of_values?;
+            let trues = *stats.bucket_statistics.as_ref()?.count.first()?;
+            if values == 0 {
+                return None;
+            }
+            (
+                AnyValue::Boolean(trues == values),
+                AnyValue::Boolean(trues > 0),
+            )
+        },
+        dt if dt.is_integer() => {
+            let stats = stats.int_statistics.as_ref()?;
+            (
+                AnyValue::Int64(stats.minimum?),
+                AnyValue::Int64(stats.maximum?),
+            )
+        },
+        dt if dt.is_float() => {
+            let stats = stats.double_statistics.as_ref()?;
+            (
+                AnyValue::Float64(stats.minimum?),
+                AnyValue::Float64(stats.maximum?),
+            )
+        },
+        DataType::String => {
+            let stats = stats.string_statistics.as_ref()?;
+            (
+                AnyValue::StringOwned(stats.minimum.as_deref()?.into()),
+                AnyValue::StringOwned(stats.maximum.as_deref()?.into()),
+            )
+        },
+        DataType::Date => {
+            let stats = stats.date_statistics.as_ref()?;
+            (
+                AnyValue::Int32(stats.minimum?),
+                AnyValue::Int32(stats.maximum?),
+            )
+        },
+        // The statistics have millisecond precision, values may have more.
+        DataType::Datetime(_, _) => {
+            let stats = stats.timestamp_statistics.as_ref()?;
+            let min = stats.minimum_utc?.checked_mul(1_000_000)?;
+            let max = stats
+                .maximum_utc?
+                .checked_mul(1_000_000)?
+                .checked_add(999_999)?;
+            (AnyValue::Int64(min), AnyValue::Int64(max))
+        },
+        DataType::Decimal(_, Some(scale)) => {
+            let stats = stats.decimal_statistics.as_ref()?;
+            (
+                AnyValue::Int128(parse_decimal(stats.minimum.as_deref()?, *scale)?),
+                AnyValue::Int128(parse_decimal(stats.maximum.as_deref()?, *scale)?),
+            )
+        },
+        _ => return None,
+    })
+}
+
+/// Parse a decimal like `-12.50` to an integer with `scale` fractional digits.
+fn parse_decimal(s: &str, scale: usize) -> Option<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let (integer, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if fraction.len() > scale {
+        return None;
+    }
+    let mut value: i128 = if integer.is_empty() {
+        0
+    } else {
+        integer.parse().ok()?
+    };
+    for i in 0..scale {
+        let digit = match fraction.as_bytes().get(i) {
+            Some(d @ b'0'..=b'9') => (d - b'0') as i128,
+            Some(_) => return None,
+            None => 0,
+        };
+        value = value.checked_mul(10)?.checked_add(digit)?;
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// The decompressed streams of the columns that are read from a stripe.
+struct Stripe<'a> {
+    metadata: &'a OrcMetadata,
+    streams: PlHashMap<(u32, StreamKind), Vec<u8>>,
+    encodings: Vec<ColumnEncodingKind>,
+}
+
+impl<'a> Stripe<'a> {
+    fn read<R: Read + Seek>(
+        reader: &mut R,
+        metadata: &'a OrcMetadata,
+        info: &StripeInformation,
+        columns: &PlHashSet<u32>,
+    ) -> PolarsResult<Self> {
+        let offset = info.offset.unwrap_or(0);
+        let footer_offset = offset + info.index_length.unwrap_or(0) + info.data_length.unwrap_or(0);
+        let mut footer = vec![0; info.footer_length.unwrap_or(0) as usize];
+        reader.seek(SeekFrom::Start(footer_offset))?;
+        reader.read_exact(&mut footer)?;
+        let footer = decompress(metadata.compression, metadata.block_size, &footer)?;
+        let footer = StripeFooter::decode(footer.as_slice())
+            .map_err(|_| polars_err!(ComputeError: "invalid ORC file: corrupt stripe footer"))?;
+
+        // Streams are stored consecutively, starting with the index streams.
+        let mut streams = PlHashMap::new();
+        let mut position = offset;
+        for stream in &footer.streams {
+            let len = stream.length.unwrap_or(0);
+            let column = stream.column.unwrap_or(0);
+            let kind = stream.kind.and_then(StreamKind::from_i32);
+            if let Some(
+                kind @ (StreamKind::Present
+                | StreamKind::Data
+                | StreamKind::Length
+                | StreamKind::DictionaryData
+                | StreamKind::Secondary),
+            ) = kind
+            {
+                if columns.contains(&column) {
+                    let mut data = vec![0; len as usize];
+                    reader.seek(SeekFrom::Start(position))?;
+                    reader.read_exact(&mut data)?;
+                    let data = decompress(metadata.compression, metadata.block_size, &data)?;
+                    streams.insert((column, kind), data);
+                }
+            }
+            position += len;
+        }
+        let encodings = footer
+            .columns
+            .iter()
+            .map(|c| {
+                ColumnEncodingKind::from_i32(c.kind.unwrap_or(0))
+                    .unwrap_or(ColumnEncodingKind::Direct)
+            })
+            .collect();
+        Ok(Self {
+            metadata,
+            streams,
+            encodings,
+        })
+    }
+
+    fn stream(&self, column: u32, kind: StreamKind) -> &[u8] {
+        self.streams
+            .get(&(column, kind))
+            .map_or(&[], |data| data.as_slice())
+    }
+
+    fn encoding(&self, column: u32) -> ColumnEncodingKind {
+        self.encodings
+            .get(column as usize)
+            .copied()
+            .unwrap_or(ColumnEncodingKind::Direct)
+    }
+
+    fn rle_version(&self, column: u32) -> RleVersion {
+        match self.encoding(column) {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::Dictionary => RleVersion::V1,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => RleVersion::V2,
+        }
+    }
+
+    fn ints(
+        &self,
+        column: u32,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> PolarsResult<Vec<i64>> {
+        decode_ints(
+            self.stream(column, kind),
+            n,
+            signed,
+            self.rle_version(column),
+        )
+    }
+
+    /// The validity of `len` values of a column. Only the values of positions where the parent
+    /// is valid are stored.
+    fn validity(
+        &self,
+        column: u32,
+        len: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<Option<Bitmap>> {
+        let present = self.streams.get(&(column, StreamKind::Present));
+        Ok(match (parent, present) {
+            (None, None) => None,
+            (Some(parent), None) => Some(parent.clone()),
+            (None, Some(present)) => Some(decode_booleans(present, len)?),
+            (Some(parent), Some(present)) => {
+                let present = decode_booleans(present, len - parent.unset_bits())?;
+                let mut present = present.iter();
+                Some(
+                    parent
+                        .iter()
+                        .map(|valid| valid && present.next().unwrap_or(false))
+                        .collect(),
+                )
+            },
+        })
+    }
+
+    /// Decode `len` values of the column with id `column`.
+    fn read_column(
+        &self,
+        column: u32,
+        len: usize,
+        parent: Option<&Bitmap>,
+    ) -> PolarsResult<ArrayRef> {
+        let dtype = &self.metadata.dtypes[column as usize];
+        let arrow_dtype = dtype.to_arrow(CompatLevel::newest());
+        let validity = self.validity(column, len, parent)?;
+        let n = len - validity.as_ref().map_or(0, |v| v.unset_bits());
+        let kind = TypeKind::from_i32(self.metadata.types[column as usize].kind.unwrap_or(0))
+            .ok_or_else(|| polars_err!(ComputeError: "invalid ORC file: unknown type kind"))?;
+
+        let array: ArrayRef = match kind {
+            TypeKind::Boolean => {
+                let values = decode_booleans(self.stream(column, StreamKind::Data), n)?;
+                let values = match &validity {
+                    None => values,
+                    Some(validity) => {
+                        let mut values = values.iter();
+                        let mut out = MutableBitmap::with_capacity(len);
+                        for valid in validity.iter() {
+                            out.push(valid && values.next().unwrap_or(false));
+                        }
+                        out.into()
+                    },
+                };
+                Box::new(BooleanArray::new(arrow_dtype, values, validity))
+            },
+            TypeKind::Byte => {
+                let values = decode_bytes(self.stream(column, StreamKind::Data), n)?;
+                primitive(
+                    values.into_iter().map(|v| v as i8).collect(),
+                    arrow_dtype,
+                    validity,
+                )
+            },
+            TypeKind::Short => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                primitive(
+                    values.into_iter().map(|v| v as i16).collect(),
+                    arrow_dtype,
+                    validity,
+                )
+            },
+            TypeKind::Int | TypeKind::Date => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                primitive(
+                    values.into_iter().map(|v| v as i32).collect(),
+                    arrow_dtype,
+                    validity,
+                )
+            },
+            TypeKind::Long => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                primitive(values, arrow_dtype, validity)
+            },
+            TypeKind::Float => {
+                let values = floats::<4>(self.stream(column, StreamKind::Data), n)?;
+                primitive(
+                    values.map(f32::from_le_bytes).collect(),
+                    arrow_dtype,
+                    validity,
+                )
+            },
+            TypeKind::Double => {
+                let values = floats::<8>(self.stream(column, StreamKind::Data), n)?;
+                primitive(
+                    values.map(f64::from_le_bytes).collect(),
+                    arrow_dtype,
+                    validity,
+                )
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let values = self.binary_values(column, n)?;
+                let mut values = values.into_iter();
+                let mut next = |valid: bool| valid.then(|| values.next().unwrap_or_default());
+                if kind == TypeKind::Binary {
+                    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(len);
+                    for i in 0..len {
+                        out.push(next(validity.as_ref().is_none_or(|v| v.get_bit(i))));
+                    }
+                    out.freeze().boxed()
+                } else {
+                    let mut out = MutableBinaryViewArray::<str>::with_capacity(len);
+                    for i in 0..len {
+                        let value = next(validity.as_ref().is_none_or(|v| v.get_bit(i)));
+                        let value = value
+                            .map(|v| {
+                                simdutf8::basic::from_utf8(v).map_err(
+                                    |_| polars_err!(ComputeError: "invalid utf-8 in ORC string column"),
+                                )
+                            })
+                            .transpose()?;
+                        out.push(value);
+                    }
+                    out.freeze().boxed()
+                }
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.ints(column, StreamKind::Data, n, true)?;
+                let nanos = self.ints(column, StreamKind::Secondary, n, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let seconds = seconds + ORC_EPOCH_OFFSET;
+                        let nanos = decode_nanos(nanos as u64);
+                        // Writers store negative timestamps with the seconds rounded towards 0.
+                        let seconds = if seconds < 0 && nanos > 999_999 {
+                            seconds - 1
+                        } else {
+                            seconds
+                        };
+                        seconds * NANOSECONDS + nanos
+                    })
+                    .collect();
+                primitive(values, arrow_dtype, validity)
+            },
+            TypeKind::Decimal => {
+                let DataType::Decimal(_, Some(scale)) = dtype else {
+                    unreachable!()
+                };
+                let values = decode_varints(self.stream(column, StreamKind::Data), n)?;
+                let scales = self.ints(column, StreamKind::Secondary, n, true)?;
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(value, value_scale)| rescale(value, value_scale, *scale as i64))
+                    .collect::<PolarsResult<_>>()?;
+                primitive(values, arrow_dtype, validity)
+            },
+            TypeKind::Struct => {
+                let children = self.metadata.types[column as usize]
+                    .subtypes
+                    .iter()
+                    .map(|child| self.read_column(*child, len, validity.as_ref()))
+                    .collect::<PolarsResult<_>>()?;
+                Box::new(StructArray::new(arrow_dtype, len, children, validity))
+            },
+            TypeKind::List | TypeKind::Map => {
+                let lengths = self.ints(column, StreamKind::Length, n, false)?;
+                let mut lengths = lengths.into_iter();
+                let offsets = Offsets::<i64>::try_from_lengths((0..len).map(|i| {
+                    if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                        lengths.next().unwrap_or(0) as usize
+                    } else {
+                        0
+                    }
+                }))?;
+                let child_len = *offsets.last() as usize;
+                let subtypes = &self.metadata.types[column as usize].subtypes;
+                let values = if kind == TypeKind::List {
+                    self.read_column(subtypes[0], child_len, None)?
+                } else {
+                    let ArrowDataType::LargeList(field) = &arrow_dtype else {
+                        unreachable!()
+                    };
+                    let entries = subtypes
+                        .iter()
+                        .map(|child| self.read_column(*child, child_len, None))
+                        .collect::<PolarsResult<_>>()?;
+                    Box::new(StructArray::new(
+                        field.dtype.clone(),
+                        child_len,
+                        entries,
+                        None,
+                    ))
+                };
+                Box::new(ListArray::<i64>::new(
+                    arrow_dtype,
+                    offsets.into(),
+                    values,
+                    validity,
+                ))
+            },
+            TypeKind::Union => polars_bail!(ComputeError: "ORC union types are not supported"),
+        };
+        Ok(array)
+    }
+
+    /// The `n` values of a string or binary column, which are either stored directly or in a
+    /// dictionary.
+    fn binary_values(&self, column: u32, n: usize) -> PolarsResult<Vec<&[u8]>> {
+        let dictionary = matches!(
+            self.encoding(column),
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2
+        );
+        let (data, lengths, indices) = if dictionary {
+            let indices = self.ints(column, StreamKind::Data, n, false)?;
+            let size = indices.iter().map(|i| *i as usize + 1).max().unwrap_or(0);
+            let lengths = self.ints(column, StreamKind::Length, size, false)?;
+            (
+                self.stream(column, StreamKind::DictionaryData),
+                lengths,
+                Some(indices),
+            )
+        } else {
+            let lengths = self.ints(column, StreamKind::Length, n, false)?;
+            (self.stream(column, StreamKind::Data), lengths, None)
+        };
+
+        let mut offset = 0;
+        let values = lengths
+            .into_iter()
+            .map(|len| {
+                let end = offset + len as usize;
+                let value = data.get(offset..end).ok_or_else(
+                    || polars_err!(ComputeError: "unexpected end of stream in ORC file"),
+                )?;
+                offset = end;
+                Ok(value)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(match indices {
+            Some(indices) => indices.into_iter().map(|i| values[i as usize]).collect(),
+            None => values,
+        })
+    }
+}
+
+fn primitive<T: NativeType>(
+    values: Vec<T>,
+    dtype: ArrowDataType,
+    validity: Option<Bitmap>,
+) -> ArrayRef {
+    // Nulls are not stored, so the values are moved to the positions of the valid values.
+    let values = match &validity {
+        None => values,
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|valid| {
+                    if valid {
+                        values.next().unwrap_or_default()
+                    } else {
+                        T::default()
+                    }
+                })
+                .collect()
+        },
+    };
+    Box::new(PrimitiveArray::new(dtype, values.into(), validity))
+}
+
+fn floats<const N: usize>(
+    data: &[u8],
+    n: usize,
+) -> PolarsResult<impl Iterator<Item = [u8; N]> + '_> {
+    polars_ensure!(
+        data.len() >= n * N,
+        ComputeError: "unexpected end of stream in ORC file"
+    );
+    Ok(data[..n * N]
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().unwrap()))
+}
+
+/// Nanoseconds are stored with their trailing decimal zeros removed. The lowest 3 bits hold
+/// the number of removed zeros minus 1.
+fn decode_nanos(value: u64) -> i64 {
+    let zeros = value & 0x07;
+    let nanos = (value >> 3) as i64;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros as u32 + 1)
+    }
+}
+
+fn rescale(value: i128, from: i64, to: i64) -> PolarsResult<i128> {
+    let overflow = || polars_err!(ComputeError: "decimal value in ORC file does not fit its type");
+    if from <= to {
+        let factor = 10i128
+            .checked_pow((to - from) as u32)
+            .ok_or_else(overflow)?;
+        value.checked_mul(factor).ok_or_else(overflow)
+    } else {
+        let factor = 10i128
+            .checked_pow((from - to) as u32)
+            .ok_or_else(overflow)?;
+        Ok(value / factor)
+    }
+}
+
+/// Read an ORC file into a DataFrame.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("data.orc")?;
+///     OrcReader::new(file).finish()
+/// }
+/// ```
+pub struct OrcReader<R: Read + Seek> {
+    reader: R,
+    rechunk: bool,
+    columns: Option<Arc<[PlSmallStr]>>,
+    n_rows: Option<usize>,
+    stripes: Option<Vec<bool>>,
+    metadata: Option<Arc<OrcMetadata>>,
+}
+
+impl<R: Read + Seek> OrcReader<R> {
+    /// Only read these columns, in this order.
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Only read the stripes for which the mask is `true`.
+    pub fn with_stripe_mask(mut self, mask: Option<Vec<bool>>) -> Self {
+        self.stripes = mask;
+        self
+    }
+
+    /// Use metadata that was read before instead of reading it from the file.
+    pub fn with_metadata(mut self, metadata: Option<Arc<OrcMetadata>>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    pub fn metadata(&mut self) -> PolarsResult<&Arc<OrcMetadata>> {
+        if self.metadata.is_none() {
+            self.metadata = Some(Arc::new(OrcMetadata::read(&mut self.reader)?));
+        }
+        Ok(self.metadata.as_ref().unwrap())
+    }
+
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.metadata()?.schema().clone())
+    }
+}
+
+impl<R: Read + Seek> SerReader<R> for OrcReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            rechunk: true,
+            columns: None,
+            n_rows: None,
+            stripes: None,
+            metadata: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let metadata = self.metadata()?.clone();
+        let schema = metadata.schema();
+        let projection = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..schema.len()).collect(),
+        };
+        let mut column_ids = PlHashSet::new();
+        for index in &projection {
+            let first = metadata.column_id(*index);
+            // Types are stored in pre-order, so the subtree of a column is the range up to the
+            // next top-level column.
+            let end = metadata.types[0]
+                .subtypes
+                .get(index + 1)
+                .copied()
+                .unwrap_or(metadata.types.len() as u32);
+            column_ids.extend(first..end);
+        }
+        let projected_schema = projection
+            .iter()
+            .map(|i| {
+                let (name, dtype) = schema.get_at_index(*i).unwrap();
+                Field::new(name.clone(), dtype.clone())
+            })
+            .collect::<Schema>();
+
+        let mut remaining = self.n_rows.unwrap_or(usize::MAX);
+        let mut frames = vec![];
+        for (i, info) in metadata.stripes.iter().enumerate() {
+            if remaining == 0 {
+                break;
+            }
+            if self.stripes.as_ref().is_some_and(|mask| !mask[i]) {
+                continue;
+            }
+            let rows = info.number_of_rows.unwrap_or(0) as usize;
+            let stripe = Stripe::read(&mut self.reader, &metadata, info, &column_ids)?;
+            let columns = projection
+                .iter()
+                .zip(projected_schema.iter_names())
+                .map(|(index, name)| {
+                    let array = stripe.read_column(metadata.column_id(*index), rows, None)?;
+                    Ok(Series::from_arrow(name.clone(), array)?.into_column())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut df = DataFrame::new_with_height(rows, columns)?;
+            if rows > remaining {
+                df = df.slice(0, remaining);
+            }
+            remaining -= df.height();
+            frames.push(df);
+        }
+        if frames.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&projected_schema));
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(frames);
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("-12.5", 2), Some(-1250));
+        assert_eq!(parse_decimal("3", 1), Some(30));
+        assert_eq!(parse_decimal(".25", 2), Some(25));
+        assert_eq!(parse_decimal("1.234", 2), None);
+    }
+
+    #[test]
+    fn test_decode_nanos() {
+        assert_eq!(decode_nanos(0), 0);
+        assert_eq!(decode_nanos(12345 << 3), 12345);
+        // 1 with 8 removed zeros.
+        assert_eq!(decode_nanos((1 << 3) | 7), 100_000_000);
+    }
+}
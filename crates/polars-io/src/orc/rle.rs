@@ -0,0 +1,507 @@
+//! The run-length encodings of ORC streams: byte RLE, boolean RLE and integer RLE versions 1
+//! and 2. Integers are decoded to `i64`, unsigned integers keep their bit pattern.
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{polars_bail, PolarsResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum RleVersion {
+    V1,
+    V2,
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        if self.data.len() < n {
+            polars_bail!(ComputeError: "unexpected end of stream in ORC file");
+        }
+        let (head, tail) = self.data.split_at(n);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> PolarsResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> PolarsResult<u128> {
+        let mut value = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            if shift >= 128 {
+                polars_bail!(ComputeError: "invalid varint in ORC file");
+            }
+            value |= ((byte & 0x7f) as u128) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn int_varint(&mut self, signed: bool) -> PolarsResult<i64> {
+        let value = self.varint()? as u64;
+        Ok(if signed {
+            unzigzag(value)
+        } else {
+            value as i64
+        })
+    }
+
+    /// Read `count` big-endian integers of `width` bits that start at a byte boundary.
+    fn unpack(&mut self, width: usize, count: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+        let bytes = self.take((count * width).div_ceil(8))?;
+        let mut bit = 0;
+        for _ in 0..count {
+            let mut value = 0u64;
+            let mut remaining = width;
+            while remaining > 0 {
+                let offset = bit % 8;
+                let available = 8 - offset;
+                let n = available.min(remaining);
+                let bits = (bytes[bit / 8] >> (available - n)) as u64 & ((1u64 << n) - 1);
+                value = (value << n) | bits;
+                remaining -= n;
+                bit += n;
+            }
+            out.push(value);
+        }
+        Ok(())
+    }
+}
+
+fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub(super) fn decode_bytes(data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut cursor = Cursor { data };
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let control = cursor.byte()? as i8;
+        if control >= 0 {
+            let value = cursor.byte()?;
+            out.extend(std::iter::repeat_n(value, control as usize + 3));
+        } else {
+            out.extend_from_slice(cursor.take(-(control as isize) as usize)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+pub(super) fn decode_booleans(data: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let bytes = decode_bytes(data, n.div_ceil(8))?;
+    let mut out = MutableBitmap::with_capacity(n);
+    for i in 0..n {
+        out.push(bytes[i / 8] & (0x80 >> (i % 8)) != 0);
+    }
+    Ok(out.into())
+}
+
+pub(super) fn decode_ints(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    version: RleVersion,
+) -> PolarsResult<Vec<i64>> {
+    let mut cursor = Cursor { data };
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n && !cursor.is_empty() {
+        match version {
+            RleVersion::V1 => decode_run_v1(&mut cursor, signed, &mut out)?,
+            RleVersion::V2 => decode_run_v2(&mut cursor, signed, &mut out)?,
+        }
+    }
+    if out.len() < n {
+        polars_bail!(ComputeError: "unexpected end of integer stream in ORC file");
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn decode_run_v1(cursor: &mut Cursor, signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let control = cursor.byte()? as i8;
+    if control >= 0 {
+        let delta = cursor.byte()? as i8 as i64;
+        let base = cursor.int_varint(signed)?;
+        out.extend((0..control as i64 + 3).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..-(control as i64) {
+            out.push(cursor.int_varint(signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// The bit width of the 5 bit width code of a run.
+fn decode_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn encode_width(width: usize) -> u8 {
+    match width {
+        1..=24 => width as u8 - 1,
+        26 => 24,
+        28 => 25,
+        30 => 26,
+        32 => 27,
+        40 => 28,
+        48 => 29,
+        56 => 30,
+        _ => 31,
+    }
+}
+
+/// The smallest width that can be encoded and holds `bits` bits.
+fn closest_fixed_bits(bits: usize) -> usize {
+    match bits {
+        0 => 1,
+        1..=24 => bits,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_run_v2(cursor: &mut Cursor, signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = cursor.byte()?;
+    let decode = |value: u64| {
+        if signed {
+            unzigzag(value)
+        } else {
+            value as i64
+        }
+    };
+    match header >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let width = ((header >> 3) & 0x07) as usize + 1;
+            let count = (header & 0x07) as usize + 3;
+            let value = cursor
+                .take(width)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | *b as u64);
+            out.extend(std::iter::repeat_n(decode(value), count));
+        },
+        // DIRECT
+        1 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let count = (((header as usize & 1) << 8) | cursor.byte()? as usize) + 1;
+            let mut values = Vec::with_capacity(count);
+            cursor.unpack(width, count, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // PATCHED_BASE
+        2 => {
+            let width = decode_width((header >> 1) & 0x1f);
+            let count = (((header as usize & 1) << 8) | cursor.byte()? as usize) + 1;
+            let third = cursor.byte()?;
+            let base_width = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_width(third & 0x1f);
+            let fourth = cursor.byte()?;
+            let gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+            let patch_count = (fourth & 0x1f) as usize;
+            if gap_width + patch_width > 64 {
+                polars_bail!(ComputeError: "invalid patched base run in ORC file");
+            }
+
+            // The base is stored in sign-magnitude representation.
+            let base = cursor
+                .take(base_width)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | *b as u64);
+            let sign_bit = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_bit != 0 {
+                -((base & !sign_bit) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(count);
+            cursor.unpack(width, count, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_count);
+            cursor.unpack(
+                closest_fixed_bits(gap_width + patch_width),
+                patch_count,
+                &mut patches,
+            )?;
+            let patch_mask = u64::MAX >> (64 - patch_width);
+            let mut index = 0;
+            for entry in patches {
+                index += (entry >> patch_width) as usize;
+                let patch = entry & patch_mask;
+                // Gaps larger than 255 are stored as entries without a patch.
+                if patch != 0 {
+                    let Some(value) = values.get_mut(index) else {
+                        polars_bail!(ComputeError: "invalid patched base run in ORC file");
+                    };
+                    *value |= patch << width;
+                }
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // DELTA
+        _ => {
+            let width_code = (header >> 1) & 0x1f;
+            let width = if width_code == 0 {
+                0
+            } else {
+                decode_width(width_code)
+            };
+            let count = (((header as usize & 1) << 8) | cursor.byte()? as usize) + 1;
+            let mut value = cursor.int_varint(signed)?;
+            let delta_base = cursor.int_varint(true)?;
+            out.push(value);
+            if count == 1 {
+                return Ok(());
+            }
+            value = value.wrapping_add(delta_base);
+            out.push(value);
+            if width == 0 {
+                for _ in 2..count {
+                    value = value.wrapping_add(delta_base);
+                    out.push(value);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(count - 2);
+                cursor.unpack(width, count - 2, &mut deltas)?;
+                for delta in deltas {
+                    // The sign of all deltas is the sign of the first one.
+                    value = if delta_base < 0 {
+                        value.wrapping_sub(delta as i64)
+                    } else {
+                        value.wrapping_add(delta as i64)
+                    };
+                    out.push(value);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Decode zigzag encoded varints of unbounded size, as used by decimals.
+pub(super) fn decode_varints(data: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut cursor = Cursor { data };
+    (0..n)
+        .map(|_| {
+            let value = cursor.varint()?;
+            Ok((value >> 1) as i128 ^ -((value & 1) as i128))
+        })
+        .collect()
+}
+
+fn write_varint(mut value: u128, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub(super) fn encode_varints(values: impl IntoIterator<Item = i128>, out: &mut Vec<u8>) {
+    for value in values {
+        write_varint(((value << 1) ^ (value >> 127)) as u128, out);
+    }
+}
+
+pub(super) fn encode_bytes(values: &[u8], out: &mut Vec<u8>) {
+    fn flush_literals(literals: &[u8], out: &mut Vec<u8>) {
+        for chunk in literals.chunks(128) {
+            out.push((256 - chunk.len()) as u8);
+            out.extend_from_slice(chunk);
+        }
+    }
+
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let run = values[i..]
+            .iter()
+            .take(130)
+            .take_while(|v| **v == values[i])
+            .count();
+        if run >= 3 {
+            flush_literals(&values[literal_start..i], out);
+            out.push((run - 3) as u8);
+            out.push(values[i]);
+            i += run;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    flush_literals(&values[literal_start..], out);
+}
+
+pub(super) fn encode_booleans(values: impl IntoIterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    for (i, value) in values.into_iter().enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if value {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_bytes(&bytes, out);
+}
+
+/// Encode integers with RLE version 2. Runs of equal values are stored as short repeats or
+/// fixed deltas, all other values are bit-packed.
+pub(super) fn encode_ints(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let encode = |value: i64| {
+        if signed {
+            zigzag(value)
+        } else {
+            value as u64
+        }
+    };
+    for chunk in values.chunks(512) {
+        let len = chunk.len() - 1;
+        if chunk.len() >= 3 && chunk.iter().all(|v| *v == chunk[0]) {
+            let value = encode(chunk[0]);
+            if chunk.len() <= 10 {
+                let width = (64 - value.leading_zeros() as usize).div_ceil(8).max(1);
+                out.push((((width - 1) << 3) | (chunk.len() - 3)) as u8);
+                out.extend_from_slice(&value.to_be_bytes()[8 - width..]);
+            } else {
+                out.push(0xc0 | (len >> 8) as u8);
+                out.push(len as u8);
+                write_varint(value as u128, out);
+                write_varint(0, out);
+            }
+            continue;
+        }
+
+        let bits = chunk
+            .iter()
+            .map(|v| 64 - encode(*v).leading_zeros() as usize)
+            .max()
+            .unwrap_or(0);
+        let width = closest_fixed_bits(bits);
+        out.push(0x40 | (encode_width(width) << 1) | (len >> 8) as u8);
+        out.push(len as u8);
+
+        let mut buffer = 0u128;
+        let mut buffered = 0;
+        for value in chunk {
+            buffer = (buffer << width) | encode(*value) as u128;
+            buffered += width;
+            while buffered >= 8 {
+                buffered -= 8;
+                out.push((buffer >> buffered) as u8);
+            }
+        }
+        if buffered > 0 {
+            out.push((buffer << (8 - buffered)) as u8);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_int_rle_v2() {
+        // The examples of the ORC specification.
+        let cases: [(&[u8], Vec<i64>); 4] = [
+            (&[0x0a, 0x27, 0x10], vec![10000; 5]),
+            (
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                vec![23713, 43806, 57005, 48879],
+            ),
+            (
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8,
+                ],
+                vec![
+                    2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110,
+                    2120, 2130, 2140, 2150, 2160, 2170, 2180, 2190,
+                ],
+            ),
+            (
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29],
+            ),
+        ];
+        for (data, expected) in cases {
+            let out = decode_ints(data, expected.len(), false, RleVersion::V2).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_int_rle_v1() {
+        // A run of 100 values starting at 0 with a delta of 1 and literals 2, 3, 6, 7, 11.
+        let data = [0x61, 0x01, 0x00, 0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b];
+        let out = decode_ints(&data, 105, false, RleVersion::V1).unwrap();
+        let expected = (0..100).chain([2, 3, 6, 7, 11]).collect::<Vec<_>>();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (0..2000i64)
+            .map(|i| match i % 700 {
+                0..200 => 7,
+                200..205 => -3,
+                _ => i * i - 1_000_000,
+            })
+            .chain([i64::MIN, i64::MAX, 0])
+            .collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_ints(&values, true, &mut out);
+        assert_eq!(
+            decode_ints(&out, values.len(), true, RleVersion::V2).unwrap(),
+            values
+        );
+
+        let bytes = (0..1000).map(|i| (i / 7 % 5) as u8).collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_bytes(&bytes, &mut out);
+        assert_eq!(decode_bytes(&out, bytes.len()).unwrap(), bytes);
+
+        let booleans = (0..77).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_booleans(booleans.iter().copied(), &mut out);
+        let decoded = decode_booleans(&out, booleans.len()).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), booleans);
+
+        let decimals = [0, -1, i128::MAX, i128::MIN, 12345];
+        let mut out = vec![];
+        encode_varints(decimals, &mut out);
+        assert_eq!(decode_varints(&out, decimals.len()).unwrap(), decimals);
+    }
+}
@@ -0,0 +1,669 @@
+use std::io::Write;
+
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+};
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use prost::Message;
+
+use super::compression::compress;
+use super::proto::{
+    self, BucketStatistics, ColumnEncoding, ColumnEncodingKind, ColumnStatistics, CompressionKind,
+    DateStatistics, DecimalStatistics, DoubleStatistics, Footer, IntegerStatistics, PostScript,
+    Stream, StreamKind, StringStatistics, StripeFooter, StripeInformation, StripeStatistics,
+    TimestampStatistics, Type, TypeKind,
+};
+use super::rle::{encode_booleans, encode_bytes, encode_ints, encode_varints};
+use super::{OrcCompression, OrcWriterOptions, NANOSECONDS, ORC_EPOCH_OFFSET};
+use crate::prelude::*;
+
+const MAGIC: &[u8] = b"ORC";
+
+/// Write a DataFrame to an ORC file.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use std::fs::File;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let file = File::create("data.orc")?;
+///     OrcWriter::new(file).finish(df)
+/// }
+/// ```
+pub struct OrcWriter<W: Write> {
+    writer: W,
+    options: OrcWriterOptions,
+}
+
+impl<W: Write> OrcWriter<W> {
+    pub fn with_options(mut self, options: OrcWriterOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<W: Write> SerWriter<W> for OrcWriter<W> {
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            options: OrcWriterOptions::default(),
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        polars_ensure!(self.options.stripe_size > 0, InvalidOperation: "the stripe size must be positive");
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let dtype = writer_dtype(c.dtype())?;
+                c.as_materialized_series().strict_cast(&dtype)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut types = vec![Type::default()];
+        types[0] = Type {
+            kind: Some(TypeKind::Struct as i32),
+            subtypes: columns
+                .iter()
+                .map(|s| add_type(&mut types, s.dtype()))
+                .collect(),
+            field_names: columns.iter().map(|s| s.name().to_string()).collect(),
+            ..Default::default()
+        };
+
+        let compression = self.options.compression;
+        let block_size = self.options.compression_block_size;
+        let mut position = MAGIC.len() as u64;
+        self.writer.write_all(MAGIC)?;
+
+        let mut stripes = vec![];
+        let mut stripe_stats = vec![];
+        let mut file_stats = vec![ColumnStats::default(); types.len()];
+        let mut offset = 0;
+        while offset < df.height() {
+            let rows = self.options.stripe_size.min(df.height() - offset);
+            let mut stripe = StripeWriter {
+                types: &types,
+                streams: vec![],
+                stats: vec![ColumnStats::default(); types.len()],
+            };
+            stripe.stats[0].values = rows as u64;
+            for (s, id) in columns.iter().zip(&types[0].subtypes) {
+                let array = s
+                    .slice(offset as i64, rows)
+                    .rechunk()
+                    .to_arrow(0, CompatLevel::newest());
+                stripe.write_column(*id, array.as_ref(), None)?;
+            }
+
+            let mut footer = StripeFooter {
+                columns: types
+                    .iter()
+                    .map(|ty| ColumnEncoding {
+                        kind: Some(encoding(ty) as i32),
+                        dictionary_size: None,
+                    })
+                    .collect(),
+                writer_timezone: Some("UTC".to_string()),
+                ..Default::default()
+            };
+            let mut data_length = 0;
+            for (column, kind, data) in stripe.streams {
+                let data = compress(compression, block_size, &data)?;
+                self.writer.write_all(&data)?;
+                data_length += data.len() as u64;
+                footer.streams.push(Stream {
+                    kind: Some(kind as i32),
+                    column: Some(column),
+                    length: Some(data.len() as u64),
+                });
+            }
+            let footer = compress(compression, block_size, &footer.encode_to_vec())?;
+            self.writer.write_all(&footer)?;
+
+            stripes.push(StripeInformation {
+                offset: Some(position),
+                index_length: Some(0),
+                data_length: Some(data_length),
+                footer_length: Some(footer.len() as u64),
+                number_of_rows: Some(rows as u64),
+            });
+            position += data_length + footer.len() as u64;
+            for (file, stripe) in file_stats.iter_mut().zip(&stripe.stats) {
+                file.merge(stripe);
+            }
+            stripe_stats.push(StripeStatistics {
+                col_stats: stripe
+                    .stats
+                    .iter()
+                    .zip(&types)
+                    .map(|(stats, ty)| stats.to_proto(ty))
+                    .collect(),
+            });
+            offset += rows;
+        }
+
+        let metadata = proto::Metadata { stripe_stats }.encode_to_vec();
+        let metadata = compress(compression, block_size, &metadata)?;
+        self.writer.write_all(&metadata)?;
+        let footer = Footer {
+            header_length: Some(MAGIC.len() as u64),
+            content_length: Some(position),
+            stripes,
+            statistics: file_stats
+                .iter()
+                .zip(&types)
+                .map(|(stats, ty)| stats.to_proto(ty))
+                .collect(),
+            types,
+            number_of_rows: Some(df.height() as u64),
+            row_index_stride: Some(0),
+            ..Default::default()
+        };
+        let footer = compress(compression, block_size, &footer.encode_to_vec())?;
+        self.writer.write_all(&footer)?;
+
+        let postscript = PostScript {
+            footer_length: Some(footer.len() as u64),
+            compression: Some(compression_kind(compression) as i32),
+            compression_block_size: Some(block_size as u64),
+            version: vec![0, 12],
+            metadata_length: Some(metadata.len() as u64),
+            // Timestamp statistics are in UTC (ORC-135).
+            writer_version: Some(6),
+            magic: Some("ORC".to_string()),
+        }
+        .encode_to_vec();
+        self.writer.write_all(&postscript)?;
+        self.writer.write_all(&[postscript.len() as u8])?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+fn compression_kind(compression: OrcCompression) -> CompressionKind {
+    match compression {
+        OrcCompression::Uncompressed => CompressionKind::None,
+        OrcCompression::Zlib => CompressionKind::Zlib,
+        OrcCompression::Snappy => CompressionKind::Snappy,
+        OrcCompression::Lz4 => CompressionKind::Lz4,
+        OrcCompression::Zstd => CompressionKind::Zstd,
+    }
+}
+
+/// The data type that a column is cast to before it is written.
+fn writer_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    Ok(match dtype {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::String
+        | DataType::Binary
+        | DataType::Date => dtype.clone(),
+        // ORC has no unsigned integers.
+        DataType::UInt8 => DataType::Int16,
+        DataType::UInt16 => DataType::Int32,
+        DataType::UInt32 | DataType::UInt64 => DataType::Int64,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::Datetime(_, tz) => DataType::Datetime(TimeUnit::Nanoseconds, tz.clone()),
+        DataType::Decimal(precision, scale) => {
+            DataType::Decimal(Some(precision.unwrap_or(38)), Some(scale.unwrap_or(0)))
+        },
+        DataType::List(inner) => DataType::List(Box::new(writer_dtype(inner)?)),
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name().clone(), writer_dtype(f.dtype())?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        dt => polars_bail!(InvalidOperation: "cannot write columns of type {} to ORC", dt),
+    })
+}
+
+/// Add the ORC type of `dtype` and its children to `types` in pre-order, returning its id.
+fn add_type(types: &mut Vec<Type>, dtype: &DataType) -> u32 {
+    let id = types.len();
+    types.push(Type::default());
+    let kind = |kind: TypeKind| Some(kind as i32);
+    let ty = match dtype {
+        DataType::Boolean => Type {
+            kind: kind(TypeKind::Boolean),
+            ..Default::default()
+        },
+        DataType::Int8 => Type {
+            kind: kind(TypeKind::Byte),
+            ..Default::default()
+        },
+        DataType::Int16 => Type {
+            kind: kind(TypeKind::Short),
+            ..Default::default()
+        },
+        DataType::Int32 => Type {
+            kind: kind(TypeKind::Int),
+            ..Default::default()
+        },
+        DataType::Int64 => Type {
+            kind: kind(TypeKind::Long),
+            ..Default::default()
+        },
+        DataType::Float32 => Type {
+            kind: kind(TypeKind::Float),
+            ..Default::default()
+        },
+        DataType::Float64 => Type {
+            kind: kind(TypeKind::Double),
+            ..Default::default()
+        },
+        DataType::String => Type {
+            kind: kind(TypeKind::String),
+            ..Default::default()
+        },
+        DataType::Binary => Type {
+            kind: kind(TypeKind::Binary),
+            ..Default::default()
+        },
+        DataType::Date => Type {
+            kind: kind(TypeKind::Date),
+            ..Default::default()
+        },
+        DataType::Datetime(_, tz) => Type {
+            kind: kind(if tz.is_some() {
+                TypeKind::TimestampInstant
+            } else {
+                TypeKind::Timestamp
+            }),
+            ..Default::default()
+        },
+        DataType::Decimal(precision, scale) => Type {
+            kind: kind(TypeKind::Decimal),
+            precision: precision.map(|p| p as u32),
+            scale: scale.map(|s| s as u32),
+            ..Default::default()
+        },
+        DataType::List(inner) => Type {
+            kind: kind(TypeKind::List),
+            subtypes: vec![add_type(types, inner)],
+            ..Default::default()
+        },
+        DataType::Struct(fields) => Type {
+            kind: kind(TypeKind::Struct),
+            subtypes: fields.iter().map(|f| add_type(types, f.dtype())).collect(),
+            field_names: fields.iter().map(|f| f.name().to_string()).collect(),
+            ..Default::default()
+        },
+        // Other types are cast by `writer_dtype`.
+        _ => unreachable!(),
+    };
+    types[id] = ty;
+    id as u32
+}
+
+fn type_kind(ty: &Type) -> TypeKind {
+    TypeKind::from_i32(ty.kind.unwrap_or(0)).unwrap()
+}
+
+fn encoding(ty: &Type) -> ColumnEncodingKind {
+    match type_kind(ty) {
+        TypeKind::Boolean
+        | TypeKind::Byte
+        | TypeKind::Float
+        | TypeKind::Double
+        | TypeKind::Struct
+        | TypeKind::Union => ColumnEncodingKind::Direct,
+        _ => ColumnEncodingKind::DirectV2,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+enum Bound {
+    Int(i64),
+    Double(f64),
+    String(String),
+    Decimal(i128),
+}
+
+/// The statistics of a column in a stripe or the file.
+#[derive(Clone, Debug, Default)]
+struct ColumnStats {
+    values: u64,
+    has_null: bool,
+    trues: u64,
+    bounds: Option<(Bound, Bound)>,
+}
+
+impl ColumnStats {
+    fn update(&mut self, min: Bound, max: Bound) {
+        self.bounds = Some(match self.bounds.take() {
+            None => (min, max),
+            Some((old_min, old_max)) => (
+                if min < old_min { min } else { old_min },
+                if max > old_max { max } else { old_max },
+            ),
+        });
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.values += other.values;
+        self.has_null |= other.has_null;
+        self.trues += other.trues;
+        if let Some((min, max)) = &other.bounds {
+            self.update(min.clone(), max.clone());
+        }
+    }
+
+    fn to_proto(&self, ty: &Type) -> ColumnStatistics {
+        let mut stats = ColumnStatistics {
+            number_of_values: Some(self.values),
+            has_null: Some(self.has_null),
+            ..Default::default()
+        };
+        let kind = type_kind(ty);
+        if kind == TypeKind::Boolean {
+            stats.bucket_statistics = Some(BucketStatistics {
+                count: vec![self.trues],
+            });
+        }
+        match (kind, self.bounds.clone()) {
+            (TypeKind::Date, Some((Bound::Int(min), Bound::Int(max)))) => {
+                stats.date_statistics = Some(DateStatistics {
+                    minimum: Some(min as i32),
+                    maximum: Some(max as i32),
+                })
+            },
+            (
+                TypeKind::Timestamp | TypeKind::TimestampInstant,
+                Some((Bound::Int(min), Bound::Int(max))),
+            ) => {
+                stats.timestamp_statistics = Some(TimestampStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                    minimum_utc: Some(min),
+                    maximum_utc: Some(max),
+                })
+            },
+            (_, Some((Bound::Int(min), Bound::Int(max)))) => {
+                stats.int_statistics = Some(IntegerStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                })
+            },
+            (_, Some((Bound::Double(min), Bound::Double(max)))) => {
+                stats.double_statistics = Some(DoubleStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                })
+            },
+            (_, Some((Bound::String(min), Bound::String(max)))) => {
+                stats.string_statistics = Some(StringStatistics {
+                    minimum: Some(min),
+                    maximum: Some(max),
+                })
+            },
+            (_, Some((Bound::Decimal(min), Bound::Decimal(max)))) => {
+                let scale = ty.scale.unwrap_or(0) as usize;
+                stats.decimal_statistics = Some(DecimalStatistics {
+                    minimum: Some(format_decimal(min, scale)),
+                    maximum: Some(format_decimal(max, scale)),
+                })
+            },
+            _ => {},
+        }
+        stats
+    }
+}
+
+fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = format!("{:0>width$}", value.unsigned_abs(), width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        format!("{sign}{integer}")
+    } else {
+        format!("{sign}{integer}.{fraction}")
+    }
+}
+
+fn bounds<T: PartialOrd + Copy>(values: impl IntoIterator<Item = T>) -> Option<(T, T)> {
+    values.into_iter().fold(None, |bounds, v| match bounds {
+        None => Some((v, v)),
+        Some((min, max)) => Some((if v < min { v } else { min }, if v > max { v } else { max })),
+    })
+}
+
+fn downcast<T: 'static>(array: &dyn Array) -> &T {
+    array.as_any().downcast_ref::<T>().unwrap()
+}
+
+fn ints<T: NativeType + Into<i64>>(array: &dyn Array, positions: &[usize]) -> Vec<i64> {
+    let array = downcast::<PrimitiveArray<T>>(array);
+    positions.iter().map(|i| array.value(*i).into()).collect()
+}
+
+/// The uncompressed streams and statistics of the columns of a stripe.
+struct StripeWriter<'a> {
+    types: &'a [Type],
+    streams: Vec<(u32, StreamKind, Vec<u8>)>,
+    stats: Vec<ColumnStats>,
+}
+
+impl StripeWriter<'_> {
+    /// Encode the values of `array` at the positions that are set in `mask`, which are the
+    /// positions where all parents are valid.
+    fn write_column(
+        &mut self,
+        id: u32,
+        array: &dyn Array,
+        mask: Option<&Bitmap>,
+    ) -> PolarsResult<()> {
+        let included = (0..array.len())
+            .filter(|i| mask.is_none_or(|m| m.get_bit(*i)))
+            .collect::<Vec<_>>();
+        let positions = included
+            .iter()
+            .copied()
+            .filter(|i| array.is_valid(*i))
+            .collect::<Vec<_>>();
+        let has_null = positions.len() < included.len();
+        if has_null {
+            let mut present = vec![];
+            encode_booleans(included.iter().map(|i| array.is_valid(*i)), &mut present);
+            self.streams.push((id, StreamKind::Present, present));
+        }
+        let stats = &mut self.stats[id as usize];
+        stats.values += positions.len() as u64;
+        stats.has_null |= has_null;
+
+        let ty = &self.types[id as usize];
+        let mut data = vec![];
+        match type_kind(ty) {
+            TypeKind::Boolean => {
+                let array = downcast::<BooleanArray>(array);
+                let values = positions.iter().map(|i| array.value(*i));
+                stats.trues += values.clone().filter(|v| *v).count() as u64;
+                encode_booleans(values, &mut data);
+            },
+            TypeKind::Byte => {
+                let array = downcast::<PrimitiveArray<i8>>(array);
+                let values = positions
+                    .iter()
+                    .map(|i| array.value(*i))
+                    .collect::<Vec<_>>();
+                if let Some((min, max)) = bounds(values.iter().copied()) {
+                    stats.update(Bound::Int(min as i64), Bound::Int(max as i64));
+                }
+                let bytes = values.iter().map(|v| *v as u8).collect::<Vec<_>>();
+                encode_bytes(&bytes, &mut data);
+            },
+            kind @ (TypeKind::Short | TypeKind::Int | TypeKind::Long | TypeKind::Date) => {
+                let values = match kind {
+                    TypeKind::Short => ints::<i16>(array, &positions),
+                    TypeKind::Long => ints::<i64>(array, &positions),
+                    _ => ints::<i32>(array, &positions),
+                };
+                if let Some((min, max)) = bounds(values.iter().copied()) {
+                    stats.update(Bound::Int(min), Bound::Int(max));
+                }
+                encode_ints(&values, true, &mut data);
+            },
+            TypeKind::Float => {
+                let array = downcast::<PrimitiveArray<f32>>(array);
+                let values = positions.iter().map(|i| array.value(*i));
+                if let Some((min, max)) = bounds(values.clone().filter(|v| !v.is_nan())) {
+                    stats.update(Bound::Double(min as f64), Bound::Double(max as f64));
+                }
+                data.extend(values.flat_map(f32::to_le_bytes));
+            },
+            TypeKind::Double => {
+                let array = downcast::<PrimitiveArray<f64>>(array);
+                let values = positions.iter().map(|i| array.value(*i));
+                if let Some((min, max)) = bounds(values.clone().filter(|v| !v.is_nan())) {
+                    stats.update(Bound::Double(min), Bound::Double(max));
+                }
+                data.extend(values.flat_map(f64::to_le_bytes));
+            },
+            kind @ (TypeKind::String | TypeKind::Binary) => {
+                let values = if kind == TypeKind::String {
+                    let array = downcast::<Utf8ViewArray>(array);
+                    let values = positions.iter().map(|i| array.value(*i));
+                    if let Some((min, max)) = bounds(values.clone()) {
+                        stats.update(Bound::String(min.into()), Bound::String(max.into()));
+                    }
+                    values.map(str::as_bytes).collect::<Vec<_>>()
+                } else {
+                    let array = downcast::<BinaryViewArray>(array);
+                    positions.iter().map(|i| array.value(*i)).collect()
+                };
+                let lengths = values.iter().map(|v| v.len() as i64).collect::<Vec<_>>();
+                data.extend(values.into_iter().flatten());
+                let mut length = vec![];
+                encode_ints(&lengths, false, &mut length);
+                self.streams.push((id, StreamKind::Data, data));
+                self.streams.push((id, StreamKind::Length, length));
+                return Ok(());
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let values = ints::<i64>(array, &positions);
+                if let Some((min, max)) = bounds(values.iter().copied()) {
+                    stats.update(
+                        Bound::Int(min.div_euclid(1_000_000)),
+                        Bound::Int(max.div_euclid(1_000_000)),
+                    );
+                }
+                let mut seconds = Vec::with_capacity(values.len());
+                let mut nanos = Vec::with_capacity(values.len());
+                for value in values {
+                    let (mut secs, ns) =
+                        (value.div_euclid(NANOSECONDS), value.rem_euclid(NANOSECONDS));
+                    // Negative seconds are rounded towards 0, as readers expect. Like in other
+                    // ORC writers, this cannot represent fractional timestamps in the second
+                    // before the unix epoch, which are read as the positive timestamp.
+                    if secs < 0 && ns > 999_999 {
+                        secs += 1;
+                    }
+                    seconds.push(secs - ORC_EPOCH_OFFSET);
+                    nanos.push(encode_nanos(ns) as i64);
+                }
+                encode_ints(&seconds, true, &mut data);
+                let mut secondary = vec![];
+                encode_ints(&nanos, false, &mut secondary);
+                self.streams.push((id, StreamKind::Data, data));
+                self.streams.push((id, StreamKind::Secondary, secondary));
+                return Ok(());
+            },
+            TypeKind::Decimal => {
+                let array = downcast::<PrimitiveArray<i128>>(array);
+                let values = positions
+                    .iter()
+                    .map(|i| array.value(*i))
+                    .collect::<Vec<_>>();
+                if let Some((min, max)) = bounds(values.iter().copied()) {
+                    stats.update(Bound::Decimal(min), Bound::Decimal(max));
+                }
+                encode_varints(values.iter().copied(), &mut data);
+                let scales = vec![ty.scale.unwrap_or(0) as i64; values.len()];
+                let mut secondary = vec![];
+                encode_ints(&scales, true, &mut secondary);
+                self.streams.push((id, StreamKind::Data, data));
+                self.streams.push((id, StreamKind::Secondary, secondary));
+                return Ok(());
+            },
+            TypeKind::Struct => {
+                let array = downcast::<StructArray>(array);
+                let mask = (0..array.len())
+                    .map(|i| mask.is_none_or(|m| m.get_bit(i)) && array.is_valid(i))
+                    .collect::<Bitmap>();
+                for (child, values) in ty.subtypes.iter().zip(array.values()) {
+                    self.write_column(*child, values.as_ref(), Some(&mask))?;
+                }
+                return Ok(());
+            },
+            TypeKind::List => {
+                let array = downcast::<ListArray<i64>>(array);
+                let offsets = array.offsets();
+                let mut lengths = Vec::with_capacity(positions.len());
+                let mut child_mask = vec![false; array.values().len()];
+                for i in &positions {
+                    let (start, end) = offsets.start_end(*i);
+                    lengths.push((end - start) as i64);
+                    child_mask[start..end].fill(true);
+                }
+                encode_ints(&lengths, false, &mut data);
+                self.streams.push((id, StreamKind::Length, data));
+                let child_mask = Bitmap::from_iter(child_mask);
+                return self.write_column(
+                    ty.subtypes[0],
+                    array.values().as_ref(),
+                    Some(&child_mask),
+                );
+            },
+            kind => polars_bail!(ComputeError: "cannot write ORC type {:?}", kind),
+        }
+        self.streams.push((id, StreamKind::Data, data));
+        Ok(())
+    }
+}
+
+/// Remove trailing decimal zeros of nanoseconds, the inverse of `decode_nanos` of the reader.
+fn encode_nanos(nanos: i64) -> u64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return (nanos as u64) << 3;
+    }
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    ((nanos as u64) << 3) | zeros
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(-1250, 2), "-12.50");
+        assert_eq!(format_decimal(5, 3), "0.005");
+        assert_eq!(format_decimal(42, 0), "42");
+    }
+
+    #[test]
+    fn test_encode_nanos() {
+        assert_eq!(encode_nanos(0), 0);
+        assert_eq!(encode_nanos(12345), 12345 << 3);
+        assert_eq!(encode_nanos(100_000_000), (1 << 3) | 7);
+        assert_eq!(encode_nanos(1_000), (1 << 3) | 2);
+    }
+}
@@ -2,7 +2,137 @@
 
 use std::sync::Arc;
 
+use polars_core::prelude::*;
 pub use polars_parquet::parquet::metadata::FileMetadata;
 pub use polars_parquet::read::statistics::{deserialize, Statistics as ParquetStatistics};
+use polars_parquet::read::statistics::{ArrowColumnStatistics, Statistics};
+use polars_parquet::read::RowGroupMetadata;
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+/// A column the rows of a Parquet file are sorted by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSortingColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    pub nulls_first: bool,
+    /// Whether the column may contain nulls according to the statistics.
+    pub has_nulls: bool,
+}
+
+/// Get the columns all rows of the file are lexicographically sorted by.
+///
+/// Parquet only records `sorting_columns` per row group. They are used for the whole file if
+/// every row group declares the same columns and the statistics of the leading column show that
+/// consecutive row groups don't overlap. If row groups touch on the leading column, only that
+/// column is returned.
+pub fn file_sorting_columns(md: &FileMetadata) -> Vec<FileSortingColumn> {
+    let Some(first) = md.row_groups.first() else {
+        return vec![];
+    };
+    let Some(sorting_columns) = first.sorting_columns() else {
+        return vec![];
+    };
+    if md
+        .row_groups
+        .iter()
+        .any(|rg| rg.sorting_columns() != Some(sorting_columns))
+    {
+        return vec![];
+    }
+
+    let schema = polars_parquet::arrow::read::infer_schema(md).ok();
+    let leaves = md.schema().columns();
+    let mut out = Vec::with_capacity(sorting_columns.len());
+    for sc in sorting_columns {
+        // Only top-level columns map to a column in the output.
+        let name = match leaves.get(sc.column_idx as usize) {
+            Some(leaf) if leaf.path_in_schema.len() == 1 => &leaf.path_in_schema[0],
+            _ => break,
+        };
+        let has_nulls = schema
+            .as_ref()
+            .and_then(|schema| schema.get(name))
+            .is_none_or(|field| {
+                md.row_groups
+                    .iter()
+                    .any(|rg| column_stats(rg, field).is_none_or(|s| s.null_count != Some(0)))
+            });
+        out.push(FileSortingColumn {
+            name: name.clone(),
+            descending: sc.descending,
+            nulls_first: sc.nulls_first,
+            has_nulls,
+        })
+    }
+
+    if md.row_groups.len() > 1 && !out.is_empty() {
+        let ordered =
+            schema.and_then(|schema| row_groups_ordered(&md.row_groups, &schema, &out[0]));
+        truncate_unordered(&mut out, ordered);
+    }
+
+    out
+}
+
+/// Keep only the leading column if rows on the boundary may be equal on it, and none if the
+/// boundary is not ordered.
+fn truncate_unordered(columns: &mut Vec<FileSortingColumn>, ordered: Option<bool>) {
+    match ordered {
+        Some(true) => {},
+        Some(false) => columns.truncate(1),
+        None => columns.clear(),
+    }
+}
+
+fn column_stats(rg: &RowGroupMetadata, field: &ArrowField) -> Option<ArrowColumnStatistics> {
+    let mut iter = rg.columns_under_root_iter(&field.name)?;
+    let Some(Statistics::Column(stats)) = deserialize(field, &mut iter).ok()? else {
+        return None;
+    };
+    stats.into_arrow().ok()
+}
+
+/// Checks that consecutive row groups are ordered on `column`.
+///
+/// Returns `Some(true)` if they are strictly ordered, `Some(false)` if boundary values may be
+/// equal and `None` if they are not known to be ordered.
+fn row_groups_ordered<'a>(
+    row_groups: impl IntoIterator<Item = &'a RowGroupMetadata>,
+    schema: &ArrowSchema,
+    column: &FileSortingColumn,
+) -> Option<bool> {
+    let field = schema.get(&column.name)?;
+
+    let min_max = |rg: &RowGroupMetadata| -> Option<(Series, Series)> {
+        let stats = column_stats(rg, field)?;
+        // Nulls of different row groups are not grouped together.
+        if stats.null_count != Some(0) {
+            return None;
+        }
+        let min = Series::try_from((PlSmallStr::EMPTY, stats.min_value?)).ok()?;
+        let max = Series::try_from((PlSmallStr::EMPTY, stats.max_value?)).ok()?;
+        Some((min, max))
+    };
+
+    let mut strict = true;
+    let mut row_groups = row_groups.into_iter();
+    let mut prev = min_max(row_groups.next()?)?;
+    for rg in row_groups {
+        let next = min_max(rg)?;
+        // The values of the next row group must not exceed those of the previous one if
+        // descending, and must not be below them if ascending.
+        let (upper, lower) = if column.descending {
+            (&next.1, &prev.0)
+        } else {
+            (&prev.1, &next.0)
+        };
+        if !upper.lt_eq(lower).ok()?.get(0)? {
+            return None;
+        }
+        strict &= upper.lt(lower).ok()?.get(0)?;
+        prev = next;
+    }
+
+    Some(strict)
+}
@@ -34,6 +34,7 @@ pub use options::{ParallelStrategy, ParquetOptions};
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
+pub(crate) use read_impl::should_copy_sortedness;
 pub use read_impl::{create_sorting_map, try_set_sorted_flag};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
@@ -42,7 +43,7 @@ pub use utils::materialize_empty_df;
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
-    pub use super::predicates::read_this_row_group;
+    pub use super::predicates::{read_this_row_group, runtime_filters_skip_row_group};
     pub use super::read_impl::{calc_prefilter_cost, PrefilterMaskSetting};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -3,7 +3,7 @@ use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
 use polars_parquet::read::RowGroupMetadata;
 
-use crate::predicates::{BatchStats, ColumnStats, ScanIOPredicate};
+use crate::predicates::{BatchStats, ColumnStats, RuntimeFilters, ScanIOPredicate};
 
 /// Collect the statistics in a row-group
 pub(crate) fn collect_statistics(
@@ -134,3 +134,16 @@ pub fn read_this_row_group(
 
     Ok(should_read)
 }
+
+/// Returns whether the statistics of a row-group show that it has no rows that pass the runtime
+/// filters of a join.
+pub fn runtime_filters_skip_row_group(
+    filters: &RuntimeFilters,
+    md: &RowGroupMetadata,
+    schema: &ArrowSchema,
+) -> PolarsResult<bool> {
+    if std::env::var("POLARS_NO_PARQUET_STATISTICS").is_ok() || filters.get().is_empty() {
+        return Ok(false);
+    }
+    Ok(collect_statistics(md, schema)?.is_some_and(|stats| filters.can_skip(&stats)))
+}
@@ -8,8 +8,11 @@ use arrow::datatypes::ArrowSchemaRef;
 use polars_core::chunked_array::builder::NullChunkedBuilder;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
-use polars_core::utils::{accumulate_dataframes_vertical, split_df};
+use polars_core::utils::accumulate_dataframes_vertical;
+#[cfg(feature = "async")]
+use polars_core::utils::split_df;
 use polars_core::{config, POOL};
+use polars_error::signals::current_cancellation_token;
 use polars_parquet::read::{
     self, ColumnChunkMetadata, FileMetadata, Filter, PredicateFilter, RowGroupMetadata,
 };
@@ -60,14 +63,14 @@ fn assert_dtypes(dtype: &ArrowDataType) {
     }
 }
 
-fn should_copy_sortedness(dtype: &DataType) -> bool {
+pub(crate) fn should_copy_sortedness(dtype: &DataType) -> bool {
     // @NOTE: For now, we are a bit conservative with this.
     use DataType as D;
 
     matches!(
         dtype,
         D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
-    )
+    ) || dtype.is_temporal()
 }
 
 pub fn try_set_sorted_flag(
@@ -89,22 +92,39 @@ pub fn try_set_sorted_flag(
     }
 }
 
+/// Map the index of a column in the file schema to its sort order in the row group.
+///
+/// The rows of a row group are lexicographically sorted by its `sorting_columns`, so only the
+/// leading column is sorted on its own.
 pub fn create_sorting_map(md: &RowGroupMetadata) -> PlHashMap<usize, IsSorted> {
-    let capacity = md.sorting_columns().map_or(0, |s| s.len());
-    let mut sorting_map = PlHashMap::with_capacity(capacity);
-
-    if let Some(sorting_columns) = md.sorting_columns() {
-        for sorting in sorting_columns {
-            let prev_value = sorting_map.insert(
-                sorting.column_idx as usize,
+    let mut sorting_map = PlHashMap::with_capacity(1);
+
+    if let Some(sorting) = md.sorting_columns().and_then(|s| s.first()) {
+        let columns = md.columns();
+        let leaf_idx = sorting.column_idx as usize;
+        // `column_idx` refers to a leaf column. Only leaves that are a column of their own can be
+        // mapped to a column in the file schema.
+        if columns
+            .get(leaf_idx)
+            .is_some_and(|leaf| leaf.descriptor().path_in_schema.len() == 1)
+        {
+            // Leaves are grouped by the column they belong to.
+            let col_idx = columns[..leaf_idx]
+                .iter()
+                .map(|c| &c.descriptor().path_in_schema[0])
+                .fold((0, None), |(count, prev), root| {
+                    (count + usize::from(prev != Some(root)), Some(root))
+                })
+                .0;
+
+            sorting_map.insert(
+                col_idx,
                 if sorting.descending {
                     IsSorted::Descending
                 } else {
                     IsSorted::Ascending
                 },
             );
-
-            debug_assert!(prev_value.is_none());
         }
     }
 
@@ -638,7 +658,11 @@ fn rg_to_dfs_optionally_par_over_columns(
         .sum();
     let slice_end = slice.0 + slice.1;
 
+    let cancel_token = current_cancellation_token();
     for rg_idx in row_group_start..row_group_end {
+        if let Some(token) = &cancel_token {
+            token.check()?;
+        }
         let md = &file_metadata.row_groups[rg_idx];
 
         let rg_slice =
@@ -781,12 +805,16 @@ fn rg_to_dfs_par_over_rg(
         row_groups.push((rg_md, rg_slice, row_count_start));
     }
 
+    let cancel_token = current_cancellation_token();
     let dfs = POOL.install(|| {
         // Set partitioned fields to prevent quadratic behavior.
         // Ensure all row groups are partitioned.
         row_groups
             .into_par_iter()
             .map(|(md, slice, row_count_start)| {
+                if let Some(token) = &cancel_token {
+                    token.check()?;
+                }
                 if slice.1 == 0 || use_statistics && !read_this_row_group(predicate, md, schema)? {
                     return Ok(None);
                 }
@@ -3,15 +3,18 @@ use std::sync::Mutex;
 
 use arrow::record_batch::RecordBatch;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::POOL;
 use polars_parquet::read::{fallible_streaming_iterator, ParquetError};
 use polars_parquet::write::{
     array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    SchemaDescriptor, SortingColumn, WriteOptions,
 };
 use rayon::prelude::*;
 
+use crate::parquet::read::should_copy_sortedness;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -23,6 +26,7 @@ pub struct BatchedWriter<W: Write> {
     pub(super) encodings: Vec<Vec<Encoding>>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
+    pub(super) sorting_columns: bool,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -38,6 +42,7 @@ impl<W: Write> BatchedWriter<W> {
             encodings,
             options,
             parallel,
+            sorting_columns: false,
         }
     }
 
@@ -73,10 +78,15 @@ impl<W: Write> BatchedWriter<W> {
             self.options,
             self.parallel,
         );
+        let sorting_columns = if self.sorting_columns {
+            get_sorting_columns(df, &self.parquet_schema)
+        } else {
+            vec![]
+        };
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            writer.write_sorted(group?, sorting_columns.clone())?;
         }
         Ok(())
     }
@@ -121,6 +131,39 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// Get the leaf columns of `df` that are known to be sorted.
+///
+/// Every column in the result is sorted on its own, so the rows are also lexicographically sorted
+/// by all of them.
+fn get_sorting_columns(df: &DataFrame, parquet_schema: &SchemaDescriptor) -> Vec<SortingColumn> {
+    parquet_schema
+        .columns()
+        .iter()
+        .enumerate()
+        .filter_map(|(column_idx, leaf)| {
+            let [name] = leaf.path_in_schema.as_slice() else {
+                return None;
+            };
+            let column = df.column(name).ok()?;
+            if !should_copy_sortedness(column.dtype()) {
+                return None;
+            }
+            let descending = match column.is_sorted_flag() {
+                IsSorted::Ascending => false,
+                IsSorted::Descending => true,
+                IsSorted::Not => return None,
+            };
+            let nulls_first = column.null_count() > 0 && column.get(0).ok()?.is_null();
+
+            Some(SortingColumn {
+                column_idx: column_idx as i32,
+                descending,
+                nulls_first,
+            })
+        })
+        .collect()
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
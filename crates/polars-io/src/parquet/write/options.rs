@@ -6,7 +6,7 @@ use polars_parquet::write::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
@@ -19,6 +19,21 @@ pub struct ParquetWriteOptions {
     pub data_page_size: Option<usize>,
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Record columns that are known to be sorted in the `sorting_columns` of the row groups.
+    pub sorting_columns: bool,
+}
+
+impl Default for ParquetWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: Default::default(),
+            statistics: Default::default(),
+            row_group_size: None,
+            data_page_size: None,
+            maintain_order: false,
+            sorting_columns: true,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
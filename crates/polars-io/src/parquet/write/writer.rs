@@ -24,6 +24,7 @@ impl ParquetWriteOptions {
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
+            .with_sorting_columns(self.sorting_columns)
     }
 }
 
@@ -41,6 +42,8 @@ pub struct ParquetWriter<W> {
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
     parallel: bool,
+    /// Record columns that are known to be sorted in the row group metadata.
+    sorting_columns: bool,
 }
 
 impl<W> ParquetWriter<W>
@@ -59,6 +62,7 @@ where
             row_group_size: None,
             data_page_size: None,
             parallel: true,
+            sorting_columns: true,
         }
     }
 
@@ -90,6 +94,13 @@ where
         self
     }
 
+    /// Record the columns that are known to be sorted in the `sorting_columns` of the row groups.
+    /// Readers use this to restore the sorted flags of the columns.
+    pub fn with_sorting_columns(mut self, toggle: bool) -> Self {
+        self.sorting_columns = toggle;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -109,6 +120,7 @@ where
             encodings,
             options,
             parallel: self.parallel,
+            sorting_columns: self.sorting_columns,
         })
     }
 
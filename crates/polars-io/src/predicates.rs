@@ -579,3 +579,477 @@ impl BatchStats {
         self.stats = indices.iter().map(|&i| self.stats[i].clone()).collect();
     }
 }
+
+/// Maximum number of distinct build keys that a [`RuntimeJoinFilter`] keeps, in addition to the
+/// range of the keys.
+const RUNTIME_FILTER_MAX_VALUES: usize = 1024;
+
+/// A filter on a key column of the probe side of a join, that is created from the keys of the
+/// build side once they are materialized.
+///
+/// Scans on the probe side use it to skip row groups and hive partitions of which the
+/// statistics show that they cannot contain a matching key.
+#[derive(Debug, Clone)]
+pub struct RuntimeJoinFilter {
+    column: PlSmallStr,
+    dtype: DataType,
+    schema: SchemaRef,
+    /// The minimum and maximum non-null build key, `None` if there are no such keys.
+    range: Option<(Series, Series)>,
+    /// The distinct non-null build keys, if there are few of them.
+    values: Option<Series>,
+}
+
+impl RuntimeJoinFilter {
+    pub fn column(&self) -> &PlSmallStr {
+        &self.column
+    }
+
+    /// Returns whether a batch with these statistics cannot contain any of the build keys.
+    pub fn can_skip(&self, stats: &BatchStats) -> bool {
+        if self.range.is_none() {
+            return true;
+        }
+        let Ok(stats) = stats.get_stats(&self.column) else {
+            return false;
+        };
+        match (stats.to_min(), stats.to_max()) {
+            (Some(min), Some(max)) => self.skip_mask(min, max).is_ok_and(|mask| mask.get_bit(0)),
+            _ => false,
+        }
+    }
+
+    /// Returns for every pair of key bounds whether a batch with these bounds cannot contain any
+    /// of the build keys. Batches with unknown bounds are not skipped.
+    fn skip_mask(&self, min: &Series, max: &Series) -> PolarsResult<Bitmap> {
+        let Some((key_min, key_max)) = &self.range else {
+            return Ok(Bitmap::new_with_value(true, min.len()));
+        };
+        let min = min.strict_cast(&self.dtype)?;
+        let max = max.strict_cast(&self.dtype)?;
+        let below = max.lt(key_min)?;
+        let above = min.gt(key_max)?;
+
+        let mut mask = MutableBitmap::with_capacity(min.len());
+        for (i, (below, above)) in below.iter().zip(above.iter()).enumerate() {
+            let skip = match (below, above) {
+                (Some(true), _) | (_, Some(true)) => true,
+                (Some(false), Some(false)) => match &self.values {
+                    Some(values) => {
+                        let (min, max) = (min.slice(i as i64, 1), max.slice(i as i64, 1));
+                        !(&values.gt_eq(&min)? & &values.lt_eq(&max)?).any()
+                    },
+                    None => false,
+                },
+                _ => false,
+            };
+            mask.push(skip);
+        }
+        Ok(mask.freeze())
+    }
+
+    /// The filter as a scan predicate, which only keeps the rows within the range of the keys.
+    pub fn to_scan_predicate(&self) -> ScanIOPredicate {
+        let filter = Arc::new(self.clone());
+        ScanIOPredicate {
+            predicate: filter.clone(),
+            live_columns: Arc::new(PlIndexSet::from_iter([self.column.clone()])),
+            skip_batch_predicate: Some(filter),
+            column_predicates: Arc::new(ColumnPredicates::default()),
+        }
+    }
+}
+
+impl PhysicalIoExpr for RuntimeJoinFilter {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let keys = df.column(&self.column)?.as_materialized_series();
+        let mask = match &self.range {
+            None => BooleanChunked::full(self.column.clone(), false, keys.len()),
+            Some((min, max)) => &keys.gt_eq(min)? & &keys.lt_eq(max)?,
+        };
+        Ok(mask.into_series())
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
+}
+
+impl StatsEvaluator for RuntimeJoinFilter {
+    fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+        Ok(!self.can_skip(stats))
+    }
+}
+
+impl SkipBatchPredicate for RuntimeJoinFilter {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let bound = |suffix: &str| {
+            df.column(&format_pl_smallstr!("{}_{suffix}", self.column))
+                .map(|c| c.as_materialized_series().clone())
+        };
+        let (min, max) = (bound("min")?, bound("max")?);
+        Ok(self
+            .skip_mask(&min, &max)
+            .unwrap_or_else(|_| Bitmap::new_zeroed(df.height())))
+    }
+}
+
+/// Collects the keys of the build side of a join into a [`RuntimeJoinFilter`].
+#[derive(Clone, Default)]
+pub struct RuntimeJoinFilterBuilder {
+    dtype: Option<DataType>,
+    range: Option<(Series, Series)>,
+    values: Option<Series>,
+    /// Whether there were too many distinct keys to keep them.
+    overflowed: bool,
+}
+
+impl RuntimeJoinFilterBuilder {
+    pub fn update(&mut self, keys: &Series) -> PolarsResult<()> {
+        self.dtype.get_or_insert_with(|| keys.dtype().clone());
+        let keys = keys.drop_nulls();
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        let min = keys.min_reduce()?.into_series(PlSmallStr::EMPTY);
+        let max = keys.max_reduce()?.into_series(PlSmallStr::EMPTY);
+        self.merge_range(min, max)?;
+
+        if !self.overflowed {
+            self.merge_values(keys.unique()?)?;
+        }
+        Ok(())
+    }
+
+    pub fn merge(&mut self, other: Self) -> PolarsResult<()> {
+        if self.dtype.is_none() {
+            self.dtype = other.dtype;
+        }
+        if let Some((min, max)) = other.range {
+            self.merge_range(min, max)?;
+        }
+        if other.overflowed {
+            self.overflowed = true;
+            self.values = None;
+        } else if let (false, Some(values)) = (self.overflowed, other.values) {
+            self.merge_values(values)?;
+        }
+        Ok(())
+    }
+
+    fn merge_range(&mut self, min: Series, max: Series) -> PolarsResult<()> {
+        self.range = Some(match self.range.take() {
+            None => (min, max),
+            Some((mut cur_min, mut cur_max)) => {
+                cur_min.append(&min)?;
+                cur_max.append(&max)?;
+                (
+                    cur_min.min_reduce()?.into_series(PlSmallStr::EMPTY),
+                    cur_max.max_reduce()?.into_series(PlSmallStr::EMPTY),
+                )
+            },
+        });
+        Ok(())
+    }
+
+    fn merge_values(&mut self, values: Series) -> PolarsResult<()> {
+        let values = match self.values.take() {
+            None => values,
+            Some(mut cur) => {
+                cur.append(&values)?;
+                cur.unique()?
+            },
+        };
+        if values.len() > RUNTIME_FILTER_MAX_VALUES {
+            self.overflowed = true;
+        } else {
+            self.values = Some(values);
+        }
+        Ok(())
+    }
+
+    /// Create the filter for the probe-side `column`.
+    pub fn finish(&self, column: PlSmallStr) -> RuntimeJoinFilter {
+        let dtype = self.dtype.clone().unwrap_or(DataType::Null);
+        RuntimeJoinFilter {
+            schema: Arc::new(Schema::from_iter([(column.clone(), dtype.clone())])),
+            column,
+            dtype,
+            range: self.range.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+/// The runtime filters of a scan, that are published by joins while the query runs.
+#[derive(Clone, Default)]
+pub struct RuntimeFilters(Arc<RuntimeFiltersInner>);
+
+#[derive(Default)]
+struct RuntimeFiltersInner {
+    /// The columns of the scan that filters can be published on.
+    columns: std::sync::RwLock<PlIndexSet<PlSmallStr>>,
+    filters: std::sync::RwLock<Vec<Arc<RuntimeJoinFilter>>>,
+}
+
+impl RuntimeFilters {
+    /// Register a column of the scan that a filter will be published on.
+    pub fn register_column(&self, column: PlSmallStr) {
+        self.0.columns.write().unwrap().insert(column);
+    }
+
+    pub fn publish(&self, filter: RuntimeJoinFilter) {
+        self.0.filters.write().unwrap().push(Arc::new(filter));
+    }
+
+    pub fn get(&self) -> Vec<Arc<RuntimeJoinFilter>> {
+        self.0.filters.read().unwrap().clone()
+    }
+
+    /// Returns whether a batch with these statistics cannot contain rows that pass all filters.
+    pub fn can_skip(&self, stats: &BatchStats) -> bool {
+        self.0
+            .filters
+            .read()
+            .unwrap()
+            .iter()
+            .any(|f| f.can_skip(stats))
+    }
+
+    /// Combine the filters with the predicate of a scan with `schema`.
+    ///
+    /// The filters are polled every time the predicate is evaluated, so filters that are
+    /// published while the scan runs still skip the batches that are read afterwards.
+    pub fn apply(
+        &self,
+        predicate: Option<ScanIOPredicate>,
+        schema: &Schema,
+    ) -> Option<ScanIOPredicate> {
+        let columns = self.0.columns.read().unwrap().clone();
+        let Some(fields) = columns
+            .iter()
+            .map(|c| Some((c.clone(), schema.get(c)?.clone())))
+            .collect::<Option<Schema>>()
+        else {
+            return predicate;
+        };
+        if fields.is_empty() {
+            return predicate;
+        }
+
+        let polled = Arc::new(PolledRuntimeFilters {
+            filters: self.clone(),
+            schema: Arc::new(fields),
+        });
+        let filter = ScanIOPredicate {
+            predicate: polled.clone(),
+            live_columns: Arc::new(columns),
+            skip_batch_predicate: Some(polled),
+            column_predicates: Arc::new(ColumnPredicates::default()),
+        };
+        Some(match predicate {
+            None => filter,
+            Some(predicate) => predicate.and(filter),
+        })
+    }
+}
+
+/// A predicate that holds for the rows that pass every filter published so far.
+struct PolledRuntimeFilters {
+    filters: RuntimeFilters,
+    schema: SchemaRef,
+}
+
+impl PhysicalIoExpr for PolledRuntimeFilters {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let mut mask = BooleanChunked::full(PlSmallStr::EMPTY, true, df.height());
+        for filter in self.filters.get() {
+            mask = &mask & filter.evaluate_io(df)?.bool()?;
+        }
+        Ok(mask.into_series())
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
+}
+
+impl StatsEvaluator for PolledRuntimeFilters {
+    fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+        Ok(!self.filters.can_skip(stats))
+    }
+}
+
+impl SkipBatchPredicate for PolledRuntimeFilters {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let mut mask = Bitmap::new_zeroed(df.height());
+        for filter in self.filters.get() {
+            mask = &mask | &filter.evaluate_with_stat_df(df)?;
+        }
+        Ok(mask)
+    }
+}
+
+impl fmt::Debug for RuntimeFilters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("runtime_filters")
+    }
+}
+
+/// The conjunction of two predicates.
+struct IoExprAnd(Arc<dyn PhysicalIoExpr>, Arc<dyn PhysicalIoExpr>);
+
+impl PhysicalIoExpr for IoExprAnd {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        let left = self.0.evaluate_io(df)?;
+        let right = self.1.evaluate_io(df)?;
+        Ok((left.bool()? & right.bool()?).into_series())
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
+        Some(self)
+    }
+}
+
+impl StatsEvaluator for IoExprAnd {
+    fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
+        for expr in [&self.0, &self.1] {
+            if let Some(evaluator) = expr.as_stats_evaluator() {
+                if !evaluator.should_read(stats)? {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// A batch can be skipped if either predicate allows it.
+struct SkipBatchEither {
+    schema: SchemaRef,
+    predicates: [Arc<dyn SkipBatchPredicate>; 2],
+}
+
+impl SkipBatchPredicate for SkipBatchEither {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+        let [a, b] = &self.predicates;
+        Ok(&a.evaluate_with_stat_df(df)? | &b.evaluate_with_stat_df(df)?)
+    }
+}
+
+impl ScanIOPredicate {
+    /// Combine two predicates that both have to hold.
+    pub fn and(self, other: Self) -> Self {
+        let mut live_columns = self.live_columns.as_ref().clone();
+        live_columns.extend(other.live_columns.iter().cloned());
+
+        let skip_batch_predicate = match (self.skip_batch_predicate, other.skip_batch_predicate) {
+            (Some(a), Some(b)) => {
+                let mut schema = a.schema().as_ref().clone();
+                schema.merge(b.schema().as_ref().clone());
+                Some(Arc::new(SkipBatchEither {
+                    schema: Arc::new(schema),
+                    predicates: [a, b],
+                }) as _)
+            },
+            _ => None,
+        };
+
+        // The column predicates of `self` remain valid, but no longer cover the whole predicate.
+        let mut column_predicates = self.column_predicates.as_ref().clone();
+        column_predicates.is_sumwise_complete = false;
+
+        Self {
+            predicate: Arc::new(IoExprAnd(self.predicate, other.predicate)),
+            live_columns: Arc::new(live_columns),
+            skip_batch_predicate,
+            column_predicates: Arc::new(column_predicates),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_join_filter_skips_per_batch() -> PolarsResult<()> {
+        let mut builder = RuntimeJoinFilterBuilder::default();
+        builder.update(&Series::new("k".into(), [Some(5i64), None, Some(57)]))?;
+        let filter = builder.finish("key".into());
+
+        // The statistics of five files, of which the second, third and fourth cannot contain a
+        // key. The keys of the file with unknown bounds may match.
+        let stats = df![
+            "len" => [10 as IdxSize; 5],
+            "key_min" => [Some(0i64), Some(10), Some(20), Some(60), None],
+            "key_max" => [Some(9i64), Some(19), Some(49), Some(69), None],
+            "key_nc" => [0 as IdxSize; 5],
+        ]?;
+        let mask = filter.evaluate_with_stat_df(&stats)?;
+        assert_eq!(
+            mask.iter().collect::<Vec<_>>(),
+            [false, true, true, true, false]
+        );
+
+        // Filters without keys skip every file.
+        let filter = RuntimeJoinFilterBuilder::default().finish("key".into());
+        assert_eq!(filter.evaluate_with_stat_df(&stats)?.set_bits(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_runtime_filters_are_polled() -> PolarsResult<()> {
+        let filters = RuntimeFilters::default();
+        filters.register_column("key".into());
+        let schema = Schema::from_iter([Field::new("key".into(), DataType::Int64)]);
+        let predicate = filters.apply(None, &schema).unwrap();
+        let skip = predicate.skip_batch_predicate.as_ref().unwrap();
+
+        let stats = df![
+            "len" => [10 as IdxSize; 2],
+            "key_min" => [0i64, 10],
+            "key_max" => [9i64, 19],
+            "key_nc" => [0 as IdxSize; 2],
+        ]?;
+        let df = df!["key" => [1i64, 5, 12]]?;
+        assert_eq!(skip.evaluate_with_stat_df(&stats)?.set_bits(), 0);
+        assert_eq!(predicate.predicate.evaluate_io(&df)?.bool()?.sum(), Some(3));
+
+        // A filter that is published afterwards applies to the same predicate.
+        let mut builder = RuntimeJoinFilterBuilder::default();
+        builder.update(&Series::new("k".into(), [3i64, 5]))?;
+        filters.publish(builder.finish("key".into()));
+        assert_eq!(
+            skip.evaluate_with_stat_df(&stats)?
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, true]
+        );
+        assert_eq!(
+            predicate
+                .predicate
+                .evaluate_io(&df)?
+                .bool()?
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(false), Some(true), Some(false)]
+        );
+
+        Ok(())
+    }
+}
@@ -1,16 +1,30 @@
 pub use crate::cloud;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
+#[cfg(feature = "delta")]
+pub use crate::delta::*;
+#[cfg(feature = "fixed_width")]
+pub use crate::fixed_width::*;
+#[cfg(feature = "flight")]
+pub use crate::flight::*;
+#[cfg(feature = "iceberg")]
+pub use crate::iceberg::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub use crate::ipc::*;
 #[cfg(feature = "json")]
 pub use crate::json::*;
 #[cfg(feature = "json")]
 pub use crate::ndjson::core::*;
+#[cfg(feature = "orc")]
+pub use crate::orc::*;
 #[cfg(feature = "parquet")]
 pub use crate::parquet::{metadata::*, read::*, write::*};
 #[cfg(feature = "parquet")]
 pub use crate::partition::write_partitioned_dataset;
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
+#[cfg(feature = "spreadsheet")]
+pub use crate::spreadsheet::*;
+#[cfg(feature = "sqlite")]
+pub use crate::sqlite::*;
 pub use crate::utils::*;
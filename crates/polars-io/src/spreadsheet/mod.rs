@@ -0,0 +1,62 @@
+//! Reading sheets of Excel (xlsx, xlsm, xlsb, xls) and OpenDocument (ods) spreadsheets.
+//!
+//! The data types of columns are inferred from their cells. Numbers, booleans and dates are
+//! typed by the spreadsheet, and text cells are inferred with the same rules as fields of CSV
+//! files, so a text cell `"1.5"` is a float. Whole numbers are integers, and columns with
+//! conflicting types are read as strings.
+mod read;
+
+use polars_core::prelude::*;
+pub use read::{sheet_names, SpreadsheetReader};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The sheet of a workbook that is read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SheetSelector {
+    /// The position of the sheet in the workbook, starting at 0.
+    Index(usize),
+    Name(PlSmallStr),
+}
+
+impl Default for SheetSelector {
+    fn default() -> Self {
+        Self::Index(0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpreadsheetReadOptions {
+    pub sheet: SheetSelector,
+    /// The cells to read in A1 notation, e.g. `B2:D10`. The end of the range can omit the row,
+    /// as in `B2:D`, or be omitted, as in `B2`, to read until the last row or column with data.
+    /// All cells with data are read if `None`.
+    pub range: Option<PlSmallStr>,
+    /// Whether the first row of the range holds the column names. If `None`, the first row is
+    /// a header if it only contains text that is not inferred as another type.
+    pub has_header: Option<bool>,
+    /// The number of rows that are used to infer the data types. If `None`, all rows are used.
+    pub infer_schema_length: Option<usize>,
+    /// Infer dates, datetimes and times in text cells.
+    pub try_parse_dates: bool,
+    /// Skip rows without any values.
+    pub drop_empty_rows: bool,
+    /// Overwrite the inferred data types of these columns.
+    pub schema_overwrite: Option<SchemaRef>,
+}
+
+impl Default for SpreadsheetReadOptions {
+    fn default() -> Self {
+        Self {
+            sheet: SheetSelector::default(),
+            range: None,
+            has_header: None,
+            infer_schema_length: Some(100),
+            try_parse_dates: false,
+            drop_empty_rows: true,
+            schema_overwrite: None,
+        }
+    }
+}
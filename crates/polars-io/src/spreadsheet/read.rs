@@ -0,0 +1,445 @@
+use std::path::{Path, PathBuf};
+
+use calamine::{open_workbook_auto, Data, Range, Reader};
+use chrono::{NaiveTime, Timelike};
+use polars_core::frame::row::AnyValueBuffer;
+use polars_core::prelude::*;
+use polars_error::{polars_ensure, polars_err, to_compute_err, PolarsResult};
+use polars_time::chunkedarray::string::infer::{
+    infer_pattern_single, DatetimeInfer, TryFromWithUnit,
+};
+use polars_utils::format_pl_smallstr;
+
+use super::{SheetSelector, SpreadsheetReadOptions};
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::path_utils::resolve_homedir;
+
+/// The names of the sheets of a workbook, in workbook order.
+pub fn sheet_names(path: impl AsRef<Path>) -> PolarsResult<Vec<String>> {
+    let path = resolve_homedir(&path);
+    let workbook = open_workbook_auto(&path).map_err(
+        |e| polars_err!(ComputeError: "failed to open spreadsheet '{}': {}", path.display(), e),
+    )?;
+    Ok(workbook.sheet_names())
+}
+
+/// Reads a sheet of a spreadsheet into a [`DataFrame`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct SpreadsheetReader {
+    path: PathBuf,
+    options: SpreadsheetReadOptions,
+    columns: Option<Arc<[PlSmallStr]>>,
+    n_rows: Option<usize>,
+}
+
+/// The cells of a sheet, split into the column names and the rows with data.
+struct Sheet {
+    cells: Range<Data>,
+    names: Vec<PlSmallStr>,
+    /// The index of the first row with data.
+    offset: usize,
+}
+
+impl SpreadsheetReader {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            options: Default::default(),
+            columns: None,
+            n_rows: None,
+        }
+    }
+
+    pub fn with_options(mut self, options: SpreadsheetReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Only read these columns.
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    fn read_sheet(&self) -> PolarsResult<Sheet> {
+        let path = resolve_homedir(&self.path);
+        let mut workbook = open_workbook_auto(&path).map_err(
+            |e| polars_err!(ComputeError: "failed to open spreadsheet '{}': {}", path.display(), e),
+        )?;
+        let cells = match &self.options.sheet {
+            SheetSelector::Index(index) => workbook.worksheet_range_at(*index).ok_or_else(
+                || polars_err!(ComputeError: "spreadsheet '{}' has no sheet {}", path.display(), index),
+            )?,
+            SheetSelector::Name(name) => {
+                polars_ensure!(
+                    workbook.sheet_names().iter().any(|n| n == name),
+                    ComputeError: "spreadsheet '{}' has no sheet '{}'", path.display(), name
+                );
+                workbook.worksheet_range(name)
+            },
+        }
+        .map_err(to_compute_err)?;
+        let cells = match &self.options.range {
+            Some(range) => select_range(&cells, range)?,
+            None => cells,
+        };
+
+        let mut rows = cells.rows();
+        let first = rows.next().unwrap_or_default();
+        let has_header = self.options.has_header.unwrap_or_else(|| is_header(first));
+        let (names, offset) = if has_header {
+            (column_names(first), 1)
+        } else {
+            let names = (0..cells.width())
+                .map(|i| format_pl_smallstr!("column_{}", i + 1))
+                .collect();
+            (names, 0)
+        };
+        Ok(Sheet {
+            cells,
+            names,
+            offset,
+        })
+    }
+
+    /// The rows of the sheet that are read.
+    fn rows<'a>(&self, sheet: &'a Sheet) -> impl Iterator<Item = &'a [Data]> {
+        let drop_empty_rows = self.options.drop_empty_rows;
+        sheet
+            .cells
+            .rows()
+            .skip(sheet.offset)
+            .filter(move |row| !drop_empty_rows || row.iter().any(|c| !is_null(c)))
+    }
+
+    fn infer_schema(&self, sheet: &Sheet) -> PolarsResult<Schema> {
+        let mut possibilities = vec![PlHashSet::new(); sheet.names.len()];
+        let n_rows = self.options.infer_schema_length.unwrap_or(usize::MAX);
+        for row in self.rows(sheet).take(n_rows) {
+            for (cell, possibilities) in row.iter().zip(&mut possibilities) {
+                if let Some(dtype) = infer_cell_dtype(cell, self.options.try_parse_dates) {
+                    possibilities.insert(dtype);
+                }
+            }
+        }
+
+        let mut schema: Schema = sheet
+            .names
+            .iter()
+            .cloned()
+            .zip(possibilities.iter().map(finish_infer_field_schema))
+            .collect();
+        if let Some(overwrite) = &self.options.schema_overwrite {
+            for (name, dtype) in overwrite.iter() {
+                polars_ensure!(
+                    schema.contains(name),
+                    ColumnNotFound: "column '{}' of the schema overwrite is not in the sheet", name
+                );
+                schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+        Ok(schema)
+    }
+
+    /// The schema of the sheet.
+    pub fn schema(&self) -> PolarsResult<SchemaRef> {
+        let sheet = self.read_sheet()?;
+        Ok(Arc::new(self.infer_schema(&sheet)?))
+    }
+
+    pub fn finish(self) -> PolarsResult<DataFrame> {
+        let sheet = self.read_sheet()?;
+        let schema = self.infer_schema(&sheet)?;
+        let indices = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..schema.len()).collect(),
+        };
+
+        let read_dtypes = indices
+            .iter()
+            .map(|&i| read_dtype(schema.get_at_index(i).unwrap().1))
+            .collect::<Vec<_>>();
+        let mut buffers = read_dtypes
+            .iter()
+            .map(|dtype| AnyValueBuffer::new(dtype, 64))
+            .collect::<Vec<_>>();
+        let mut height = 0;
+        let rows = self.rows(&sheet).take(self.n_rows.unwrap_or(usize::MAX));
+        for row in rows {
+            for ((&i, dtype), buffer) in indices.iter().zip(&read_dtypes).zip(&mut buffers) {
+                let cell = &row[i];
+                let av = cell_to_any_value(cell, dtype).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "cannot read value {} of column '{}' as {}",
+                        cell, sheet.names[i], dtype
+                    )
+                })?;
+                buffer.add(av).unwrap();
+            }
+            height += 1;
+        }
+
+        let columns = indices
+            .iter()
+            .zip(buffers)
+            .map(|(&i, buffer)| {
+                let (name, dtype) = schema.get_at_index(i).unwrap();
+                let s = buffer.into_series().with_name(name.clone());
+                Ok(s.cast(dtype)?.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new_with_height(height, columns)
+    }
+}
+
+/// Select the cells of a range in A1 notation.
+fn select_range(cells: &Range<Data>, range: &str) -> PolarsResult<Range<Data>> {
+    let invalid = || polars_err!(InvalidOperation: "invalid cell range '{}'", range);
+    let (start, end) = range.split_once(':').unwrap_or((range, ""));
+    let (start_row, start_col) = parse_cell(start).ok_or_else(invalid)?;
+    let start_row = start_row.ok_or_else(invalid)?;
+    let (last_row, last_col) = cells.end().unwrap_or_default();
+    let (end_row, end_col) = match end {
+        "" => (last_row, last_col),
+        end => {
+            let (row, col) = parse_cell(end).ok_or_else(invalid)?;
+            (row.unwrap_or(last_row), col)
+        },
+    };
+    polars_ensure!(start_row <= end_row && start_col <= end_col, InvalidOperation: "invalid cell range '{}'", range);
+
+    if cells.is_empty() {
+        return Ok(Range::new((start_row, start_col), (end_row, end_col)));
+    }
+    Ok(cells.range((start_row, start_col), (end_row, end_col)))
+}
+
+/// Parse a cell reference, e.g. `B10`, into its zero-based row and column. The row is
+/// optional.
+fn parse_cell(cell: &str) -> Option<(Option<u32>, u32)> {
+    let cell = cell.trim().replace('$', "");
+    let split = cell
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(cell.len());
+    let (col, row) = cell.split_at(split);
+    if col.is_empty() || !col.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let col = col.chars().try_fold(0u32, |acc, c| {
+        acc.checked_mul(26)?
+            .checked_add(c.to_ascii_uppercase() as u32 - 'A' as u32 + 1)
+    })? - 1;
+    let row = match row {
+        "" => None,
+        row => Some(row.parse::<u32>().ok()?.checked_sub(1)?),
+    };
+    Some((row, col))
+}
+
+fn is_null(cell: &Data) -> bool {
+    matches!(cell, Data::Empty | Data::Error(_))
+}
+
+/// A row is a header if it has text, and all of its cells are empty or text that is not
+/// inferred as another type.
+fn is_header(row: &[Data]) -> bool {
+    row.iter().any(|c| matches!(c, Data::String(_)))
+        && row.iter().all(|c| match c {
+            Data::String(s) => infer_field_schema(s, true, false) == DataType::String,
+            c => is_null(c),
+        })
+}
+
+fn column_names(header: &[Data]) -> Vec<PlSmallStr> {
+    let mut counts = PlHashMap::new();
+    header
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            let name = match cell {
+                Data::String(s) if !s.trim().is_empty() => PlSmallStr::from_str(s.trim()),
+                _ => format_pl_smallstr!("column_{}", i + 1),
+            };
+            let count = counts.entry(name.clone()).or_insert(0usize);
+            *count += 1;
+            match *count {
+                1 => name,
+                n => format_pl_smallstr!("{}_duplicated_{}", name, n - 2),
+            }
+        })
+        .collect()
+}
+
+fn is_whole(v: f64) -> bool {
+    v.fract() == 0.0 && v.abs() < i64::MAX as f64
+}
+
+/// The data type of a cell, or `None` if it has no value.
+fn infer_cell_dtype(cell: &Data, try_parse_dates: bool) -> Option<DataType> {
+    let dtype = match cell {
+        Data::Empty | Data::Error(_) => return None,
+        Data::Int(_) => DataType::Int64,
+        Data::Float(v) if is_whole(*v) => DataType::Int64,
+        Data::Float(_) => DataType::Float64,
+        Data::Bool(_) => DataType::Boolean,
+        Data::String(s) => infer_field_schema(s, try_parse_dates, false),
+        Data::DateTime(dt) if dt.is_duration() => DataType::Duration(TimeUnit::Microseconds),
+        // Times are fractions of the first day.
+        Data::DateTime(dt) if dt.as_f64() < 1.0 => DataType::Time,
+        Data::DateTime(dt) if dt.as_f64().fract() == 0.0 => DataType::Date,
+        Data::DateTime(_) => DataType::Datetime(TimeUnit::Microseconds, None),
+        // OpenDocument dates and times are typed, but stored as ISO 8601 text.
+        Data::DateTimeIso(s) => match infer_field_schema(s, true, false) {
+            DataType::String if parse_time(s).is_some() => DataType::Time,
+            dtype => dtype,
+        },
+        Data::DurationIso(_) => DataType::String,
+    };
+    Some(dtype)
+}
+
+/// The data type that cells are converted to before they are cast to `dtype`.
+fn read_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Boolean
+        | DataType::String
+        | DataType::Date
+        | DataType::Time
+        | DataType::Duration(_)
+        | DataType::Datetime(_, None) => dtype.clone(),
+        DataType::Datetime(tu, Some(_)) => DataType::Datetime(*tu, None),
+        dt if dt.is_integer() => DataType::Int64,
+        dt if dt.is_float() => DataType::Float64,
+        _ => DataType::String,
+    }
+}
+
+/// Convert a cell to a value of `dtype`, or to its physical value for temporal types. Returns
+/// `None` if the cell cannot be converted.
+fn cell_to_any_value(cell: &Data, dtype: &DataType) -> Option<AnyValue<'static>> {
+    let av = match (dtype, cell) {
+        (_, Data::Empty | Data::Error(_)) => AnyValue::Null,
+        (DataType::String, cell) => AnyValue::StringOwned(cell_to_string(cell)?.into()),
+        (DataType::Boolean, Data::Bool(v)) => AnyValue::Boolean(*v),
+        (DataType::Boolean, Data::String(s)) => match s.trim().to_ascii_lowercase().as_str() {
+            "true" => AnyValue::Boolean(true),
+            "false" => AnyValue::Boolean(false),
+            _ => return None,
+        },
+        (DataType::Int64, Data::Int(v)) => AnyValue::Int64(*v),
+        (DataType::Int64, Data::Float(v)) if is_whole(*v) => AnyValue::Int64(*v as i64),
+        (DataType::Int64, Data::String(s)) => AnyValue::Int64(s.trim().parse().ok()?),
+        (DataType::Float64, Data::Int(v)) => AnyValue::Float64(*v as f64),
+        (DataType::Float64, Data::Float(v)) => AnyValue::Float64(*v),
+        (DataType::Float64, Data::String(s)) => AnyValue::Float64(s.trim().parse().ok()?),
+        (DataType::Date, Data::DateTime(dt)) if dt.is_datetime() => {
+            let date = dt.as_datetime()?.date();
+            AnyValue::Int32((date - chrono::NaiveDate::default()).num_days() as i32)
+        },
+        (DataType::Datetime(tu, _), Data::DateTime(dt)) if dt.is_datetime() => {
+            let datetime = dt.as_datetime()?.and_utc();
+            AnyValue::Int64(match tu {
+                TimeUnit::Nanoseconds => datetime.timestamp_nanos_opt()?,
+                TimeUnit::Microseconds => datetime.timestamp_micros(),
+                TimeUnit::Milliseconds => datetime.timestamp_millis(),
+            })
+        },
+        (DataType::Time, Data::DateTime(dt)) if dt.is_datetime() => {
+            let time = dt.as_datetime()?.time();
+            AnyValue::Int64(
+                time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64,
+            )
+        },
+        (DataType::Duration(tu), Data::DateTime(dt)) => {
+            let duration = dt.as_duration()?;
+            AnyValue::Int64(match tu {
+                TimeUnit::Nanoseconds => duration.num_nanoseconds()?,
+                TimeUnit::Microseconds => duration.num_microseconds()?,
+                TimeUnit::Milliseconds => duration.num_milliseconds(),
+            })
+        },
+        (DataType::Date, Data::String(s) | Data::DateTimeIso(s)) => {
+            let s = s.trim();
+            let mut infer =
+                DatetimeInfer::<Int32Type>::try_from_with_unit(infer_pattern_single(s)?, None)
+                    .ok()?;
+            AnyValue::Int32(infer.parse(s)?)
+        },
+        (DataType::Datetime(tu, _), Data::String(s) | Data::DateTimeIso(s)) => {
+            let s = s.trim();
+            let mut infer =
+                DatetimeInfer::<Int64Type>::try_from_with_unit(infer_pattern_single(s)?, Some(*tu))
+                    .ok()?;
+            AnyValue::Int64(infer.parse(s)?)
+        },
+        (DataType::Time, Data::String(s) | Data::DateTimeIso(s)) => {
+            AnyValue::Int64(parse_time(s.trim())?)
+        },
+        _ => return None,
+    };
+    Some(av)
+}
+
+fn cell_to_string(cell: &Data) -> Option<String> {
+    let s = match cell {
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.clone(),
+        Data::DateTime(dt) if dt.is_duration() => format!("{}", dt.as_duration()?),
+        Data::DateTime(dt) if dt.as_f64().fract() == 0.0 => {
+            dt.as_datetime()?.date().format("%Y-%m-%d").to_string()
+        },
+        Data::DateTime(dt) => dt.as_datetime()?.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+        cell => cell.to_string(),
+    };
+    Some(s)
+}
+
+/// Nanoseconds since midnight of an ISO 8601 time, or of an OpenDocument time such as
+/// `PT12H30M00S`.
+fn parse_time(s: &str) -> Option<i64> {
+    let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+        .or_else(|_| NaiveTime::parse_from_str(s, "PT%HH%MM%S%.fS"))
+        .ok()?;
+    Some(time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cell() {
+        assert_eq!(parse_cell("A1"), Some((Some(0), 0)));
+        assert_eq!(parse_cell("$AB$12"), Some((Some(11), 27)));
+        assert_eq!(parse_cell("c"), Some((None, 2)));
+        assert_eq!(parse_cell("A0"), None);
+        assert_eq!(parse_cell("12"), None);
+    }
+
+    #[test]
+    fn test_header() {
+        let header = [
+            Data::String("a".into()),
+            Data::Empty,
+            Data::String("a".into()),
+        ];
+        assert!(is_header(&header));
+        assert_eq!(column_names(&header), ["a", "column_2", "a_duplicated_0"]);
+        assert!(!is_header(&[Data::String("a".into()), Data::Float(1.0)]));
+        assert!(!is_header(&[
+            Data::String("a".into()),
+            Data::String("1".into())
+        ]));
+        assert!(!is_header(&[Data::Empty]));
+    }
+}
@@ -0,0 +1,53 @@
+//! Reading and writing tables of [SQLite](https://sqlite.org) databases.
+//!
+//! Columns in SQLite are dynamically typed, so the data types of the columns that are read are
+//! derived from their declared types, following the type affinity rules of SQLite. Columns
+//! without a declared type, such as expressions in queries, get the type of their values.
+//!
+//! SQLite has no storage classes for temporal values. Dates, datetimes and times are written as
+//! ISO 8601 text or as integers, see [`SqliteTemporalFormat`], and both are read.
+mod read;
+mod write;
+
+pub use read::SqliteReader;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use write::SqliteWriter;
+
+/// How temporal values are stored in SQLite.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SqliteTemporalFormat {
+    /// ISO 8601 text, e.g. `2024-01-31`, `2024-01-31 12:00:00.5` and `12:00:00`. Datetimes with
+    /// a time zone are written in UTC.
+    #[default]
+    Text,
+    /// Dates as days, datetimes as microseconds and times as nanoseconds since the Unix epoch
+    /// and midnight respectively.
+    Integer,
+}
+
+/// What to do when the table that is written to already exists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SqliteIfTableExists {
+    /// Insert the rows into the table.
+    #[default]
+    Append,
+    /// Drop the table and create it again.
+    Replace,
+    /// Raise an error.
+    Fail,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SqliteWriteOptions {
+    pub if_table_exists: SqliteIfTableExists,
+    pub temporal_format: SqliteTemporalFormat,
+}
+
+/// Quote an identifier, e.g. a table or column name.
+pub fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
@@ -0,0 +1,415 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use polars_core::frame::row::AnyValueBuffer;
+use polars_core::prelude::*;
+use polars_error::{polars_ensure, polars_err, to_compute_err, PolarsResult};
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+
+use super::quote_identifier;
+use crate::path_utils::resolve_homedir;
+
+/// Reads a table or the result of a query of a SQLite database into a [`DataFrame`].
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct SqliteReader {
+    path: PathBuf,
+    table_or_query: String,
+    schema: Option<SchemaRef>,
+    columns: Option<Arc<[PlSmallStr]>>,
+    filter: Option<String>,
+    n_rows: Option<usize>,
+    infer_schema_length: Option<usize>,
+}
+
+impl SqliteReader {
+    /// `table_or_query` is the name of a table or view of the database, or a `SELECT` query.
+    pub fn new(path: impl Into<PathBuf>, table_or_query: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            table_or_query: table_or_query.into(),
+            schema: None,
+            columns: None,
+            filter: None,
+            n_rows: None,
+            infer_schema_length: Some(100),
+        }
+    }
+
+    /// Set the schema of the table or query instead of deriving it from the database.
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Only read these columns.
+    pub fn with_columns(mut self, columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only read the rows for which this SQL condition is true.
+    pub fn with_filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Stop reading after `n_rows` rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// The number of rows that are used to derive the type of columns without a declared type.
+    /// If `None`, all rows are used.
+    pub fn infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    fn connect(&self) -> PolarsResult<Connection> {
+        let path = resolve_homedir(&self.path);
+        Connection::open_with_flags(
+            &path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(|e| {
+            polars_err!(ComputeError: "failed to open SQLite database '{}': {}", path.display(), e)
+        })
+    }
+
+    /// The source of the rows in a `FROM` clause, and whether it is a table with a `rowid`.
+    fn source_clause(&self, conn: &Connection) -> PolarsResult<(String, bool)> {
+        let source_type = conn
+            .query_row(
+                "SELECT type FROM sqlite_master WHERE type IN ('table', 'view') AND name = ?1",
+                [&self.table_or_query],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(to_compute_err)?;
+        Ok(match source_type {
+            Some(source_type) => {
+                let from = quote_identifier(&self.table_or_query);
+                // Tables created `WITHOUT ROWID` have no `rowid` column.
+                let has_rowid = source_type == "table"
+                    && conn
+                        .prepare(&format!("SELECT rowid FROM {from} LIMIT 0"))
+                        .is_ok();
+                (from, has_rowid)
+            },
+            None => (
+                format!("({})", self.table_or_query.trim().trim_end_matches(';')),
+                false,
+            ),
+        })
+    }
+
+    /// The schema of the table or query.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        if let Some(schema) = &self.schema {
+            return Ok(schema.clone());
+        }
+        let conn = self.connect()?;
+        let (from, _) = self.source_clause(&conn)?;
+        let schema = Arc::new(infer_schema(&conn, &from, self.infer_schema_length)?);
+        self.schema = Some(schema.clone());
+        Ok(schema)
+    }
+
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        let schema = self.schema()?;
+        let schema = match &self.columns {
+            Some(columns) => Arc::new(schema.try_project(columns.iter())?),
+            None => schema,
+        };
+
+        let conn = self.connect()?;
+        let mut sql = String::from("SELECT ");
+        if schema.is_empty() {
+            sql.push('1');
+        } else {
+            let columns = schema
+                .iter_names()
+                .map(|name| quote_identifier(name))
+                .collect::<Vec<_>>();
+            sql.push_str(&columns.join(", "));
+        }
+        let (from, has_rowid) = self.source_clause(&conn)?;
+        write!(sql, " FROM {from}").unwrap();
+        if let Some(filter) = &self.filter {
+            write!(sql, " WHERE {filter}").unwrap();
+        }
+        // SQLite may return the rows in the order of an index that it uses for the filter. The
+        // rows of tables are therefore ordered by `rowid`, so that their order and the rows that
+        // are kept by the limit don't depend on the filter.
+        if has_rowid {
+            sql.push_str(" ORDER BY rowid");
+        }
+        if let Some(n_rows) = self.n_rows {
+            write!(sql, " LIMIT {n_rows}").unwrap();
+        }
+
+        let mut stmt = conn.prepare(&sql).map_err(to_compute_err)?;
+        let mut rows = stmt.query([]).map_err(to_compute_err)?;
+        let mut buffers = schema
+            .iter_values()
+            .map(|dtype| AnyValueBuffer::new(dtype, 1024))
+            .collect::<Vec<_>>();
+        let mut height = 0;
+        while let Some(row) = rows.next().map_err(to_compute_err)? {
+            for (i, ((name, dtype), buffer)) in schema.iter().zip(&mut buffers).enumerate() {
+                let value = row.get_ref(i).map_err(to_compute_err)?;
+                let av = to_any_value(value, dtype).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "cannot read SQLite value {:?} of column '{}' as {}",
+                        value, name, dtype
+                    )
+                })?;
+                buffer.add(av).unwrap();
+            }
+            height += 1;
+        }
+
+        let columns = schema
+            .iter_names()
+            .zip(buffers)
+            .map(|(name, buffer)| buffer.into_series().with_name(name.clone()).into_column())
+            .collect();
+        DataFrame::new_with_height(height, columns)
+    }
+}
+
+/// The data type of a column with the given declared type, following the type affinity rules of
+/// SQLite. Returns `None` for columns whose type is derived from their values.
+fn declared_dtype(declared_type: &str) -> Option<DataType> {
+    let declared_type = declared_type.to_ascii_uppercase();
+    // Without the size of the type, e.g. in `VARCHAR(10)`.
+    let name = declared_type.split('(').next().unwrap().trim();
+    let contains = |names: &[&str]| names.iter().any(|n| declared_type.contains(n));
+    let dtype = match name {
+        "BOOL" | "BOOLEAN" => DataType::Boolean,
+        "DATE" => DataType::Date,
+        "DATETIME" | "TIMESTAMP" => DataType::Datetime(TimeUnit::Microseconds, None),
+        "TIME" => DataType::Time,
+        _ if contains(&["INT"]) => DataType::Int64,
+        _ if contains(&["CHAR", "CLOB", "TEXT"]) => DataType::String,
+        _ if contains(&["BLOB"]) => DataType::Binary,
+        _ if contains(&["REAL", "FLOA", "DOUB"]) => DataType::Float64,
+        // Columns with numeric affinity or without a declared type can hold values of any type.
+        _ => return None,
+    };
+    Some(dtype)
+}
+
+/// The storage classes of the values of a column.
+#[derive(Clone, Copy, Default)]
+struct StorageClasses {
+    integer: bool,
+    real: bool,
+    text: bool,
+    blob: bool,
+}
+
+impl StorageClasses {
+    fn update(&mut self, value: ValueRef) {
+        match value {
+            ValueRef::Null => {},
+            ValueRef::Integer(_) => self.integer = true,
+            ValueRef::Real(_) => self.real = true,
+            ValueRef::Text(_) => self.text = true,
+            ValueRef::Blob(_) => self.blob = true,
+        }
+    }
+
+    fn dtype(self) -> DataType {
+        if self.blob {
+            DataType::Binary
+        } else if self.text {
+            DataType::String
+        } else if self.real {
+            DataType::Float64
+        } else if self.integer {
+            DataType::Int64
+        } else {
+            DataType::Null
+        }
+    }
+}
+
+fn infer_schema(
+    conn: &Connection,
+    from: &str,
+    infer_schema_length: Option<usize>,
+) -> PolarsResult<Schema> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT * FROM {from}"))
+        .map_err(to_compute_err)?;
+    let (names, mut dtypes): (Vec<PlSmallStr>, Vec<Option<DataType>>) = stmt
+        .columns()
+        .iter()
+        .map(|c| (c.name().into(), c.decl_type().and_then(declared_dtype)))
+        .unzip();
+
+    if dtypes.iter().any(Option::is_none) {
+        let mut classes = vec![StorageClasses::default(); dtypes.len()];
+        let mut rows = stmt.query([]).map_err(to_compute_err)?;
+        let mut n_rows = 0;
+        while infer_schema_length.is_none_or(|len| n_rows < len) {
+            let Some(row) = rows.next().map_err(to_compute_err)? else {
+                break;
+            };
+            for (i, dtype) in dtypes.iter().enumerate() {
+                if dtype.is_none() {
+                    classes[i].update(row.get_ref(i).map_err(to_compute_err)?);
+                }
+            }
+            n_rows += 1;
+        }
+        for (dtype, classes) in dtypes.iter_mut().zip(classes) {
+            dtype.get_or_insert_with(|| classes.dtype());
+        }
+    }
+
+    let mut schema = Schema::with_capacity(names.len());
+    for (name, dtype) in names.into_iter().zip(dtypes) {
+        polars_ensure!(
+            !schema.contains(&name),
+            Duplicate: "column '{}' appears more than once in the SQLite query", name
+        );
+        schema.insert(name, dtype.unwrap());
+    }
+    Ok(schema)
+}
+
+/// Convert a SQLite value to a value of `dtype`, or to its physical value for temporal types.
+/// Returns `None` if the value cannot be converted.
+fn to_any_value(value: ValueRef, dtype: &DataType) -> Option<AnyValue<'static>> {
+    fn text(v: &[u8]) -> Option<&str> {
+        std::str::from_utf8(v).ok().map(str::trim)
+    }
+    let av = match (dtype, value) {
+        (_, ValueRef::Null) => AnyValue::Null,
+        (DataType::Boolean, ValueRef::Integer(v)) => AnyValue::Boolean(v != 0),
+        (DataType::Boolean, ValueRef::Real(v)) => AnyValue::Boolean(v != 0.0),
+        (DataType::Boolean, ValueRef::Text(v)) => match text(v)?.to_ascii_lowercase().as_str() {
+            "true" | "1" => AnyValue::Boolean(true),
+            "false" | "0" => AnyValue::Boolean(false),
+            _ => return None,
+        },
+        (DataType::Int64, ValueRef::Integer(v)) => AnyValue::Int64(v),
+        (DataType::Int64, ValueRef::Real(v)) if v.fract() == 0.0 => AnyValue::Int64(v as i64),
+        (DataType::Int64, ValueRef::Text(v)) => AnyValue::Int64(text(v)?.parse().ok()?),
+        (DataType::Float64, ValueRef::Integer(v)) => AnyValue::Float64(v as f64),
+        (DataType::Float64, ValueRef::Real(v)) => AnyValue::Float64(v),
+        (DataType::Float64, ValueRef::Text(v)) => AnyValue::Float64(text(v)?.parse().ok()?),
+        (DataType::String, ValueRef::Text(v)) => {
+            AnyValue::StringOwned(std::str::from_utf8(v).ok()?.into())
+        },
+        (DataType::String, ValueRef::Integer(v)) => AnyValue::StringOwned(v.to_string().into()),
+        (DataType::String, ValueRef::Real(v)) => AnyValue::StringOwned(v.to_string().into()),
+        (DataType::Binary, ValueRef::Blob(v) | ValueRef::Text(v)) => {
+            AnyValue::BinaryOwned(v.to_vec())
+        },
+        (DataType::Date, ValueRef::Integer(v)) => AnyValue::Int32(v.try_into().ok()?),
+        (DataType::Date, ValueRef::Text(v)) => AnyValue::Int32(parse_date(text(v)?)?),
+        (DataType::Datetime(_, _), ValueRef::Integer(v)) => AnyValue::Int64(v),
+        (DataType::Datetime(_, _), ValueRef::Text(v)) => AnyValue::Int64(parse_datetime(text(v)?)?),
+        (DataType::Time, ValueRef::Integer(v)) => AnyValue::Int64(v),
+        (DataType::Time, ValueRef::Text(v)) => AnyValue::Int64(parse_time(text(v)?)?),
+        _ => return None,
+    };
+    Some(av)
+}
+
+/// Days since the Unix epoch of an ISO 8601 date, or of the date of a datetime.
+fn parse_date(s: &str) -> Option<i32> {
+    let date = NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()?;
+    (date - NaiveDate::default()).num_days().try_into().ok()
+}
+
+/// Microseconds since the Unix epoch of an ISO 8601 datetime. Datetimes with an offset are
+/// converted to UTC.
+fn parse_datetime(s: &str) -> Option<i64> {
+    for format in [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+            return Some(datetime.and_utc().timestamp_micros());
+        }
+    }
+    for format in ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%dT%H:%M:%S%.f%#z"] {
+        if let Ok(datetime) = DateTime::parse_from_str(s, format) {
+            return Some(datetime.timestamp_micros());
+        }
+    }
+    let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp_micros())
+}
+
+/// Nanoseconds since midnight of an ISO 8601 time.
+fn parse_time(s: &str) -> Option<i64> {
+    let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+        .ok()?;
+    Some(time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_dtype() {
+        assert_eq!(declared_dtype("VARCHAR(10)"), Some(DataType::String));
+        assert_eq!(declared_dtype("unsigned big int"), Some(DataType::Int64));
+        assert_eq!(declared_dtype("DOUBLE PRECISION"), Some(DataType::Float64));
+        assert_eq!(
+            declared_dtype("timestamp"),
+            Some(DataType::Datetime(TimeUnit::Microseconds, None))
+        );
+        assert_eq!(declared_dtype("DECIMAL(10,5)"), None);
+        assert_eq!(declared_dtype(""), None);
+    }
+
+    #[test]
+    fn test_filter_keeps_rowid_order() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("rowid_order.sqlite");
+        let conn = Connection::open(&path).map_err(to_compute_err)?;
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER PRIMARY KEY, v INTEGER);
+            CREATE INDEX t_v ON t (v);
+            INSERT INTO t VALUES (1, 30), (2, 10), (3, 20), (4, 40);",
+        )
+        .map_err(to_compute_err)?;
+
+        // The index on `v` covers the query, but the rows are returned in the order of the table.
+        let read = |n_rows| {
+            SqliteReader::new(&path, "t")
+                .with_filter(Some("v > 15".into()))
+                .with_n_rows(n_rows)
+                .finish()
+        };
+        let ids = |df: DataFrame| df.column("id").unwrap().i64().unwrap().to_vec();
+        assert_eq!(ids(read(None)?), [Some(1), Some(3), Some(4)]);
+        assert_eq!(ids(read(Some(2))?), [Some(1), Some(3)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_temporal() {
+        assert_eq!(parse_date("1970-01-02"), Some(1));
+        assert_eq!(parse_date("1970-01-02 12:00:00"), Some(1));
+        assert_eq!(parse_datetime("1970-01-01 00:00:01.5"), Some(1_500_000));
+        assert_eq!(parse_datetime("1970-01-01T01:00:00+01:00"), Some(0));
+        assert_eq!(parse_datetime("1970-01-02"), Some(86_400_000_000));
+        assert_eq!(parse_time("00:01:00.000000001"), Some(60_000_000_001));
+        assert_eq!(parse_time("13:30"), Some(48_600_000_000_000));
+    }
+}
@@ -0,0 +1,276 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta};
+use polars_core::prelude::*;
+use polars_error::{polars_bail, polars_ensure, polars_err, to_compute_err, PolarsResult};
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection};
+
+use super::{quote_identifier, SqliteIfTableExists, SqliteTemporalFormat, SqliteWriteOptions};
+use crate::path_utils::resolve_homedir;
+
+/// Writes a [`DataFrame`] to a table of a SQLite database. The database and the table are
+/// created if they do not exist, and all rows are inserted in a single transaction.
+#[must_use]
+pub struct SqliteWriter {
+    path: PathBuf,
+    table: String,
+    options: SqliteWriteOptions,
+}
+
+impl SqliteWriter {
+    pub fn new(path: impl Into<PathBuf>, table: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            table: table.into(),
+            options: Default::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: SqliteWriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn finish(self, df: &mut DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            df.width() > 0,
+            InvalidOperation: "cannot write a DataFrame without columns to SQLite"
+        );
+        let encoders = df
+            .get_columns()
+            .iter()
+            .map(|c| ColumnEncoder::new(c.as_materialized_series(), self.options.temporal_format))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let path = resolve_homedir(&self.path);
+        let mut conn = Connection::open(&path).map_err(|e| {
+            polars_err!(ComputeError: "failed to open SQLite database '{}': {}", path.display(), e)
+        })?;
+        let tx = conn.transaction().map_err(to_compute_err)?;
+        let table = quote_identifier(&self.table);
+
+        let exists = tx
+            .query_row(
+                "SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                [&self.table],
+                |row| row.get::<_, i64>(0),
+            )
+            .map_err(to_compute_err)?
+            > 0;
+        let create = match (exists, self.options.if_table_exists) {
+            (false, _) => true,
+            (true, SqliteIfTableExists::Append) => false,
+            (true, SqliteIfTableExists::Replace) => {
+                tx.execute(&format!("DROP TABLE {table}"), [])
+                    .map_err(to_compute_err)?;
+                true
+            },
+            (true, SqliteIfTableExists::Fail) => polars_bail!(
+                InvalidOperation: "table '{}' already exists in SQLite database '{}'",
+                self.table, path.display()
+            ),
+        };
+
+        let names = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| quote_identifier(name))
+            .collect::<Vec<_>>();
+        if create {
+            let columns = names
+                .iter()
+                .zip(&encoders)
+                .map(|(name, encoder)| format!("{name} {}", encoder.declared_type))
+                .collect::<Vec<_>>();
+            tx.execute(
+                &format!("CREATE TABLE {table} ({})", columns.join(", ")),
+                [],
+            )
+            .map_err(to_compute_err)?;
+        }
+
+        let placeholders = (1..=names.len())
+            .map(|i| format!("?{i}"))
+            .collect::<Vec<_>>();
+        let insert = format!(
+            "INSERT INTO {table} ({}) VALUES ({})",
+            names.join(", "),
+            placeholders.join(", ")
+        );
+        {
+            let mut stmt = tx.prepare(&insert).map_err(to_compute_err)?;
+            let mut row = Vec::with_capacity(encoders.len());
+            for offset in (0..df.height()).step_by(BATCH_SIZE) {
+                let mut columns = encoders
+                    .iter()
+                    .map(|encoder| Ok(encoder.encode(offset, BATCH_SIZE)?.into_iter()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                for _ in offset..(offset + BATCH_SIZE).min(df.height()) {
+                    row.clear();
+                    row.extend(columns.iter_mut().map(|values| values.next().unwrap()));
+                    stmt.execute(params_from_iter(&row))
+                        .map_err(to_compute_err)?;
+                }
+            }
+        }
+        tx.commit().map_err(to_compute_err)
+    }
+}
+
+/// The number of rows that are converted to SQLite values at a time.
+const BATCH_SIZE: usize = 1 << 14;
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Plain,
+    Date,
+    Datetime,
+    Time,
+}
+
+/// Converts the values of a column to SQLite values.
+struct ColumnEncoder {
+    /// The physical representation of the column, with datetimes in microseconds.
+    physical: Series,
+    declared_type: &'static str,
+    kind: Kind,
+    temporal_format: SqliteTemporalFormat,
+}
+
+impl ColumnEncoder {
+    fn new(s: &Series, temporal_format: SqliteTemporalFormat) -> PolarsResult<Self> {
+        use DataType::*;
+        let (declared_type, kind, s) = match s.dtype() {
+            Boolean => ("BOOLEAN", Kind::Plain, s.clone()),
+            dt if dt.is_integer() => ("INTEGER", Kind::Plain, s.clone()),
+            dt if dt.is_float() => ("REAL", Kind::Plain, s.clone()),
+            String => ("TEXT", Kind::Plain, s.clone()),
+            #[cfg(feature = "dtype-categorical")]
+            Categorical(_, _) | Enum(_, _) => ("TEXT", Kind::Plain, s.cast(&String)?),
+            Binary => ("BLOB", Kind::Plain, s.clone()),
+            Date => ("DATE", Kind::Date, s.clone()),
+            Datetime(_, tz) => (
+                "DATETIME",
+                Kind::Datetime,
+                s.cast(&Datetime(TimeUnit::Microseconds, tz.clone()))?,
+            ),
+            Time => ("TIME", Kind::Time, s.clone()),
+            #[cfg(feature = "dtype-duration")]
+            Duration(_) => ("INTEGER", Kind::Plain, s.clone()),
+            Null => ("", Kind::Plain, s.clone()),
+            dt => polars_bail!(
+                InvalidOperation: "cannot write column '{}' of type {} to SQLite", s.name(), dt
+            ),
+        };
+        Ok(Self {
+            physical: s.to_physical_repr().rechunk(),
+            declared_type,
+            kind,
+            temporal_format,
+        })
+    }
+
+    /// Convert `len` values of the column from `offset` on.
+    fn encode(&self, offset: usize, len: usize) -> PolarsResult<Vec<Value>> {
+        let s = self.physical.slice(offset as i64, len);
+        let name = s.name();
+        let out_of_range = |v: &dyn std::fmt::Display, target: &str| {
+            polars_err!(
+                ComputeError: "value {} of column '{}' is out of the range of a SQLite {}",
+                v, name, target
+            )
+        };
+
+        let values = match (self.kind, self.temporal_format) {
+            (Kind::Plain, _) | (_, SqliteTemporalFormat::Integer) => match s.dtype() {
+                DataType::Null => vec![Value::Null; s.len()],
+                DataType::Boolean => s
+                    .bool()?
+                    .iter()
+                    .map(|v| v.map_or(Value::Null, |v| Value::Integer(v as i64)))
+                    .collect(),
+                DataType::UInt64 => s
+                    .u64()?
+                    .iter()
+                    .map(|v| match v {
+                        None => Ok(Value::Null),
+                        Some(v) => i64::try_from(v)
+                            .map(Value::Integer)
+                            .map_err(|_| out_of_range(&v, "integer")),
+                    })
+                    .collect::<PolarsResult<_>>()?,
+                DataType::Float32 | DataType::Float64 => s
+                    .cast(&DataType::Float64)?
+                    .f64()?
+                    .iter()
+                    .map(|v| v.map_or(Value::Null, Value::Real))
+                    .collect(),
+                DataType::String => s
+                    .str()?
+                    .iter()
+                    .map(|v| v.map_or(Value::Null, |v| Value::Text(v.to_string())))
+                    .collect(),
+                DataType::Binary => s
+                    .binary()?
+                    .iter()
+                    .map(|v| v.map_or(Value::Null, |v| Value::Blob(v.to_vec())))
+                    .collect(),
+                _ => s
+                    .cast(&DataType::Int64)?
+                    .i64()?
+                    .iter()
+                    .map(|v| v.map_or(Value::Null, Value::Integer))
+                    .collect(),
+            },
+            (Kind::Date, SqliteTemporalFormat::Text) => s
+                .i32()?
+                .iter()
+                .map(|v| {
+                    let Some(days) = v else {
+                        return Ok(Value::Null);
+                    };
+                    let date = TimeDelta::try_days(days as i64)
+                        .and_then(|days| NaiveDate::default().checked_add_signed(days))
+                        .ok_or_else(|| out_of_range(&days, "date"))?;
+                    Ok(Value::Text(date.format("%Y-%m-%d").to_string()))
+                })
+                .collect::<PolarsResult<_>>()?,
+            (Kind::Datetime, SqliteTemporalFormat::Text) => s
+                .i64()?
+                .iter()
+                .map(|v| {
+                    let Some(micros) = v else {
+                        return Ok(Value::Null);
+                    };
+                    let datetime = DateTime::from_timestamp_micros(micros)
+                        .ok_or_else(|| out_of_range(&micros, "datetime"))?
+                        .naive_utc();
+                    Ok(Value::Text(
+                        datetime.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
+                    ))
+                })
+                .collect::<PolarsResult<_>>()?,
+            (Kind::Time, SqliteTemporalFormat::Text) => s
+                .i64()?
+                .iter()
+                .map(|v| {
+                    let Some(nanos) = v else {
+                        return Ok(Value::Null);
+                    };
+                    let time = u32::try_from(nanos / 1_000_000_000)
+                        .ok()
+                        .and_then(|secs| {
+                            NaiveTime::from_num_seconds_from_midnight_opt(
+                                secs,
+                                (nanos % 1_000_000_000) as u32,
+                            )
+                        })
+                        .ok_or_else(|| out_of_range(&nanos, "time"))?;
+                    Ok(Value::Text(time.format("%H:%M:%S%.f").to_string()))
+                })
+                .collect::<PolarsResult<_>>()?,
+        };
+        Ok(values)
+    }
+}
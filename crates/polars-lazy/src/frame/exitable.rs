@@ -1,4 +1,3 @@
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver};
 use std::sync::Mutex;
 
@@ -22,18 +21,38 @@ impl LazyFrame {
             token,
         })
     }
+
+    /// Execute the query on a new thread and return a handle to fetch the result.
+    ///
+    /// The query stops once `token` is cancelled or its timeout has passed, see
+    /// [`LazyFrame::collect_with_token`]. Dropping the handle cancels `token`.
+    pub fn collect_async(self, token: CancellationToken) -> PolarsResult<InProcessQuery> {
+        let (tx, rx) = channel();
+        let query_token = token.clone();
+        std::thread::Builder::new()
+            .name("polars-query".into())
+            .spawn(move || {
+                // The receiver is gone if the handle was dropped.
+                let _ = tx.send(self.collect_with_token(query_token));
+            })?;
+
+        Ok(InProcessQuery {
+            rx: Arc::new(Mutex::new(rx)),
+            token,
+        })
+    }
 }
 
 #[derive(Clone)]
 pub struct InProcessQuery {
     rx: Arc<Mutex<Receiver<PolarsResult<DataFrame>>>>,
-    token: Arc<AtomicBool>,
+    token: CancellationToken,
 }
 
 impl InProcessQuery {
     /// Cancel the query at earliest convenience.
     pub fn cancel(&self) {
-        self.token.store(true, Ordering::Relaxed)
+        self.token.cancel()
     }
 
     /// Fetch the result.
@@ -54,6 +73,6 @@ impl InProcessQuery {
 
 impl Drop for InProcessQuery {
     fn drop(&mut self) {
-        self.token.store(true, Ordering::Relaxed);
+        self.token.cancel();
     }
 }
@@ -6,6 +6,8 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "persistent_cache")]
+mod persistent_cache;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
@@ -19,19 +21,35 @@ use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "catalog")]
+pub use catalog::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "fixed_width")]
+pub use fixed_width::*;
+#[cfg(feature = "flight")]
+pub use flight::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
+use polars_core::error::signals::{catch_cancellation, with_cancellation_token};
 use polars_core::prelude::*;
+use polars_core::POOL;
 use polars_expr::{create_physical_expr, ExpressionConversionState};
 use polars_io::RowIndex;
 use polars_mem_engine::{create_physical_plan, Executor};
@@ -41,6 +59,10 @@ use polars_ops::prelude::ClosedInterval;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "spreadsheet")]
+pub use spreadsheet::*;
+#[cfg(feature = "sqlite")]
+pub use sqlite::*;
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
@@ -154,6 +176,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle cost-based reordering of inner join chains.
+    pub fn with_join_reorder(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::JOIN_REORDER, toggle);
+        self
+    }
+
+    /// Toggle removal of sorts and distincts whose input is already sorted or unique.
+    pub fn with_sort_distinct_elim(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::SORT_DISTINCT_ELIM, toggle);
+        self
+    }
+
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
@@ -728,6 +762,49 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> PolarsResult<DataFrame> {
+        self.collect_with_state(ExecutionState::new())
+    }
+
+    /// Execute all the lazy operations and collect them into a [`DataFrame`], stopping with an
+    /// error once `token` is cancelled or its timeout has passed.
+    ///
+    /// Cancellation is cooperative: the token is checked between the operations of the query, at
+    /// chunk boundaries of joins, group-bys and readers, and whenever a task of the streaming
+    /// engine is polled.
+    ///
+    /// When called from a thread of the Polars thread pool, the token is only checked when the
+    /// query starts: it can't be installed on the threads of the pool, as they run work of other
+    /// queries while they wait. A query that is cancelled while it runs then completes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<DataFrame> {
+    ///     let token = CancellationToken::with_timeout(Duration::from_secs(10));
+    ///     df.lazy()
+    ///       .group_by([col("foo")])
+    ///       .agg([col("bar").sum()])
+    ///       .collect_with_token(token)
+    /// }
+    /// ```
+    pub fn collect_with_token(self, token: CancellationToken) -> PolarsResult<DataFrame> {
+        token.check()?;
+        let mut state = ExecutionState::new();
+        state.set_cancel_token(token.clone());
+        // Threads of the pool run work of other queries while they wait, so we can't install the
+        // token on them. The executors still check the token of the state when they start.
+        let thread_token = POOL.current_thread_index().is_none().then_some(token);
+        catch_cancellation(|| {
+            with_cancellation_token(thread_token, || self.collect_with_state(state))
+        })
+    }
+
+    fn collect_with_state(self, mut state: ExecutionState) -> PolarsResult<DataFrame> {
         #[cfg(feature = "new_streaming")]
         {
             let mut slf = self;
@@ -741,11 +818,13 @@ impl LazyFrame {
                 &mut alp_plan.lp_arena,
                 &mut alp_plan.expr_arena,
             )?;
-            let mut state = ExecutionState::new();
             physical_plan.execute(&mut state)
         }
         #[cfg(not(feature = "new_streaming"))]
-        self._collect_post_opt(|_, _, _| Ok(()))
+        {
+            let (_, mut physical_plan, _) = self.prepare_collect(false)?;
+            physical_plan.execute(&mut state)
+        }
     }
 
     /// Profile a LazyFrame.
@@ -763,6 +842,32 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Run the query on the streaming engine and return the result together with the physical
+    /// plan annotated with the metrics of every node: rows, morsels and bytes received and sent,
+    /// wall and CPU time, and peak buffered memory.
+    ///
+    /// The metrics are returned both as text and as a DataFrame with a row per node. The units of
+    /// the timings in the DataFrame are microseconds.
+    #[cfg(feature = "new_streaming")]
+    pub fn explain_analyze(self) -> PolarsResult<(DataFrame, QueryMetrics)> {
+        let mut lf = self;
+        lf.opt_state |= OptFlags::NEW_STREAMING;
+        lf.opt_state &= !OptFlags::STREAMING;
+        let mut alp_plan = lf.to_alp_optimized()?;
+        let stream_lp_top = alp_plan.lp_arena.add(IR::Sink {
+            input: alp_plan.lp_top,
+            payload: SinkType::Memory,
+        });
+
+        let _hold = StringCacheHolder::hold();
+        let (out, metrics) = polars_stream::run_query_with_metrics(
+            stream_lp_top,
+            &mut alp_plan.lp_arena,
+            &mut alp_plan.expr_arena,
+        )?;
+        Ok((out.unwrap(), metrics))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -843,6 +948,137 @@ impl LazyFrame {
         )
     }
 
+    /// Write the query result to a Delta Lake table, creating the table if it does not exist.
+    ///
+    /// The result is written as parquet files, which are added to the table by an atomic
+    /// commit to its `_delta_log`. Concurrent writers that conflict with this write make the
+    /// commit fail; blind appends are retried on top of the new version of the table.
+    #[cfg(feature = "delta")]
+    pub fn sink_delta(
+        mut self,
+        path: impl AsRef<Path>,
+        options: DeltaWriteOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    ) -> PolarsResult<()> {
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload: SinkType::Delta {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                options,
+                cloud_options,
+            },
+        };
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        let _ = physical_plan.execute(&mut state)?;
+        Ok(())
+    }
+
+    /// Execute the query and write the result as an Arrow IPC stream to `writer`, e.g.
+    /// stdout, a pipe or a socket.
+    ///
+    /// The query runs in the streaming engine if it can, which writes and flushes every
+    /// batch as soon as it is processed, so a query on [`scan_ipc_stream`] forwards the
+    /// batches of its input as they arrive. Otherwise the result is collected first. Batches
+    /// are only written in the order of the result if the options maintain the order; the new
+    /// streaming engine always writes them in order.
+    ///
+    /// [`scan_ipc_stream`]: LazyFrame::scan_ipc_stream
+    #[cfg(feature = "ipc_streaming")]
+    pub fn sink_ipc_stream<W: std::io::Write + Send + 'static>(
+        mut self,
+        writer: W,
+        options: polars_io::ipc::IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        let payload = SinkType::IpcStream {
+            writer: DynWriter::new(writer),
+            options,
+        };
+        #[cfg(feature = "new_streaming")]
+        if let Some(result) = self.try_new_streaming_if_requested(payload.clone()) {
+            return result.map(|_| ());
+        }
+
+        self.logical_plan = DslPlan::Sink {
+            input: Arc::new(self.logical_plan),
+            payload,
+        };
+        self.opt_state |= OptFlags::STREAMING;
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        let _ = physical_plan.execute(&mut state)?;
+        Ok(())
+    }
+
+    /// Serve the query result to Arrow Flight clients on `addr`.
+    ///
+    /// The query is executed for every `DoGet` call, whatever its ticket, and its batches are
+    /// sent as they are produced, as with [`LazyFrame::sink_ipc_stream`]. `GetSchema` calls
+    /// are answered without executing the query. The service stops accepting connections when
+    /// the returned server is dropped.
+    #[cfg(feature = "flight")]
+    pub fn serve_flight(
+        self,
+        addr: impl std::net::ToSocketAddrs,
+    ) -> PolarsResult<polars_io::flight::FlightServer> {
+        struct Handler(LazyFrame);
+
+        impl polars_io::flight::FlightHandler for Handler {
+            fn schema(&self, _ticket: &[u8]) -> PolarsResult<SchemaRef> {
+                self.0.clone().collect_schema()
+            }
+
+            fn write(
+                &self,
+                _ticket: &[u8],
+                writer: Box<dyn std::io::Write + Send>,
+            ) -> PolarsResult<()> {
+                let options = polars_io::ipc::IpcWriterOptions {
+                    maintain_order: true,
+                    ..Default::default()
+                };
+                self.0.clone().sink_ipc_stream(writer, options)
+            }
+        }
+
+        polars_io::flight::FlightServer::bind(addr, Arc::new(Handler(self)))
+    }
+
+    /// Write the query result to an ORC file.
+    ///
+    /// The result is collected and written with one stripe per `stripe_size` rows of the
+    /// options.
+    #[cfg(feature = "orc")]
+    pub fn sink_orc(
+        self,
+        path: impl AsRef<std::path::Path>,
+        options: polars_io::orc::OrcWriterOptions,
+    ) -> PolarsResult<()> {
+        use polars_io::SerWriter;
+
+        let mut df = self.collect()?;
+        let file = polars_utils::open_file_write(path.as_ref())?;
+        polars_io::orc::OrcWriter::new(file)
+            .with_options(options)
+            .finish(&mut df)
+    }
+
+    /// Write the query result to a table of a SQLite database, creating the database and the
+    /// table if they do not exist.
+    ///
+    /// The result is collected and inserted in a single transaction, so the table is left
+    /// untouched if the query or the write fails.
+    #[cfg(feature = "sqlite")]
+    pub fn sink_sqlite(
+        self,
+        path: impl Into<std::path::PathBuf>,
+        table: impl Into<String>,
+        options: polars_io::sqlite::SqliteWriteOptions,
+    ) -> PolarsResult<()> {
+        let mut df = self.collect()?;
+        polars_io::sqlite::SqliteWriter::new(path, table)
+            .with_options(options)
+            .finish(&mut df)
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -0,0 +1,217 @@
+//! A result cache for subplans that persists across processes.
+//!
+//! The key of a cached result is a hash of the serialized plan, the Polars version, and a
+//! fingerprint (size and modification time or ETag) of every file the plan scans. Results are
+//! stored as IPC files named after their key.
+use std::any::Any;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_io::ipc::{IpcReader, IpcWriter};
+use polars_io::path_utils::is_cloud_url;
+use polars_io::{SerReader, SerWriter};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::prelude::*;
+
+struct PersistentCache {
+    input: LazyFrame,
+    dir: PathBuf,
+    schema: SchemaRef,
+}
+
+impl PersistentCache {
+    fn read(path: &Path, columns: Option<Vec<String>>) -> PolarsResult<DataFrame> {
+        IpcReader::new(File::open(path)?)
+            .with_columns(columns)
+            .finish()
+    }
+
+    /// Write `df` to `path` through a temporary file, so that concurrent readers never see a
+    /// partially written result.
+    fn write(&self, path: &Path, df: &mut DataFrame) -> PolarsResult<()> {
+        static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        std::fs::create_dir_all(&self.dir)?;
+        // Unique per write, as threads of this process may write the same key concurrently.
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(format!(
+            ".{}-{}.tmp",
+            std::process::id(),
+            TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = File::create(&tmp)
+            .map_err(PolarsError::from)
+            .and_then(|file| IpcWriter::new(file).finish(df))
+            .and_then(|_| Ok(std::fs::rename(&tmp, path)?));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp);
+        }
+        result
+    }
+}
+
+impl AnonymousScan for PersistentCache {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let key = cache_key(&self.input)?;
+        let path = self.dir.join(format!("{key}.ipc"));
+        let columns = scan_opts
+            .with_columns
+            .map(|cols| cols.iter().map(|c| c.to_string()).collect::<Vec<_>>());
+
+        if path.exists() {
+            match Self::read(&path, columns.clone()) {
+                Ok(df) => {
+                    if verbose() {
+                        eprintln!("persistent cache hit: {}", path.display());
+                    }
+                    return Ok(df);
+                },
+                Err(err) => {
+                    if verbose() {
+                        eprintln!(
+                            "persistent cache entry {} is unreadable: {err}",
+                            path.display()
+                        );
+                    }
+                },
+            }
+        } else if verbose() {
+            eprintln!("persistent cache miss: {}", path.display());
+        }
+
+        let mut df = self.input.clone().collect()?;
+        self.write(&path, &mut df)?;
+        match columns {
+            Some(columns) => df.select(columns),
+            None => Ok(df),
+        }
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+}
+
+/// Hash the plan of `lf` together with the files it scans.
+fn cache_key(lf: &LazyFrame) -> PolarsResult<String> {
+    let mut bytes = vec![];
+    lf.logical_plan
+        .serialize_versioned(&mut bytes)
+        .map_err(|err| {
+            polars_err!(
+                ComputeError: "cannot persist the result of a plan that can't be serialized: {err}"
+            )
+        })?;
+
+    let mut hasher = Xxh3::new();
+    hasher.update(&bytes);
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+
+    let plan = lf.clone().to_alp()?;
+    for (_, ir) in (&plan.lp_arena).iter(plan.lp_top) {
+        if let IR::Scan {
+            sources, scan_type, ..
+        } = ir
+        {
+            let ScanSources::Paths(paths) = sources else {
+                polars_bail!(ComputeError: "cannot persist the result of a scan of open files or buffers")
+            };
+            for path in paths.iter() {
+                hasher.update(path.to_string_lossy().as_bytes());
+                hasher.update(file_fingerprint(path, scan_type)?.as_bytes());
+            }
+        }
+    }
+
+    Ok(format!("{:032x}", hasher.digest128()))
+}
+
+/// A string that changes whenever the file at `path` is modified.
+#[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+fn file_fingerprint(path: &Path, scan_type: &FileScan) -> PolarsResult<String> {
+    if is_cloud_url(path) {
+        #[cfg(feature = "cloud")]
+        {
+            return cloud_fingerprint(path.to_str().unwrap(), cloud_options(scan_type));
+        }
+        #[cfg(not(feature = "cloud"))]
+        polars_bail!(ComputeError: "the 'cloud' feature is required to persist the results of cloud scans")
+    }
+
+    let metadata = std::fs::metadata(path)?;
+    let modified = metadata
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    Ok(format!("{}:{modified}", metadata.len()))
+}
+
+#[cfg(feature = "cloud")]
+fn cloud_fingerprint(
+    url: &str,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<String> {
+    use polars_io::cloud::{build_object_store, object_path_from_str};
+    use polars_io::pl_async;
+
+    pl_async::get_runtime().block_on_potential_spawn(async {
+        let (location, store) = build_object_store(url, cloud_options, false).await?;
+        let meta = store.head(&object_path_from_str(&location.prefix)?).await?;
+        Ok(match meta.e_tag {
+            Some(e_tag) => format!("{}:{e_tag}", meta.size),
+            None => format!("{}:{}", meta.size, meta.last_modified.timestamp_millis()),
+        })
+    })
+}
+
+#[cfg(feature = "cloud")]
+fn cloud_options(scan_type: &FileScan) -> Option<&polars_io::cloud::CloudOptions> {
+    match scan_type {
+        #[cfg(feature = "csv")]
+        FileScan::Csv { cloud_options, .. } => cloud_options.as_ref(),
+        #[cfg(feature = "json")]
+        FileScan::NDJson { cloud_options, .. } => cloud_options.as_ref(),
+        #[cfg(feature = "parquet")]
+        FileScan::Parquet { cloud_options, .. } => cloud_options.as_ref(),
+        #[cfg(feature = "ipc")]
+        FileScan::Ipc { cloud_options, .. } => cloud_options.as_ref(),
+        FileScan::Anonymous { .. } => None,
+    }
+}
+
+impl LazyFrame {
+    /// Cache the result of this query in `dir`, so that it is reused by later queries, also in
+    /// other processes.
+    ///
+    /// A cached result is reused as long as the query and the files it scans are unchanged, and is
+    /// recomputed otherwise. The query must be serializable, so it can't contain user-defined
+    /// functions or scans of open files and buffers. Queries that use [`LazyFrame::cache`] are assigned
+    /// a new key in every process. Stale entries are never removed from `dir`.
+    pub fn cache_persistent(self, dir: impl Into<PathBuf>) -> PolarsResult<LazyFrame> {
+        let schema = self.clone().collect_schema()?;
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            name: "PERSISTENT CACHE",
+            ..Default::default()
+        };
+        let function = PersistentCache {
+            input: self,
+            dir: dir.into(),
+            schema,
+        };
+        LazyFrame::anonymous_scan(Arc::new(function), scan_args)
+    }
+}
@@ -16,6 +16,8 @@ pub use polars_plan::dsl::AnonymousScanOptions;
 pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, Null, NULL};
 pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(feature = "new_streaming")]
+pub use polars_stream::QueryMetrics;
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -1,10 +1,89 @@
 use polars_core::error::{feature_gated, polars_bail, PolarsResult};
+use polars_io::catalog::unity::client::CatalogClient;
 use polars_io::catalog::unity::models::{DataSourceFormat, TableInfo};
 use polars_io::catalog::unity::schema::table_info_to_schemas;
 use polars_io::cloud::CloudOptions;
+use polars_io::pl_async;
+#[cfg(feature = "delta")]
+use polars_plan::dsl::DeltaWriteOptions;
 
 use crate::frame::LazyFrame;
 
+/// Reading and writing the tables of a Unity catalog, addressed by their catalog, namespace and
+/// table name.
+pub trait CatalogClientExtension {
+    /// Create a LazyFrame that scans a table, using temporary credentials of the catalog to
+    /// access its storage location.
+    fn scan_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PolarsResult<LazyFrame>;
+
+    /// Write the result of a query to a Delta table, using temporary credentials of the catalog
+    /// to access its storage location.
+    #[cfg(feature = "delta")]
+    fn write_table(
+        &self,
+        lf: LazyFrame,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        options: DeltaWriteOptions,
+    ) -> PolarsResult<()>;
+}
+
+impl CatalogClientExtension for CatalogClient {
+    fn scan_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PolarsResult<LazyFrame> {
+        let (table_info, cloud_options) =
+            pl_async::get_runtime().block_on_potential_spawn(async {
+                let table_info = self
+                    .get_table_info(catalog_name, namespace, table_name)
+                    .await?;
+                let cloud_options = self.get_table_cloud_options(&table_info, false).await?;
+                PolarsResult::Ok((table_info, cloud_options))
+            })?;
+
+        LazyFrame::scan_catalog_table(&table_info, Some(cloud_options))
+    }
+
+    #[cfg(feature = "delta")]
+    fn write_table(
+        &self,
+        lf: LazyFrame,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        options: DeltaWriteOptions,
+    ) -> PolarsResult<()> {
+        let (table_info, cloud_options) =
+            pl_async::get_runtime().block_on_potential_spawn(async {
+                let table_info = self
+                    .get_table_info(catalog_name, namespace, table_name)
+                    .await?;
+                if !matches!(table_info.data_source_format, Some(DataSourceFormat::Delta)) {
+                    polars_bail!(
+                        ComputeError:
+                        "write_table requires a table with data_source_format DELTA, got: {:?}",
+                        table_info.data_source_format
+                    )
+                }
+                let cloud_options = self.get_table_cloud_options(&table_info, true).await?;
+                PolarsResult::Ok((table_info, cloud_options))
+            })?;
+
+        // `get_table_cloud_options` checked that the table has a storage location.
+        let storage_location = table_info.storage_location.as_deref().unwrap();
+        lf.sink_delta(storage_location, options, Some(cloud_options))
+    }
+}
+
 impl LazyFrame {
     pub fn scan_catalog_table(
         table_info: &TableInfo,
@@ -45,6 +124,17 @@ impl LazyFrame {
                     .with_schema(schema)
                     .finish()
             }),
+            DataSourceFormat::Delta => feature_gated!("delta", {
+                use crate::frame::ScanArgsDelta;
+
+                Self::scan_delta(
+                    storage_location,
+                    ScanArgsDelta {
+                        cloud_options,
+                        ..Default::default()
+                    },
+                )
+            }),
             v => polars_bail!(
                 ComputeError:
                 "not yet supported data_source_format: {:?}",
@@ -143,6 +143,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_separator(separator))
     }
 
+    /// Set the CSV file's column separator to a string that may consist of multiple characters.
+    #[must_use]
+    pub fn with_multi_char_separator(self, separator: &str) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_char_separator(separator))
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     #[must_use]
     pub fn with_comment_prefix(self, comment_prefix: Option<PlSmallStr>) -> Self {
@@ -163,6 +169,13 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_quote_char(quote_char))
     }
 
+    /// Set the `char` used to escape quotes within quoted fields. The default is [`None`],
+    /// in which case quotes are escaped by doubling them.
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_eol_char(self, eol_char: u8) -> Self {
@@ -0,0 +1,220 @@
+use std::any::Any;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::{DeltaSnapshot, DeltaTable, DeltaVersion};
+use polars_io::parquet::read::ParallelStrategy;
+use polars_io::{HiveOptions, RowIndex};
+use polars_ops::frame::MaintainOrderJoin;
+
+use super::{finish_scan, skip_batch_mask};
+use crate::prelude::*;
+
+const DELTA_ROW_INDEX: &str = "__POLARS_DELTA_ROW_INDEX";
+const DELTA_FILE_PATH: &str = "__POLARS_DELTA_FILE_PATH";
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    /// The version of the table to read.
+    pub version: DeltaVersion,
+    pub n_rows: Option<usize>,
+    pub parallel: ParallelStrategy,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    /// Prune data files with their partition values and the statistics in the log, and use
+    /// the statistics of the parquet files.
+    pub use_statistics: bool,
+    pub low_memory: bool,
+    pub rechunk: bool,
+    pub cache: bool,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: DeltaVersion::Latest,
+            n_rows: None,
+            parallel: Default::default(),
+            row_index: None,
+            cloud_options: None,
+            use_statistics: true,
+            low_memory: false,
+            rechunk: false,
+            cache: true,
+        }
+    }
+}
+
+/// Plans the parquet scan of a snapshot once the pushed-down predicate is known.
+struct DeltaScan {
+    snapshot: DeltaSnapshot,
+    schema: SchemaRef,
+    args: ScanArgsDelta,
+}
+
+impl DeltaScan {
+    /// The indices of the data files that can contain rows matching `predicate`.
+    fn files(&self, predicate: Option<&Expr>) -> PolarsResult<Vec<IdxSize>> {
+        let mut files = (0..self.snapshot.files.len() as IdxSize).collect::<Vec<_>>();
+        if let Some(predicate) = predicate.filter(|_| self.args.use_statistics) {
+            let statistics = self.snapshot.file_statistics()?;
+            if let Some(skip) = skip_batch_mask(predicate, &self.schema, statistics)? {
+                let mut skip = skip.into_iter();
+                files.retain(|_| !skip.next().unwrap());
+            }
+        }
+        Ok(files)
+    }
+
+    /// Plan a single scan of the files that can contain rows matching `predicate`.
+    ///
+    /// The partition values are joined on the path of the file that a row was read from.
+    /// The files with deletion vectors are read first, so that the position of a deleted row
+    /// in the scan follows from the number of rows of the files before it, and deleted rows are
+    /// filtered on that position.
+    fn plan(&self, predicate: Option<&Expr>) -> PolarsResult<LazyFrame> {
+        let mut files = self.files(predicate)?;
+        if files.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.schema).lazy());
+        }
+        files.sort_by_key(|&idx| self.snapshot.files[idx as usize].deletion_vector.is_none());
+
+        let partition_schema = self.snapshot.partition_schema()?;
+        let data_schema = self
+            .schema
+            .iter()
+            .filter(|(name, _)| !partition_schema.contains(name))
+            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .collect::<Schema>();
+        let uris = files
+            .iter()
+            .map(|&idx| self.snapshot.file_uri(&self.snapshot.files[idx as usize]))
+            .collect::<Vec<_>>();
+
+        let mut deleted = vec![];
+        let mut offset = 0;
+        for &idx in &files {
+            let file = &self.snapshot.files[idx as usize];
+            let Some(rows) = self.snapshot.deleted_rows(file)? else {
+                break;
+            };
+            deleted.extend(rows.into_iter().map(|row| offset + row));
+            offset += self.snapshot.num_rows(file)? as u64;
+        }
+        let path_name = PlSmallStr::from_static(DELTA_FILE_PATH);
+        let needs_path = !partition_schema.is_empty();
+
+        let parquet_args = ScanArgsParquet {
+            parallel: self.args.parallel,
+            rechunk: self.args.rechunk,
+            cloud_options: self.args.cloud_options.clone(),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            use_statistics: self.args.use_statistics,
+            schema: Some(Arc::new(data_schema)),
+            low_memory: self.args.low_memory,
+            cache: self.args.cache,
+            glob: false,
+            include_file_paths: needs_path.then(|| path_name.clone()),
+            // Columns that were added to the table are missing from older files.
+            allow_missing_columns: true,
+            ..Default::default()
+        };
+        let paths = uris.iter().map(PathBuf::from).collect::<Vec<_>>();
+        let mut lf = LazyFrame::scan_parquet_files(paths.into(), parquet_args)?;
+
+        if !deleted.is_empty() {
+            let index_name = PlSmallStr::from_static(DELTA_ROW_INDEX);
+            let deleted = Series::new(PlSmallStr::EMPTY, deleted).strict_cast(&IDX_DTYPE)?;
+            lf = lf
+                .with_row_index(index_name.clone(), None)
+                .filter(col(index_name.clone()).is_in(lit(deleted), false).not())
+                .drop([index_name]);
+        }
+
+        if !partition_schema.is_empty() {
+            let mut partition_values = self
+                .snapshot
+                .partition_values()?
+                .take(&IdxCa::from_vec(PlSmallStr::EMPTY, files))?;
+            partition_values.with_column(Column::new(path_name.clone(), uris))?;
+
+            let mut args = JoinArgs::new(JoinType::Left);
+            args.maintain_order = MaintainOrderJoin::Left;
+            lf = lf.join(
+                partition_values.lazy(),
+                [col(path_name.clone())],
+                [col(path_name.clone())],
+                args,
+            );
+        }
+
+        Ok(lf.select(
+            self.schema
+                .iter_names()
+                .map(|name| col(name.clone()))
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
+
+impl AnonymousScan for DeltaScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let lf = self.plan(scan_opts.predicate.as_ref())?;
+        finish_scan(lf, scan_opts).collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a Delta Lake table.
+    pub fn scan_delta(uri: &str, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot =
+            DeltaTable::open(uri, args.cloud_options.as_ref())?.snapshot(args.version)?;
+        Self::scan_delta_snapshot(snapshot, args)
+    }
+
+    /// Create a LazyFrame from a snapshot of a Delta Lake table.
+    ///
+    /// The data files are read by a single parquet scan. Files are pruned with the predicate
+    /// that is pushed down into the scan, using their partition values and the statistics in
+    /// the log.
+    pub fn scan_delta_snapshot(snapshot: DeltaSnapshot, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let schema = snapshot.metadata.schema.clone();
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index.clone(),
+            name: "DELTA SCAN",
+            ..Default::default()
+        };
+        let function = Arc::new(DeltaScan {
+            snapshot,
+            schema,
+            args,
+        });
+        Self::anonymous_scan(function, scan_args)
+    }
+}
@@ -0,0 +1,157 @@
+use std::any::Any;
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::csv::read::{CsvEncoding, NullValues};
+use polars_io::fixed_width::{
+    FixedWidthColumn, FixedWidthReadOptions, FixedWidthReader, FixedWidthTrim,
+};
+use polars_io::path_utils::resolve_homedir;
+use polars_io::{RowIndex, SerReader};
+
+use crate::prelude::*;
+
+/// Lazily read a fixed-width text file. The column layout must be given up front, as
+/// it cannot be inferred from the file.
+#[derive(Clone)]
+pub struct LazyFixedWidthReader {
+    path: PathBuf,
+    read_options: FixedWidthReadOptions,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+}
+
+impl LazyFixedWidthReader {
+    pub fn new(path: impl AsRef<Path>, columns: impl Into<Arc<[FixedWidthColumn]>>) -> Self {
+        LazyFixedWidthReader {
+            path: path.as_ref().to_path_buf(),
+            read_options: FixedWidthReadOptions::new(columns),
+            n_rows: None,
+            row_index: None,
+        }
+    }
+
+    /// Add a row index column.
+    #[must_use]
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Stop reading after `n` rows.
+    #[must_use]
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Skip the first `n` lines of the file, e.g. a header line.
+    #[must_use]
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.read_options.skip_rows = skip_rows;
+        self
+    }
+
+    /// Which padding to strip from fields.
+    #[must_use]
+    pub fn with_trim(mut self, trim: FixedWidthTrim) -> Self {
+        self.read_options.trim = trim;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null.
+    #[must_use]
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.read_options.null_values = null_values;
+        self
+    }
+
+    /// Read blank fields as null instead of as empty strings.
+    #[must_use]
+    pub fn with_blank_is_null(mut self, blank_is_null: bool) -> Self {
+        self.read_options.blank_is_null = blank_is_null;
+        self
+    }
+
+    /// Set the `char` used as end of line. The default is `b'\n'`.
+    #[must_use]
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.read_options.eol_char = eol_char;
+        self
+    }
+
+    /// Set  [`CsvEncoding`]
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.read_options.encoding = encoding;
+        self
+    }
+
+    /// Set fields that fail to parse to null instead of raising an error.
+    #[must_use]
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.read_options.ignore_errors = ignore;
+        self
+    }
+
+    pub fn finish(self) -> PolarsResult<LazyFrame> {
+        let schema = Arc::new(self.read_options.schema());
+        let function = Arc::new(FixedWidthScan {
+            path: resolve_homedir(&self.path),
+            read_options: self.read_options,
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some(schema),
+            n_rows: self.n_rows,
+            row_index: self.row_index,
+            name: "FIXED WIDTH SCAN",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(function, args)
+    }
+}
+
+struct FixedWidthScan {
+    path: PathBuf,
+    read_options: FixedWidthReadOptions,
+}
+
+impl AnonymousScan for FixedWidthScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let projection = scan_opts
+            .with_columns
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|name| scan_opts.schema.try_index_of(name))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .transpose()?;
+
+        let read_options = self
+            .read_options
+            .clone()
+            .with_projection(projection.map(Arc::new))
+            .with_n_rows(scan_opts.n_rows);
+
+        FixedWidthReader::new(polars_utils::open_file(&self.path)?)
+            .with_options(read_options)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(self.read_options.schema()))
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,66 @@
+use std::any::Any;
+
+use polars_core::prelude::*;
+use polars_io::flight::FlightReader;
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsFlight {
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    /// The schema of the stream. If `None`, it is read from the service with a `GetSchema`
+    /// call.
+    pub schema: Option<SchemaRef>,
+}
+
+struct FlightScan {
+    reader: FlightReader,
+    schema: SchemaRef,
+}
+
+impl AnonymousScan for FlightScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        self.reader.clone().with_n_rows(scan_opts.n_rows).finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the record batches that an Arrow Flight service returns for a
+    /// `DoGet` call with `ticket`.
+    ///
+    /// `location` is the address of the service, such as `grpc://localhost:8815`. Slices are
+    /// pushed down by cancelling the stream once enough rows are read.
+    pub fn scan_flight(
+        location: impl Into<String>,
+        ticket: impl Into<Vec<u8>>,
+        args: ScanArgsFlight,
+    ) -> PolarsResult<Self> {
+        let reader = FlightReader::new(location, ticket);
+        let schema = match args.schema {
+            Some(schema) => schema,
+            None => reader.schema()?,
+        };
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "FLIGHT SCAN",
+            ..Default::default()
+        };
+        Self::anonymous_scan(Arc::new(FlightScan { reader, schema }), scan_args)
+    }
+}
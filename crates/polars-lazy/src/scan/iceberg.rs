@@ -0,0 +1,310 @@
+use std::any::Any;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_io::iceberg::{
+    IcebergContent, IcebergDataFile, IcebergFileColumn, IcebergSnapshot, IcebergTable,
+};
+use polars_io::parquet::read::ParallelStrategy;
+use polars_io::{HiveOptions, RowIndex};
+
+use super::{finish_scan, skip_batch_mask};
+use crate::prelude::*;
+
+const ICEBERG_ROW_INDEX: &str = "__POLARS_ICEBERG_ROW_INDEX";
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// The snapshot to read, the current snapshot of the table if `None`.
+    pub snapshot_id: Option<i64>,
+    pub n_rows: Option<usize>,
+    pub parallel: ParallelStrategy,
+    pub row_index: Option<RowIndex>,
+    /// Prune data files with the statistics of the manifests and use the statistics of the
+    /// parquet files.
+    pub use_statistics: bool,
+    pub low_memory: bool,
+    pub rechunk: bool,
+    pub cache: bool,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            n_rows: None,
+            parallel: Default::default(),
+            row_index: None,
+            use_statistics: true,
+            low_memory: false,
+            rechunk: false,
+            cache: true,
+        }
+    }
+}
+
+/// Plans the parquet scans of a snapshot once the pushed-down predicate is known.
+struct IcebergScan {
+    snapshot: IcebergSnapshot,
+    schema: SchemaRef,
+    args: ScanArgsIceberg,
+}
+
+impl IcebergScan {
+    /// The data files that can contain rows matching `predicate`, and all delete files.
+    fn files(
+        &self,
+        predicate: Option<&Expr>,
+    ) -> PolarsResult<(Vec<IcebergDataFile>, Vec<IcebergDataFile>)> {
+        let predicate = predicate.filter(|_| self.args.use_statistics);
+        let skip_manifests = match predicate {
+            Some(predicate) => skip_batch_mask(
+                predicate,
+                &self.schema,
+                self.snapshot.manifest_statistics()?,
+            )?,
+            None => None,
+        };
+        let (mut data_files, delete_files): (Vec<_>, Vec<_>) = self
+            .snapshot
+            .read_manifests(skip_manifests.as_deref())?
+            .into_iter()
+            .partition(|file| file.content == IcebergContent::Data);
+
+        if let Some(predicate) = predicate {
+            let statistics = self.snapshot.file_statistics(&data_files)?;
+            if let Some(skip) = skip_batch_mask(predicate, &self.schema, statistics)? {
+                let mut skip = skip.into_iter();
+                data_files.retain(|_| !skip.next().unwrap());
+            }
+        }
+        Ok((data_files, delete_files))
+    }
+
+    fn parquet_args(&self) -> ScanArgsParquet {
+        ScanArgsParquet {
+            parallel: self.args.parallel,
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+            use_statistics: self.args.use_statistics,
+            low_memory: self.args.low_memory,
+            cache: self.args.cache,
+            glob: false,
+            ..Default::default()
+        }
+    }
+
+    fn scan_file(&self, file: &IcebergDataFile) -> PolarsResult<LazyFrame> {
+        LazyFrame::scan_parquet_files([self.snapshot.file_path(file)?].into(), self.parquet_args())
+    }
+
+    /// Select the columns of the table from a scan of files with the given columns.
+    fn to_table_columns(&self, lf: LazyFrame, columns: &[IcebergFileColumn]) -> LazyFrame {
+        let exprs = self
+            .schema
+            .iter()
+            .map(|(name, dtype)| {
+                let value = match columns.iter().find(|c| c.table_name == name) {
+                    Some(column) => col(column.file_name.clone()),
+                    // Columns that were added to the table are missing from older files.
+                    None => lit(Null {}),
+                };
+                value.cast(dtype.clone()).alias(name.clone())
+            })
+            .collect::<Vec<_>>();
+        lf.select(exprs)
+    }
+
+    /// Read a data file with the rows that are deleted by `deletes` removed.
+    fn scan_with_deletes(
+        &self,
+        file: &IcebergDataFile,
+        columns: &[IcebergFileColumn],
+        deletes: &[&IcebergDataFile],
+    ) -> PolarsResult<LazyFrame> {
+        let index_name = PlSmallStr::from_static(ICEBERG_ROW_INDEX);
+        let mut lf = self.scan_file(file)?;
+
+        let position_deletes = deletes
+            .iter()
+            .filter(|d| d.content == IcebergContent::PositionDeletes)
+            .map(|d| {
+                Ok(self
+                    .scan_file(d)?
+                    .filter(col("file_path").eq(lit(file.path.as_str())))
+                    .select([col("pos").cast(IDX_DTYPE).alias(index_name.clone())]))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if !position_deletes.is_empty() {
+            let deleted = concat(position_deletes, Default::default())?;
+            lf = lf
+                .with_row_index(index_name.clone(), None)
+                .join(
+                    deleted,
+                    [col(index_name.clone())],
+                    [col(index_name.clone())],
+                    JoinArgs::new(JoinType::Anti),
+                )
+                .drop([index_name]);
+        }
+        lf = self.to_table_columns(lf, columns);
+
+        for delete in deletes
+            .iter()
+            .filter(|d| d.content == IcebergContent::EqualityDeletes)
+        {
+            let delete_columns = self.snapshot.file_columns(delete)?;
+            let on = delete
+                .equality_ids
+                .iter()
+                .map(|id| {
+                    let field = self.snapshot.schema.field_by_id(*id).ok_or_else(|| {
+                        polars_err!(
+                            ComputeError: "equality delete file '{}' refers to field {}, which is not in the table",
+                            delete.path, id
+                        )
+                    })?;
+                    let column = delete_columns
+                        .iter()
+                        .find(|c| c.table_name == field.name)
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError: "equality delete file '{}' does not contain column '{}'",
+                                delete.path, field.name
+                            )
+                        })?;
+                    Ok((column, field))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let deleted = self.scan_file(delete)?.select(
+                on.iter()
+                    .map(|(column, field)| {
+                        col(column.file_name.clone())
+                            .cast(field.dtype.clone())
+                            .alias(field.name.clone())
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let keys = on
+                .iter()
+                .map(|(_, field)| col(field.name.clone()))
+                .collect::<Vec<_>>();
+            let mut args = JoinArgs::new(JoinType::Anti);
+            // Equality deletes match null values.
+            args.nulls_equal = true;
+            lf = lf.join(deleted, &keys, &keys, args);
+        }
+        Ok(lf)
+    }
+
+    /// Plan the scans of the files that can contain rows matching `predicate`.
+    fn plan(&self, predicate: Option<&Expr>) -> PolarsResult<LazyFrame> {
+        let (data_files, delete_files) = self.files(predicate)?;
+
+        // Data files without deletes that store the same columns are read by a single scan.
+        let mut groups: PlIndexMap<Vec<IcebergFileColumn>, Vec<PathBuf>> = PlIndexMap::new();
+        let mut inputs = vec![];
+        for file in &data_files {
+            let columns = self.snapshot.file_columns(file)?;
+            let deletes = delete_files
+                .iter()
+                .filter(|d| {
+                    let unpartitioned = self
+                        .snapshot
+                        .partition_specs
+                        .get(&d.spec_id)
+                        .is_none_or(|spec| spec.is_unpartitioned());
+                    d.applies_to(file, unpartitioned)
+                })
+                .collect::<Vec<_>>();
+            if deletes.is_empty() {
+                groups
+                    .entry(columns)
+                    .or_default()
+                    .push(self.snapshot.file_path(file)?);
+            } else {
+                inputs.push(self.scan_with_deletes(file, &columns, &deletes)?);
+            }
+        }
+        for (columns, paths) in groups {
+            let lf = LazyFrame::scan_parquet_files(paths.into(), self.parquet_args())?;
+            inputs.push(self.to_table_columns(lf, &columns));
+        }
+
+        Ok(match inputs.len() {
+            0 => DataFrame::empty_with_schema(&self.schema).lazy(),
+            1 => inputs.pop().unwrap(),
+            _ => concat(
+                inputs,
+                UnionArgs {
+                    rechunk: self.args.rechunk,
+                    ..Default::default()
+                },
+            )?,
+        })
+    }
+}
+
+impl AnonymousScan for IcebergScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let lf = self.plan(scan_opts.predicate.as_ref())?;
+        finish_scan(lf, scan_opts).collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Iceberg table on the local filesystem.
+    ///
+    /// `path` is the directory of the table or one of its metadata files.
+    pub fn scan_iceberg(path: &str, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let snapshot = IcebergTable::open(path)?.snapshot(args.snapshot_id)?;
+        Self::scan_iceberg_snapshot(snapshot, args)
+    }
+
+    /// Create a LazyFrame from a snapshot of an Iceberg table.
+    ///
+    /// Manifests and data files are pruned with the predicate that is pushed down into the scan,
+    /// using the partition values and column bounds in the manifests. Columns are matched with
+    /// the columns of the data files by field ID, and position and equality deletes are applied.
+    pub fn scan_iceberg_snapshot(
+        snapshot: IcebergSnapshot,
+        args: ScanArgsIceberg,
+    ) -> PolarsResult<Self> {
+        let schema = Arc::new(snapshot.schema.to_schema());
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index.clone(),
+            name: "ICEBERG SCAN",
+            ..Default::default()
+        };
+        let function = Arc::new(IcebergScan {
+            snapshot,
+            schema,
+            args,
+        });
+        Self::anonymous_scan(function, scan_args)
+    }
+}
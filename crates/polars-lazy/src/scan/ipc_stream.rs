@@ -0,0 +1,174 @@
+use std::any::Any;
+use std::io::{Read, Write};
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
+use polars_io::ipc::{BatchedIpcStreamReader, IpcStreamReader, IpcStreamWriter, IpcWriterOptions};
+use polars_io::{RowIndex, SerReader, SerWriter};
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIpcStream {
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+enum StreamState<R: Read> {
+    Pending(IpcStreamReader<R>),
+    Reading(BatchedIpcStreamReader<R>),
+    Consumed,
+}
+
+/// An Arrow IPC stream as a scan source. A stream can only be read once.
+struct IpcStreamScan<R: Read> {
+    schema: SchemaRef,
+    state: Mutex<StreamState<R>>,
+}
+
+impl<R: Read> IpcStreamScan<R> {
+    fn start_reading(
+        state: &mut StreamState<R>,
+        scan_opts: &AnonymousScanArgs,
+    ) -> PolarsResult<()> {
+        if let StreamState::Pending(_) = state {
+            let StreamState::Pending(reader) = std::mem::replace(state, StreamState::Consumed)
+            else {
+                unreachable!()
+            };
+            let columns = scan_opts
+                .with_columns
+                .as_ref()
+                .map(|columns| columns.iter().map(|c| c.to_string()).collect());
+            *state = StreamState::Reading(
+                reader
+                    .with_columns(columns)
+                    .with_n_rows(scan_opts.n_rows)
+                    .batched()?,
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read + Send + 'static> AnonymousScan for IpcStreamScan<R> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Reads the rest of the stream, or until `n_rows` rows have arrived. Used by the
+    /// in-memory engine; the streaming engine reads the stream with `next_batch`.
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut state = self.state.lock().unwrap();
+        Self::start_reading(&mut state, &scan_opts)?;
+        let StreamState::Reading(mut reader) =
+            std::mem::replace(&mut *state, StreamState::Consumed)
+        else {
+            polars_bail!(ComputeError: "the IPC stream has already been consumed")
+        };
+
+        let mut batches = vec![];
+        while let Some(batch) = reader.next_batch()? {
+            batches.push(batch);
+        }
+        Ok(accumulate_dataframes_vertical_unchecked_optional(batches)
+            .unwrap_or_else(|| DataFrame::empty_with_schema(&reader.schema())))
+    }
+
+    /// Returns the record batches of the stream one by one, as they arrive.
+    fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+        let mut state = self.state.lock().unwrap();
+        Self::start_reading(&mut state, &scan_opts)?;
+        match &mut *state {
+            StreamState::Reading(reader) => match reader.next_batch()? {
+                Some(df) => Ok(Some(df)),
+                None => {
+                    *state = StreamState::Consumed;
+                    Ok(None)
+                },
+            },
+            StreamState::Consumed => Ok(None),
+            StreamState::Pending(_) => unreachable!(),
+        }
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Arrow IPC stream, e.g. stdin, a pipe or a socket.
+    ///
+    /// This blocks until the schema message of the stream has been received. The
+    /// stream is read when the query is executed and can only be read once. A `limit`
+    /// stops reading as soon as enough rows have arrived.
+    pub fn scan_ipc_stream<R: Read + Send + 'static>(
+        reader: R,
+        args: ScanArgsIpcStream,
+    ) -> PolarsResult<Self> {
+        let mut reader = IpcStreamReader::new(reader);
+        let schema = Arc::new(reader.schema()?);
+        let function = Arc::new(IpcStreamScan {
+            schema: schema.clone(),
+            state: Mutex::new(StreamState::Pending(reader)),
+        });
+
+        LazyFrame::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                n_rows: args.n_rows,
+                row_index: args.row_index,
+                name: "IPC STREAM SCAN",
+                ..Default::default()
+            },
+        )
+    }
+}
+
+/// Apply `f` to every record batch of the Arrow IPC stream `reader` and write the results
+/// as an Arrow IPC stream to `writer`.
+///
+/// Batches are processed one at a time as they arrive, so this works on unbounded streams
+/// and lets polars processes be chained through pipes or sockets. Because `f` only sees a
+/// single batch, it should consist of row-wise operations; aggregations are computed per
+/// batch.
+pub fn map_ipc_stream<R, W, F>(
+    reader: R,
+    writer: W,
+    options: IpcWriterOptions,
+    f: F,
+) -> PolarsResult<()>
+where
+    R: Read,
+    W: Write,
+    F: Fn(LazyFrame) -> LazyFrame,
+{
+    let mut reader = IpcStreamReader::new(reader).batched()?;
+    let input_schema = reader.schema();
+    let output_schema = f(DataFrame::empty_with_schema(&input_schema).lazy()).collect_schema()?;
+    let mut writer = IpcStreamWriter::new(writer)
+        .with_compression(options.compression)
+        .batched(&output_schema)?;
+
+    while let Some(batch) = reader.next_batch()? {
+        let mut out = f(batch.lazy()).collect()?;
+        out.align_chunks_par();
+        writer.write_batch(&out)?;
+    }
+    writer.finish()
+}
@@ -1,13 +1,105 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "catalog")]
+pub(super) mod catalog;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
+#[cfg(feature = "fixed_width")]
+pub(super) mod fixed_width;
+#[cfg(feature = "flight")]
+pub(super) mod flight;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub(super) mod ipc_stream;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "spreadsheet")]
+pub(super) mod spreadsheet;
+#[cfg(feature = "sqlite")]
+pub(super) mod sqlite;
 
-#[cfg(feature = "catalog")]
-mod catalog;
+#[cfg(any(
+    feature = "delta",
+    feature = "iceberg",
+    feature = "orc",
+    feature = "sqlite"
+))]
+use polars_core::prelude::*;
+#[cfg(any(feature = "delta", feature = "iceberg", feature = "orc"))]
+use polars_plan::plans::predicates::aexpr_to_skip_batch_predicate;
+#[cfg(any(feature = "orc", feature = "sqlite"))]
+use polars_plan::utils::expr_to_leaf_column_names_iter;
+
+#[cfg(any(
+    feature = "delta",
+    feature = "iceberg",
+    feature = "orc",
+    feature = "sqlite"
+))]
+use crate::prelude::*;
+
+/// Evaluate the batch skipping predicate that is derived from `predicate` on a frame of
+/// statistics with a row per file, manifest or stripe. Returns `None` if no skipping predicate
+/// can be derived.
+#[cfg(any(feature = "delta", feature = "iceberg", feature = "orc"))]
+fn skip_batch_mask(
+    predicate: &Expr,
+    schema: &Schema,
+    statistics: DataFrame,
+) -> PolarsResult<Option<Vec<bool>>> {
+    let height = statistics.height();
+    let mut expr_arena = Arena::with_capacity(16);
+    let node = to_expr_ir(predicate.clone(), &mut expr_arena)?.node();
+    let Some(skip) = aexpr_to_skip_batch_predicate(node, &mut expr_arena, schema) else {
+        return Ok(None);
+    };
+    let skip = node_to_expr(skip, &expr_arena);
+    let out = statistics.lazy().select([skip]).collect()?;
+    let mask = out.get_columns()[0].bool()?;
+    let mask = match mask.len() {
+        1 => vec![mask.get(0).unwrap_or(false); height],
+        _ => mask.iter().map(|skip| skip.unwrap_or(false)).collect(),
+    };
+    Ok(Some(mask))
+}
+
+/// The columns that an anonymous scan reads: the projected columns and the columns that are
+/// needed to evaluate the predicate.
+#[cfg(any(feature = "orc", feature = "sqlite"))]
+fn scan_columns(scan_opts: &AnonymousScanArgs) -> Option<Arc<[PlSmallStr]>> {
+    let columns = scan_opts.with_columns.as_ref()?;
+    let mut names: PlIndexSet<PlSmallStr> = columns.iter().cloned().collect();
+    if let Some(predicate) = &scan_opts.predicate {
+        names.extend(expr_to_leaf_column_names_iter(predicate));
+    }
+    Some(names.into_iter().collect())
+}
+
+/// Apply the predicate, projection and row limit of an anonymous scan to the data it read.
+#[cfg(any(
+    feature = "delta",
+    feature = "iceberg",
+    feature = "orc",
+    feature = "sqlite"
+))]
+fn finish_scan(mut lf: LazyFrame, scan_opts: AnonymousScanArgs) -> LazyFrame {
+    if let Some(predicate) = scan_opts.predicate {
+        lf = lf.filter(predicate);
+    }
+    if let Some(columns) = scan_opts.with_columns {
+        lf = lf.select(columns.iter().cloned().map(col).collect::<Vec<_>>());
+    }
+    if let Some(n_rows) = scan_opts.n_rows {
+        lf = lf.limit(n_rows as IdxSize);
+    }
+    lf
+}
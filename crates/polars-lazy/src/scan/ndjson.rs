@@ -21,6 +21,7 @@ pub struct LazyJsonLineReader {
     pub(crate) schema_overwrite: Option<SchemaRef>,
     pub(crate) row_index: Option<RowIndex>,
     pub(crate) infer_schema_length: Option<NonZeroUsize>,
+    pub(crate) infer_schema_n_files: Option<NonZeroUsize>,
     pub(crate) n_rows: Option<usize>,
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
@@ -42,6 +43,7 @@ impl LazyJsonLineReader {
             schema_overwrite: None,
             row_index: None,
             infer_schema_length: NonZeroUsize::new(100),
+            infer_schema_n_files: NonZeroUsize::new(1),
             ignore_errors: false,
             n_rows: None,
             include_file_paths: None,
@@ -82,6 +84,16 @@ impl LazyJsonLineReader {
         self.infer_schema_length = num_rows;
         self
     }
+    /// Set the number of files to use when inferring the json schema.
+    /// The default is 1 file.
+    /// The schemas inferred from each file are merged: fields that only appear in some
+    /// files are added and differing types are promoted to their supertype.
+    /// Setting to `None` will infer from all files.
+    #[must_use]
+    pub fn with_infer_schema_n_files(mut self, num_files: Option<NonZeroUsize>) -> Self {
+        self.infer_schema_n_files = num_files;
+        self
+    }
     /// Set the JSON file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -143,6 +155,7 @@ impl LazyFileListReader for LazyJsonLineReader {
         let options = NDJsonReadOptions {
             n_threads: None,
             infer_schema_length: self.infer_schema_length,
+            infer_schema_n_files: self.infer_schema_n_files,
             chunk_size: NonZeroUsize::new(1 << 18).unwrap(),
             low_memory: self.low_memory,
             ignore_errors: self.ignore_errors,
@@ -0,0 +1,110 @@
+use std::any::Any;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_io::orc::{OrcMetadata, OrcReader};
+use polars_io::{resolve_homedir, RowIndex, SerReader};
+use polars_utils::open_file;
+
+use super::{finish_scan, scan_columns, skip_batch_mask};
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsOrc {
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub rechunk: bool,
+    /// Skip stripes with the statistics of the file.
+    pub use_statistics: bool,
+}
+
+impl Default for ScanArgsOrc {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            row_index: None,
+            rechunk: true,
+            use_statistics: true,
+        }
+    }
+}
+
+struct OrcScan {
+    path: PathBuf,
+    metadata: Arc<OrcMetadata>,
+    args: ScanArgsOrc,
+}
+
+impl OrcScan {
+    /// The stripes that can contain rows matching `predicate`, derived from the batch skipping
+    /// predicate evaluated on the stripe statistics.
+    fn stripe_mask(&self, predicate: &Expr) -> PolarsResult<Option<Vec<bool>>> {
+        let statistics = self.metadata.stripe_statistics()?;
+        let skip = skip_batch_mask(predicate, self.metadata.schema(), statistics)?;
+        Ok(skip.map(|skip| skip.into_iter().map(|skip| !skip).collect()))
+    }
+}
+
+impl AnonymousScan for OrcScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let stripe_mask = match &scan_opts.predicate {
+            Some(predicate) if self.args.use_statistics => self.stripe_mask(predicate)?,
+            _ => None,
+        };
+        let file = open_file(&self.path)?;
+        let reader = OrcReader::new(file)
+            .with_metadata(Some(self.metadata.clone()))
+            .with_columns(scan_columns(&scan_opts))
+            .with_stripe_mask(stripe_mask)
+            .set_rechunk(self.args.rechunk);
+
+        if scan_opts.predicate.is_none() {
+            return reader.with_n_rows(scan_opts.n_rows).finish();
+        }
+        finish_scan(reader.finish()?.lazy(), scan_opts).collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.metadata.schema().clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an ORC file.
+    ///
+    /// Only the streams of projected columns are read, and stripes whose statistics show
+    /// that they cannot match a predicate are skipped.
+    pub fn scan_orc(path: impl AsRef<std::path::Path>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        let path = resolve_homedir(&path);
+        let metadata = OrcReader::new(open_file(&path)?).metadata()?.clone();
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(metadata.schema().clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index.clone(),
+            name: "ORC SCAN",
+            ..Default::default()
+        };
+        let scan = OrcScan {
+            path,
+            metadata,
+            args,
+        };
+        Self::anonymous_scan(Arc::new(scan), scan_args)
+    }
+}
@@ -0,0 +1,66 @@
+use std::any::Any;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_io::spreadsheet::{SpreadsheetReadOptions, SpreadsheetReader};
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsSpreadsheet {
+    pub read_options: SpreadsheetReadOptions,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+}
+
+struct SpreadsheetScan {
+    reader: SpreadsheetReader,
+    schema: SchemaRef,
+}
+
+impl AnonymousScan for SpreadsheetScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        self.reader
+            .clone()
+            .with_columns(scan_opts.with_columns)
+            .with_n_rows(scan_opts.n_rows)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a sheet of an Excel (xlsx, xlsm, xlsb, xls) or OpenDocument
+    /// (ods) spreadsheet.
+    pub fn scan_spreadsheet(
+        path: impl Into<PathBuf>,
+        args: ScanArgsSpreadsheet,
+    ) -> PolarsResult<Self> {
+        let reader = SpreadsheetReader::new(path).with_options(args.read_options);
+        let schema = reader.schema()?;
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "SPREADSHEET SCAN",
+            ..Default::default()
+        };
+        Self::anonymous_scan(Arc::new(SpreadsheetScan { reader, schema }), scan_args)
+    }
+}
@@ -0,0 +1,243 @@
+use std::any::Any;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_io::sqlite::{quote_identifier, SqliteReader};
+use polars_io::RowIndex;
+
+use super::{finish_scan, scan_columns};
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsSqlite {
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    /// The number of rows that are used to derive the type of columns without a declared type.
+    pub infer_schema_length: Option<usize>,
+    /// The schema of the table or query, derived from the database if `None`.
+    pub schema: Option<SchemaRef>,
+}
+
+impl Default for ScanArgsSqlite {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            row_index: None,
+            infer_schema_length: Some(100),
+            schema: None,
+        }
+    }
+}
+
+struct SqliteScan {
+    reader: SqliteReader,
+    schema: SchemaRef,
+}
+
+impl SqliteScan {
+    /// Translate `expr` to a SQL expression. Returns `None` if (a part of) the expression cannot
+    /// be evaluated by SQLite with the same result.
+    fn to_sql(&self, expr: &Expr) -> Option<String> {
+        match expr {
+            Expr::BinaryExpr { left, op, right } => match op {
+                Operator::And | Operator::LogicalAnd | Operator::Or | Operator::LogicalOr => {
+                    let sql_op = if matches!(op, Operator::And | Operator::LogicalAnd) {
+                        "AND"
+                    } else {
+                        "OR"
+                    };
+                    Some(format!(
+                        "({} {sql_op} {})",
+                        self.to_sql(left)?,
+                        self.to_sql(right)?
+                    ))
+                },
+                _ => self.comparison_to_sql(left, *op, right),
+            },
+            Expr::Function {
+                input,
+                function: FunctionExpr::Boolean(function),
+                ..
+            } if input.len() == 1 => match function {
+                BooleanFunction::IsNull => Some(format!("({} IS NULL)", self.column(&input[0])?)),
+                BooleanFunction::IsNotNull => {
+                    Some(format!("({} IS NOT NULL)", self.column(&input[0])?))
+                },
+                BooleanFunction::Not => Some(format!("(NOT {})", self.to_sql(&input[0])?)),
+                _ => None,
+            },
+            // Booleans are stored as integers.
+            Expr::Column(name) if self.schema.get(name) == Some(&DataType::Boolean) => {
+                Some(format!("({} = 1)", quote_identifier(name)))
+            },
+            _ => None,
+        }
+    }
+
+    fn comparison_to_sql(&self, left: &Expr, op: Operator, right: &Expr) -> Option<String> {
+        // Comparisons of a column with a literal, with the column on the left.
+        let (column, op, literal) = match (left, right) {
+            (_, Expr::Literal(_) | Expr::Cast { .. }) => (left, op, right),
+            (Expr::Literal(_) | Expr::Cast { .. }, _) => (right, op.swap_operands(), left),
+            _ => return None,
+        };
+        let sql_op = match op {
+            Operator::Eq => "=",
+            Operator::EqValidity => "IS",
+            Operator::NotEq => "<>",
+            Operator::NotEqValidity => "IS NOT",
+            Operator::Lt => "<",
+            Operator::LtEq => "<=",
+            Operator::Gt => ">",
+            Operator::GtEq => ">=",
+            _ => return None,
+        };
+        let name = self.column(column)?;
+        let value = literal_to_sql(literal, self.schema.get(column_name(column)?)?)?;
+        Some(format!("({name} {sql_op} {value})"))
+    }
+
+    fn column(&self, expr: &Expr) -> Option<String> {
+        let name = column_name(expr)?;
+        self.schema.contains(name).then(|| quote_identifier(name))
+    }
+
+    /// Split `predicate` into its conjuncts and translate those that can be evaluated by SQLite.
+    /// Returns the SQL condition and whether all conjuncts were translated.
+    fn filter(&self, predicate: &Expr) -> (Option<String>, bool) {
+        let mut conjuncts = vec![];
+        let mut stack = vec![predicate];
+        while let Some(expr) = stack.pop() {
+            match expr {
+                Expr::BinaryExpr {
+                    left,
+                    op: Operator::And | Operator::LogicalAnd,
+                    right,
+                } => {
+                    stack.push(right);
+                    stack.push(left);
+                },
+                _ => conjuncts.push(expr),
+            }
+        }
+        let sql = conjuncts
+            .iter()
+            .filter_map(|expr| self.to_sql(expr))
+            .collect::<Vec<_>>();
+        let complete = sql.len() == conjuncts.len();
+        let sql = (!sql.is_empty()).then(|| sql.join(" AND "));
+        (sql, complete)
+    }
+}
+
+fn column_name(expr: &Expr) -> Option<&PlSmallStr> {
+    match expr {
+        Expr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// A SQL literal that compares with values of `dtype` as `literal` does in Polars.
+fn literal_to_sql(literal: &Expr, dtype: &DataType) -> Option<String> {
+    let value = match literal {
+        Expr::Literal(literal) => literal.to_any_value()?,
+        // Casts of literals that are not yet folded.
+        Expr::Cast {
+            expr, dtype: to, ..
+        } => match expr.as_ref() {
+            Expr::Literal(literal) => lossless_cast(literal.to_any_value()?, to)?,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    match (dtype, value) {
+        (DataType::Boolean, AnyValue::Boolean(v)) => Some((v as u8).to_string()),
+        (DataType::String, AnyValue::String(v)) => Some(format!("'{}'", v.replace('\'', "''"))),
+        (DataType::String, AnyValue::StringOwned(v)) => {
+            Some(format!("'{}'", v.replace('\'', "''")))
+        },
+        // SQLite stores NaN as NULL, and infinity has no literal.
+        (dt, AnyValue::Float32(v)) if dt.is_primitive_numeric() && v.is_finite() => {
+            Some(format!("{v:?}"))
+        },
+        (dt, AnyValue::Float64(v)) if dt.is_primitive_numeric() && v.is_finite() => {
+            Some(format!("{v:?}"))
+        },
+        (dt, v) if dt.is_primitive_numeric() && v.is_integer() => Some(v.to_string()),
+        _ => None,
+    }
+}
+
+/// `value` cast to `dtype`, if casting it back gives the same value.
+fn lossless_cast<'a>(value: AnyValue<'a>, dtype: &'a DataType) -> Option<AnyValue<'a>> {
+    let cast = value.strict_cast(dtype)?;
+    (cast.strict_cast(&value.dtype())? == value).then_some(cast)
+}
+
+impl AnonymousScan for SqliteScan {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let (filter, complete) = match &scan_opts.predicate {
+            Some(predicate) => self.filter(predicate),
+            None => (None, true),
+        };
+        let reader = self
+            .reader
+            .clone()
+            .with_schema(Some(self.schema.clone()))
+            .with_columns(scan_columns(&scan_opts))
+            .with_filter(filter)
+            .with_n_rows(scan_opts.n_rows.filter(|_| complete));
+
+        let df = reader.finish()?;
+        if scan_opts.predicate.is_none() {
+            return Ok(df);
+        }
+        finish_scan(df.lazy(), scan_opts).collect()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_predicate_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a table or query of a SQLite database.
+    ///
+    /// `table_or_query` is the name of a table or view, or a `SELECT` query. Projections,
+    /// slices and the parts of predicates that compare columns with literals are pushed down
+    /// into the SQL query that is run by SQLite.
+    pub fn scan_sqlite(
+        path: impl Into<PathBuf>,
+        table_or_query: impl Into<String>,
+        args: ScanArgsSqlite,
+    ) -> PolarsResult<Self> {
+        let mut reader = SqliteReader::new(path, table_or_query)
+            .with_schema(args.schema)
+            .infer_schema_length(args.infer_schema_length);
+        let schema = reader.schema()?;
+        let scan_args = ScanArgsAnonymous {
+            schema: Some(schema.clone()),
+            n_rows: args.n_rows,
+            row_index: args.row_index,
+            name: "SQLITE SCAN",
+            ..Default::default()
+        };
+        Self::anonymous_scan(Arc::new(SqliteScan { reader, schema }), scan_args)
+    }
+}
@@ -380,6 +380,53 @@ fn test_parquet_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_ndjson_infer_schema_n_files() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path();
+    std::fs::write(dir.join("0.ndjson"), "{\"a\": 1, \"s\": {\"x\": 1}}\n")?;
+    std::fs::write(
+        dir.join("1.ndjson"),
+        "{\"a\": 1.5, \"b\": \"foo\", \"s\": {\"y\": true}}\n",
+    )?;
+    let glob = dir.join("*.ndjson");
+
+    // Only the first file is used by default.
+    let schema = LazyJsonLineReader::new(&glob).finish()?.collect_schema()?;
+    assert_eq!(schema.get("a"), Some(&DataType::Int64));
+    assert!(schema.get("b").is_none());
+
+    let df = LazyJsonLineReader::new(&glob)
+        .with_infer_schema_n_files(None)
+        .finish()?
+        .collect()?;
+    let expected_schema = Schema::from_iter([
+        Field::new("a".into(), DataType::Float64),
+        Field::new(
+            "s".into(),
+            DataType::Struct(vec![
+                Field::new("x".into(), DataType::Int64),
+                Field::new("y".into(), DataType::Boolean),
+            ]),
+        ),
+        Field::new("b".into(), DataType::String),
+    ]);
+    assert_eq!(df.schema().as_ref(), &expected_schema);
+    assert_eq!(df.column("a")?.f64()?.to_vec(), &[Some(1.0), Some(1.5)]);
+    assert_eq!(df.column("b")?.null_count(), 1);
+
+    // Types without a supertype are reported with the offending file.
+    std::fs::write(dir.join("2.ndjson"), "{\"a\": [1]}\n")?;
+    let err = LazyJsonLineReader::new(&glob)
+        .with_infer_schema_n_files(None)
+        .finish()?
+        .collect_schema()
+        .unwrap_err();
+    assert!(err.to_string().contains("2.ndjson"));
+    Ok(())
+}
+
 #[test]
 fn test_scan_parquet_limit_9001() {
     init_files();
@@ -404,6 +451,33 @@ fn test_scan_parquet_limit_9001() {
     });
 }
 
+#[test]
+#[cfg(feature = "ipc_streaming")]
+fn test_sink_ipc_stream_maintain_order() -> PolarsResult<()> {
+    use std::io::{Seek, SeekFrom};
+
+    use polars_io::ipc::{IpcStreamReader, IpcWriterOptions};
+
+    let df = df!["a" => (0..100_000i64).collect::<Vec<_>>()]?;
+    let options = IpcWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    let sink = |lf: LazyFrame| -> PolarsResult<DataFrame> {
+        let mut file = tempfile::tempfile()?;
+        lf.with_column(col("a") * lit(2))
+            .sink_ipc_stream(file.try_clone()?, options)?;
+        file.seek(SeekFrom::Start(0))?;
+        IpcStreamReader::new(file).finish()
+    };
+    let expected = df.clone().lazy().with_column(col("a") * lit(2)).collect()?;
+
+    assert!(sink(df.clone().lazy())?.equals(&expected));
+    #[cfg(feature = "new_streaming")]
+    assert!(sink(df.lazy().with_new_streaming(true))?.equals(&expected));
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_ipc_globbing() -> PolarsResult<()> {
@@ -687,6 +761,48 @@ fn scan_anonymous_fn_with_options() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn scan_anonymous_fn_projection_pushdown_without_columns() -> PolarsResult<()> {
+    struct MyScan {}
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            match scan_opts.with_columns {
+                Some(columns) => fruits_cars().select(columns.iter().cloned()),
+                None => Ok(fruits_cars()),
+            }
+        }
+    }
+
+    let args = || ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        row_index: Some(RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        }),
+        ..ScanArgsAnonymous::default()
+    };
+
+    // Selecting every column leaves the projection to be initialized from the reader schema.
+    let df = LazyFrame::anonymous_scan(Arc::new(MyScan {}), args())?.collect()?;
+    assert_eq!(df.shape(), (5, 5));
+
+    // Selecting only the row index still needs a column from the reader schema.
+    let df = LazyFrame::anonymous_scan(Arc::new(MyScan {}), args())?
+        .select([col("idx")])
+        .collect()?;
+    assert_eq!(df.shape(), (5, 1));
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
@@ -711,3 +827,343 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+/// Overwrite the data of the selected row groups of a parquet file, so that reading them fails.
+#[cfg(feature = "parquet")]
+fn corrupt_row_groups(path: &std::path::Path, select: impl Fn(usize) -> bool) -> PolarsResult<()> {
+    let metadata = ParquetReader::new(std::fs::File::open(path)?)
+        .get_metadata()?
+        .clone();
+    let mut bytes = std::fs::read(path)?;
+    for (i, row_group) in metadata.row_groups.iter().enumerate() {
+        if select(i) {
+            for range in row_group.byte_ranges_iter() {
+                bytes[range.start as usize..range.end as usize].fill(0xff);
+            }
+        }
+    }
+    std::fs::write(path, bytes)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_join_runtime_filters_parquet() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path();
+
+    // A file with row groups of 10 keys each, and a hive partition per part.
+    let mut df = df![
+        "key" => (0..100i64).collect::<Vec<_>>(),
+        "value" => (0..100i64).map(|v| v * 10).collect::<Vec<_>>(),
+    ]?;
+    let file = dir.join("row_groups.parquet");
+    ParquetWriter::new(std::fs::File::create(&file)?)
+        .with_row_group_size(Some(10))
+        .with_statistics(StatisticsOptions::full())
+        .finish(&mut df)?;
+    let intact = dir.join("intact.parquet");
+    std::fs::copy(&file, &intact)?;
+    for part in 0..5i64 {
+        let part_dir = dir.join("hive").join(format!("part={part}"));
+        std::fs::create_dir_all(&part_dir)?;
+        let mut df = df![
+            "key" => [part * 2, part * 2 + 1],
+        ]?;
+        let path = part_dir.join("0.parquet");
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        // Only the partition that is joined below is read.
+        if part != 3 {
+            corrupt_row_groups(&path, |_| true)?;
+        }
+    }
+    // Only the row groups with the keys that are joined below are read.
+    corrupt_row_groups(&file, |i| i != 0 && i != 5)?;
+
+    let scan =
+        |path: &std::path::Path| LazyFrame::scan_parquet(path, ScanArgsParquet::default()).unwrap();
+    let build = |keys: &[i64]| {
+        df![
+            "k" => keys,
+        ]
+        .unwrap()
+        .lazy()
+    };
+    // Sequential joins publish the filters before the probe side is scanned, so the corrupted
+    // row groups and partitions are never read.
+    let join = |probe: LazyFrame, on: &str, keys: &[i64], parallel: bool| {
+        probe
+            .join_builder()
+            .with(build(keys))
+            .left_on([col(on)])
+            .right_on([col("k")])
+            .how(JoinType::Inner)
+            .allow_parallel(parallel)
+            .finish()
+    };
+
+    let out = join(scan(&file), "key", &[5, 57, 1000], false)
+        .sort(["key"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("value")?.i64()?.to_vec(), &[Some(50), Some(570)]);
+    // Parallel joins scan the probe side while the filters are built, it skips the row groups
+    // that it reads once they are published.
+    assert!(join(scan(&intact), "key", &[5, 57, 1000], true)
+        .sort(["key"], Default::default())
+        .collect()?
+        .equals(&out));
+    // The probe side is sampled before the filter is complete, so it may read any row group.
+    #[cfg(feature = "new_streaming")]
+    assert!(scan(&intact)
+        .join(
+            build(&[5, 57, 1000]),
+            [col("key")],
+            [col("k")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["key"], Default::default())
+        .with_new_streaming(true)
+        .collect()?
+        .equals(&out));
+
+    let out = join(scan(&dir.join("hive")), "part", &[3], false)
+        .sort(["key"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("key")?.i64()?.to_vec(), &[Some(6), Some(7)]);
+
+    // All files and row groups are skipped, the output keeps its schema.
+    for path in [file.clone(), dir.join("hive")] {
+        let lf = scan(&path);
+        let schema = lf.clone().collect_schema()?;
+        let out = join(lf, "key", &[-1], false).collect()?;
+        assert_eq!(out.height(), 0);
+        assert_eq!(out.width(), schema.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "is_in"))]
+fn test_is_in_context_runtime_filters_hive() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path();
+    for part in 0..5i64 {
+        let part_dir = dir.join(format!("part={part}"));
+        std::fs::create_dir_all(&part_dir)?;
+        let mut df = df![
+            "key" => [part * 2, part * 2 + 1],
+        ]?;
+        let path = part_dir.join("0.parquet");
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        // Only the partitions that are selected below are read.
+        if part != 1 && part != 3 {
+            corrupt_row_groups(&path, |_| true)?;
+        }
+    }
+
+    // The values of the context decide which partitions are read, as for an SQL `IN` subquery.
+    let filter = |values: &[i64]| -> PolarsResult<DataFrame> {
+        let values = df!["values" => values]?
+            .lazy()
+            .filter(col("values").gt(lit(0)));
+        LazyFrame::scan_parquet(dir, ScanArgsParquet::default())?
+            .with_context([values])
+            .filter(col("part").is_in(col("values"), false))
+            .sort(["key"], Default::default())
+            .collect()
+    };
+
+    let out = filter(&[1, 3, 7])?;
+    assert_eq!(
+        out.column("key")?.i64()?.to_vec(),
+        &[Some(2), Some(3), Some(6), Some(7)]
+    );
+    let out = filter(&[-1])?;
+    assert_eq!(out.shape(), (0, 2));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_sorting_columns() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path();
+
+    let write = |name: &str, range: std::ops::Range<i64>| -> PolarsResult<std::path::PathBuf> {
+        let mut a = Series::new("a".into(), range.clone().collect::<Vec<_>>());
+        a.set_sorted_flag(IsSorted::Ascending);
+        let mut b = Series::new("b".into(), range.rev().collect::<Vec<_>>());
+        b.set_sorted_flag(IsSorted::Descending);
+        let c = Series::new("c".into(), [1i64, 3, 2, 4, 5, 6, 7, 8, 9, 10]);
+        let mut df = DataFrame::new(vec![a.into(), b.into(), c.into()])?;
+        let path = dir.join(name);
+        ParquetWriter::new(std::fs::File::create(&path)?)
+            .with_row_group_size(Some(4))
+            .finish(&mut df)?;
+        Ok(path)
+    };
+    let first = write("0.parquet", 0..10)?;
+    let second = write("1.parquet", 10..20)?;
+    let overlapping = write("2.parquet", 5..15)?;
+
+    let mut reader = ParquetReader::new(std::fs::File::open(&first)?);
+    let md = reader.get_metadata()?.clone();
+    assert!(md.row_groups.len() > 1);
+    for rg in &md.row_groups {
+        let sorting = rg
+            .sorting_columns()
+            .unwrap()
+            .iter()
+            .map(|s| (s.column_idx, s.descending))
+            .collect::<Vec<_>>();
+        assert_eq!(sorting, [(0, false), (1, true)]);
+    }
+
+    // The rows are sorted by `b` only within equal values of `a`, so only `a` is flagged.
+    let df = reader.finish()?;
+    assert_eq!(df.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(df.column("b")?.is_sorted_flag(), IsSorted::Not);
+    assert_eq!(df.column("c")?.is_sorted_flag(), IsSorted::Not);
+
+    // The sort order is known at planning time, so sorting again is a no-op.
+    let lf = LazyFrame::scan_parquet(&first, ScanArgsParquet::default())?
+        .with_sort_distinct_elim(true)
+        .sort(["a"], Default::default());
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = lf.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(!(&lp_arena)
+        .iter(root)
+        .any(|(_, lp)| matches!(lp, IR::Sort { .. })));
+    assert!(lf.collect()?.equals(&df));
+
+    // Metadata is not trusted by default.
+    let lf = LazyFrame::scan_parquet(&first, ScanArgsParquet::default())?
+        .sort(["a"], Default::default());
+    let root = lf.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena)
+        .iter(root)
+        .any(|(_, lp)| matches!(lp, IR::Sort { .. })));
+
+    // Multiple files keep the flag only if they are ordered.
+    let scan = |paths: &[&std::path::PathBuf]| {
+        let paths = paths.iter().map(|p| (*p).clone()).collect::<Vec<_>>();
+        LazyFrame::scan_parquet_files(paths.into(), ScanArgsParquet::default())?.collect()
+    };
+    let df = scan(&[&first, &second])?;
+    assert_eq!(df.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+    assert_eq!(df.column("b")?.is_sorted_flag(), IsSorted::Not);
+    let df = scan(&[&first, &overlapping])?;
+    assert_eq!(df.column("a")?.is_sorted_flag(), IsSorted::Not);
+
+    // Only the footer of the first file is read while planning, so multiple files are not known
+    // to be sorted.
+    let has_sort = |paths: &[&std::path::PathBuf]| -> PolarsResult<bool> {
+        let paths = paths.iter().map(|p| (*p).clone()).collect::<Vec<_>>();
+        let lf = LazyFrame::scan_parquet_files(paths.into(), ScanArgsParquet::default())?
+            .with_sort_distinct_elim(true)
+            .sort(["a"], Default::default());
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let root = lf.optimize(&mut lp_arena, &mut expr_arena)?;
+        Ok((&lp_arena)
+            .iter(root)
+            .any(|(_, lp)| matches!(lp, IR::Sort { .. })))
+    };
+    assert!(!has_sort(&[&first])?);
+    assert!(has_sort(&[&first, &second])?);
+
+    // Descending row groups are ordered only if they don't overlap.
+    let write_descending = |name: &str, batches: &[std::ops::Range<i64>]| {
+        let path = dir.join(name);
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+        let mut writer = ParquetWriter::new(std::fs::File::create(&path)?).batched(&schema)?;
+        for range in batches {
+            let mut a = Series::new("a".into(), range.clone().rev().collect::<Vec<_>>());
+            a.set_sorted_flag(IsSorted::Descending);
+            writer.write_batch(&DataFrame::new(vec![a.into()])?)?;
+        }
+        writer.finish()?;
+        let md = ParquetReader::new(std::fs::File::open(&path)?)
+            .get_metadata()?
+            .clone();
+        assert_eq!(md.row_groups.len(), batches.len());
+        PolarsResult::Ok(polars_io::parquet::metadata::file_sorting_columns(&md))
+    };
+    assert!(write_descending("3.parquet", &[5..11, 7..13])?.is_empty());
+    let sorting = write_descending("4.parquet", &[7..13, 0..6])?;
+    assert_eq!(sorting.len(), 1);
+    assert!(sorting[0].descending);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "persistent_cache")]
+fn test_cache_persistent() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let dir = dir.path();
+    let cache_dir = dir.join("cache");
+    let cache_entries = || -> PolarsResult<Vec<std::path::PathBuf>> {
+        Ok(std::fs::read_dir(&cache_dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<_, _>>()?)
+    };
+
+    let path = dir.join("input.parquet");
+    let write_input = |n: i64| -> PolarsResult<()> {
+        let mut df = df![
+            "a" => (0..n).map(|i| i % 3).collect::<Vec<_>>(),
+            "b" => (0..n).collect::<Vec<_>>(),
+        ]?;
+        ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+        Ok(())
+    };
+    write_input(10)?;
+
+    let query = || -> PolarsResult<LazyFrame> {
+        LazyFrame::scan_parquet(&path, Default::default())?
+            .group_by([col("a")])
+            .agg([col("b").sum()])
+            .sort(["a"], Default::default())
+            .cache_persistent(&cache_dir)
+    };
+
+    let expected = df![
+        "a" => [0i64, 1, 2],
+        "b" => [18i64, 12, 15],
+    ]?;
+    let out = query()?.collect()?;
+    assert!(out.equals(&expected));
+    let entries = cache_entries()?;
+    assert_eq!(entries.len(), 1);
+
+    // A new query with the same plan reads the cached result.
+    let mut tampered = df!["a" => [7i64], "b" => [7i64]]?;
+    IpcWriter::new(std::fs::File::create(&entries[0])?).finish(&mut tampered)?;
+    let out = query()?.collect()?;
+    assert!(out.equals(&tampered));
+    let out = query()?.select([col("b")]).collect()?;
+    assert!(out.equals(&tampered.select(["b"])?));
+
+    // Modifying the input invalidates the result.
+    write_input(12)?;
+    let out = query()?.collect()?;
+    let expected = df![
+        "a" => [0i64, 1, 2],
+        "b" => [18i64, 22, 26],
+    ]?;
+    assert!(out.equals(&expected));
+    assert_eq!(cache_entries()?.len(), 2);
+
+    // Plans with user-defined functions can't be serialized, so their results aren't cached.
+    let udf = expected
+        .lazy()
+        .map(Ok, Default::default(), None, None)
+        .cache_persistent(&cache_dir)?;
+    assert!(udf.collect().is_err());
+
+    Ok(())
+}
@@ -681,3 +681,177 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_join_reorder() -> PolarsResult<()> {
+    let n = 2000;
+    let facts = df![
+        "id" => (0..n).collect::<Vec<i32>>(),
+        "a" => (0..n).map(|i| i % 50).collect::<Vec<i32>>(),
+        "b" => (0..n).collect::<Vec<i32>>(),
+    ]?;
+    let mid = df![
+        "a" => (0..50).collect::<Vec<i32>>(),
+        "x" => (0..50).map(|i| i * 2).collect::<Vec<i32>>(),
+    ]?;
+    let small = df![
+        "b" => [3, 500, 1999, 5000, 7],
+        "y" => ["p", "q", "r", "s", "t"],
+    ]?;
+
+    let q = facts
+        .lazy()
+        .inner_join(mid.lazy(), col("a"), col("a"))
+        .inner_join(small.lazy(), col("b"), col("b"));
+
+    // The selective join with `small` has to be executed first.
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q
+        .clone()
+        .with_join_reorder(true)
+        .optimize(&mut lp_arena, &mut expr_arena)?;
+    let heights = |node: Node| match lp_arena.get(node) {
+        IR::DataFrameScan { df, .. } => Some(df.height()),
+        _ => None,
+    };
+    let first_join = (&lp_arena).iter(lp).find_map(|(_, lp)| match lp {
+        IR::Join {
+            input_left,
+            input_right,
+            ..
+        } => Some((heights(*input_left)?, heights(*input_right)?)),
+        _ => None,
+    });
+    assert_eq!(first_join, Some((2000, 5)));
+
+    let expected = q.clone().collect()?.sort(["id"], Default::default())?;
+    let out = q
+        .with_join_reorder(true)
+        .collect()?
+        .sort(["id"], Default::default())?;
+    assert_eq!(out.get_column_names(), ["id", "a", "b", "x", "y"]);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_join_reorder_renamed_keys() -> PolarsResult<()> {
+    let a = df![
+        "k" => (0..100).collect::<Vec<i32>>(),
+        "v" => (0..100).collect::<Vec<i32>>(),
+    ]?;
+    let b = df![
+        "bk" => (0..100).map(|i| i % 10).collect::<Vec<i32>>(),
+        "w" => (0..100).collect::<Vec<i32>>(),
+    ]?;
+    let c = df![
+        "ck" => [1, 2],
+        "z" => [10, 20],
+    ]?;
+
+    let q = a
+        .lazy()
+        .inner_join(b.lazy(), col("k"), col("bk"))
+        .inner_join(c.lazy(), col("k"), col("ck"));
+
+    let expected = q.clone().collect()?.sort(["v", "w"], Default::default())?;
+    let out = q
+        .with_join_reorder(true)
+        .collect()?
+        .sort(["v", "w"], Default::default())?;
+    assert_eq!(out.get_column_names(), ["k", "v", "w", "z"]);
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+fn count_nodes(q: LazyFrame, f: impl Fn(&IR) -> bool) -> PolarsResult<usize> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    Ok((&lp_arena).iter(lp).filter(|(_, lp)| f(lp)).count())
+}
+
+#[test]
+fn test_sort_distinct_elim() -> PolarsResult<()> {
+    let df = df![
+        "a" => [3, 1, 2, 1],
+        "b" => [1, 2, 3, 4],
+    ]?;
+    let is_sort = |lp: &IR| matches!(lp, IR::Sort { .. });
+    let is_distinct = |lp: &IR| matches!(lp, IR::Distinct { .. });
+
+    // Sorting twice on the same key.
+    let q = df
+        .clone()
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .sort(["a"], Default::default())
+        .with_columns([(col("b") * lit(2)).alias("c")])
+        .filter(col("b").gt(lit(1)))
+        .sort(["a"], Default::default());
+    assert_eq!(count_nodes(q.clone(), is_sort)?, 1);
+    assert_eq!(
+        count_nodes(q.clone().with_sort_distinct_elim(false), is_sort)?,
+        2
+    );
+    assert!(q
+        .collect()?
+        .equals(&df!["a" => [1, 1, 2], "b" => [2, 4, 3], "c" => [4, 8, 6]]?));
+
+    // A different order is still applied.
+    let q = df
+        .clone()
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .sort(["a"], Default::default())
+        .sort(
+            ["a"],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_maintain_order(true),
+        );
+    assert_eq!(count_nodes(q, is_sort)?, 2);
+
+    // The sort key was overwritten.
+    let q = df
+        .clone()
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .sort(["a"], Default::default())
+        .with_columns([col("b").alias("a")])
+        .sort(
+            ["a"],
+            SortMultipleOptions::default().with_maintain_order(true),
+        );
+    assert_eq!(count_nodes(q, is_sort)?, 2);
+
+    // The sorted flag of a `DataFrame` is used.
+    let sorted = df.sort(["a"], Default::default())?;
+    let q = sorted
+        .clone()
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .sort(["a"], Default::default());
+    assert_eq!(count_nodes(q.clone(), is_sort)?, 0);
+    assert!(q.collect()?.equals(&sorted));
+
+    // The keys of a group-by are unique.
+    let q = df
+        .clone()
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .group_by([col("a")])
+        .agg([col("b").sum()])
+        .unique(Some(vec!["a".to_string()]), UniqueKeepStrategy::First);
+    assert_eq!(count_nodes(q.clone(), is_distinct)?, 0);
+    assert_eq!(q.collect()?.height(), 3);
+
+    let q = df
+        .lazy()
+        .with_sort_distinct_elim(true)
+        .group_by([col("a")])
+        .agg([col("b").sum()])
+        .unique(Some(vec!["b".to_string()]), UniqueKeepStrategy::First);
+    assert_eq!(count_nodes(q, is_distinct)?, 1);
+
+    Ok(())
+}
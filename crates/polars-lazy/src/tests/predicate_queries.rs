@@ -330,3 +330,98 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_predicate_pushdown_window_partition_keys() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 1, 2, 2],
+        "b" => [1, 2, 3, 4],
+    ]?;
+
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns([col("b").sum().over([col("k")]).alias("s")])
+        .filter(col("k").eq(lit(1)));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    let expected = df![
+        "k" => [1, 1],
+        "b" => [1, 2],
+        "s" => [3, 3],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // The window output overwrites the partition column, so the predicate
+    // refers to the window result and must stay above it.
+    let q = df
+        .clone()
+        .lazy()
+        .with_columns([col("b").sum().over([col("k")]).alias("k")])
+        .filter(col("k").eq(lit(3)));
+    assert!(!predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    let expected = df![
+        "k" => [3, 3],
+        "b" => [1, 2],
+    ]?;
+    assert!(out.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_predicate_pushdown_explode_non_exploded() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 2],
+        "l" => [Series::new("".into(), [1, 2]), Series::new("".into(), [3, 4])],
+    ]?;
+
+    let q = df
+        .lazy()
+        .explode([col("l")])
+        .filter(col("k").eq(lit(2)).and(col("l").gt(lit(3))));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    // The predicate on `l` stays above the explode, the one on `k` goes below it.
+    assert!(matches!(lp_arena.get(root), IR::Filter { .. }));
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    let expected = df![
+        "k" => [2],
+        "l" => [4],
+    ]?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_predicate_pushdown_unpivot_index() -> PolarsResult<()> {
+    let df = df![
+        "k" => [1, 2],
+        "b" => [1, 2],
+        "c" => [3, 4],
+    ]?;
+
+    let q = df
+        .lazy()
+        .unpivot(UnpivotArgsDSL {
+            on: vec!["b".into(), "c".into()],
+            index: vec!["k".into()],
+            ..Default::default()
+        })
+        .filter(col("k").eq(lit(2)).and(col("value").gt(lit(2))));
+
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let root = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!(matches!(lp_arena.get(root), IR::Filter { .. }));
+    assert!(predicate_at_scan(q.clone()));
+
+    let out = q.collect()?;
+    assert_eq!(out.column("value")?.i32()?.get(0), Some(4));
+    assert_eq!(out.height(), 1);
+    Ok(())
+}
@@ -1948,3 +1948,146 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_explain_analyze() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+    ]?;
+    let q = df
+        .lazy()
+        .filter(col("a").lt(lit(100)))
+        .select([col("a") * lit(2)]);
+
+    let (out, metrics) = q.clone().explain_analyze()?;
+    assert!(out.equals(&q.collect()?));
+
+    let nodes = &metrics.nodes;
+    let node = |name: &str| -> PolarsResult<usize> {
+        let names = nodes.column("node")?.str()?;
+        Ok(names.iter().position(|n| n == Some(name)).unwrap())
+    };
+    let get = |col: &str, idx: usize| -> PolarsResult<u64> {
+        Ok(nodes.column(col)?.u64()?.get(idx).unwrap())
+    };
+
+    let sink = node("in-memory-sink")?;
+    assert_eq!(sink, 0);
+    assert_eq!(get("rows_in", sink)?, 100);
+    assert_eq!(get("rows_out", sink)?, 0);
+    assert!(get("bytes_in", sink)? > 0);
+    assert!(get("peak_buffered_bytes", sink)? > 0);
+
+    let filter = node("filter")?;
+    assert_eq!(get("rows_in", filter)?, 1000);
+    assert_eq!(get("rows_out", filter)?, 100);
+    assert_eq!(get("morsels_in", filter)?, get("morsels_out", filter)?);
+
+    let source = node("in-memory-source")?;
+    assert_eq!(get("rows_out", source)?, 1000);
+    assert_eq!(get("rows_in", source)?, 0);
+
+    assert!(metrics.plan.starts_with("in-memory-sink\n"));
+    assert!(metrics.plan.contains("rows: 1000 in, 100 out"));
+    Ok(())
+}
+
+#[test]
+fn test_collect_with_token() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use polars_core::error::signals::{
+        catch_cancellation, current_cancellation_token, with_cancellation_token,
+    };
+    use polars_core::POOL;
+
+    let df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+    ]?;
+    let q = || {
+        df.clone()
+            .lazy()
+            .group_by([col("a") % lit(10)])
+            .agg([col("a").sum().alias("sum")])
+            .sort(["a"], Default::default())
+    };
+    let expected = q().collect()?;
+
+    let out = q().collect_with_token(CancellationToken::new())?;
+    assert!(out.equals(&expected));
+    let query = q().collect_async(CancellationToken::new())?;
+    assert!(query.fetch_blocking()?.equals(&expected));
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let err = q().collect_with_token(token).unwrap_err();
+    assert!(err.to_string().contains("query interrupted"));
+
+    let token = CancellationToken::with_timeout(std::time::Duration::ZERO);
+    let err = q().collect_with_token(token).unwrap_err();
+    assert!(err.to_string().contains("query timed out"));
+
+    // Cancel the query while it runs.
+    let cancelled_during_query = |lf: LazyFrame| {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        let err = lf
+            .select([col("a").map(
+                move |c| {
+                    cancel.cancel();
+                    Ok(Some(c))
+                },
+                GetOutput::same_type(),
+            )])
+            .group_by([col("a")])
+            .agg([col("a").count().alias("n")])
+            .collect_with_token(token)
+            .unwrap_err();
+        assert!(err.to_string().contains("query interrupted"));
+    };
+    cancelled_during_query(df.clone().lazy());
+    #[cfg(feature = "new_streaming")]
+    cancelled_during_query(df.clone().lazy().with_new_streaming(true));
+
+    // The token is not installed on threads of the pool, so it is only checked when the query
+    // starts.
+    let (out, installed) = POOL.install(|| {
+        let token = CancellationToken::new();
+        let installed = Arc::new(AtomicBool::new(true));
+        let (cancel, seen) = (token.clone(), installed.clone());
+        let out = df
+            .clone()
+            .lazy()
+            .select([col("a").map(
+                move |c| {
+                    seen.store(current_cancellation_token().is_some(), Ordering::Relaxed);
+                    cancel.cancel();
+                    Ok(Some(c))
+                },
+                GetOutput::same_type(),
+            )])
+            .collect_with_token(token.clone());
+        let err = df.clone().lazy().collect_with_token(token).unwrap_err();
+        assert!(err.to_string().contains("query interrupted"));
+        (out, installed.load(Ordering::Relaxed))
+    });
+    assert!(out?.equals(&df));
+    assert!(!installed);
+
+    // Tokens only slow down the checks of kernels while they are installed, and a timeout is
+    // noticed by the cheap checks once it was seen at a chunk boundary.
+    let token = CancellationToken::with_timeout(std::time::Duration::ZERO);
+    let err = catch_cancellation(|| {
+        with_cancellation_token(Some(token.clone()), || {
+            try_raise_keyboard_interrupt();
+            assert!(token.is_cancelled());
+            try_raise_keyboard_interrupt();
+            Ok(())
+        })
+    })
+    .unwrap_err();
+    assert!(err.to_string().contains("query timed out"));
+    try_raise_keyboard_interrupt();
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::path::PathBuf;
+
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::{DeltaOperation, DeltaSnapshot, DeltaTable, DeltaTransaction};
+
+use super::*;
+use crate::ScanPredicate;
+
+pub struct DeltaSinkExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) path: Arc<PathBuf>,
+    pub(crate) options: DeltaWriteOptions,
+    pub(crate) cloud_options: Option<CloudOptions>,
+    /// The predicate of [`DeltaWriteMode::OverwriteWhere`].
+    pub(crate) predicate: Option<ScanPredicate>,
+}
+
+/// Evaluate a predicate to a mask without nulls, with a value for every row of `df`.
+fn evaluate_mask(
+    predicate: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<BooleanChunked> {
+    let mask = predicate.evaluate(df, state)?;
+    let mask = mask.bool().map_err(|_| {
+        polars_err!(
+            ComputeError: "Delta overwrite predicate must be of type `Boolean`, got `{}`", mask.dtype()
+        )
+    })?;
+    let mask = mask.fill_null_with_values(false)?;
+    Ok(if mask.len() == 1 && df.height() != 1 {
+        mask.new_from_index(0, df.height())
+    } else {
+        mask
+    })
+}
+
+impl DeltaSinkExec {
+    /// Remove the rows of the table that match the predicate. Files in which only some rows match
+    /// are rewritten without those rows.
+    fn remove_matching(
+        &self,
+        txn: &mut DeltaTransaction,
+        snapshot: &DeltaSnapshot,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        let Some(predicate) = &self.predicate else {
+            return Ok(());
+        };
+
+        let partition_columns = &snapshot.metadata.partition_columns;
+        if predicate
+            .live_columns
+            .iter()
+            .all(|name| partition_columns.contains(name))
+        {
+            // All rows of a file have the same partition values, so the file matches as a whole.
+            let mask = evaluate_mask(
+                predicate.predicate.as_ref(),
+                &snapshot.partition_values()?,
+                state,
+            )?;
+            for (file, matches) in snapshot.files.iter().zip(mask.into_no_null_iter()) {
+                if matches {
+                    txn.remove(file);
+                }
+            }
+            return Ok(());
+        }
+
+        // Files whose statistics show that no row matches are kept without reading them.
+        let skip = match &predicate.skip_batch_predicate {
+            Some(skip_batch_predicate) => {
+                let statistics = snapshot.file_statistics()?;
+                let skip = skip_batch_predicate.evaluate(&statistics, state)?;
+                let skip = skip.bool()?;
+                if skip.len() == 1 {
+                    vec![skip.get(0).unwrap_or(false); snapshot.files.len()]
+                } else {
+                    skip.iter().map(|skip| skip.unwrap_or(false)).collect()
+                }
+            },
+            None => vec![false; snapshot.files.len()],
+        };
+
+        for (file, skip) in snapshot.files.iter().zip(skip) {
+            state.should_stop()?;
+            if skip {
+                continue;
+            }
+            let df = snapshot.read_file(file)?;
+            let mask = evaluate_mask(predicate.predicate.as_ref(), &df, state)?;
+            let n_matching = mask.num_trues();
+            if n_matching == 0 {
+                continue;
+            }
+            txn.remove(file);
+            if n_matching < df.height() {
+                txn.write(&df.filter(&!&mask)?)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Executor for DeltaSinkExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run DeltaSinkExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+
+        state.clone().record(
+            || {
+                let uri = self.path.to_str().ok_or_else(
+                    || polars_err!(ComputeError: "invalid Delta table path {:?}", self.path),
+                )?;
+                let table = DeltaTable::open(uri, self.cloud_options.as_ref())?;
+                let operation = match &self.options.mode {
+                    DeltaWriteMode::Append => DeltaOperation::Append,
+                    DeltaWriteMode::Overwrite => DeltaOperation::Overwrite,
+                    DeltaWriteMode::OverwriteWhere(predicate) => DeltaOperation::OverwriteWhere {
+                        predicate: format!("{predicate:?}"),
+                    },
+                };
+                let mut txn = DeltaTransaction::new(
+                    &table,
+                    operation,
+                    df.schema(),
+                    &self.options.partition_by,
+                    self.options.parquet_options,
+                )?;
+
+                if let Some(predicate) = &self.predicate {
+                    let mask = evaluate_mask(predicate.predicate.as_ref(), &df, state)?;
+                    polars_ensure!(
+                        mask.num_trues() == df.height(),
+                        ComputeError: "all rows written to a Delta table with `OverwriteWhere` must match the predicate"
+                    );
+                    if let Some(snapshot) = txn.snapshot().cloned() {
+                        self.remove_matching(&mut txn, &snapshot, state)?;
+                    }
+                }
+
+                txn.write(&df)?;
+                txn.commit()?;
+                Ok(DataFrame::empty())
+            },
+            Cow::Borrowed("sink_delta"),
+        )
+    }
+}
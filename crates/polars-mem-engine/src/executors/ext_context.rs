@@ -3,6 +3,9 @@ use super::*;
 pub struct ExternalContext {
     pub input: Box<dyn Executor>,
     pub contexts: Vec<Box<dyn Executor>>,
+    /// The context columns that `is_in` predicates on the input filter with, with the scans of
+    /// the input that they filter.
+    pub runtime_filters: RuntimeFilterTargets,
 }
 
 impl Executor for ExternalContext {
@@ -19,6 +22,14 @@ impl Executor for ExternalContext {
             .iter_mut()
             .map(|e| e.execute(state))
             .collect::<PolarsResult<Vec<_>>>()?;
+        for filter in &self.runtime_filters {
+            if let Some(df) = contexts
+                .iter()
+                .find(|df| df.get_column_index(&filter.0).is_some())
+            {
+                publish_runtime_filters(std::slice::from_ref(filter), df, state.verbose())?;
+            }
+        }
         state.ext_contexts = Arc::new(contexts);
         let df = self.input.execute(state)?;
 
@@ -0,0 +1,33 @@
+use polars_io::ipc::{IpcStreamWriter, IpcWriterOptions};
+use polars_io::SerWriter;
+
+use super::*;
+
+pub struct IpcStreamSinkExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) writer: DynWriter,
+    pub(crate) options: IpcWriterOptions,
+}
+
+impl Executor for IpcStreamSinkExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run IpcStreamSinkExec")
+            }
+        }
+        let mut df = self.input.execute(state)?;
+
+        state.clone().record(
+            || {
+                IpcStreamWriter::new(self.writer.take()?)
+                    .with_compression(self.options.compression)
+                    .finish(&mut df)?;
+                Ok(DataFrame::empty())
+            },
+            Cow::Borrowed("sink_ipc_stream"),
+        )
+    }
+}
@@ -1,7 +1,55 @@
+use polars_io::predicates::{RuntimeFilters, RuntimeJoinFilterBuilder};
 use polars_ops::frame::DataFrameJoinOps;
 
 use super::*;
 
+/// Key columns, with the filter slots of the scans that they filter and the column of each scan.
+pub type RuntimeFilterTargets = Vec<(PlSmallStr, Vec<(RuntimeFilters, PlSmallStr)>)>;
+
+/// Publish the values of the key columns of `df` to the scans that they filter.
+pub(super) fn publish_runtime_filters(
+    targets: &[(PlSmallStr, Vec<(RuntimeFilters, PlSmallStr)>)],
+    df: &DataFrame,
+    verbose: bool,
+) -> PolarsResult<()> {
+    for (key, targets) in targets {
+        let mut builder = RuntimeJoinFilterBuilder::default();
+        builder.update(df.column(key)?.as_materialized_series())?;
+        for (slot, column) in targets {
+            slot.publish(builder.finish(column.clone()));
+        }
+        if verbose {
+            eprintln!(
+                "runtime filters: published filter on {} to {} scans",
+                key,
+                targets.len()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Publish the runtime filters of `df` if it is the left or right build side of the join.
+fn publish_build_side(
+    filters: Option<&JoinRuntimeFilters>,
+    left: bool,
+    df: PolarsResult<DataFrame>,
+    verbose: bool,
+) -> PolarsResult<DataFrame> {
+    let df = df?;
+    if let Some(filters) = filters.filter(|f| f.build_left == left) {
+        publish_runtime_filters(&filters.filters, &df, verbose)?;
+    }
+    Ok(df)
+}
+
+/// The runtime filters that a join publishes to the scans of its probe side.
+pub struct JoinRuntimeFilters {
+    pub build_left: bool,
+    /// Every build key column, with the filter slots of the probe-side scans and their column.
+    pub filters: RuntimeFilterTargets,
+}
+
 pub struct JoinExec {
     input_left: Option<Box<dyn Executor>>,
     input_right: Option<Box<dyn Executor>>,
@@ -10,6 +58,7 @@ pub struct JoinExec {
     parallel: bool,
     args: JoinArgs,
     options: Option<JoinTypeOptions>,
+    runtime_filters: Option<JoinRuntimeFilters>,
 }
 
 impl JoinExec {
@@ -22,6 +71,7 @@ impl JoinExec {
         parallel: bool,
         args: JoinArgs,
         options: Option<JoinTypeOptions>,
+        runtime_filters: Option<JoinRuntimeFilters>,
     ) -> Self {
         JoinExec {
             input_left: Some(input_left),
@@ -31,6 +81,7 @@ impl JoinExec {
             parallel,
             args,
             options,
+            runtime_filters,
         }
     }
 }
@@ -50,6 +101,11 @@ impl Executor for JoinExec {
         let mut input_left = self.input_left.take().unwrap();
         let mut input_right = self.input_right.take().unwrap();
 
+        // The build side publishes its filters as soon as it is materialized. The scans of the
+        // probe side poll them, so they skip the batches they read afterwards.
+        let runtime_filters = self.runtime_filters.as_ref();
+        let build_left = runtime_filters.is_none_or(|f| f.build_left);
+
         let (df_left, df_right) = if self.parallel {
             let mut state_right = state.split();
             let mut state_left = state.split();
@@ -57,18 +113,65 @@ impl Executor for JoinExec {
             // propagate the fetch_rows static value to the spawning threads.
             let fetch_rows = FETCH_ROWS.with(|fetch_rows| fetch_rows.get());
 
-            POOL.join(
-                move || {
-                    FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_left.execute(&mut state_left)
-                },
-                move || {
-                    FETCH_ROWS.with(|fr| fr.set(fetch_rows));
-                    input_right.execute(&mut state_right)
-                },
+            let left = move || {
+                FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                let verbose = state_left.verbose();
+                publish_build_side(
+                    runtime_filters,
+                    true,
+                    input_left.execute(&mut state_left),
+                    verbose,
+                )
+            };
+            let right = move || {
+                FETCH_ROWS.with(|fr| fr.set(fetch_rows));
+                let verbose = state_right.verbose();
+                publish_build_side(
+                    runtime_filters,
+                    false,
+                    input_right.execute(&mut state_right),
+                    verbose,
+                )
+            };
+            // The first closure runs on this thread, so the build side starts first.
+            if build_left {
+                POOL.join(left, right)
+            } else {
+                let (df_right, df_left) = POOL.join(right, left);
+                (df_left, df_right)
+            }
+        } else if build_left {
+            let df_left = publish_build_side(
+                runtime_filters,
+                true,
+                input_left.execute(state),
+                state.verbose(),
+            );
+            (
+                df_left,
+                publish_build_side(
+                    runtime_filters,
+                    false,
+                    input_right.execute(state),
+                    state.verbose(),
+                ),
             )
         } else {
-            (input_left.execute(state), input_right.execute(state))
+            let df_right = publish_build_side(
+                runtime_filters,
+                false,
+                input_right.execute(state),
+                state.verbose(),
+            );
+            (
+                publish_build_side(
+                    runtime_filters,
+                    true,
+                    input_left.execute(state),
+                    state.verbose(),
+                ),
+                df_right,
+            )
         };
 
         let df_left = df_left?;
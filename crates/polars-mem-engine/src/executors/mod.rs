@@ -1,4 +1,6 @@
 mod cache;
+#[cfg(feature = "delta")]
+mod delta_sink;
 mod executor;
 mod ext_context;
 mod filter;
@@ -7,6 +9,8 @@ mod group_by_dynamic;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod hconcat;
+#[cfg(feature = "ipc_streaming")]
+mod ipc_stream_sink;
 mod join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
@@ -32,6 +36,8 @@ use projection_utils::*;
 use rayon::prelude::*;
 
 pub(super) use self::cache::*;
+#[cfg(feature = "delta")]
+pub(super) use self::delta_sink::*;
 pub(super) use self::ext_context::*;
 pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
@@ -41,6 +47,8 @@ pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
 pub(super) use self::hconcat::*;
+#[cfg(feature = "ipc_streaming")]
+pub(super) use self::ipc_stream_sink::*;
 pub(super) use self::join::*;
 #[cfg(feature = "merge_sorted")]
 pub(super) use self::merge_sorted::*;
@@ -134,6 +134,7 @@ fn source_to_exec(
                 cloud_options.clone(),
                 file_options.clone(),
                 metadata.cloned(),
+                None,
             ))
         },
         #[cfg(feature = "csv")]
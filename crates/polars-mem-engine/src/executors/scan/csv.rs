@@ -296,6 +296,7 @@ impl ScanExec for CsvExec {
             bytes,
             popt.separator,
             popt.quote_char,
+            popt.escape_char,
             popt.comment_prefix.as_ref(),
             popt.eol_char,
             self.options.has_header,
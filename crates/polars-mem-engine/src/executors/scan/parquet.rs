@@ -6,7 +6,7 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_error::feature_gated;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetadataRef;
-use polars_io::predicates::{ScanIOPredicate, SkipBatchPredicate};
+use polars_io::predicates::{RuntimeFilters, ScanIOPredicate, SkipBatchPredicate};
 use polars_io::utils::slice::split_slice_at_file;
 use polars_io::RowIndex;
 
@@ -21,6 +21,8 @@ pub struct ParquetExec {
 
     predicate: Option<ScanPredicate>,
     skip_batch_predicate: Option<Arc<dyn SkipBatchPredicate>>,
+    /// Filters on the keys of this scan, that are published before it is executed.
+    runtime_filters: Option<RuntimeFilters>,
 
     pub(crate) options: ParquetOptions,
     #[allow(dead_code)]
@@ -41,6 +43,7 @@ impl ParquetExec {
         cloud_options: Option<CloudOptions>,
         file_options: FileScanOptions,
         metadata: Option<FileMetadataRef>,
+        runtime_filters: Option<RuntimeFilters>,
     ) -> Self {
         ParquetExec {
             sources,
@@ -50,6 +53,7 @@ impl ParquetExec {
 
             predicate,
             skip_batch_predicate: None,
+            runtime_filters,
 
             options,
             cloud_options,
@@ -87,6 +91,10 @@ impl ParquetExec {
                 self.file_info.schema.clone(),
             )
         });
+        let predicate = match &self.runtime_filters {
+            Some(filters) => filters.apply(predicate, &self.file_info.schema),
+            None => predicate,
+        };
         let mut base_row_index = self.file_options.row_index.take();
 
         // (offset, end)
@@ -299,6 +307,10 @@ impl ParquetExec {
                 .or_else(|| p.to_dyn_skip_batch_predicate(self.file_info.schema.clone())),
             column_predicates: Arc::new(Default::default()),
         });
+        let predicate = match &self.runtime_filters {
+            Some(filters) => filters.apply(predicate, &self.file_info.schema),
+            None => predicate,
+        };
         let mut base_row_index = self.file_options.row_index.take();
 
         // Modified if we have a negative slice
@@ -496,7 +508,48 @@ impl ParquetExec {
         Ok(result)
     }
 
+    /// Drop the files of which the hive partitions cannot contain rows that pass the runtime
+    /// filters.
+    // TODO: Prune the partition directories while they are listed. This requires the paths to be
+    // expanded when the scan runs rather than when the plan is built.
+    fn apply_runtime_filters_to_hive_parts(&mut self) {
+        let (Some(filters), Some(hive_parts), ScanSources::Paths(paths)) =
+            (&self.runtime_filters, &self.hive_parts, &self.sources)
+        else {
+            return;
+        };
+
+        let mut keep = (0..paths.len())
+            .filter(|&i| !filters.can_skip(hive_parts[i].get_statistics()))
+            .collect::<Vec<_>>();
+        if keep.len() == paths.len() {
+            return;
+        }
+        if config::verbose() {
+            eprintln!(
+                "runtime filters: skipped {} files, first file : {}",
+                paths.len() - keep.len(),
+                paths[0].display()
+            )
+        }
+        // Read a single file if all are skipped, so that the output keeps its schema. Its rows
+        // are removed by the predicate of the runtime filters.
+        if keep.is_empty() {
+            keep.push(0);
+        }
+        if keep[0] != 0 {
+            self.metadata = None;
+        }
+
+        let paths = keep.iter().map(|&i| paths[i].clone()).collect::<Vec<_>>();
+        let hive_parts = keep.iter().map(|&i| hive_parts[i].clone()).collect();
+        self.sources = ScanSources::Paths(paths.into());
+        self.hive_parts = Some(Arc::new(hive_parts));
+    }
+
     fn read_impl(&mut self) -> PolarsResult<DataFrame> {
+        self.apply_runtime_filters_to_hive_parts();
+
         // FIXME: The row index implementation is incorrect when a predicate is
         // applied. This code mitigates that by applying the predicate after the
         // collection of the entire dataframe if a row index is requested. This is
@@ -1,6 +1,10 @@
+use std::cell::RefCell;
+
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_expr::state::ExecutionState;
+use polars_io::predicates::RuntimeFilters;
+use polars_ops::frame::JoinArgs;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_utils::format_pl_smallstr;
@@ -50,16 +54,80 @@ fn partitionable_gb(
 
 struct ConversionState {
     expr_depth: u16,
+    /// The runtime filter slots of the scans that joins publish filters to.
+    runtime_filters: RefCell<PlHashMap<Node, RuntimeFilters>>,
+    /// The runtime filters that external contexts publish for the `is_in` predicates on them.
+    context_filters: RefCell<PlHashMap<Node, executors::RuntimeFilterTargets>>,
 }
 
 impl ConversionState {
     fn new() -> PolarsResult<Self> {
         Ok(ConversionState {
             expr_depth: get_expr_depth_limit()?,
+            runtime_filters: Default::default(),
+            context_filters: Default::default(),
         })
     }
 }
 
+/// Register the runtime filters that the build side of a join publishes to the scans of its
+/// probe side. The right side is preferred as build side.
+#[allow(clippy::too_many_arguments)]
+fn create_join_runtime_filters(
+    input_left: Node,
+    input_right: Node,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    args: &JoinArgs,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    state: &ConversionState,
+) -> Option<executors::JoinRuntimeFilters> {
+    let (build_left, targets) = [false, true]
+        .into_iter()
+        .map(|build_left| {
+            let targets = join_runtime_filter_targets(
+                build_left,
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                args,
+                lp_arena,
+                expr_arena,
+            );
+            (build_left, targets)
+        })
+        .find(|(_, targets)| !targets.is_empty())?;
+
+    Some(executors::JoinRuntimeFilters {
+        build_left,
+        filters: register_runtime_filters(targets, state),
+    })
+}
+
+/// Create the filter slots of the scans that the keys filter.
+fn register_runtime_filters(
+    targets: Vec<(PlSmallStr, Vec<RuntimeFilterTarget>)>,
+    state: &ConversionState,
+) -> executors::RuntimeFilterTargets {
+    let mut slots = state.runtime_filters.borrow_mut();
+    targets
+        .into_iter()
+        .map(|(key, targets)| {
+            let targets = targets
+                .into_iter()
+                .map(|(node, column)| {
+                    let slot = slots.entry(node).or_default();
+                    slot.register_column(column.clone());
+                    (slot.clone(), column)
+                })
+                .collect();
+            (key, targets)
+        })
+        .collect()
+}
+
 pub fn create_physical_plan(
     root: Node,
     lp_arena: &mut Arena<IR>,
@@ -126,7 +194,8 @@ fn create_physical_plan_impl(
                 predicate_serialized,
             }))
         },
-        Sink { payload, .. } => match payload {
+        #[allow(unused_variables)]
+        Sink { input, payload } => match payload {
             SinkType::Memory => {
                 polars_bail!(InvalidOperation: "memory sink not supported in the standard engine")
             },
@@ -135,6 +204,46 @@ fn create_physical_plan_impl(
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_{file_type:?}()'"
                 )
             },
+            #[cfg(feature = "delta")]
+            SinkType::Delta {
+                path,
+                options,
+                cloud_options,
+            } => {
+                let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
+                let predicate = match &options.mode {
+                    DeltaWriteMode::OverwriteWhere(expr) => {
+                        let expr_ir = to_expr_ir(expr.clone(), expr_arena)?;
+                        let mut state = ExpressionConversionState::new(true, state.expr_depth);
+                        Some(create_scan_predicate(
+                            &expr_ir,
+                            expr_arena,
+                            &input_schema,
+                            &mut state,
+                            true,
+                            false,
+                        )?)
+                    },
+                    _ => None,
+                };
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                Ok(Box::new(executors::DeltaSinkExec {
+                    input,
+                    path,
+                    options,
+                    cloud_options,
+                    predicate,
+                }))
+            },
+            #[cfg(feature = "ipc_streaming")]
+            SinkType::IpcStream { writer, options } => {
+                let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
+                Ok(Box::new(executors::IpcStreamSinkExec {
+                    input,
+                    writer,
+                    options,
+                }))
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
@@ -177,6 +286,13 @@ fn create_physical_plan_impl(
                         }
                     }
             }
+            if matches!(lp_arena.get(input), ExtContext { .. }) {
+                let targets = is_in_runtime_filter_targets(&predicate, input, lp_arena, expr_arena);
+                if !targets.is_empty() {
+                    let filters = register_runtime_filters(targets, state);
+                    state.context_filters.borrow_mut().insert(input, filters);
+                }
+            }
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
             let mut state = ExpressionConversionState::new(true, state.expr_depth);
             let predicate = create_physical_expr(
@@ -209,10 +325,14 @@ fn create_physical_plan_impl(
                 _set_n_rows_for_scan(None).map(|x| (0, x))
             };
 
-            let mut state = ExpressionConversionState::new(true, state.expr_depth);
             let do_new_multifile = (sources.len() > 1 || hive_parts.is_some())
                 && !matches!(scan_type, FileScan::Anonymous { .. })
                 && std::env::var("POLARS_NEW_MULTIFILE").as_deref() == Ok("1");
+            // The multi-file scan does not use runtime filters, so these scans are never
+            // filter targets.
+            let runtime_filters = state.runtime_filters.borrow_mut().remove(&root);
+            debug_assert!(!do_new_multifile || runtime_filters.is_none());
+            let mut state = ExpressionConversionState::new(true, state.expr_depth);
 
             let mut create_skip_batch_predicate = false;
             create_skip_batch_predicate |= do_new_multifile;
@@ -292,6 +412,7 @@ fn create_physical_plan_impl(
                     cloud_options,
                     file_options,
                     metadata,
+                    runtime_filters,
                 ))),
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options, .. } => Ok(Box::new(executors::JsonExec::new(
@@ -517,6 +638,16 @@ fn create_physical_plan_impl(
             } else {
                 false
             };
+            let runtime_filters = create_join_runtime_filters(
+                input_left,
+                input_right,
+                &left_on,
+                &right_on,
+                &options.args,
+                lp_arena,
+                expr_arena,
+                state,
+            );
             let schema_left = lp_arena.get(input_left).schema(lp_arena).into_owned();
             let schema_right = lp_arena.get(input_right).schema(lp_arena).into_owned();
 
@@ -573,6 +704,7 @@ fn create_physical_plan_impl(
                 parallel,
                 options.args,
                 join_type_options,
+                runtime_filters,
             )))
         },
         HStack {
@@ -620,12 +752,21 @@ fn create_physical_plan_impl(
         ExtContext {
             input, contexts, ..
         } => {
+            let runtime_filters = state
+                .context_filters
+                .borrow_mut()
+                .remove(&root)
+                .unwrap_or_default();
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
             let contexts = contexts
                 .into_iter()
                 .map(|node| create_physical_plan_impl(node, lp_arena, expr_arena, state))
                 .collect::<PolarsResult<_>>()?;
-            Ok(Box::new(executors::ExternalContext { input, contexts }))
+            Ok(Box::new(executors::ExternalContext {
+                input,
+                contexts,
+                runtime_filters,
+            }))
         },
         SimpleProjection { input, columns } => {
             let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
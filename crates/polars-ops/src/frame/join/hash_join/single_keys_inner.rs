@@ -1,4 +1,5 @@
 use polars_core::utils::flatten;
+use polars_error::signals::{current_cancellation_token, try_raise_cancelled};
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::idx_vec::IdxVec;
 use polars_utils::itertools::Itertools;
@@ -75,11 +76,13 @@ where
     let offsets = probe_to_offsets(&probe);
     // next we probe the other relation
     // code duplication is because we want to only do the swap check once
+    let cancel_token = current_cancellation_token();
     let out = POOL.install(|| {
         let tuples = probe
             .into_par_iter()
             .zip(offsets)
             .map(|(probe, offset)| {
+                try_raise_cancelled(cancel_token.as_ref());
                 let probe = probe.into_iter();
                 // local reference
                 let hash_tbls = &hash_tbls;
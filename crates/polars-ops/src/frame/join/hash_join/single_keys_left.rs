@@ -1,4 +1,5 @@
 use polars_core::utils::flatten::flatten_par;
+use polars_error::signals::{current_cancellation_token, try_raise_cancelled};
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -143,6 +144,7 @@ where
     let offsets = probe_to_offsets(&probe);
 
     // next we probe the other relation
+    let cancel_token = current_cancellation_token();
     let result: Vec<LeftJoinIds> = POOL.install(move || {
         probe
             .into_par_iter()
@@ -150,6 +152,7 @@ where
             // probes_hashes: Vec<u64> processed by this thread
             // offset: offset index
             .map(move |(probe, offset)| {
+                try_raise_cancelled(cancel_token.as_ref());
                 // local reference
                 let hash_tbls = &hash_tbls;
 
@@ -4,7 +4,7 @@ use arrow::datatypes::ArrowSchema;
 use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
-use super::{to_parquet_schema, ThriftFileMetadata, WriteOptions};
+use super::{to_parquet_schema, SortingColumn, ThriftFileMetadata, WriteOptions};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
@@ -91,6 +91,15 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group whose rows are sorted by `sorting_columns` to the file.
+    pub fn write_sorted(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        sorting_columns: Vec<SortingColumn>,
+    ) -> PolarsResult<()> {
+        Ok(self.writer.write_sorted(row_group, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
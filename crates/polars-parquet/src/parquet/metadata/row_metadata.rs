@@ -53,6 +53,11 @@ impl RowGroupMetadata {
         self.columns.len()
     }
 
+    /// The metadata of all leaf columns in the order of the schema.
+    pub fn columns(&self) -> &[ColumnChunkMetadata] {
+        &self.columns
+    }
+
     /// Fetch all columns under this root name if it exists.
     pub fn columns_under_root_iter(
         &self,
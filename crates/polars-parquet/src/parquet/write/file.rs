@@ -1,7 +1,7 @@
 use std::io::Write;
 
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
-use polars_parquet_format::RowGroup;
+use polars_parquet_format::{RowGroup, SortingColumn};
 
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
@@ -173,6 +173,23 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes a row group whose rows are sorted by `sorting_columns` to the file.
+    pub fn write_sorted<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        sorting_columns: Vec<SortingColumn>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write(row_group)?;
+        if !sorting_columns.is_empty() {
+            self.row_groups.last_mut().unwrap().sorting_columns = Some(sorting_columns);
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
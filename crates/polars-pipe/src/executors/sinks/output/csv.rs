@@ -23,8 +23,10 @@ impl CsvSink {
             .include_bom(options.include_bom)
             .include_header(options.include_header)
             .with_separator(options.serialize_options.separator)
+            .with_multi_char_separator(options.serialize_options.multi_char_separator)
             .with_line_terminator(options.serialize_options.line_terminator)
             .with_quote_char(options.serialize_options.quote_char)
+            .with_escape_char(options.serialize_options.escape_char)
             .with_batch_size(options.batch_size)
             .with_datetime_format(options.serialize_options.datetime_format)
             .with_date_format(options.serialize_options.date_format)
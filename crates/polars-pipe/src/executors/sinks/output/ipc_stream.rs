@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_io::ipc::{BatchedIpcStreamWriter, IpcStreamWriter, IpcWriterOptions};
+use polars_io::SerWriter;
+
+use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
+
+/// Writes the result as an Arrow IPC stream, e.g. to stdout, a pipe or a socket.
+///
+/// Unlike the file sinks, every chunk is written and flushed as soon as it reaches the sink,
+/// so that a reader on the other side of a pipe doesn't wait for more data. Chunks are written
+/// in the order in which they are processed, unless the order is maintained: then the output
+/// of a chunk is written as soon as the chunks before it are done.
+pub struct IpcStreamSink {
+    state: Arc<Mutex<IpcStreamState>>,
+    maintain_order: bool,
+    thread_no: usize,
+    /// The output of the chunk this thread is processing, if the order is maintained.
+    chunk: Vec<DataFrame>,
+}
+
+struct IpcStreamState {
+    writer: BatchedIpcStreamWriter<Box<dyn Write + Send>>,
+    /// The index of the first chunk of the batch that the threads are processing. Thread `i`
+    /// processes the chunk at `batch_offset + i`, and a batch is done before the next one starts.
+    batch_offset: Option<IdxSize>,
+    /// The thread whose chunk is written next.
+    next_thread: usize,
+    /// The output of the done chunks that wait for the chunks of earlier threads.
+    pending: BTreeMap<usize, Vec<DataFrame>>,
+}
+
+impl IpcStreamState {
+    fn write(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        df.align_chunks_par();
+        self.writer.write_batch(&df)
+    }
+
+    fn chunk_done(
+        &mut self,
+        chunk_index: IdxSize,
+        thread_no: usize,
+        dfs: Vec<DataFrame>,
+    ) -> PolarsResult<()> {
+        let batch_offset = chunk_index.saturating_sub(thread_no as IdxSize);
+        if self.batch_offset != Some(batch_offset) {
+            self.write_pending()?;
+            self.batch_offset = Some(batch_offset);
+            self.next_thread = 0;
+        }
+        self.pending.insert(thread_no, dfs);
+        while let Some(dfs) = self.pending.remove(&self.next_thread) {
+            for df in dfs {
+                self.write(df)?;
+            }
+            self.next_thread += 1;
+        }
+        Ok(())
+    }
+
+    fn write_pending(&mut self) -> PolarsResult<()> {
+        for df in std::mem::take(&mut self.pending).into_values().flatten() {
+            self.write(df)?;
+        }
+        Ok(())
+    }
+}
+
+impl IpcStreamSink {
+    pub fn new(
+        writer: Box<dyn Write + Send>,
+        options: IpcWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Self> {
+        let writer = IpcStreamWriter::new(writer)
+            .with_compression(options.compression)
+            .batched(schema)?;
+        Ok(Self {
+            state: Arc::new(Mutex::new(IpcStreamState {
+                writer,
+                batch_offset: None,
+                next_thread: 0,
+                pending: BTreeMap::new(),
+            })),
+            maintain_order: options.maintain_order,
+            thread_no: 0,
+            chunk: vec![],
+        })
+    }
+}
+
+impl Sink for IpcStreamSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        // don't add empty dataframes
+        if chunk.data.height() > 0 {
+            if self.maintain_order {
+                self.chunk.push(chunk.data);
+            } else {
+                self.state.lock().unwrap().write(chunk.data)?;
+            }
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn chunk_done(&mut self, chunk_index: IdxSize) -> PolarsResult<()> {
+        if self.maintain_order {
+            let dfs = std::mem::take(&mut self.chunk);
+            let mut state = self.state.lock().unwrap();
+            state.chunk_done(chunk_index, self.thread_no, dfs)?;
+        }
+        Ok(())
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        // Output that operators flush at the end of the stream isn't part of a chunk.
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        self.chunk.append(&mut other.chunk);
+    }
+
+    fn split(&self, thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self {
+            state: self.state.clone(),
+            maintain_order: self.maintain_order,
+            thread_no,
+            chunk: vec![],
+        })
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let mut state = self.state.lock().unwrap();
+        state.write_pending()?;
+        for df in std::mem::take(&mut self.chunk) {
+            state.write(df)?;
+        }
+        state.writer.finish()?;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "ipc_stream_sink"
+    }
+}
@@ -9,6 +9,8 @@ mod csv;
 mod file_sink;
 #[cfg(feature = "ipc")]
 mod ipc;
+#[cfg(feature = "ipc_streaming")]
+mod ipc_stream;
 #[cfg(feature = "json")]
 mod json;
 #[cfg(feature = "parquet")]
@@ -18,6 +20,8 @@ mod parquet;
 pub use csv::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
+#[cfg(feature = "ipc_streaming")]
+pub use ipc_stream::*;
 #[cfg(feature = "json")]
 pub use json::*;
 #[cfg(feature = "parquet")]
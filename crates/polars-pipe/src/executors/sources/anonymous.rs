@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::schema::SchemaRef;
+use polars_plan::prelude::{AnonymousScan, AnonymousScanArgs};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// A source that pulls the batches of an [`AnonymousScan`] that allows streaming.
+pub struct AnonymousSource {
+    function: Arc<dyn AnonymousScan>,
+    n_rows: Option<usize>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
+    finished: bool,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        n_rows: Option<usize>,
+        with_columns: Option<Arc<[PlSmallStr]>>,
+        schema: SchemaRef,
+        output_schema: Option<SchemaRef>,
+    ) -> Self {
+        Self {
+            function,
+            n_rows,
+            with_columns,
+            schema,
+            output_schema,
+            finished: false,
+        }
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        if self.finished {
+            return Ok(SourceResult::Finished);
+        }
+        let args = AnonymousScanArgs {
+            n_rows: self.n_rows,
+            with_columns: self.with_columns.clone(),
+            schema: self.schema.clone(),
+            output_schema: self.output_schema.clone(),
+            predicate: None,
+        };
+        match self.function.next_batch(args)? {
+            Some(data) => {
+                let chunk_index = get_source_index(1) as IdxSize;
+                Ok(SourceResult::GotMoreData(vec![DataChunk {
+                    chunk_index,
+                    data,
+                }]))
+            },
+            None => {
+                self.finished = true;
+                Ok(SourceResult::Finished)
+            },
+        }
+    }
+
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
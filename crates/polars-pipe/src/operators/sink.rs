@@ -45,6 +45,13 @@ pub trait Sink: Send + Sync {
         false
     }
 
+    /// Called once all output of the source chunk with `chunk_index` has been pushed into
+    /// this sink. Sinks that write their output while the pipeline runs use this to keep the
+    /// order of the chunks.
+    fn chunk_done(&mut self, _chunk_index: IdxSize) -> PolarsResult<()> {
+        Ok(())
+    }
+
     // Only implemented for Join sinks
     fn node(&self) -> Node {
         unimplemented!()
@@ -155,6 +155,20 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { function, .. } => {
+                    let src = sources::AnonymousSource::new(
+                        function,
+                        file_options.slice.map(|(offset, len)| {
+                            debug_assert_eq!(offset, 0);
+                            len
+                        }),
+                        file_options.with_columns,
+                        file_info.schema,
+                        output_schema,
+                    );
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -222,6 +236,17 @@ where
                         _ => unreachable!(),
                     }
                 },
+                #[cfg(feature = "ipc_streaming")]
+                SinkType::IpcStream { writer, options } => Box::new(IpcStreamSink::new(
+                    writer.take()?,
+                    *options,
+                    input_schema.as_ref(),
+                )?)
+                    as Box<dyn SinkTrait>,
+                #[cfg(feature = "delta")]
+                SinkType::Delta { .. } => {
+                    polars_bail!(nyi = "Delta sink in the streaming engine")
+                },
             }
         },
         Join {
@@ -44,11 +44,16 @@ pub(super) fn par_process_chunks(
             let operator_pipe = &mut operator_pipe[operator_start..operator_end];
 
             s.spawn(move |_| {
+                let chunk_index = chunk.chunk_index;
                 let out = if operator_pipe.is_empty() {
                     sink.sink(ec, chunk)
                 } else {
                     push_operators_single_thread(chunk, ec, operator_pipe, sink, must_flush)
                 };
+                let out = out.and_then(|out| {
+                    sink.chunk_done(chunk_index)?;
+                    Ok(out)
+                });
 
                 match out {
                     Ok(SinkResult::Finished) | Err(_) => {
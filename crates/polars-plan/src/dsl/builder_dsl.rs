@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use either::Either;
 use polars_core::prelude::*;
 #[cfg(any(feature = "parquet", feature = "ipc", feature = "csv"))]
 use polars_io::cloud::CloudOptions;
@@ -39,7 +40,11 @@ impl DslBuilder {
             None => function.schema(infer_schema_length)?,
         };
 
-        let file_info = FileInfo::new(schema.clone(), None, (n_rows, n_rows.unwrap_or(usize::MAX)));
+        let file_info = FileInfo::new(
+            schema.clone(),
+            Some(Either::Right(schema.clone())),
+            (n_rows, n_rows.unwrap_or(usize::MAX)),
+        );
         let file_options = FileScanOptions {
             slice: n_rows.map(|x| (0, x)),
             with_columns: None,
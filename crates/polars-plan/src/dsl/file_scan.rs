@@ -209,8 +209,7 @@ impl FileScan {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
-            #[allow(unreachable_patterns)]
-            _ => false,
+            Self::Anonymous { function, .. } => function.allows_streaming(),
         }
     }
 }
@@ -30,6 +30,8 @@ use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
 use super::ExprIR;
+#[cfg(feature = "delta")]
+use crate::dsl::Expr;
 use crate::dsl::Selector;
 
 #[derive(Copy, Clone, PartialEq, Debug, Eq, Hash)]
@@ -298,20 +300,115 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    #[cfg(feature = "delta")]
+    Delta {
+        path: Arc<PathBuf>,
+        options: DeltaWriteOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+    },
+    #[cfg(feature = "ipc_streaming")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    IpcStream {
+        writer: DynWriter,
+        options: IpcWriterOptions,
+    },
+}
+
+/// A writer that a query result is streamed into, e.g. stdout, a pipe or a socket.
+///
+/// The sink takes the writer out when the query is executed, so a plan holding it can only
+/// run once. Two `DynWriter`s are equal if they share the same writer.
+#[cfg(feature = "ipc_streaming")]
+#[derive(Clone)]
+pub struct DynWriter(Arc<std::sync::Mutex<Option<Box<dyn std::io::Write + Send>>>>);
+
+#[cfg(feature = "ipc_streaming")]
+impl DynWriter {
+    pub fn new(writer: impl std::io::Write + Send + 'static) -> Self {
+        Self(Arc::new(std::sync::Mutex::new(Some(Box::new(writer)))))
+    }
+
+    pub fn take(&self) -> PolarsResult<Box<dyn std::io::Write + Send>> {
+        self.0.lock().unwrap().take().ok_or_else(
+            || polars_err!(ComputeError: "the writer of this sink has already been consumed"),
+        )
+    }
+}
+
+#[cfg(feature = "ipc_streaming")]
+impl std::fmt::Debug for DynWriter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DynWriter")
+    }
+}
+
+#[cfg(feature = "ipc_streaming")]
+impl PartialEq for DynWriter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "ipc_streaming")]
+impl Eq for DynWriter {}
+
+#[cfg(feature = "ipc_streaming")]
+impl Hash for DynWriter {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
 }
 
 impl SinkType {
     pub(crate) fn is_cloud_destination(&self) -> bool {
-        if let Self::File { path, .. } = self {
-            if is_cloud_url(path.as_ref()) {
-                return true;
-            }
+        match self {
+            Self::File { path, .. } => is_cloud_url(path.as_ref()),
+            #[cfg(feature = "delta")]
+            Self::Delta { path, .. } => is_cloud_url(path.as_ref()),
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => false,
+            Self::Memory => false,
         }
+    }
 
-        false
+    /// Whether the sink can be the final sink of a streaming pipeline.
+    pub fn is_streamable(&self) -> bool {
+        match self {
+            Self::Memory | Self::File { .. } => true,
+            #[cfg(feature = "delta")]
+            Self::Delta { .. } => false,
+            #[cfg(feature = "ipc_streaming")]
+            Self::IpcStream { .. } => true,
+        }
     }
 }
 
+/// How data is written to a Delta table.
+#[cfg(feature = "delta")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DeltaWriteMode {
+    /// Add the data to the table.
+    #[default]
+    Append,
+    /// Replace all data of the table. This may change the schema and the partitioning.
+    Overwrite,
+    /// Replace the rows of the table that match the predicate. All written rows must match the
+    /// predicate.
+    OverwriteWhere(Expr),
+}
+
+#[cfg(feature = "delta")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DeltaWriteOptions {
+    pub mode: DeltaWriteMode,
+    /// The columns to partition a new table by. For an existing table these must be empty or
+    /// match the partitioning of the table, unless it is overwritten.
+    pub partition_by: Vec<PlSmallStr>,
+    pub parquet_options: ParquetWriteOptions,
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct FileSinkOptions {
@@ -378,6 +475,8 @@ impl From<UnionArgs> for UnionOptions {
 pub struct NDJsonReadOptions {
     pub n_threads: Option<usize>,
     pub infer_schema_length: Option<NonZeroUsize>,
+    /// Number of files to infer the schema from. `None` means all files.
+    pub infer_schema_n_files: Option<NonZeroUsize>,
     pub chunk_size: NonZeroUsize,
     pub low_memory: bool,
     pub ignore_errors: bool,
@@ -40,6 +40,11 @@ bitflags! {
         const CHECK_ORDER_OBSERVE = 1 << 16;
         /// Do type checking of the IR.
         const TYPE_CHECK = 1 << 17;
+        /// Reorder chains of inner joins based on cardinality estimates.
+        const JOIN_REORDER = 1 << 18;
+        /// Remove sorts and distincts whose input is already sorted or unique. This trusts the
+        /// sortedness recorded in file metadata, so it is opt-in.
+        const SORT_DISTINCT_ELIM = 1 << 19;
     }
 }
 
@@ -60,6 +65,14 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn join_reorder(&self) -> bool {
+        self.contains(OptFlags::JOIN_REORDER)
+    }
+
+    pub fn sort_distinct_elim(&self) -> bool {
+        self.contains(OptFlags::SORT_DISTINCT_ELIM)
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -89,6 +102,8 @@ impl Default for OptFlags {
         Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::STREAMING & !Self::EAGER
             // will be toggled by a scan operation such as csv scan or parquet scan
             & !Self::FILE_CACHING
+            & !Self::JOIN_REORDER
+            & !Self::SORT_DISTINCT_ELIM
     }
 }
 
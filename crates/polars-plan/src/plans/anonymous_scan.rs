@@ -47,6 +47,13 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if [`next_batch`][Self::next_batch] returns the data in batches and `None`
+    /// once it is exhausted, so that the scan can be a source of the streaming engine.
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
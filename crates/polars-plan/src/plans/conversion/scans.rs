@@ -359,9 +359,27 @@ pub fn ndjson_file_info(
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
         let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
 
-        let schema =
+        let mut schema =
             polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
 
+        let n_files = ndjson_options
+            .infer_schema_n_files
+            .map_or(sources.len(), |n| n.get().min(sources.len()));
+        for i in 1..n_files {
+            let source = sources.at(i);
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            owned.clear();
+            let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+            let other =
+                polars_io::ndjson::infer_schema(&mut reader, ndjson_options.infer_schema_length)?;
+            polars_io::ndjson::merge_inferred_schema(
+                &mut schema,
+                &other,
+                source.to_include_path_name(),
+            )?;
+        }
+
         prepare_schemas(schema, file_options.row_index.as_ref())
     };
 
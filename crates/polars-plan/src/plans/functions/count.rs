@@ -100,6 +100,7 @@ fn count_all_rows_csv(
                 path,
                 parse_options.separator,
                 parse_options.quote_char,
+                parse_options.escape_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
                 options.has_header,
@@ -111,6 +112,7 @@ fn count_all_rows_csv(
                     &memslice[..],
                     parse_options.separator,
                     parse_options.quote_char,
+                    parse_options.escape_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
                     options.has_header,
@@ -311,6 +311,10 @@ impl<'a> IRDotDisplay<'a> {
                     f.write_str(match payload {
                         SinkType::Memory => "SINK (MEMORY)",
                         SinkType::File { .. } => "SINK (FILE)",
+                        #[cfg(feature = "delta")]
+                        SinkType::Delta { .. } => "SINK (DELTA)",
+                        #[cfg(feature = "ipc_streaming")]
+                        SinkType::IpcStream { .. } => "SINK (IPC STREAM)",
                     })
                 })?;
             },
@@ -392,6 +392,10 @@ impl<'a> IRDisplay<'a> {
                 let name = match payload {
                     SinkType::Memory => "SINK (memory)",
                     SinkType::File { .. } => "SINK (file)",
+                    #[cfg(feature = "delta")]
+                    SinkType::Delta { .. } => "SINK (delta)",
+                    #[cfg(feature = "ipc_streaming")]
+                    SinkType::IpcStream { .. } => "SINK (ipc stream)",
                 };
                 write!(f, "{:indent$}{name}", "")?;
                 self.with_root(*input)._format(f, sub_indent)
@@ -1,6 +1,7 @@
 mod dot;
 mod format;
 mod inputs;
+mod properties;
 mod schema;
 pub(crate) mod tree_format;
 
@@ -12,6 +13,7 @@ pub use format::{ExprIRDisplay, IRDisplay};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unitvec;
+pub use properties::*;
 #[cfg(feature = "ir_serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
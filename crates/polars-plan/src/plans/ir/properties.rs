@@ -0,0 +1,292 @@
+//! Sortedness and uniqueness properties of the output of IR nodes.
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+use crate::prelude::*;
+
+/// A column the rows of a node are sorted by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SortedColumn {
+    pub name: PlSmallStr,
+    pub descending: bool,
+    /// `None` if the column is known to contain no nulls.
+    pub nulls_last: Option<bool>,
+}
+
+impl SortedColumn {
+    fn satisfies(&self, name: &str, descending: bool, nulls_last: bool) -> bool {
+        self.name == name
+            && self.descending == descending
+            && self.nulls_last.is_none_or(|v| v == nulls_last)
+    }
+}
+
+/// Properties of the output of an IR node that are known at planning time.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IRProperties {
+    /// Columns the rows are lexicographically sorted by.
+    pub sorted: Vec<SortedColumn>,
+    /// Sets of columns that uniquely identify a row.
+    pub unique: Vec<Arc<[PlSmallStr]>>,
+}
+
+impl IRProperties {
+    /// Whether sorting by the given columns would not change the order of the rows.
+    pub fn is_sorted_by(
+        &self,
+        by: &[PlSmallStr],
+        descending: &[bool],
+        nulls_last: &[bool],
+    ) -> bool {
+        !by.is_empty()
+            && by.len() <= self.sorted.len()
+            && by
+                .iter()
+                .zip(descending)
+                .zip(nulls_last)
+                .zip(&self.sorted)
+                .all(|(((name, &d), &n), s)| s.satisfies(name, d, n))
+    }
+
+    /// Whether no two rows have the same values in `subset`.
+    pub fn is_unique_by(&self, subset: &[PlSmallStr]) -> bool {
+        self.unique
+            .iter()
+            .any(|key| key.iter().all(|c| subset.contains(c)))
+    }
+
+    /// Apply a mapping from input to output column names. Columns that map to `None` are dropped.
+    fn project(&self, map: impl Fn(&PlSmallStr) -> Option<PlSmallStr>) -> Self {
+        let sorted = self
+            .sorted
+            .iter()
+            .map_while(|s| {
+                Some(SortedColumn {
+                    name: map(&s.name)?,
+                    ..s.clone()
+                })
+            })
+            .collect();
+        let unique = self
+            .unique
+            .iter()
+            .filter_map(|key| key.iter().map(&map).collect::<Option<Arc<[_]>>>())
+            .collect();
+
+        Self { sorted, unique }
+    }
+}
+
+/// Names of the columns an expression selects without modifying them.
+pub(crate) fn column_names(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> Option<Vec<PlSmallStr>> {
+    exprs
+        .iter()
+        .map(|e| match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Map input column names to the first output name they are selected under.
+fn projected_columns(
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> PlHashMap<PlSmallStr, PlSmallStr> {
+    let mut map = PlHashMap::with_capacity(exprs.len());
+    for e in exprs {
+        if let AExpr::Column(name) = expr_arena.get(e.node()) {
+            map.entry(name.clone())
+                .or_insert_with(|| e.output_name().clone());
+        }
+    }
+    map
+}
+
+/// Derive the properties of a single node from the properties of its inputs.
+pub(crate) fn derive_properties(
+    ir: &IR,
+    inputs: &[Arc<IRProperties>],
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> IRProperties {
+    use IR::*;
+    match ir {
+        Slice { .. } | Filter { .. } | Cache { .. } => inputs[0].as_ref().clone(),
+        SimpleProjection { columns, .. } => {
+            inputs[0].project(|name| columns.contains(name).then(|| name.clone()))
+        },
+        Select { expr, .. } => {
+            let map = projected_columns(expr, expr_arena);
+            inputs[0].project(|name| map.get(name).cloned())
+        },
+        HStack { exprs, .. } => {
+            let map = projected_columns(exprs, expr_arena);
+            let overwritten = exprs.iter().map(|e| e.output_name()).collect::<Vec<_>>();
+            inputs[0].project(|name| {
+                if overwritten.contains(&name) {
+                    map.get(name).cloned()
+                } else {
+                    Some(name.clone())
+                }
+            })
+        },
+        Sort {
+            by_column,
+            sort_options,
+            ..
+        } => {
+            let sorted = by_column
+                .iter()
+                .zip(&sort_options.descending)
+                .zip(&sort_options.nulls_last)
+                .map_while(
+                    |((e, &descending), &nulls_last)| match expr_arena.get(e.node()) {
+                        AExpr::Column(name) => Some(SortedColumn {
+                            name: name.clone(),
+                            descending,
+                            nulls_last: Some(nulls_last),
+                        }),
+                        _ => None,
+                    },
+                )
+                .collect();
+            IRProperties {
+                sorted,
+                unique: inputs[0].unique.clone(),
+            }
+        },
+        Distinct { options, input } => {
+            let subset = options.subset.clone().unwrap_or_else(|| {
+                lp_arena
+                    .get(*input)
+                    .schema(lp_arena)
+                    .iter_names_cloned()
+                    .collect()
+            });
+            let mut props = inputs[0].as_ref().clone();
+            if !options.maintain_order {
+                props.sorted.clear();
+            }
+            props.unique.push(subset);
+            props
+        },
+        GroupBy {
+            keys,
+            maintain_order,
+            options,
+            apply,
+            ..
+        } => {
+            if apply.is_some() || options.is_rolling() || options.is_dynamic() || keys.is_empty() {
+                return IRProperties::default();
+            }
+            if column_names(keys, expr_arena).is_none() {
+                return IRProperties::default();
+            }
+            let map = projected_columns(keys, expr_arena);
+            // Groups are emitted in order of first occurrence, so a sort order on a prefix of the
+            // keys is preserved.
+            let sorted = if *maintain_order {
+                inputs[0].project(|name| map.get(name).cloned()).sorted
+            } else {
+                vec![]
+            };
+            let unique = keys.iter().map(|e| e.output_name().clone()).collect();
+            IRProperties {
+                sorted,
+                unique: vec![unique],
+            }
+        },
+        MapFunction { function, .. } => match function {
+            FunctionIR::Rename { existing, new, .. } => {
+                inputs[0].project(|name| match existing.iter().position(|e| e == name) {
+                    Some(idx) => Some(new[idx].clone()),
+                    None => (!new.contains(name)).then(|| name.clone()),
+                })
+            },
+            FunctionIR::RowIndex { name, .. } => with_row_index(inputs[0].as_ref().clone(), name),
+            _ => IRProperties::default(),
+        },
+        DataFrameScan {
+            df, output_schema, ..
+        } => {
+            let sorted = df
+                .get_columns()
+                .iter()
+                .filter(|c| {
+                    output_schema
+                        .as_ref()
+                        .is_none_or(|schema| schema.contains(c.name()))
+                })
+                .find_map(|c| {
+                    let descending = match c.is_sorted_flag() {
+                        IsSorted::Ascending => false,
+                        IsSorted::Descending => true,
+                        IsSorted::Not => return None,
+                    };
+                    (c.null_count() == 0).then(|| SortedColumn {
+                        name: c.name().clone(),
+                        descending,
+                        nulls_last: None,
+                    })
+                });
+            IRProperties {
+                sorted: sorted.into_iter().collect(),
+                unique: vec![],
+            }
+        },
+        #[allow(unused_variables)]
+        Scan {
+            sources,
+            file_info,
+            output_schema,
+            scan_type,
+            file_options,
+            ..
+        } => {
+            #[allow(unused_mut)]
+            let mut props = IRProperties::default();
+            // Only the metadata of the first file is resolved while planning, and reading the
+            // footers of the other files here would be too expensive, so only single files are
+            // known to be sorted.
+            #[cfg(feature = "parquet")]
+            if let (
+                FileScan::Parquet {
+                    metadata: Some(md), ..
+                },
+                1,
+            ) = (scan_type, sources.len())
+            {
+                let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+                props.sorted = polars_io::parquet::metadata::file_sorting_columns(md)
+                    .into_iter()
+                    .take_while(|c| schema.contains(&c.name))
+                    .map(|c| SortedColumn {
+                        name: c.name,
+                        descending: c.descending,
+                        nulls_last: c.has_nulls.then_some(!c.nulls_first),
+                    })
+                    .collect();
+            }
+            match &file_options.row_index {
+                Some(ri) => with_row_index(props, &ri.name),
+                None => props,
+            }
+        },
+        _ => IRProperties::default(),
+    }
+}
+
+fn with_row_index(mut props: IRProperties, name: &PlSmallStr) -> IRProperties {
+    if props.sorted.is_empty() {
+        props.sorted.push(SortedColumn {
+            name: name.clone(),
+            descending: false,
+            nulls_last: None,
+        });
+    }
+    props.unique.push(Arc::from([name.clone()]));
+    props
+}
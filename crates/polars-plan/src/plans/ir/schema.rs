@@ -38,6 +38,10 @@ impl IR {
             Sink { payload, .. } => match payload {
                 SinkType::Memory => "sink (memory)",
                 SinkType::File { .. } => "sink (file)",
+                #[cfg(feature = "delta")]
+                SinkType::Delta { .. } => "sink (delta)",
+                #[cfg(feature = "ipc_streaming")]
+                SinkType::IpcStream { .. } => "sink (ipc stream)",
             },
             SimpleProjection { .. } => "simple_projection",
             #[cfg(feature = "merge_sorted")]
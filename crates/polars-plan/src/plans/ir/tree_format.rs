@@ -355,6 +355,10 @@ impl<'a> TreeFmtNode<'a> {
                             match payload {
                                 SinkType::Memory => "SINK (memory)",
                                 SinkType::File { .. } => "SINK (file)",
+                                #[cfg(feature = "delta")]
+                                SinkType::Delta { .. } => "SINK (delta)",
+                                #[cfg(feature = "ipc_streaming")]
+                                SinkType::IpcStream { .. } => "SINK (ipc stream)",
                             },
                         ),
                         vec![self.lp_node(None, *input)],
@@ -24,6 +24,7 @@ pub(crate) mod options;
 pub mod python;
 #[cfg(feature = "python")]
 pub use python::*;
+mod runtime_filter;
 mod schema;
 pub mod visitor;
 
@@ -38,6 +39,7 @@ pub use ir::*;
 pub use iterator::*;
 pub use lit::*;
 pub use optimizer::*;
+pub use runtime_filter::*;
 pub use schema::*;
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -0,0 +1,829 @@
+//! Cost-based reordering of inner join trees.
+//!
+//! The inputs of a tree of inner equi-joins are joined in the order that minimizes the estimated
+//! sizes of the intermediate results, and the larger side of every join is used as the probe
+//! side. Cardinalities are estimated from the heights of in-memory frames and the row counts of
+//! scans, and the distinct counts of the join keys are sampled from in-memory frames or read from
+//! parquet statistics. Trees with an input of which the cardinality is unknown keep the order of
+//! the query.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_ops::frame::{JoinType, JoinValidation, MaintainOrderJoin};
+use polars_utils::arena::{Arena, Node};
+
+use super::{AExpr, JoinOptions, IR};
+use crate::dsl::Operator;
+use crate::plans::{ExprIR, IRBuilder, MintermIter, OutputName, ProjectionOptions};
+
+/// Join trees with more inputs than this are ordered greedily instead of exhaustively.
+const MAX_EXHAUSTIVE_INPUTS: usize = 10;
+/// Number of rows that are sampled to estimate the distinct count of a column of a frame.
+const NDV_SAMPLE_SIZE: usize = 100_000;
+
+/// A column of one of the inputs of a join tree.
+type InputColumn = (usize, PlSmallStr);
+
+/// The output columns of a (sub)tree, and the input columns they stem from.
+type ColumnMap = PlIndexMap<PlSmallStr, InputColumn>;
+
+/// A join order, where inputs are referred to by their index.
+enum JoinOrder {
+    Input(usize),
+    Join(Box<JoinOrder>, Box<JoinOrder>),
+}
+
+impl JoinOrder {
+    fn mask(&self) -> u64 {
+        match self {
+            Self::Input(i) => 1 << i,
+            Self::Join(l, r) => l.mask() | r.mask(),
+        }
+    }
+}
+
+/// A tree of inner equi-joins that can be executed in any order.
+struct JoinTree {
+    inputs: Vec<Node>,
+    /// Input columns that are equal in the output, because they are joined on.
+    classes: Vec<Vec<InputColumn>>,
+    /// The order in which the query joins the inputs.
+    order: JoinOrder,
+    output: ColumnMap,
+    /// The options of the root join, that are reused for every join.
+    options: Arc<JoinOptions>,
+}
+
+pub fn optimize(
+    root: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    verbose: bool,
+) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if let Some(tree) = JoinTree::collect(node, lp_arena, expr_arena) {
+            stack.extend_from_slice(&tree.inputs);
+            tree.reorder(node, lp_arena, expr_arena, verbose);
+        } else {
+            lp_arena.get(node).copy_inputs(&mut stack);
+        }
+    }
+}
+
+/// The key columns of `node` if it is a join that can be reordered with joins that have
+/// `template` as options.
+fn reorderable_keys(
+    node: Node,
+    template: Option<&JoinOptions>,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<(PlSmallStr, PlSmallStr)>> {
+    let IR::Join {
+        left_on,
+        right_on,
+        options,
+        ..
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    let args = &options.args;
+    let reorderable = matches!(args.how, JoinType::Inner)
+        && options.options.is_none()
+        && args.slice.is_none()
+        && matches!(args.validation, JoinValidation::ManyToMany)
+        && matches!(args.maintain_order, MaintainOrderJoin::None)
+        && args.should_coalesce()
+        && template.is_none_or(|t| t.args.nulls_equal == args.nulls_equal);
+    if !reorderable {
+        return None;
+    }
+
+    let column = |e: &ExprIR| match expr_arena.get(e.node()) {
+        AExpr::Column(name) => Some(name.clone()),
+        _ => None,
+    };
+    left_on
+        .iter()
+        .zip(right_on)
+        .map(|(l, r)| Some((column(l)?, column(r)?)))
+        .collect()
+}
+
+/// Union-find over the key columns of a join tree.
+#[derive(Default)]
+struct KeyClasses {
+    ids: PlIndexMap<InputColumn, usize>,
+    parents: Vec<usize>,
+}
+
+impl KeyClasses {
+    fn id(&mut self, column: &InputColumn) -> usize {
+        if let Some(id) = self.ids.get(column) {
+            return *id;
+        }
+        let id = self.parents.len();
+        self.parents.push(id);
+        self.ids.insert(column.clone(), id);
+        id
+    }
+
+    fn find(&mut self, mut id: usize) -> usize {
+        while self.parents[id] != id {
+            self.parents[id] = self.parents[self.parents[id]];
+            id = self.parents[id];
+        }
+        id
+    }
+
+    fn union(&mut self, a: &InputColumn, b: &InputColumn) {
+        let (a, b) = (self.id(a), self.id(b));
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+    }
+
+    fn into_classes(mut self) -> Vec<Vec<InputColumn>> {
+        let mut classes = PlIndexMap::<usize, Vec<InputColumn>>::default();
+        let ids = std::mem::take(&mut self.ids);
+        for (column, id) in ids {
+            let root = self.find(id);
+            classes.entry(root).or_default().push(column);
+        }
+        classes.into_values().collect()
+    }
+}
+
+impl JoinTree {
+    fn collect(root: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<Self> {
+        reorderable_keys(root, None, lp_arena, expr_arena)?;
+        let IR::Join { options, .. } = lp_arena.get(root) else {
+            unreachable!()
+        };
+        let options = options.clone();
+
+        let mut inputs = vec![];
+        let mut classes = KeyClasses::default();
+        let (order, output) = Self::collect_rec(
+            root,
+            &options,
+            &mut inputs,
+            &mut classes,
+            lp_arena,
+            expr_arena,
+        )?;
+        if inputs.len() < 3 || inputs.len() > 64 {
+            return None;
+        }
+        let classes = classes.into_classes();
+
+        // Every order must be able to compare the keys, and must not have to suffix columns.
+        let dtype =
+            |(i, name): &InputColumn| lp_arena.get(inputs[*i]).schema(lp_arena).get(name).cloned();
+        for class in &classes {
+            let first = dtype(&class[0])?;
+            if class[1..].iter().any(|c| dtype(c).as_ref() != Some(&first)) {
+                return None;
+            }
+        }
+        let mut owners = PlHashMap::<PlSmallStr, usize>::default();
+        for &input in &inputs {
+            for name in lp_arena.get(input).schema(lp_arena).iter_names() {
+                *owners.entry(name.clone()).or_default() += 1;
+            }
+        }
+        let is_key = |name: &str| classes.iter().flatten().any(|(_, n)| n == name);
+        if owners.iter().any(|(name, n)| *n > 1 && !is_key(name)) {
+            return None;
+        }
+
+        Some(Self {
+            inputs,
+            classes,
+            order,
+            output,
+            options,
+        })
+    }
+
+    fn collect_rec(
+        node: Node,
+        template: &JoinOptions,
+        inputs: &mut Vec<Node>,
+        classes: &mut KeyClasses,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<(JoinOrder, ColumnMap)> {
+        let Some(keys) = reorderable_keys(node, Some(template), lp_arena, expr_arena) else {
+            let i = inputs.len();
+            inputs.push(node);
+            let output = lp_arena
+                .get(node)
+                .schema(lp_arena)
+                .iter_names()
+                .map(|name| (name.clone(), (i, name.clone())))
+                .collect();
+            return Some((JoinOrder::Input(i), output));
+        };
+        let IR::Join {
+            input_left,
+            input_right,
+            ..
+        } = lp_arena.get(node)
+        else {
+            unreachable!()
+        };
+        let (left, mut output) =
+            Self::collect_rec(*input_left, template, inputs, classes, lp_arena, expr_arena)?;
+        let (right, right_output) = Self::collect_rec(
+            *input_right,
+            template,
+            inputs,
+            classes,
+            lp_arena,
+            expr_arena,
+        )?;
+
+        for (l, r) in &keys {
+            classes.union(output.get(l)?, right_output.get(r)?);
+        }
+        for (name, column) in right_output {
+            if keys.iter().any(|(_, r)| *r == name) {
+                continue;
+            }
+            if output.insert(name, column).is_some() {
+                return None;
+            }
+        }
+        Some((JoinOrder::Join(Box::new(left), Box::new(right)), output))
+    }
+
+    fn reorder(
+        self,
+        root: Node,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        verbose: bool,
+    ) {
+        let Some(rows) = self
+            .inputs
+            .iter()
+            .map(|input| estimate_rows(*input, lp_arena, expr_arena))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+        let ndv = self
+            .classes
+            .iter()
+            .map(|class| {
+                class
+                    .iter()
+                    .map(|(i, name)| {
+                        column_ndv(self.inputs[*i], name, lp_arena, expr_arena)
+                            .map(|ndv| ndv.clamp(1.0, rows[*i].max(1.0)))
+                    })
+                    .collect()
+            })
+            .collect();
+        let estimator = Estimator {
+            rows,
+            ndv,
+            classes: &self.classes,
+        };
+
+        let Some((_, original_cost)) = estimator.cost(&self.order) else {
+            return;
+        };
+        let Some(order) = estimator.best_order() else {
+            return;
+        };
+        let Some((_, cost)) = estimator.cost(&order) else {
+            return;
+        };
+        if cost >= original_cost {
+            return;
+        }
+        let Some(new_root) = self.build(&order, &estimator, lp_arena, expr_arena) else {
+            return;
+        };
+        if verbose {
+            eprintln!(
+                "join reordering: reordered {} inputs, estimated cost {original_cost:.0} -> {cost:.0}",
+                self.inputs.len()
+            );
+        }
+        lp_arena.swap(root, new_root);
+    }
+
+    /// Build the joins of `order`, followed by a projection to the schema of the original tree.
+    fn build(
+        &self,
+        order: &JoinOrder,
+        estimator: &Estimator,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<Node> {
+        let (node, output, _) = self.build_rec(order, estimator, lp_arena, expr_arena)?;
+
+        let class_of = |column: &InputColumn| self.classes.iter().position(|c| c.contains(column));
+        let mut exprs = Vec::with_capacity(self.output.len());
+        for (name, column) in &self.output {
+            let source = match output.get(name) {
+                Some(c) if c == column => name.clone(),
+                _ => {
+                    let class = class_of(column)?;
+                    output
+                        .iter()
+                        .find(|(_, c)| class_of(c) == Some(class))?
+                        .0
+                        .clone()
+                },
+            };
+            exprs.push((name.clone(), source));
+        }
+
+        let builder = IRBuilder::new(node, expr_arena, lp_arena);
+        if exprs.iter().all(|(name, source)| name == source) {
+            if output.keys().eq(self.output.keys()) {
+                return Some(node);
+            }
+            return Some(
+                builder
+                    .project_simple(self.output.keys().cloned())
+                    .ok()?
+                    .node(),
+            );
+        }
+        let exprs = exprs
+            .into_iter()
+            .map(|(name, source)| {
+                let node = expr_arena.add(AExpr::Column(source.clone()));
+                if name == source {
+                    ExprIR::from_node(node, expr_arena)
+                } else {
+                    ExprIR::new(node, OutputName::Alias(name))
+                }
+            })
+            .collect();
+        let builder = IRBuilder::new(node, expr_arena, lp_arena);
+        Some(builder.project(exprs, ProjectionOptions::default()).node())
+    }
+
+    fn build_rec(
+        &self,
+        order: &JoinOrder,
+        estimator: &Estimator,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+    ) -> Option<(Node, ColumnMap, f64)> {
+        let (left, right) = match order {
+            JoinOrder::Input(i) => {
+                let node = self.inputs[*i];
+                let output = lp_arena
+                    .get(node)
+                    .schema(lp_arena)
+                    .iter_names()
+                    .map(|name| (name.clone(), (*i, name.clone())))
+                    .collect();
+                return Some((node, output, estimator.rows[*i]));
+            },
+            JoinOrder::Join(left, right) => (left, right),
+        };
+        let mut left = self.build_rec(left, estimator, lp_arena, expr_arena)?;
+        let mut right = self.build_rec(right, estimator, lp_arena, expr_arena)?;
+        // The right side is used to build the hash table.
+        if left.2 < right.2 {
+            std::mem::swap(&mut left, &mut right);
+        }
+        let (left_node, mut output, left_rows) = left;
+        let (right_node, right_output, right_rows) = right;
+
+        let mut left_on = vec![];
+        let mut right_on = vec![];
+        for class in &self.classes {
+            let candidates = |output: &ColumnMap| {
+                output
+                    .iter()
+                    .filter(|(_, c)| class.contains(c))
+                    .map(|(name, _)| name.clone())
+                    .collect::<Vec<_>>()
+            };
+            let (l, r) = (candidates(&output), candidates(&right_output));
+            if l.is_empty() || r.is_empty() {
+                continue;
+            }
+            let (l, r) = l
+                .iter()
+                .find(|name| r.contains(name))
+                .map_or((&l[0], &r[0]), |name| (name, name));
+            left_on.push(ExprIR::from_node(
+                expr_arena.add(AExpr::Column(l.clone())),
+                expr_arena,
+            ));
+            right_on.push(ExprIR::from_node(
+                expr_arena.add(AExpr::Column(r.clone())),
+                expr_arena,
+            ));
+        }
+        if left_on.is_empty() {
+            return None;
+        }
+
+        for (name, column) in right_output {
+            if right_on.iter().any(|e| *e.output_name() == name) {
+                continue;
+            }
+            if output.insert(name, column).is_some() {
+                return None;
+            }
+        }
+
+        let (rows, _) = estimator.cost(order)?;
+        let options = Arc::new(JoinOptions {
+            rows_left: (None, left_rows as usize),
+            rows_right: (None, right_rows as usize),
+            ..(*self.options).clone()
+        });
+        let node = IRBuilder::new(left_node, expr_arena, lp_arena)
+            .join(right_node, left_on, right_on, options)
+            .node();
+        Some((node, output, rows))
+    }
+}
+
+struct Estimator<'a> {
+    rows: Vec<f64>,
+    /// The estimated distinct count of every column of every key class.
+    ndv: Vec<Vec<Option<f64>>>,
+    classes: &'a [Vec<InputColumn>],
+}
+
+impl Estimator<'_> {
+    /// The estimated number of rows of joining `left` and `right`, or `None` if they do not share
+    /// a key.
+    fn join_rows(&self, left: (u64, f64), right: (u64, f64)) -> Option<f64> {
+        let ((left, left_rows), (right, right_rows)) = (left, right);
+        let class_ndv = |mask: u64, class: usize| {
+            let mut in_mask = false;
+            let ndv = self.classes[class]
+                .iter()
+                .zip(&self.ndv[class])
+                .filter(|((i, _), _)| mask & (1 << i) != 0)
+                .inspect(|_| in_mask = true)
+                .filter_map(|(_, ndv)| *ndv)
+                .min_by(f64::total_cmp);
+            (in_mask, ndv)
+        };
+
+        // Keys are assumed to be correlated, so only the most selective key is taken into
+        // account.
+        let mut selectivity = None::<f64>;
+        for class in 0..self.classes.len() {
+            let (in_left, left_ndv) = class_ndv(left, class);
+            let (in_right, right_ndv) = class_ndv(right, class);
+            if !(in_left && in_right) {
+                continue;
+            }
+            let ndv = match (left_ndv, right_ndv) {
+                (Some(l), Some(r)) => l.max(r),
+                (Some(ndv), None) | (None, Some(ndv)) => ndv,
+                (None, None) => left_rows.min(right_rows),
+            };
+            let s = 1.0 / ndv.max(1.0);
+            selectivity = Some(selectivity.map_or(s, |v| v.min(s)));
+        }
+        Some((left_rows * right_rows * selectivity?).max(1.0))
+    }
+
+    /// The estimated number of rows and the cost of joining in `order`.
+    fn cost(&self, order: &JoinOrder) -> Option<(f64, f64)> {
+        match order {
+            JoinOrder::Input(i) => Some((self.rows[*i], 0.0)),
+            JoinOrder::Join(left, right) => {
+                let (left_rows, left_cost) = self.cost(left)?;
+                let (right_rows, right_cost) = self.cost(right)?;
+                let rows = self.join_rows((left.mask(), left_rows), (right.mask(), right_rows))?;
+                Some((rows, left_cost + right_cost + rows))
+            },
+        }
+    }
+
+    fn best_order(&self) -> Option<JoinOrder> {
+        if self.rows.len() <= MAX_EXHAUSTIVE_INPUTS {
+            self.best_order_exhaustive()
+        } else {
+            self.best_order_greedy()
+        }
+    }
+
+    /// Dynamic programming over the connected subsets of the inputs.
+    fn best_order_exhaustive(&self) -> Option<JoinOrder> {
+        struct Best {
+            rows: f64,
+            cost: f64,
+            split: u64,
+        }
+
+        let n = self.rows.len();
+        let mut best = (0..1u64 << n).map(|_| None).collect::<Vec<Option<Best>>>();
+        for (i, rows) in self.rows.iter().enumerate() {
+            best[1 << i] = Some(Best {
+                rows: *rows,
+                cost: 0.0,
+                split: 0,
+            });
+        }
+        for mask in 1u64..1 << n {
+            if mask.count_ones() < 2 {
+                continue;
+            }
+            let mut current = None::<Best>;
+            // Every split is visited once, with the lowest input on the left.
+            let low = mask & mask.wrapping_neg();
+            let mut left = (mask - 1) & mask;
+            while left != 0 {
+                let right = mask & !left;
+                if left & low != 0 {
+                    if let (Some(l), Some(r)) = (&best[left as usize], &best[right as usize]) {
+                        if let Some(rows) = self.join_rows((left, l.rows), (right, r.rows)) {
+                            let cost = l.cost + r.cost + rows;
+                            if current.as_ref().is_none_or(|c| cost < c.cost) {
+                                current = Some(Best {
+                                    rows,
+                                    cost,
+                                    split: left,
+                                });
+                            }
+                        }
+                    }
+                }
+                left = (left - 1) & mask;
+            }
+            best[mask as usize] = current;
+        }
+
+        fn to_order(mask: u64, best: &[Option<Best>]) -> JoinOrder {
+            let split = best[mask as usize].as_ref().unwrap().split;
+            if split == 0 {
+                return JoinOrder::Input(mask.trailing_zeros() as usize);
+            }
+            JoinOrder::Join(
+                Box::new(to_order(split, best)),
+                Box::new(to_order(mask & !split, best)),
+            )
+        }
+        let all = (1u64 << n) - 1;
+        best[all as usize].as_ref()?;
+        Some(to_order(all, &best))
+    }
+
+    /// Repeatedly join the two connected subtrees with the smallest result.
+    fn best_order_greedy(&self) -> Option<JoinOrder> {
+        let mut trees = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, rows)| (JoinOrder::Input(i), 1u64 << i, *rows))
+            .collect::<Vec<_>>();
+        while trees.len() > 1 {
+            let mut best = None::<(usize, usize, f64)>;
+            for a in 0..trees.len() {
+                for b in a + 1..trees.len() {
+                    let Some(rows) =
+                        self.join_rows((trees[a].1, trees[a].2), (trees[b].1, trees[b].2))
+                    else {
+                        continue;
+                    };
+                    if best.is_none_or(|(_, _, r)| rows < r) {
+                        best = Some((a, b, rows));
+                    }
+                }
+            }
+            let (a, b, rows) = best?;
+            let (right, right_mask, _) = trees.swap_remove(b);
+            let (left, left_mask, _) = trees.swap_remove(a);
+            trees.push((
+                JoinOrder::Join(Box::new(left), Box::new(right)),
+                left_mask | right_mask,
+                rows,
+            ));
+        }
+        trees.pop().map(|(order, _, _)| order)
+    }
+}
+
+/// The estimated fraction of rows for which `predicate` holds.
+fn selectivity(predicate: Node, expr_arena: &Arena<AExpr>) -> f64 {
+    MintermIter::new(predicate, expr_arena)
+        .map(|node| match expr_arena.get(node) {
+            AExpr::BinaryExpr { op, .. } => match op {
+                Operator::Eq | Operator::EqValidity => 0.1,
+                Operator::NotEq | Operator::NotEqValidity => 0.9,
+                Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq => 1.0 / 3.0,
+                _ => 0.5,
+            },
+            _ => 0.5,
+        })
+        .product()
+}
+
+/// The estimated number of rows of `node`, if the sizes of its inputs are known.
+fn estimate_rows(node: Node, lp_arena: &Arena<IR>, expr_arena: &Arena<AExpr>) -> Option<f64> {
+    use IR::*;
+    let estimate = |node: &Node| estimate_rows(*node, lp_arena, expr_arena);
+    let rows = match lp_arena.get(node) {
+        DataFrameScan { df, .. } => df.height() as f64,
+        Scan {
+            file_info,
+            predicate,
+            file_options,
+            ..
+        } => {
+            let mut rows = match file_info.row_estimation {
+                (Some(known), _) => known as f64,
+                (None, estimated) if estimated != usize::MAX => estimated as f64,
+                _ => return None,
+            };
+            if let Some(predicate) = predicate {
+                rows *= selectivity(predicate.node(), expr_arena);
+            }
+            if let Some((_, len)) = file_options.slice {
+                rows = rows.min(len as f64);
+            }
+            rows
+        },
+        Filter { input, predicate } => estimate(input)? * selectivity(predicate.node(), expr_arena),
+        Slice { input, len, .. } => estimate(input)?.min(*len as f64),
+        Sort { input, slice, .. } => {
+            let rows = estimate(input)?;
+            slice.map_or(rows, |(_, len)| rows.min(len as f64))
+        },
+        GroupBy { input, keys, .. } => {
+            let rows = estimate(input)?;
+            let groups = keys
+                .iter()
+                .map(|key| match expr_arena.get(key.node()) {
+                    AExpr::Column(name) => column_ndv(*input, name, lp_arena, expr_arena),
+                    _ => None,
+                })
+                .product::<Option<f64>>();
+            groups.map_or(rows, |groups| groups.min(rows))
+        },
+        Join {
+            input_left,
+            input_right,
+            options,
+            ..
+        } => {
+            let (left, right) = (estimate(input_left)?, estimate(input_right)?);
+            match options.args.how {
+                JoinType::Left => left,
+                JoinType::Right => right,
+                JoinType::Full => left + right,
+                JoinType::Cross => left * right,
+                _ => left.max(right),
+            }
+        },
+        Union { inputs, .. } => inputs.iter().map(estimate).sum::<Option<f64>>()?,
+        HConcat { inputs, .. } => inputs
+            .iter()
+            .map(estimate)
+            .try_fold(0.0, |acc, rows| Some(rows?.max(acc)))?,
+        SimpleProjection { input, .. }
+        | Select { input, .. }
+        | HStack { input, .. }
+        | Cache { input, .. }
+        | Distinct { input, .. }
+        | MapFunction { input, .. } => estimate(input)?,
+        _ => return None,
+    };
+    Some(rows.max(1.0))
+}
+
+/// The estimated number of distinct values of the column `name` of `node`.
+fn column_ndv(
+    node: Node,
+    name: &str,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<f64> {
+    use IR::*;
+    let source = |exprs: &[ExprIR]| match exprs.iter().find(|e| e.output_name() == name) {
+        Some(e) => match expr_arena.get(e.node()) {
+            AExpr::Column(name) => Some(Some(name.clone())),
+            _ => Some(None),
+        },
+        None => None,
+    };
+    match lp_arena.get(node) {
+        DataFrameScan { df, .. } => sampled_ndv(df.column(name).ok()?.as_materialized_series()),
+        #[cfg(feature = "parquet")]
+        Scan {
+            sources,
+            scan_type:
+                crate::dsl::FileScan::Parquet {
+                    metadata: Some(metadata),
+                    ..
+                },
+            ..
+        } if sources.len() == 1 => parquet_ndv(metadata, name),
+        Filter { input, .. }
+        | Slice { input, .. }
+        | Sort { input, .. }
+        | Cache { input, .. }
+        | SimpleProjection { input, .. }
+        | Distinct { input, .. } => column_ndv(*input, name, lp_arena, expr_arena),
+        HStack { input, exprs, .. } => match source(exprs) {
+            Some(Some(source)) => column_ndv(*input, &source, lp_arena, expr_arena),
+            Some(None) => None,
+            None => column_ndv(*input, name, lp_arena, expr_arena),
+        },
+        Select { input, expr, .. } => column_ndv(*input, &source(expr)??, lp_arena, expr_arena),
+        GroupBy { input, keys, .. } => column_ndv(*input, &source(keys)??, lp_arena, expr_arena),
+        Join {
+            input_left,
+            input_right,
+            ..
+        } => {
+            if lp_arena.get(*input_left).schema(lp_arena).contains(name) {
+                column_ndv(*input_left, name, lp_arena, expr_arena)
+            } else {
+                column_ndv(*input_right, name, lp_arena, expr_arena)
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Estimate the number of distinct values from a sample of `s`, with the guaranteed-error
+/// estimator of Charikar et al.
+fn sampled_ndv(s: &Series) -> Option<f64> {
+    let n = s.len();
+    if n == 0 {
+        return Some(0.0);
+    }
+    let sample = if n > NDV_SAMPLE_SIZE {
+        let step = n / NDV_SAMPLE_SIZE;
+        let idx = IdxCa::from_vec(
+            PlSmallStr::EMPTY,
+            (0..NDV_SAMPLE_SIZE)
+                .map(|i| (i * step) as IdxSize)
+                .collect(),
+        );
+        s.take(&idx).ok()?
+    } else {
+        s.clone()
+    };
+    let groups = sample.group_tuples(true, false).ok()?;
+    let distinct = groups.len();
+    let singletons = groups.iter().filter(|g| g.len() == 1).count();
+    let scale = (n as f64 / sample.len() as f64).sqrt();
+    Some(scale * singletons as f64 + (distinct - singletons) as f64)
+}
+
+/// Estimate the number of distinct values of a column from the statistics of a parquet file.
+#[cfg(feature = "parquet")]
+fn parquet_ndv(
+    metadata: &polars_parquet::parquet::metadata::FileMetadata,
+    name: &str,
+) -> Option<f64> {
+    use polars_parquet::parquet::statistics::Statistics;
+
+    let mut distinct = Some(0.0);
+    let mut range = Some(None::<(i64, i64)>);
+    for row_group in &metadata.row_groups {
+        let mut columns = row_group.columns_under_root_iter(name)?;
+        // Nested columns have multiple leaves.
+        if columns.len() != 1 {
+            return None;
+        }
+        let statistics = columns.next()?.statistics()?.ok()?;
+        let (count, min, max) = match statistics {
+            Statistics::Int32(s) => (
+                s.distinct_count,
+                s.min_value.map(i64::from),
+                s.max_value.map(i64::from),
+            ),
+            Statistics::Int64(s) => (s.distinct_count, s.min_value, s.max_value),
+            Statistics::Binary(s) => (s.distinct_count, None, None),
+            _ => return None,
+        };
+        // The sum over the row groups is an upper bound of the distinct count of the file.
+        distinct = distinct.zip(count).map(|(acc, count)| acc + count as f64);
+        range = match (range, min, max) {
+            (Some(range), Some(min), Some(max)) => Some(Some(
+                range.map_or((min, max), |(lo, hi)| (lo.min(min), hi.max(max))),
+            )),
+            _ => None,
+        };
+    }
+    let range = range
+        .flatten()
+        .map(|(min, max)| max.abs_diff(min) as f64 + 1.0);
+    match (distinct, range) {
+        (Some(distinct), Some(range)) => Some(distinct.min(range)),
+        (distinct, range) => distinct.or(range),
+    }
+}
@@ -15,6 +15,7 @@ mod cse;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod join_reorder;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod predicate_pushdown;
@@ -23,6 +24,7 @@ mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+mod sort_distinct_elim;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
@@ -198,6 +200,18 @@ More information on the new streaming engine: https://github.com/pola-rs/polars/
         collapse_joins::optimize(lp_top, lp_arena, expr_arena);
     }
 
+    if opt_flags.join_reorder() && !opt_flags.eager() && get_or_init_members!().has_joins_or_unions
+    {
+        join_reorder::optimize(lp_top, lp_arena, expr_arena, verbose);
+    }
+
+    if opt_flags.sort_distinct_elim() {
+        let members = get_or_init_members!();
+        if members.has_sort | members.has_distinct {
+            sort_distinct_elim::optimize(lp_top, lp_arena, expr_arena);
+        }
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
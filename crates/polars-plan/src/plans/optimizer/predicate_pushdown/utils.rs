@@ -334,7 +334,10 @@ pub fn pushdown_eligibility(
     // Note: has_window is constant.
     let can_use_column = |col: &str| {
         if has_window {
-            common_window_inputs.contains(col)
+            // The partition column may be overwritten by one of the projections, e.g.:
+            // .with_columns(sum().over(A).alias(A))
+            // .filter(col(A) == ..)
+            common_window_inputs.contains(col) && !modified_projection_columns.contains(col)
         } else {
             !modified_projection_columns.contains(col)
         }
@@ -0,0 +1,82 @@
+//! Removes sorts and distincts that don't change their input.
+//!
+//! An input is already sorted if it is the output of an earlier sort or of a scan of sorted data
+//! (e.g. a `DataFrame` with sorted flags or a Parquet file with `sorting_columns`). It is already
+//! unique if it is the output of an earlier distinct or group-by on a subset of the columns.
+use polars_utils::unitvec;
+use recursive::recursive;
+
+use super::*;
+
+pub(super) fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &Arena<AExpr>) {
+    let mut cache = PlHashMap::default();
+    visit(root, lp_arena, expr_arena, &mut cache);
+}
+
+#[recursive]
+fn visit(
+    node: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache: &mut PlHashMap<Node, Arc<IRProperties>>,
+) -> Arc<IRProperties> {
+    if let Some(props) = cache.get(&node) {
+        return props.clone();
+    }
+
+    let mut inputs = unitvec![];
+    lp_arena.get(node).copy_inputs(&mut inputs);
+    let input_props = inputs
+        .iter()
+        .map(|&input| visit(input, lp_arena, expr_arena, cache))
+        .collect::<Vec<_>>();
+
+    let redundant = match lp_arena.get(node) {
+        IR::Sort {
+            input,
+            by_column,
+            slice,
+            sort_options,
+        } if sort_options.limit.is_none() => column_names(by_column, expr_arena)
+            .filter(|by| {
+                input_props[0].is_sorted_by(by, &sort_options.descending, &sort_options.nulls_last)
+            })
+            .map(|_| (*input, *slice)),
+        IR::Distinct { input, options } => {
+            let is_unique = match &options.subset {
+                Some(subset) => input_props[0].is_unique_by(subset),
+                None => {
+                    let schema = lp_arena.get(*input).schema(lp_arena);
+                    input_props[0].is_unique_by(&schema.iter_names_cloned().collect::<Vec<_>>())
+                },
+            };
+            is_unique.then_some((*input, options.slice))
+        },
+        _ => None,
+    };
+
+    let props = match redundant {
+        Some((input, slice)) => {
+            let ir = match slice {
+                Some((offset, len)) => IR::Slice {
+                    input,
+                    offset,
+                    len: len as IdxSize,
+                },
+                // The input may be shared, so we copy it instead of swapping the nodes.
+                None => lp_arena.get(input).clone(),
+            };
+            lp_arena.replace(node, ir);
+            input_props[0].clone()
+        },
+        None => Arc::new(derive_properties(
+            lp_arena.get(node),
+            &input_props,
+            lp_arena,
+            expr_arena,
+        )),
+    };
+
+    cache.insert(node, props.clone());
+    props
+}
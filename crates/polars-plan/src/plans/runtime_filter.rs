@@ -0,0 +1,224 @@
+//! Planning of runtime join filters.
+//!
+//! Once the build side of a join is materialized, its keys can be summarized in a filter that is
+//! passed to the scans of the probe side. These scans then skip row groups and hive partitions
+//! that cannot contain a matching key. The same holds for the values of an `is_in` on a column of
+//! an external context, such as an SQL `IN` subquery. This module finds the scans that such a
+//! filter can reach.
+//!
+//! # Limitations
+//!
+//! - Partition directories are not pruned while they are listed. The paths of a scan, and with
+//!   them its hive partitions, are resolved when the plan is built, before any filter is known.
+//!   Filters only decide which of these files are opened, so the directories of every partition
+//!   are still listed. Listing only the matching directories needs the expansion of the paths to
+//!   be deferred to the execution of the scan, which is not implemented yet.
+//! - The in-memory engine runs the inputs of a join in parallel where it can. Scans of the probe
+//!   side then poll the filters, so they only skip the batches that they read after the build
+//!   side is materialized. Hive partitions are only skipped if the filters are published when
+//!   the scan starts.
+//! - The in-memory engine skips files, hive partitions and row groups. The new streaming engine
+//!   only skips parquet row groups by their statistics, and not hive partitions. The old
+//!   streaming engine does not use runtime filters.
+//! - Scans of multiple files or of hive partitions are not filtered if `POLARS_NEW_MULTIFILE` is
+//!   set, as the multi-file scan of the in-memory engine does not use runtime filters.
+//! - Filters from `is_in` on external contexts only apply on the in-memory engine, as neither
+//!   streaming engine runs external contexts.
+
+use polars_core::prelude::*;
+use polars_ops::frame::JoinArgs;
+use polars_utils::arena::{Arena, Node};
+
+use super::{is_elementwise_rec, AExpr, ExprIR, MintermIter, IR};
+
+/// A scan of the probe side of a join, and its column that the probe key stems from.
+pub type RuntimeFilterTarget = (Node, PlSmallStr);
+
+/// The scans that can be filtered with the keys of the build side of a join.
+///
+/// Returns the build key column of every key of which both sides are plain columns, with the
+/// scans that the probe key stems from. Keys of which the probe side does not reach a scan are
+/// omitted.
+#[allow(clippy::too_many_arguments)]
+pub fn join_runtime_filter_targets(
+    build_left: bool,
+    input_left: Node,
+    input_right: Node,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    args: &JoinArgs,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<(PlSmallStr, Vec<RuntimeFilterTarget>)> {
+    use polars_ops::frame::JoinType;
+
+    // Rows of the probe side without a matching key may only be dropped if the join drops them.
+    let allowed = match args.how {
+        JoinType::Inner => true,
+        #[cfg(feature = "semi_anti_join")]
+        JoinType::Semi => !build_left,
+        _ => false,
+    };
+    if !allowed || args.nulls_equal || std::env::var("POLARS_NO_RUNTIME_FILTERS").is_ok() {
+        return vec![];
+    }
+
+    let (probe, build_on, probe_on) = if build_left {
+        (input_right, left_on, right_on)
+    } else {
+        (input_left, right_on, left_on)
+    };
+    let column = |e: &ExprIR| match expr_arena.get(e.node()) {
+        AExpr::Column(name) => Some(name.clone()),
+        _ => None,
+    };
+    build_on
+        .iter()
+        .zip(probe_on)
+        .filter_map(|(build_key, probe_key)| {
+            let (build_key, probe_key) = (column(build_key)?, column(probe_key)?);
+            let mut targets = vec![];
+            collect_targets(probe, probe_key, lp_arena, expr_arena, &mut targets);
+            (!targets.is_empty()).then_some((build_key, targets))
+        })
+        .collect()
+}
+
+/// The scans that can be filtered with the values of `is_in` predicates on a column of the
+/// contexts of `ext_context`, that are evaluated before its input.
+///
+/// Returns the context column of every such predicate of `predicate`, with the scans that the
+/// filtered column stems from.
+///
+/// Only the in-memory engine uses these targets, see the limitations in the module docs.
+pub fn is_in_runtime_filter_targets(
+    predicate: &ExprIR,
+    ext_context: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Vec<(PlSmallStr, Vec<RuntimeFilterTarget>)> {
+    let IR::ExtContext { input, schema, .. } = lp_arena.get(ext_context) else {
+        return vec![];
+    };
+    if std::env::var("POLARS_NO_RUNTIME_FILTERS").is_ok() {
+        return vec![];
+    }
+    let input_schema = lp_arena.get(*input).schema(lp_arena);
+
+    // Rows of which the predicate is false or null are dropped, so every `is_in` of the
+    // conjunction may drop the rows of which the value is not in the context column.
+    MintermIter::new(predicate.node(), expr_arena)
+        .filter_map(|node| {
+            let (column, values) = is_in_columns(node, expr_arena)?;
+            let dtype = input_schema.get(&column)?;
+            if input_schema.contains(&values) || schema.get(&values) != Some(dtype) {
+                return None;
+            }
+
+            let mut targets = vec![];
+            collect_targets(*input, column, lp_arena, expr_arena, &mut targets);
+            (!targets.is_empty()).then_some((values, targets))
+        })
+        .collect()
+}
+
+/// The column and the values column of an `is_in` of two columns that does not match nulls.
+fn is_in_columns(node: Node, expr_arena: &Arena<AExpr>) -> Option<(PlSmallStr, PlSmallStr)> {
+    match expr_arena.get(node) {
+        #[cfg(feature = "is_in")]
+        AExpr::Function {
+            input,
+            function:
+                crate::dsl::FunctionExpr::Boolean(crate::dsl::BooleanFunction::IsIn {
+                    nulls_equal: false,
+                }),
+            ..
+        } => {
+            let column = |e: &ExprIR| match expr_arena.get(e.node()) {
+                AExpr::Column(name) => Some(name.clone()),
+                _ => None,
+            };
+            Some((column(input.first()?)?, column(input.get(1)?)?))
+        },
+        _ => None,
+    }
+}
+
+/// Collect the scans that `column` of `node` stems from, through nodes of which every output row
+/// only depends on a single input row. Dropping rows from these scans then only drops the
+/// corresponding rows of `node`.
+fn collect_targets(
+    node: Node,
+    column: PlSmallStr,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    targets: &mut Vec<RuntimeFilterTarget>,
+) {
+    use IR::*;
+    let all_elementwise = |exprs: &[ExprIR]| {
+        exprs
+            .iter()
+            .all(|e| is_elementwise_rec(expr_arena.get(e.node()), expr_arena))
+    };
+    // The input column of a projected column, if it is a plain column.
+    let source = |exprs: &[ExprIR]| {
+        exprs
+            .iter()
+            .find(|e| e.output_name() == &column)
+            .map(|e| match expr_arena.get(e.node()) {
+                AExpr::Column(name) => Some(name.clone()),
+                _ => None,
+            })
+    };
+
+    match lp_arena.get(node) {
+        Filter { input, .. } => collect_targets(*input, column, lp_arena, expr_arena, targets),
+        Sort {
+            input, slice: None, ..
+        } => collect_targets(*input, column, lp_arena, expr_arena, targets),
+        SimpleProjection { input, columns } if columns.contains(&column) => {
+            collect_targets(*input, column, lp_arena, expr_arena, targets)
+        },
+        Select { input, expr, .. } if all_elementwise(expr) => {
+            if let Some(Some(column)) = source(expr) {
+                collect_targets(*input, column, lp_arena, expr_arena, targets)
+            }
+        },
+        HStack { input, exprs, .. } if all_elementwise(exprs) => {
+            if let Some(column) = source(exprs).unwrap_or(Some(column)) {
+                collect_targets(*input, column, lp_arena, expr_arena, targets)
+            }
+        },
+        Union { inputs, options } if options.slice.is_none() => {
+            for input in inputs {
+                collect_targets(*input, column.clone(), lp_arena, expr_arena, targets);
+            }
+        },
+        #[cfg(feature = "parquet")]
+        Scan {
+            sources,
+            file_info,
+            hive_parts,
+            scan_type: crate::dsl::FileScan::Parquet { options, .. },
+            file_options,
+            ..
+        } => {
+            let prunable = options.use_statistics || hive_parts.is_some();
+            // The multi-file scan of the in-memory engine does not use runtime filters, see the
+            // limitations in the module docs.
+            let new_multifile = (sources.len() > 1 || hive_parts.is_some())
+                && std::env::var("POLARS_NEW_MULTIFILE").as_deref() == Ok("1");
+            if prunable
+                && !new_multifile
+                && file_options.row_index.is_none()
+                && file_options.slice.is_none()
+                && file_info.schema.get(&column).is_some_and(|dtype| {
+                    dtype.to_physical().is_primitive_numeric() || dtype.is_string()
+                })
+            {
+                targets.push((node, column));
+            }
+        },
+        _ => {},
+    }
+}
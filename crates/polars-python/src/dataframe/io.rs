@@ -444,6 +444,7 @@ impl PyDataFrame {
                     row_group_size,
                     data_page_size,
                     maintain_order: true,
+                    sorting_columns: true,
                 };
                 write_partitioned_dataset(
                     &mut self.df,
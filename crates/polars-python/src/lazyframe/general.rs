@@ -706,6 +706,7 @@ impl PyLazyFrame {
             row_group_size,
             data_page_size,
             maintain_order,
+            sorting_columns: true,
         };
 
         let cloud_options = {
@@ -797,7 +798,9 @@ impl PyLazyFrame {
             float_scientific,
             float_precision,
             separator,
+            multi_char_separator: None,
             quote_char,
+            escape_char: None,
             null: null_value,
             line_terminator,
             quote_style,
@@ -16,12 +16,17 @@ use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
+use polars_error::signals::{
+    current_cancellation_token, with_cancellation_token, CancellationToken,
+};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use slotmap::SlotMap;
 pub use task::{AbortOnDropHandle, JoinHandle};
 use task::{CancelHandle, Runnable};
 
+use crate::metrics::NodeMetrics;
+
 static NUM_EXECUTOR_THREADS: AtomicUsize = AtomicUsize::new(0);
 pub fn set_num_threads(t: usize) {
     NUM_EXECUTOR_THREADS.store(t, Ordering::Relaxed);
@@ -78,6 +83,9 @@ struct TaskMetadata {
     priority: TaskPriority,
     freshly_spawned: AtomicBool,
     scoped: Option<ScopedTaskMetadata>,
+    metrics: Option<Arc<NodeMetrics>>,
+    // The token of the query that spawned this task, checked whenever it is polled.
+    cancel_token: Option<CancellationToken>,
 }
 
 impl Drop for TaskMetadata {
@@ -256,7 +264,17 @@ impl Executor {
                     }
                 }
                 worker.recruit_next();
-                task.run();
+                let cancel_token = task.metadata().cancel_token.clone();
+                with_cancellation_token(cancel_token, || match task.metadata().metrics.clone() {
+                    Some(metrics) => {
+                        let start = std::time::Instant::now();
+                        task.run();
+                        metrics.record_poll(start, std::time::Instant::now());
+                    },
+                    None => {
+                        task.run();
+                    },
+                });
             }
         }
     }
@@ -302,6 +320,8 @@ pub struct TaskScope<'scope, 'env: 'scope> {
     // reclaim the memory used by the cancel_handles.
     cancel_handles: Mutex<SlotMap<TaskKey, CancelHandle>>,
     completed_tasks: Arc<Mutex<Vec<TaskKey>>>,
+    // The metrics that the polls of newly spawned tasks are recorded in.
+    task_metrics: Mutex<Option<Arc<NodeMetrics>>>,
 
     // Copied from std::thread::scope. Necessary to prevent unsoundness.
     scope: PhantomData<&'scope mut &'scope ()>,
@@ -324,6 +344,11 @@ impl<'scope> TaskScope<'scope, '_> {
         }
     }
 
+    /// Record the time spent polling tasks spawned from now on in `metrics`.
+    pub fn set_task_metrics(&self, metrics: Option<Arc<NodeMetrics>>) {
+        *self.task_metrics.lock() = metrics;
+    }
+
     #[track_caller]
     pub fn spawn_task<F: Future + Send + 'scope>(
         &self,
@@ -335,6 +360,7 @@ impl<'scope> TaskScope<'scope, '_> {
     {
         let spawn_location = Location::caller();
         self.clear_completed_tasks();
+        let metrics = self.task_metrics.lock().clone();
 
         let mut runnable = None;
         let mut join_handle = None;
@@ -355,6 +381,8 @@ impl<'scope> TaskScope<'scope, '_> {
                             task_key,
                             completed_tasks: Arc::downgrade(&self.completed_tasks),
                         }),
+                        metrics,
+                        cancel_token: current_cancellation_token(),
                     },
                 )
             };
@@ -378,6 +406,7 @@ where
     let scope = TaskScope {
         cancel_handles: Mutex::default(),
         completed_tasks: Arc::new(Mutex::default()),
+        task_metrics: Mutex::default(),
         scope: PhantomData,
         env: PhantomData,
     };
@@ -410,6 +439,8 @@ where
             priority,
             freshly_spawned: AtomicBool::new(true),
             scoped: None,
+            metrics: None,
+            cancel_token: current_cancellation_token(),
         },
     );
     runnable.schedule();
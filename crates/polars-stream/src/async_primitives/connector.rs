@@ -15,10 +15,16 @@ pub fn connector<T>() -> (Sender<T>, Receiver<T>) {
         Sender {
             connector: connector.clone(),
         },
-        Receiver { connector },
+        Receiver {
+            connector,
+            hook: None,
+        },
     )
 }
 
+/// Called with a reference to every value a [`Receiver`] receives.
+pub type RecvHook<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
 /*
     For UnsafeCell safety, a sender may only set the FULL_BIT (giving exclusive
     access to value to the receiver), and a receiver may only unset the FULL_BIT
@@ -201,6 +207,7 @@ impl<T> Drop for Sender<T> {
 
 pub struct Receiver<T> {
     connector: Arc<Connector<T>>,
+    hook: Option<RecvHook<T>>,
 }
 
 unsafe impl<T: Send> Send for Receiver<T> {}
@@ -252,6 +259,7 @@ impl<T> std::future::Future for SendFuture<'_, T> {
 pin_project! {
     pub struct RecvFuture<'a, T> {
         connector: &'a Connector<T>,
+        hook: Option<&'a RecvHook<T>>,
         done: bool,
     }
 }
@@ -266,13 +274,23 @@ impl<T: Send> Receiver<T> {
     pub fn recv(&mut self) -> RecvFuture<'_, T> {
         RecvFuture {
             connector: &self.connector,
+            hook: self.hook.as_ref(),
             done: false,
         }
     }
 
     #[allow(unused)]
     pub fn try_recv(&mut self) -> Result<T, RecvError> {
-        unsafe { self.connector.try_recv() }
+        let value = unsafe { self.connector.try_recv() }?;
+        if let Some(hook) = &self.hook {
+            hook(&value);
+        }
+        Ok(value)
+    }
+
+    /// Call `hook` on every value received from now on.
+    pub fn set_hook(&mut self, hook: RecvHook<T>) {
+        self.hook = Some(hook);
     }
 }
 
@@ -284,6 +302,10 @@ impl<T> std::future::Future for RecvFuture<'_, T> {
             !self.done,
             "re-poll after Poll::Ready in connector SendFuture"
         );
-        unsafe { self.connector.poll_recv(cx.waker()) }
+        let poll = unsafe { self.connector.poll_recv(cx.waker()) };
+        if let (Poll::Ready(Ok(value)), Some(hook)) = (&poll, self.hook) {
+            hook(value);
+        }
+        poll
     }
 }
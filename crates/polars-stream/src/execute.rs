@@ -1,5 +1,8 @@
+use std::sync::Arc;
+
 use polars_core::frame::DataFrame;
 use polars_core::POOL;
+use polars_error::signals::current_cancellation_token;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_utils::aliases::PlHashSet;
@@ -7,6 +10,7 @@ use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::async_executor;
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
+use crate::metrics::{pipe_hook, NodeMetrics};
 use crate::pipe::PhysicalPipe;
 
 /// Finds all runnable pipeline blockers in the graph, that is, nodes which:
@@ -105,11 +109,18 @@ fn run_subgraph(
     nodes: &PlHashSet<GraphNodeKey>,
     pipes: &[LogicalPipeKey],
     num_pipelines: usize,
+    metrics: Option<&SecondaryMap<GraphNodeKey, Arc<NodeMetrics>>>,
 ) -> PolarsResult<()> {
     // Construct physical pipes for the logical pipes we'll use.
     let mut physical_pipes = SecondaryMap::new();
+    let mut pipe_hooks = SecondaryMap::new();
     for pipe_key in pipes.iter().copied() {
         physical_pipes.insert(pipe_key, PhysicalPipe::new(num_pipelines));
+        if let Some(metrics) = metrics {
+            let pipe = &graph.pipes[pipe_key];
+            let hook = pipe_hook(metrics[pipe.sender].clone(), metrics[pipe.receiver].clone());
+            pipe_hooks.insert(pipe_key, hook);
+        }
     }
 
     // We do a topological sort of the graph: we want to spawn each node,
@@ -158,14 +169,16 @@ fn run_subgraph(
             }
 
             // Construct the receive/send ports.
-            for input_pipe in &mut input_pipes {
-                recv_ports.push(input_pipe.as_mut().map(|p| p.recv_port()));
+            for (input, input_pipe) in node.inputs.iter().zip(&mut input_pipes) {
+                let hook = pipe_hooks.get(*input).cloned();
+                recv_ports.push(input_pipe.as_mut().map(|p| p.recv_port().with_hook(hook)));
             }
             for output_pipe in &mut output_pipes {
                 send_ports.push(output_pipe.as_mut().map(|p| p.send_port()));
             }
 
             // Spawn a task per pipeline.
+            scope.set_task_metrics(metrics.map(|m| m[node_key].clone()));
             node.compute.spawn(
                 scope,
                 &mut recv_ports[..],
@@ -213,6 +226,9 @@ fn run_subgraph(
             output_pipes = reuse_vec(output_pipes);
         }
 
+        // Tasks spawned from here on aren't attributed to a node.
+        scope.set_task_metrics(None);
+
         // Spawn tasks for all the physical pipes (no-op on most, but needed for
         // those with distributors or linearizers).
         for pipe in physical_pipes.values_mut() {
@@ -237,11 +253,21 @@ fn run_subgraph(
         ret
     })?;
 
+    if let Some(metrics) = metrics {
+        for node_key in nodes {
+            metrics[*node_key].end_phase(graph.nodes[*node_key].compute.buffered_size());
+        }
+    }
+
     Ok(())
 }
 
+/// Executes the graph and returns the output of the in-memory nodes.
+///
+/// If `metrics` is given, the metrics of every node are recorded in it.
 pub fn execute_graph(
     graph: &mut Graph,
+    metrics: Option<&SecondaryMap<GraphNodeKey, Arc<NodeMetrics>>>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -263,7 +289,11 @@ pub fn execute_graph(
         node.compute.initialize(num_pipelines);
     }
 
+    let cancel_token = current_cancellation_token();
     loop {
+        if let Some(token) = &cancel_token {
+            token.check()?;
+        }
         if polars_core::config::verbose() {
             eprintln!("polars-stream: updating graph state");
         }
@@ -280,7 +310,7 @@ pub fn execute_graph(
         if nodes.is_empty() {
             break;
         }
-        run_subgraph(graph, &nodes, &pipes, num_pipelines)?;
+        run_subgraph(graph, &nodes, &pipes, num_pipelines, metrics)?;
         if polars_core::config::verbose() {
             eprintln!("polars-stream: done running graph phase");
         }
@@ -2,11 +2,13 @@ mod async_executor;
 mod async_primitives;
 mod skeleton;
 
-pub use skeleton::run_query;
+pub use metrics::PhysNodeMetrics;
+pub use skeleton::{run_query, run_query_with_metrics, QueryMetrics};
 
 mod execute;
 pub(crate) mod expression;
 mod graph;
+mod metrics;
 mod morsel;
 mod nodes;
 mod physical_plan;
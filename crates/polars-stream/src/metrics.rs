@@ -0,0 +1,98 @@
+//! Runtime metrics of the nodes in a streaming graph, as reported by `EXPLAIN ANALYZE`.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::async_primitives::connector::RecvHook;
+use crate::morsel::Morsel;
+
+/// Metrics of a single graph node, accumulated while the graph executes.
+#[derive(Default)]
+pub struct NodeMetrics {
+    rows_in: AtomicU64,
+    morsels_in: AtomicU64,
+    bytes_in: AtomicU64,
+    rows_out: AtomicU64,
+    morsels_out: AtomicU64,
+    bytes_out: AtomicU64,
+    cpu_ns: AtomicU64,
+    wall_ns: AtomicU64,
+    peak_buffered_bytes: AtomicU64,
+    /// Start of the first and end of the last poll of a task of this node in the current phase.
+    phase_span: Mutex<Option<(Instant, Instant)>>,
+}
+
+impl NodeMetrics {
+    /// Record a poll of one of the tasks of this node.
+    pub fn record_poll(&self, start: Instant, end: Instant) {
+        self.cpu_ns
+            .fetch_add((end - start).as_nanos() as u64, Ordering::Relaxed);
+        let mut span = self.phase_span.lock();
+        *span = Some(match *span {
+            Some((first, last)) => (first.min(start), last.max(end)),
+            None => (start, end),
+        });
+    }
+
+    /// Close the current execution phase, given the memory the node buffers at the end of it.
+    pub fn end_phase(&self, buffered_bytes: usize) {
+        if let Some((first, last)) = self.phase_span.lock().take() {
+            self.wall_ns
+                .fetch_add((last - first).as_nanos() as u64, Ordering::Relaxed);
+        }
+        self.peak_buffered_bytes
+            .fetch_max(buffered_bytes as u64, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> PhysNodeMetrics {
+        let load = |v: &AtomicU64| v.load(Ordering::Relaxed);
+        PhysNodeMetrics {
+            rows_in: load(&self.rows_in),
+            morsels_in: load(&self.morsels_in),
+            bytes_in: load(&self.bytes_in),
+            rows_out: load(&self.rows_out),
+            morsels_out: load(&self.morsels_out),
+            bytes_out: load(&self.bytes_out),
+            wall_time: Duration::from_nanos(load(&self.wall_ns)),
+            cpu_time: Duration::from_nanos(load(&self.cpu_ns)),
+            peak_buffered_bytes: load(&self.peak_buffered_bytes),
+        }
+    }
+}
+
+/// A hook for the receiving end of a pipe that counts the morsels sent from `sender` to
+/// `receiver`.
+pub fn pipe_hook(sender: Arc<NodeMetrics>, receiver: Arc<NodeMetrics>) -> RecvHook<Morsel> {
+    Arc::new(move |morsel: &Morsel| {
+        let rows = morsel.df().height() as u64;
+        let bytes = morsel.df().estimated_size() as u64;
+        for (morsels, num_rows, num_bytes) in [
+            (&sender.morsels_out, &sender.rows_out, &sender.bytes_out),
+            (&receiver.morsels_in, &receiver.rows_in, &receiver.bytes_in),
+        ] {
+            morsels.fetch_add(1, Ordering::Relaxed);
+            num_rows.fetch_add(rows, Ordering::Relaxed);
+            num_bytes.fetch_add(bytes, Ordering::Relaxed);
+        }
+    })
+}
+
+/// The metrics of a node of the physical plan after the query finished.
+///
+/// The CPU time is the time spent polling the tasks of the node, the wall time the time between
+/// the first and last poll in each execution phase the node took part in. The peak buffered memory
+/// is sampled at the end of every phase, and only reported by nodes that hold on to morsels.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhysNodeMetrics {
+    pub rows_in: u64,
+    pub morsels_in: u64,
+    pub bytes_in: u64,
+    pub rows_out: u64,
+    pub morsels_out: u64,
+    pub bytes_out: u64,
+    pub wall_time: Duration,
+    pub cpu_time: Duration,
+    pub peak_buffered_bytes: u64,
+}
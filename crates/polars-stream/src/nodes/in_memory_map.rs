@@ -86,6 +86,13 @@ impl ComputeNode for InMemoryMapNode {
         matches!(self, Self::Sink { .. })
     }
 
+    fn buffered_size(&self) -> usize {
+        match self {
+            Self::Sink { sink_node, .. } => sink_node.buffered_size(),
+            Self::Source(_) | Self::Done => 0,
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
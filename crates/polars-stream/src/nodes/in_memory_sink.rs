@@ -42,6 +42,15 @@ impl ComputeNode for InMemorySinkNode {
         true
     }
 
+    fn buffered_size(&self) -> usize {
+        self.morsels_per_pipe
+            .lock()
+            .iter()
+            .flatten()
+            .map(|m| m.df().estimated_size())
+            .sum()
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
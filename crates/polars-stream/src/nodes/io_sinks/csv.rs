@@ -77,8 +77,12 @@ impl SinkNode for CsvSinkNode {
                         .include_bom(false) // Handled once in the IO task.
                         .include_header(false) // Handled once in the IO task.
                         .with_separator(options.serialize_options.separator)
+                        .with_multi_char_separator(
+                            options.serialize_options.multi_char_separator.clone(),
+                        )
                         .with_line_terminator(options.serialize_options.line_terminator.clone())
                         .with_quote_char(options.serialize_options.quote_char)
+                        .with_escape_char(options.serialize_options.escape_char)
                         .with_datetime_format(options.serialize_options.datetime_format.clone())
                         .with_date_format(options.serialize_options.date_format.clone())
                         .with_time_format(options.serialize_options.time_format.clone())
@@ -0,0 +1,102 @@
+use std::io::Write;
+use std::sync::Mutex;
+
+use polars_core::prelude::Schema;
+use polars_error::PolarsResult;
+use polars_expr::state::ExecutionState;
+use polars_io::ipc::{BatchedIpcStreamWriter, IpcStreamWriter, IpcWriterOptions};
+use polars_io::SerWriter;
+
+use super::{SinkNode, SinkRecvPort};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::connector;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+type Writer = BatchedIpcStreamWriter<Box<dyn Write + Send>>;
+
+/// Writes the morsels as an Arrow IPC stream, e.g. to stdout, a pipe or a socket.
+///
+/// Every morsel is written and flushed as soon as it reaches the sink, in the order of the
+/// input.
+pub struct IpcStreamSinkNode {
+    writer: Mutex<Option<Writer>>,
+}
+
+impl IpcStreamSinkNode {
+    pub fn new(
+        writer: Box<dyn Write + Send>,
+        options: IpcWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Self> {
+        let writer = IpcStreamWriter::new(writer)
+            .with_compression(options.compression)
+            .batched(schema)?;
+        Ok(Self {
+            writer: Mutex::new(Some(writer)),
+        })
+    }
+}
+
+impl SinkNode for IpcStreamSinkNode {
+    fn name(&self) -> &str {
+        "ipc_stream_sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        false
+    }
+
+    fn spawn_sink(
+        &mut self,
+        _num_pipelines: usize,
+        recv_ports_recv: SinkRecvPort,
+        _state: &ExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        // .. -> Receive task
+        let mut rx = recv_ports_recv.serial(join_handles);
+        // Receive task -> IO task
+        let (mut io_tx, mut io_rx) = connector();
+
+        // Receive task.
+        join_handles.push(spawn(TaskPriority::High, async move {
+            while let Ok(morsel) = rx.recv().await {
+                let (df, _, _, consume_token) = morsel.into_inner();
+                if df.height() > 0 && io_tx.send(df).await.is_err() {
+                    return Ok(());
+                }
+                drop(consume_token);
+            }
+            PolarsResult::Ok(())
+        }));
+
+        // IO task.
+        //
+        // The writer may block, e.g. on a full pipe, so every write runs on a blocking thread.
+        let mut writer = self
+            .writer
+            .get_mut()
+            .unwrap()
+            .take()
+            .expect("sink spawned twice");
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            while let Ok(mut df) = io_rx.recv().await {
+                writer = tokio::task::spawn_blocking(move || {
+                    df.align_chunks_par();
+                    writer.write_batch(&df)?;
+                    PolarsResult::Ok(writer)
+                })
+                .await
+                .unwrap()?;
+            }
+            tokio::task::spawn_blocking(move || writer.finish())
+                .await
+                .unwrap()
+        });
+        join_handles.push(spawn(TaskPriority::Low, async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }));
+    }
+}
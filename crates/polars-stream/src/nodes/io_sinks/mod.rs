@@ -21,6 +21,8 @@ use crate::nodes::TaskPriority;
 pub mod csv;
 #[cfg(feature = "ipc")]
 pub mod ipc;
+#[cfg(feature = "ipc_streaming")]
+pub mod ipc_stream;
 #[cfg(feature = "json")]
 pub mod json;
 #[cfg(feature = "parquet")]
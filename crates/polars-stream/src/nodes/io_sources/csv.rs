@@ -206,6 +206,7 @@ impl CsvSourceNode {
         let parse_options = self.options.parse_options.as_ref();
 
         let quote_char = parse_options.quote_char;
+        let escape_char = parse_options.escape_char;
         let eol_char = parse_options.eol_char;
 
         let skip_lines = options.skip_lines;
@@ -253,7 +254,7 @@ impl CsvSourceNode {
                     return Err(err);
                 }
 
-                let line_counter = CountLines::new(quote_char, eol_char);
+                let line_counter = CountLines::new(quote_char, escape_char, eol_char);
 
                 let morsel_seq_ref = &mut MorselSeq::default();
                 let current_row_offset_ref = &mut 0usize;
@@ -281,6 +282,7 @@ impl CsvSourceNode {
                 let i = find_starting_point(
                     bytes,
                     quote_char,
+                    escape_char,
                     eol_char,
                     schema_len,
                     skip_lines,
@@ -447,6 +449,8 @@ impl ChunkReader {
 
         // Logic from `CoreReader::new()`
 
+        parse_options.check_separator()?;
+
         let null_values = parse_options
             .null_values
             .clone()
@@ -624,6 +628,7 @@ impl MultiScanable for CsvSourceNode {
             &mem_slice[..],
             parse_options.separator,
             parse_options.quote_char,
+            parse_options.escape_char,
             parse_options.comment_prefix.as_ref(),
             parse_options.eol_char,
             self.options.has_header,
@@ -1,5 +1,5 @@
 use std::cmp::Reverse;
-use std::io::Cursor;
+use std::io::{Read, Seek, SeekFrom};
 use std::ops::Range;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -10,15 +10,19 @@ use polars_core::prelude::DataType;
 use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_core::utils::arrow::array::TryExtend;
 use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::io::ipc::format::ipc::Block;
 use polars_core::utils::arrow::io::ipc::read::{
-    get_row_count_from_blocks, prepare_projection, read_file_metadata, FileMetadata, FileReader,
-    ProjectionInfo,
+    deserialize_footer, get_row_count_from_blocks, prepare_projection, read_file_metadata,
+    FileMetadata, FileReader, ProjectionInfo,
 };
 use polars_core::utils::slice_offsets;
-use polars_error::{polars_err, ErrString, PolarsError, PolarsResult};
+use polars_error::{
+    polars_ensure, polars_err, to_compute_err, ErrString, PolarsError, PolarsResult,
+};
 use polars_expr::state::ExecutionState;
 use polars_io::cloud::CloudOptions;
 use polars_io::ipc::IpcScanOptions;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource, DynByteSourceBuilder};
 use polars_io::utils::columns_to_projection;
 use polars_io::RowIndex;
 use polars_plan::dsl::ScanSource;
@@ -48,7 +52,11 @@ consider compiling with polars-bigidx feature (polars-u64-idx package on python)
 ));
 
 pub struct IpcSourceNode {
-    memslice: MemSlice,
+    /// The parts of the file that are available in memory. For local files this is the entire
+    /// file, for cloud files only the dictionaries and the record batch headers.
+    bytes: SparseBytes,
+    /// Set if the record batches have to be fetched on demand.
+    remote: Option<RemoteBatches>,
     metadata: Arc<FileMetadata>,
 
     row_index: Option<RowIndex>,
@@ -66,9 +74,19 @@ impl IpcSourceNode {
         source: ScanSource,
         file_info: FileInfo,
         options: IpcScanOptions,
-        _cloud_options: Option<CloudOptions>,
+        cloud_options: Option<CloudOptions>,
+        file_options: FileScanOptions,
+        metadata: Option<Arc<FileMetadata>>,
+    ) -> PolarsResult<Self> {
+        let file = open_ipc_file(&source, cloud_options.as_ref(), metadata)?;
+        Self::from_opened_file(file, file_info, options, file_options)
+    }
+
+    fn from_opened_file(
+        (bytes, remote, metadata): OpenedIpcFile,
+        file_info: FileInfo,
+        options: IpcScanOptions,
         file_options: FileScanOptions,
-        mut metadata: Option<Arc<FileMetadata>>,
     ) -> PolarsResult<Self> {
         // All these things should be handled by the MultiScan node
         assert!(file_options.include_file_paths.is_none());
@@ -89,22 +107,12 @@ impl IpcSourceNode {
             allow_missing_columns: _,
         } = file_options;
 
-        let memslice = source.as_scan_source_ref().to_memslice()?;
-        let metadata = match metadata.take() {
-            Some(md) => md,
-            None => Arc::new(read_file_metadata(&mut std::io::Cursor::new(
-                memslice.as_ref(),
-            ))?),
-        };
-
         // Always create a slice. If no slice was given, just make the biggest slice possible.
         let slice = match slice {
             None => (0, usize::MAX),
             Some((offset, length)) if offset < 0 => {
-                let file_num_rows = get_row_count_from_blocks(
-                    &mut std::io::Cursor::new(memslice.as_ref()),
-                    &metadata.blocks,
-                )?;
+                let file_num_rows =
+                    get_row_count_from_blocks(&mut bytes.reader(), &metadata.blocks)?;
                 slice_offsets(offset, length, file_num_rows as usize)
             },
             Some((offset, length)) => (offset as usize, length),
@@ -121,7 +129,8 @@ impl IpcSourceNode {
             .map(|p| prepare_projection(&metadata.schema, p.clone()));
 
         Ok(IpcSourceNode {
-            memslice,
+            bytes,
+            remote,
             metadata,
 
             slice,
@@ -135,6 +144,242 @@ impl IpcSourceNode {
     }
 }
 
+type OpenedIpcFile = (SparseBytes, Option<RemoteBatches>, Arc<FileMetadata>);
+
+/// Open an IPC file for reading.
+///
+/// Cloud files are not downloaded as a whole. Only the footer, the dictionaries and the
+/// headers of the record batches are fetched here, the record batches themselves are fetched
+/// once a decoder needs them.
+fn open_ipc_file(
+    source: &ScanSource,
+    cloud_options: Option<&CloudOptions>,
+    metadata: Option<Arc<FileMetadata>>,
+) -> PolarsResult<OpenedIpcFile> {
+    let path = match source {
+        ScanSource::Path(path) if source.run_async() => path.to_str().unwrap(),
+        _ => {
+            let memslice = source.as_scan_source_ref().to_memslice()?;
+            let metadata = match metadata {
+                Some(md) => md,
+                None => Arc::new(read_file_metadata(&mut std::io::Cursor::new(
+                    memslice.as_ref(),
+                ))?),
+            };
+            return Ok((SparseBytes::full(memslice), None, metadata));
+        },
+    };
+
+    polars_io::pl_async::get_runtime().block_on_potential_spawn(async {
+        let byte_source = Arc::new(
+            DynByteSourceBuilder::ObjectStore
+                .try_build_from_path(path, cloud_options)
+                .await?,
+        );
+        let file_size = byte_source.get_size().await?;
+
+        let metadata = match metadata {
+            Some(md) => md,
+            None => Arc::new(fetch_file_metadata(&byte_source, file_size).await?),
+        };
+
+        let mut ranges = metadata
+            .dictionaries
+            .iter()
+            .flatten()
+            .map(|block| block_byte_range(block, true))
+            .chain(
+                metadata
+                    .blocks
+                    .iter()
+                    .map(|block| block_byte_range(block, false)),
+            )
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut fetched = byte_source.get_ranges(&mut ranges).await?;
+
+        let dictionaries = metadata
+            .dictionaries
+            .iter()
+            .flatten()
+            .map(|block| {
+                let range = block_byte_range(block, true)?;
+                Ok((range.start, fetched.remove(&range.start).unwrap()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let bytes = SparseBytes::new(
+            fetched
+                .into_iter()
+                .chain(dictionaries.iter().cloned())
+                .collect(),
+            file_size,
+        );
+
+        let remote = RemoteBatches {
+            byte_source,
+            dictionaries: dictionaries.into(),
+            file_size,
+        };
+        Ok((bytes, Some(remote), metadata))
+    })
+}
+
+/// Fetch and deserialize the footer of an IPC file.
+async fn fetch_file_metadata(
+    byte_source: &DynByteSource,
+    file_size: usize,
+) -> PolarsResult<FileMetadata> {
+    const FOOTER_METADATA_SIZE: usize = 10;
+
+    polars_ensure!(
+        file_size >= FOOTER_METADATA_SIZE,
+        ComputeError: "ipc file size is smaller than the minimum"
+    );
+    let footer_metadata = byte_source
+        .get_range(file_size - FOOTER_METADATA_SIZE..file_size)
+        .await?;
+    let footer_metadata = footer_metadata.as_ref();
+    polars_ensure!(
+        &footer_metadata[4..] == b"ARROW1",
+        ComputeError: "ipc file does not end with the arrow magic bytes"
+    );
+    let footer_size = usize::try_from(i32::from_le_bytes(footer_metadata[..4].try_into().unwrap()))
+        .map_err(|_| polars_err!(ComputeError: "ipc file has a negative footer length"))?;
+
+    let footer_end = file_size - FOOTER_METADATA_SIZE;
+    let footer_start = footer_end
+        .checked_sub(footer_size)
+        .ok_or_else(|| polars_err!(ComputeError: "ipc footer size is larger than the file"))?;
+    let footer = byte_source.get_range(footer_start..footer_end).await?;
+
+    deserialize_footer(footer.as_ref(), file_size as u64)
+}
+
+/// The byte range of an IPC block, optionally including its body.
+fn block_byte_range(block: &Block, with_body: bool) -> PolarsResult<Range<usize>> {
+    let err = || polars_err!(ComputeError: "ipc block has a negative offset or length");
+    let start = usize::try_from(block.offset).map_err(|_| err())?;
+    let mut len = usize::try_from(block.meta_data_length).map_err(|_| err())?;
+    if with_body {
+        len += usize::try_from(block.body_length).map_err(|_| err())?;
+    }
+    Ok(start..start + len)
+}
+
+/// Fetches the record batches of a cloud IPC file.
+#[derive(Clone)]
+struct RemoteBatches {
+    byte_source: Arc<DynByteSource>,
+    /// The dictionary batches, which are needed to decode any of the record batches.
+    dictionaries: Arc<[(usize, MemSlice)]>,
+    file_size: usize,
+}
+
+impl RemoteBatches {
+    /// Fetch the record batches in `blocks`, coalescing the requests where possible.
+    async fn fetch(&self, blocks: &[Block]) -> PolarsResult<SparseBytes> {
+        let mut ranges = blocks
+            .iter()
+            .map(|block| block_byte_range(block, true))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let byte_source = self.byte_source.clone();
+        let fetched = polars_io::pl_async::get_runtime()
+            .spawn(async move { byte_source.get_ranges(&mut ranges).await })
+            .await
+            .map_err(to_compute_err)??;
+
+        Ok(SparseBytes::new(
+            fetched
+                .into_iter()
+                .chain(self.dictionaries.iter().cloned())
+                .collect(),
+            self.file_size,
+        ))
+    }
+}
+
+/// A file of which only some byte ranges are available.
+#[derive(Clone)]
+struct SparseBytes {
+    /// Byte ranges as `(offset, bytes)`, sorted by offset.
+    ranges: Arc<[(usize, MemSlice)]>,
+    size: usize,
+}
+
+impl SparseBytes {
+    fn new(mut ranges: Vec<(usize, MemSlice)>, size: usize) -> Self {
+        // If several ranges start at the same offset, the longest one is sorted last and will
+        // be the one that is read from.
+        ranges.sort_unstable_by_key(|(offset, bytes)| (*offset, bytes.len()));
+        Self {
+            ranges: ranges.into(),
+            size,
+        }
+    }
+
+    fn full(memslice: MemSlice) -> Self {
+        let size = memslice.len();
+        Self::new(vec![(0, memslice)], size)
+    }
+
+    fn reader(&self) -> SparseBytesReader<'_> {
+        SparseBytesReader {
+            bytes: self,
+            position: 0,
+        }
+    }
+}
+
+struct SparseBytesReader<'a> {
+    bytes: &'a SparseBytes,
+    position: u64,
+}
+
+impl Read for SparseBytesReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if buf.is_empty() || self.position >= self.bytes.size as u64 {
+            return Ok(0);
+        }
+
+        let position = self.position as usize;
+        let ranges = &self.bytes.ranges;
+        let idx = ranges.partition_point(|(offset, _)| *offset <= position);
+        let data = idx
+            .checked_sub(1)
+            .map(|i| &ranges[i])
+            .and_then(|(offset, bytes)| bytes.as_ref().get(position - offset..))
+            .filter(|data| !data.is_empty())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    format!("byte {position} of the ipc file has not been fetched"),
+                )
+            })?;
+
+        let n = buf.len().min(data.len());
+        buf[..n].copy_from_slice(&data[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for SparseBytesReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (self.bytes.size as u64).checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        self.position = position.ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.position)
+    }
+}
+
 /// Move `slice` forward by `n` and return the slice until then.
 fn slice_take(slice: &mut Range<usize>, n: usize) -> Range<usize> {
     let offset = slice.start;
@@ -184,7 +429,8 @@ impl SourceNode for IpcSourceNode {
         let source_token = SourceToken::new();
 
         let Self {
-            memslice,
+            bytes,
+            remote,
             metadata,
             row_index,
             slice,
@@ -254,7 +500,8 @@ impl SourceNode for IpcSourceNode {
         // into smaller pieces an spread among the pipelines.
         let decoder_tasks = decoded_tx.into_iter().zip(batch_rxs)
             .map(|(mut send, mut rx)| {
-                let memslice = memslice.clone();
+                let bytes = bytes.clone();
+                let remote = remote.clone();
                 let metadata = metadata.clone();
                 let rechunk = *rechunk;
                 let row_index = row_index.clone();
@@ -283,8 +530,14 @@ impl SourceNode for IpcSourceNode {
                         let mut df = if pl_schema.is_empty() {
                             DataFrame::empty_with_height(slice.len())
                         } else {
+                            // Record batches of cloud files are only fetched once we decode them.
+                            let bytes = match &remote {
+                                None => bytes.clone(),
+                                Some(remote) => remote.fetch(&metadata.blocks[block_range.clone()]).await?,
+                            };
+
                             let mut reader = FileReader::new_with_projection_info(
-                                Cursor::new(memslice.as_ref()),
+                                bytes.reader(),
                                 metadata.as_ref().clone(),
                                 projection_info.clone(),
                                 None,
@@ -334,7 +587,7 @@ impl SourceNode for IpcSourceNode {
             })
             .collect::<Vec<_>>();
 
-        let memslice = memslice.clone();
+        let bytes = bytes.clone();
         let metadata = metadata.clone();
         let slice = slice.clone();
         let row_index = row_index.clone();
@@ -346,10 +599,7 @@ impl SourceNode for IpcSourceNode {
         join_handles.push(spawn(TaskPriority::Low, async move {
             // Calculate the unrestricted row count if needed.
             if let Some(rc) = unrestricted_row_count {
-                let num_rows = get_row_count_from_blocks(
-                    &mut std::io::Cursor::new(memslice.as_ref()),
-                    &metadata.blocks,
-                )?;
+                let num_rows = get_row_count_from_blocks(&mut bytes.reader(), &metadata.blocks)?;
                 let num_rows = IdxSize::try_from(num_rows)
                     .map_err(|_| polars_err!(bigidx, ctx = "ipc file", size = num_rows))?;
                 rc.store(num_rows, Ordering::Relaxed);
@@ -370,8 +620,9 @@ impl SourceNode for IpcSourceNode {
             let sliced_batch_size_limit = slice.len().div_ceil(num_pipelines);
             let batch_block_limit = metadata.blocks.len().div_ceil(num_pipelines);
 
+            // This only reads the record batch headers, which are always available.
             let mut reader = FileReader::new_with_projection_info(
-                Cursor::new(memslice.as_ref()),
+                bytes.reader(),
                 metadata.as_ref().clone(),
                 projection_info.clone(),
                 None,
@@ -499,10 +750,8 @@ impl MultiScanable for IpcSourceNode {
     ) -> PolarsResult<Self> {
         let options = options.clone();
 
-        let memslice = source.as_scan_source_ref().to_memslice()?;
-        let metadata = Arc::new(read_file_metadata(&mut std::io::Cursor::new(
-            memslice.as_ref(),
-        ))?);
+        let file = open_ipc_file(&source, cloud_options, None)?;
+        let metadata = file.2.clone();
 
         let arrow_schema = metadata.schema.clone();
         let schema = Schema::from_arrow_schema(arrow_schema.as_ref());
@@ -519,14 +768,7 @@ impl MultiScanable for IpcSourceNode {
             (None, usize::MAX),
         );
 
-        IpcSourceNode::new(
-            source,
-            file_info,
-            options,
-            cloud_options.cloned(),
-            file_options,
-            Some(metadata),
-        )
+        IpcSourceNode::from_opened_file(file, file_info, options, file_options)
     }
 
     fn with_projection(&mut self, projection: Option<&Bitmap>) {
@@ -546,13 +788,97 @@ impl MultiScanable for IpcSourceNode {
     }
 
     async fn unrestricted_row_count(&mut self) -> PolarsResult<IdxSize> {
-        get_row_count_from_blocks(
-            &mut std::io::Cursor::new(self.memslice.as_ref()),
-            &self.metadata.blocks,
-        )
-        .map(|v| v as IdxSize)
+        get_row_count_from_blocks(&mut self.bytes.reader(), &self.metadata.blocks)
+            .map(|v| v as IdxSize)
     }
     async fn physical_schema(&mut self) -> PolarsResult<SchemaRef> {
         Ok(self.file_info.schema.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Seek, SeekFrom};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use polars_core::df;
+    use polars_core::frame::DataFrame;
+    use polars_core::utils::arrow::array::TryExtend;
+    use polars_core::utils::arrow::io::ipc::read::{get_row_count_from_blocks, FileReader};
+    use polars_error::PolarsResult;
+    use polars_io::ipc::IpcWriter;
+    use polars_io::SerWriter;
+    use polars_plan::dsl::ScanSource;
+    use polars_utils::mmap::MemSlice;
+
+    use super::{open_ipc_file, SparseBytes};
+
+    #[test]
+    fn test_sparse_bytes_reader() {
+        let bytes = SparseBytes::new(
+            vec![
+                (8, MemSlice::from_vec(b"ijklmnop".to_vec())),
+                (0, MemSlice::from_vec(b"abcd".to_vec())),
+                (8, MemSlice::from_vec(b"ij".to_vec())),
+            ],
+            16,
+        );
+        let mut reader = bytes.reader();
+
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"abcd");
+        // Bytes 4..8 have not been fetched.
+        assert!(reader.read_exact(&mut buf).is_err());
+
+        reader.seek(SeekFrom::End(-6)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"klmn");
+        reader.seek(SeekFrom::Current(-6)).unwrap();
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"ijkl");
+        assert!(reader.seek(SeekFrom::Current(-20)).is_err());
+    }
+
+    #[test]
+    fn test_remote_batches_local_object_store() -> PolarsResult<()> {
+        let df = df!(
+            "a" => (0..30).collect::<Vec<i32>>(),
+            "b" => (0..30).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+        )?;
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("data.ipc");
+        let mut writer = IpcWriter::new(std::fs::File::create(&path)?).batched(df.schema())?;
+        for i in 0..3 {
+            writer.write_batch(&df.slice(i * 10, 10))?;
+        }
+        writer.finish()?;
+
+        // `file://` paths are read through the object store, like cloud files.
+        let source = ScanSource::Path(Arc::from(PathBuf::from(format!(
+            "file://{}",
+            path.display()
+        ))));
+        let (bytes, remote, metadata) = open_ipc_file(&source, None, None)?;
+        let remote = remote.expect("record batches should be fetched on demand");
+        assert_eq!(metadata.blocks.len(), 3);
+
+        // The headers are enough to count the rows, but the bodies have not been fetched.
+        assert_eq!(
+            get_row_count_from_blocks(&mut bytes.reader(), &metadata.blocks)?,
+            30
+        );
+        let mut reader = FileReader::new(bytes.reader(), metadata.as_ref().clone(), None, None);
+        assert!(reader.next().unwrap().is_err());
+
+        let fetched =
+            polars_io::pl_async::get_runtime().block_on(remote.fetch(&metadata.blocks[1..]))?;
+        let mut reader = FileReader::new(fetched.reader(), metadata.as_ref().clone(), None, None);
+        reader.set_current_block(1);
+        let mut out = DataFrame::empty_with_schema(df.schema());
+        out.try_extend(reader)?;
+        assert!(out.equals(&df.slice(10, 20)));
+        Ok(())
+    }
+}
@@ -55,6 +55,7 @@ impl ParquetSourceNode {
         let row_group_prefetch_size = self.config.row_group_prefetch_size;
         let projection = self.file_options.with_columns.clone();
         let predicate = self.predicate.clone();
+        let runtime_filters = self.runtime_filters.clone();
         let memory_prefetch_func = self.memory_prefetch_func;
 
         let mut row_group_data_fetcher = RowGroupDataFetcher {
@@ -64,6 +65,7 @@ impl ParquetSourceNode {
             reader_schema,
             projection,
             predicate,
+            runtime_filters,
             slice_range: None, // Initialized later
             memory_prefetch_func,
             current_path_index: 0,
@@ -7,7 +7,7 @@ use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::{polars_err, PolarsResult};
 use polars_io::cloud::CloudOptions;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{RuntimeFilters, ScanIOPredicate};
 use polars_io::prelude::{FileMetadata, ParquetOptions};
 use polars_io::utils::byte_source::DynByteSourceBuilder;
 use polars_io::RowIndex;
@@ -49,6 +49,8 @@ pub struct ParquetSourceNode {
     file_info: FileInfo,
     hive_parts: Option<Arc<Vec<HivePartitions>>>,
     predicate: Option<ScanIOPredicate>,
+    /// Filters that joins publish on the keys of this scan, used to skip row groups.
+    runtime_filters: Option<RuntimeFilters>,
     options: ParquetOptions,
     cloud_options: Option<CloudOptions>,
     file_options: FileScanOptions,
@@ -113,6 +115,7 @@ impl ParquetSourceNode {
             file_info,
             hive_parts: None,
             predicate,
+            runtime_filters: None,
             options,
             cloud_options,
             file_options,
@@ -136,6 +139,11 @@ impl ParquetSourceNode {
             morsel_stream_starter: None,
         }
     }
+
+    pub fn with_runtime_filters(mut self, runtime_filters: Option<RuntimeFilters>) -> Self {
+        self.runtime_filters = runtime_filters;
+        self
+    }
 }
 
 impl SourceNode for ParquetSourceNode {
@@ -4,8 +4,8 @@ use polars_core::prelude::{ArrowSchema, PlHashMap};
 use polars_core::series::IsSorted;
 use polars_core::utils::operation_exceeded_idxsize_msg;
 use polars_error::{polars_err, PolarsResult};
-use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::_internal::read_this_row_group;
+use polars_io::predicates::{RuntimeFilters, ScanIOPredicate};
+use polars_io::prelude::_internal::{read_this_row_group, runtime_filters_skip_row_group};
 use polars_io::prelude::{create_sorting_map, FileMetadata};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_io::utils::slice::SplitSlicePosition;
@@ -42,6 +42,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Option<Arc<[PlSmallStr]>>,
     #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) runtime_filters: Option<RuntimeFilters>,
     pub(super) slice_range: Option<std::ops::Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) current_path_index: usize,
@@ -107,6 +108,28 @@ impl RowGroupDataFetcher {
                     continue;
                 }
 
+                if let (true, Some(runtime_filters)) = (self.use_statistics, &self.runtime_filters)
+                {
+                    match runtime_filters_skip_row_group(
+                        runtime_filters,
+                        &row_group_metadata,
+                        self.reader_schema.as_ref(),
+                    ) {
+                        Ok(false) => {},
+                        Ok(true) => {
+                            if self.verbose {
+                                eprintln!(
+                                    "[ParquetSource]: Runtime join filters: \
+                                    Skipped row group {} in file {} ({} rows)",
+                                    current_row_group_idx, self.current_path_index, num_rows
+                                );
+                            }
+                            continue;
+                        },
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+
                 if num_rows > IdxSize::MAX as usize {
                     let msg = operation_exceeded_idxsize_msg(
                         format!("number of rows in row group ({})", num_rows).as_str(),
@@ -10,6 +10,7 @@ use polars_core::{config, POOL};
 use polars_expr::chunked_idx_table::{new_chunked_idx_table, ChunkedIdxTable};
 use polars_expr::hash_keys::HashKeys;
 use polars_io::pl_async::get_runtime;
+use polars_io::predicates::{RuntimeFilters, RuntimeJoinFilterBuilder};
 use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
 use polars_ops::prelude::TakeChunked;
 use polars_ops::series::coalesce_columns;
@@ -37,6 +38,20 @@ static SAMPLE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
 // smaller side as the build side without checking cardinalities.
 const LOPSIDED_SAMPLE_FACTOR: usize = 10;
 
+/// Every build key column, with the filter slots of the probe-side scans and their column.
+pub type RuntimeFilterTargets = Vec<(PlSmallStr, Vec<(RuntimeFilters, PlSmallStr)>)>;
+
+/// The runtime filters that an equi-join publishes to the scans of its probe side. The build side
+/// is only chosen at runtime, so there are targets for either choice.
+///
+/// The parquet sources of this engine use the filters to skip row groups, but not hive
+/// partitions, of which the values are added outside of the source.
+#[derive(Clone, Debug, Default)]
+pub struct JoinRuntimeFilters {
+    pub build_left: RuntimeFilterTargets,
+    pub build_right: RuntimeFilterTargets,
+}
+
 /// A payload selector contains for each column whether that column should be
 /// included in the payload, and if yes with what name.
 fn compute_payload_selector(
@@ -417,6 +432,7 @@ impl SampleState {
         let partitioner = HashPartitioner::new(num_pipelines, 0);
         let mut build_state = BuildState {
             partitions_per_worker: (0..num_pipelines).map(|_| Vec::new()).collect(),
+            runtime_filters_per_worker: (0..num_pipelines).map(|_| Vec::new()).collect(),
             sampled_probe_morsels,
         };
 
@@ -429,13 +445,18 @@ impl SampleState {
                     .reinsert(num_pipelines, None, scope, &mut join_handles)
                     .unwrap();
 
-                for (worker_ps, recv) in build_state.partitions_per_worker.iter_mut().zip(receivers)
+                for ((worker_ps, worker_rf), recv) in build_state
+                    .partitions_per_worker
+                    .iter_mut()
+                    .zip(build_state.runtime_filters_per_worker.iter_mut())
+                    .zip(receivers)
                 {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
                         BuildState::partition_and_sink(
                             recv,
                             worker_ps,
+                            worker_rf,
                             partitioner.clone(),
                             params,
                             &state,
@@ -466,6 +487,8 @@ struct BuildPartition {
 #[derive(Default)]
 struct BuildState {
     partitions_per_worker: Vec<Vec<BuildPartition>>,
+    /// The keys of every runtime filter target, collected per worker.
+    runtime_filters_per_worker: Vec<Vec<RuntimeJoinFilterBuilder>>,
     sampled_probe_morsels: BufferedStream,
 }
 
@@ -473,6 +496,7 @@ impl BuildState {
     async fn partition_and_sink(
         mut recv: Receiver<Morsel>,
         partitions: &mut Vec<BuildPartition>,
+        runtime_filters: &mut Vec<RuntimeJoinFilterBuilder>,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &ExecutionState,
@@ -491,7 +515,16 @@ impl BuildState {
             key_selectors = &params.right_key_selectors;
         };
 
+        let runtime_filter_targets = params.runtime_filter_targets();
+        runtime_filters.resize_with(runtime_filter_targets.len(), Default::default);
+
         while let Ok(morsel) = recv.recv().await {
+            for ((build_key, _), builder) in
+                runtime_filter_targets.iter().zip(&mut *runtime_filters)
+            {
+                builder.update(morsel.df().column(build_key)?.as_materialized_series())?;
+            }
+
             // Compute hashed keys and payload. We must rechunk the payload for
             // later chunked gathers.
             let hash_keys = select_keys(morsel.df(), key_selectors, params, state).await?;
@@ -521,6 +554,30 @@ impl BuildState {
         Ok(())
     }
 
+    /// Publish the keys of the build side to the scans of the probe side.
+    fn publish_runtime_filters(&mut self, params: &EquiJoinParams) -> PolarsResult<()> {
+        let targets = params.runtime_filter_targets();
+        let mut builders = vec![RuntimeJoinFilterBuilder::default(); targets.len()];
+        for worker in self.runtime_filters_per_worker.drain(..) {
+            for (builder, worker_builder) in builders.iter_mut().zip(worker) {
+                builder.merge(worker_builder)?;
+            }
+        }
+
+        for ((build_key, targets), builder) in targets.iter().zip(&builders) {
+            for (slot, column) in targets {
+                slot.publish(builder.finish(column.clone()));
+            }
+            if config::verbose() {
+                eprintln!(
+                    "published runtime filter on {build_key} to {} scans",
+                    targets.len()
+                );
+            }
+        }
+        Ok(())
+    }
+
     fn finalize(&mut self, params: &EquiJoinParams, table: &dyn ChunkedIdxTable) -> ProbeState {
         // Transpose.
         let num_workers = self.partitions_per_worker.len();
@@ -997,6 +1054,7 @@ struct EquiJoinParams {
     right_payload_schema: Schema,
     args: JoinArgs,
     random_state: PlRandomState,
+    runtime_filters: JoinRuntimeFilters,
 }
 
 impl EquiJoinParams {
@@ -1009,6 +1067,15 @@ impl EquiJoinParams {
         }
     }
 
+    /// The runtime filters to publish to the scans of the probe side.
+    fn runtime_filter_targets(&self) -> &RuntimeFilterTargets {
+        if self.left_is_build.unwrap() {
+            &self.runtime_filters.build_left
+        } else {
+            &self.runtime_filters.build_right
+        }
+    }
+
     /// Should we emit unmatched rows from the probe side?
     fn emit_unmatched_probe(&self) -> bool {
         if self.left_is_build.unwrap() {
@@ -1027,6 +1094,7 @@ pub struct EquiJoinNode {
 }
 
 impl EquiJoinNode {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         left_input_schema: Arc<Schema>,
         right_input_schema: Arc<Schema>,
@@ -1035,6 +1103,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        runtime_filters: JoinRuntimeFilters,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
             MaintainOrderJoin::None => {
@@ -1102,6 +1171,7 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::new(),
+                runtime_filters,
             },
             table,
         })
@@ -1147,6 +1217,7 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
+                build_state.publish_runtime_filters(&self.params)?;
                 self.state = EquiJoinState::Probe(
                     build_state.finalize(&self.params, self.table.as_deref().unwrap()),
                 );
@@ -1323,14 +1394,22 @@ impl ComputeNode for EquiJoinNode {
                 build_state
                     .partitions_per_worker
                     .resize_with(self.num_pipelines, Vec::new);
+                build_state
+                    .runtime_filters_per_worker
+                    .resize_with(self.num_pipelines, Vec::new);
                 let partitioner = HashPartitioner::new(self.num_pipelines, 0);
-                for (worker_ps, recv) in build_state.partitions_per_worker.iter_mut().zip(receivers)
+                for ((worker_ps, worker_rf), recv) in build_state
+                    .partitions_per_worker
+                    .iter_mut()
+                    .zip(build_state.runtime_filters_per_worker.iter_mut())
+                    .zip(receivers)
                 {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
                         BuildState::partition_and_sink(
                             recv,
                             worker_ps,
+                            worker_rf,
                             partitioner.clone(),
                             &self.params,
                             state,
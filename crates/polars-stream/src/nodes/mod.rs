@@ -65,6 +65,11 @@ pub trait ComputeNode: Send {
         false
     }
 
+    /// The estimated size in bytes of the data this node holds on to between execution phases.
+    fn buffered_size(&self) -> usize {
+        0
+    }
+
     /// Spawn the tasks that this compute node needs to receive input(s),
     /// process it and send to its output(s). Called once per execution phase.
     fn spawn<'env, 's>(
@@ -89,6 +89,19 @@ impl ComputeNode for MultiplexerNode {
         Ok(())
     }
 
+    fn buffered_size(&self) -> usize {
+        // The buffers share the data of the morsels they hold, so the largest one is what we hold
+        // on to.
+        self.buffers
+            .iter()
+            .map(|b| match b {
+                BufferedStream::Open(v) => v.iter().map(|m| m.df().estimated_size()).sum(),
+                BufferedStream::Closed => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
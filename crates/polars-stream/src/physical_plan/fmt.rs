@@ -1,5 +1,8 @@
 use std::fmt::Write;
 
+use polars_core::frame::column::Column;
+use polars_core::frame::DataFrame;
+use polars_error::PolarsResult;
 use polars_plan::dsl::FileScan;
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, EscapeLabel};
@@ -8,7 +11,8 @@ use polars_utils::arena::Arena;
 use polars_utils::itertools::Itertools;
 use slotmap::{Key, SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
+use crate::metrics::PhysNodeMetrics;
 
 fn escape_graphviz(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -24,19 +28,12 @@ fn fmt_exprs(exprs: &[ExprIR], expr_arena: &Arena<AExpr>) -> String {
         .join("\\n")
 }
 
-#[recursive::recursive]
-fn visualize_plan_rec(
+/// The graphviz label of a node, and its inputs.
+fn node_label(
     node_key: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &Arena<AExpr>,
-    visited: &mut SecondaryMap<PhysNodeKey, ()>,
-    out: &mut Vec<String>,
-) {
-    if visited.contains_key(node_key) {
-        return;
-    }
-    visited.insert(node_key, ());
-
+) -> (String, Vec<PhysStream>) {
     use std::slice::from_ref;
     let (label, inputs) = match &phys_sm[node_key].kind {
         PhysNodeKind::InMemorySource { df } => (
@@ -119,6 +116,10 @@ fn visualize_plan_rec(
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
+        #[cfg(feature = "ipc_streaming")]
+        PhysNodeKind::IpcStreamSink { input, .. } => {
+            ("ipc-stream-sink".to_string(), from_ref(input))
+        },
         PhysNodeKind::InMemoryMap { input, map: _ } => {
             ("in-memory-map".to_string(), from_ref(input))
         },
@@ -162,6 +163,7 @@ fn visualize_plan_rec(
             scan_type,
             predicate,
             file_options,
+            runtime_filters: _,
         } => {
             let name = match scan_type {
                 #[cfg(feature = "parquet")]
@@ -233,6 +235,7 @@ fn visualize_plan_rec(
             left_on,
             right_on,
             args,
+            ..
         } => {
             let mut label = if matches!(phys_sm[node_key].kind, PhysNodeKind::EquiJoin { .. }) {
                 "equi-join".to_string()
@@ -262,7 +265,23 @@ fn visualize_plan_rec(
             &[*input_left, *input_right][..],
         ),
     };
+    (label, inputs.to_vec())
+}
 
+#[recursive::recursive]
+fn visualize_plan_rec(
+    node_key: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    visited: &mut SecondaryMap<PhysNodeKey, ()>,
+    out: &mut Vec<String>,
+) {
+    if visited.contains_key(node_key) {
+        return;
+    }
+    visited.insert(node_key, ());
+
+    let (label, inputs) = node_label(node_key, phys_sm, expr_arena);
     out.push(format!(
         "{} [label=\"{}\"];",
         node_key.data().as_ffi(),
@@ -290,3 +309,133 @@ pub fn visualize_plan(
     out.push("}".to_string());
     out.join("\n")
 }
+
+/// Undo the escaping of a graphviz label.
+fn unescape_graphviz(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some(next)) => {
+                out.push(if next == 'n' { '\n' } else { next });
+                chars.next();
+            },
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Visit the nodes of the plan in pre-order, together with their depth and label. Nodes with
+/// multiple outputs are visited once per output, the inputs of such nodes only once.
+#[recursive::recursive]
+fn visit_plan_rec(
+    node_key: PhysNodeKey,
+    depth: usize,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    visited: &mut SecondaryMap<PhysNodeKey, ()>,
+    f: &mut dyn FnMut(PhysNodeKey, usize, String, bool),
+) {
+    let (label, inputs) = node_label(node_key, phys_sm, expr_arena);
+    let repeated = visited.insert(node_key, ()).is_some();
+    f(node_key, depth, unescape_graphviz(&label), repeated);
+    if repeated {
+        return;
+    }
+    for input in inputs {
+        visit_plan_rec(input.node, depth + 1, phys_sm, expr_arena, visited, f);
+    }
+}
+
+/// Render the plan as an indented tree in which every node is annotated with its metrics.
+pub fn format_plan_with_metrics(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    metrics: &SecondaryMap<PhysNodeKey, PhysNodeMetrics>,
+) -> String {
+    let mut out = String::new();
+    let mut visited = SecondaryMap::new();
+    visit_plan_rec(
+        root,
+        0,
+        phys_sm,
+        expr_arena,
+        &mut visited,
+        &mut |node_key, depth, label, repeated| {
+            let indent = "  ".repeat(depth);
+            let mut lines = label.lines();
+            let name = lines.next().unwrap_or_default();
+            if repeated {
+                writeln!(out, "{indent}{name} (see above)").unwrap();
+                return;
+            }
+            writeln!(out, "{indent}{name}").unwrap();
+            for line in lines {
+                writeln!(out, "{indent}    {line}").unwrap();
+            }
+            let m = metrics.get(node_key).cloned().unwrap_or_default();
+            writeln!(
+                out,
+                "{indent}    rows: {} in, {} out | morsels: {} in, {} out | bytes: {} in, {} out | wall: {:?} | cpu: {:?} | peak buffered: {} bytes",
+                m.rows_in,
+                m.rows_out,
+                m.morsels_in,
+                m.morsels_out,
+                m.bytes_in,
+                m.bytes_out,
+                m.wall_time,
+                m.cpu_time,
+                m.peak_buffered_bytes,
+            )
+            .unwrap();
+        },
+    );
+    out
+}
+
+/// The metrics of the nodes of the plan as a `DataFrame`, with a row per node in the order of
+/// [`format_plan_with_metrics`]. Times are in microseconds.
+pub fn plan_metrics_df(
+    root: PhysNodeKey,
+    phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
+    expr_arena: &Arena<AExpr>,
+    metrics: &SecondaryMap<PhysNodeKey, PhysNodeMetrics>,
+) -> PolarsResult<DataFrame> {
+    let mut names = vec![];
+    let mut depths = vec![];
+    let mut rows = vec![];
+    let mut visited = SecondaryMap::new();
+    visit_plan_rec(
+        root,
+        0,
+        phys_sm,
+        expr_arena,
+        &mut visited,
+        &mut |node_key, depth, label, repeated| {
+            if !repeated {
+                names.push(label.lines().next().unwrap_or_default().to_string());
+                depths.push(depth as u32);
+                rows.push(metrics.get(node_key).cloned().unwrap_or_default());
+            }
+        },
+    );
+
+    let column = |name: &str, f: fn(&PhysNodeMetrics) -> u64| {
+        Column::new(name.into(), rows.iter().map(f).collect::<Vec<_>>())
+    };
+    DataFrame::new(vec![
+        Column::new("node".into(), names),
+        Column::new("depth".into(), depths),
+        column("rows_in", |m| m.rows_in),
+        column("rows_out", |m| m.rows_out),
+        column("morsels_in", |m| m.morsels_in),
+        column("morsels_out", |m| m.morsels_out),
+        column("bytes_in", |m| m.bytes_in),
+        column("bytes_out", |m| m.bytes_out),
+        column("wall_time", |m| m.wall_time.as_micros() as u64),
+        column("cpu_time", |m| m.cpu_time.as_micros() as u64),
+        column("peak_buffered_bytes", |m| m.peak_buffered_bytes),
+    ])
+}
@@ -5,13 +5,18 @@ use polars_core::frame::{DataFrame, UniqueKeepStrategy};
 use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_core::utils::arrow::bitmap::MutableBitmap;
+#[cfg(feature = "delta")]
+use polars_error::polars_bail;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
+use polars_io::predicates::RuntimeFilters;
 use polars_io::RowIndex;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::dsl::{FileScan, ScanFlags, ScanSource};
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
-use polars_plan::plans::{AExpr, FunctionIR, IRAggExpr, LiteralValue, IR};
+use polars_plan::plans::{
+    join_runtime_filter_targets, AExpr, FunctionIR, IRAggExpr, LiteralValue, IR,
+};
 use polars_plan::prelude::{FileType, GroupbyOptions, SinkType};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
@@ -21,6 +26,7 @@ use slotmap::SlotMap;
 use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 use crate::nodes::io_sources::multi_scan::MultiscanRowRestriction;
 use crate::nodes::io_sources::RowRestriction;
+use crate::nodes::joins::equi_join::{JoinRuntimeFilters, RuntimeFilterTargets};
 use crate::physical_plan::lower_expr::{
     build_length_preserving_select_stream, build_select_stream, is_elementwise_rec_cached,
     lower_exprs, ExprCache,
@@ -98,6 +104,7 @@ fn build_filter_stream(
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 #[recursive::recursive]
 pub fn lower_ir(
     node: Node,
@@ -107,6 +114,7 @@ pub fn lower_ir(
     schema_cache: &mut PlHashMap<Node, Arc<Schema>>,
     expr_cache: &mut ExprCache,
     cache_nodes: &mut PlHashMap<usize, PhysStream>,
+    runtime_filters: &mut PlHashMap<Node, RuntimeFilters>,
 ) -> PolarsResult<PhysStream> {
     // Helper macro to simplify recursive calls.
     macro_rules! lower_ir {
@@ -119,6 +127,7 @@ pub fn lower_ir(
                 schema_cache,
                 expr_cache,
                 cache_nodes,
+                runtime_filters,
             )
         };
     }
@@ -272,6 +281,21 @@ pub fn lower_ir(
                     },
                 }
             },
+            #[cfg(feature = "ipc_streaming")]
+            SinkType::IpcStream { writer, options } => {
+                let writer = writer.clone();
+                let options = *options;
+                let phys_input = lower_ir!(*input)?;
+                PhysNodeKind::IpcStreamSink {
+                    writer,
+                    options,
+                    input: phys_input,
+                }
+            },
+            #[cfg(feature = "delta")]
+            SinkType::Delta { .. } => {
+                polars_bail!(nyi = "Delta sink in the streaming engine")
+            },
         },
 
         #[cfg(feature = "merge_sorted")]
@@ -404,15 +428,6 @@ pub fn lower_ir(
                     match ScanSource::from_sources(scan_sources) {
                         Err(s) => scan_sources = s,
                         Ok(scan_source) => {
-                            #[cfg(feature = "ipc")]
-                            if matches!(scan_type, FileScan::Ipc { .. }) {
-                                // @TODO: All the things the IPC source does not support yet.
-                                if matches!(&scan_source, ScanSource::Path(p) if polars_io::is_cloud_url(p))
-                                {
-                                    todo!();
-                                }
-                            }
-
                             // Operation ordering:
                             // * with_row_index() -> slice() -> filter()
 
@@ -455,6 +470,7 @@ pub fn lower_ir(
                                 scan_type,
                                 predicate,
                                 file_options,
+                                runtime_filters: runtime_filters.remove(&node),
                             };
 
                             let (row_index, slice, predicate) = opt_rewrite_to_nodes;
@@ -696,9 +712,41 @@ pub fn lower_ir(
             let right_on = right_on.clone();
             let args = options.args.clone();
             let options = options.options.clone();
+            let is_equi_join = args.how.is_equi() && !args.validation.needs_checks();
+
+            // The build side is only chosen at runtime, so register the filters for both sides.
+            let mut join_runtime_filters = JoinRuntimeFilters::default();
+            if is_equi_join {
+                let mut register = |build_left| -> RuntimeFilterTargets {
+                    join_runtime_filter_targets(
+                        build_left,
+                        input_left,
+                        input_right,
+                        &left_on,
+                        &right_on,
+                        &args,
+                        ir_arena,
+                        expr_arena,
+                    )
+                    .into_iter()
+                    .map(|(build_key, targets)| {
+                        let targets = targets
+                            .into_iter()
+                            .map(|(node, column)| {
+                                (runtime_filters.entry(node).or_default().clone(), column)
+                            })
+                            .collect();
+                        (build_key, targets)
+                    })
+                    .collect()
+                };
+                join_runtime_filters.build_left = register(true);
+                join_runtime_filters.build_right = register(false);
+            }
+
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
-            if args.how.is_equi() && !args.validation.needs_checks() {
+            if is_equi_join {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
@@ -728,6 +776,7 @@ pub fn lower_ir(
                         left_on: trans_left_on,
                         right_on: trans_right_on,
                         args: args.clone(),
+                        runtime_filters: join_runtime_filters,
                     },
                 ));
                 let mut stream = PhysStream::first(node);
@@ -6,8 +6,13 @@ use polars_core::prelude::{IdxSize, InitHashMaps, PlHashMap, SortMultipleOptions
 use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
+#[cfg(feature = "ipc_streaming")]
+use polars_io::ipc::IpcWriterOptions;
+use polars_io::predicates::RuntimeFilters;
 use polars_io::RowIndex;
 use polars_ops::frame::JoinArgs;
+#[cfg(feature = "ipc_streaming")]
+use polars_plan::dsl::DynWriter;
 use polars_plan::dsl::{FileScan, JoinTypeOptionsIR, ScanSource, ScanSources};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_plan::plans::{AExpr, DataFrameUdf, FileInfo, IR};
@@ -19,7 +24,7 @@ mod lower_group_by;
 mod lower_ir;
 mod to_graph;
 
-pub use fmt::visualize_plan;
+pub use fmt::{format_plan_with_metrics, plan_metrics_df, visualize_plan};
 use polars_plan::prelude::{FileScanOptions, FileType};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
@@ -27,6 +32,7 @@ use slotmap::{SecondaryMap, SlotMap};
 pub use to_graph::physical_plan_to_graph;
 
 use crate::nodes::io_sources::multi_scan::MultiscanRowRestriction;
+use crate::nodes::joins::equi_join::JoinRuntimeFilters;
 use crate::physical_plan::lower_expr::ExprCache;
 
 slotmap::new_key_type! {
@@ -133,6 +139,13 @@ pub enum PhysNodeKind {
         input: PhysStream,
     },
 
+    #[cfg(feature = "ipc_streaming")]
+    IpcStreamSink {
+        writer: DynWriter,
+        options: IpcWriterOptions,
+        input: PhysStream,
+    },
+
     /// Generic fallback for (as-of-yet) unsupported streaming mappings.
     /// Fully sinks all data to an in-memory data frame and uses the in-memory
     /// engine to perform the map.
@@ -203,6 +216,8 @@ pub enum PhysNodeKind {
         output_schema: Option<SchemaRef>,
         scan_type: FileScan,
         file_options: FileScanOptions,
+        /// Filters that joins publish on the keys of this scan while the query runs.
+        runtime_filters: Option<RuntimeFilters>,
     },
 
     GroupBy {
@@ -217,6 +232,7 @@ pub enum PhysNodeKind {
         left_on: Vec<ExprIR>,
         right_on: Vec<ExprIR>,
         args: JoinArgs,
+        runtime_filters: JoinRuntimeFilters,
     },
 
     /// Generic fallback for (as-of-yet) unsupported streaming joins.
@@ -280,6 +296,12 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "ipc_streaming")]
+            PhysNodeKind::IpcStreamSink { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
@@ -354,6 +376,7 @@ pub fn build_physical_plan(
     let mut schema_cache = PlHashMap::with_capacity(ir_arena.len());
     let mut expr_cache = ExprCache::with_capacity(expr_arena.len());
     let mut cache_nodes = PlHashMap::new();
+    let mut runtime_filters = PlHashMap::new();
     let phys_root = lower_ir::lower_ir(
         root,
         ir_arena,
@@ -362,6 +385,7 @@ pub fn build_physical_plan(
         &mut schema_cache,
         &mut expr_cache,
         &mut cache_nodes,
+        &mut runtime_filters,
     )?;
     insert_multiplexers(vec![phys_root.node], phys_sm);
     Ok(phys_root.node)
@@ -275,6 +275,24 @@ fn to_graph_rec<'a>(
             }
         },
 
+        #[cfg(feature = "ipc_streaming")]
+        IpcStreamSink {
+            writer,
+            options,
+            input,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                SinkComputeNode::from(nodes::io_sinks::ipc_stream::IpcStreamSinkNode::new(
+                    writer.take()?,
+                    *options,
+                    &input_schema,
+                )?),
+                [(input_key, input.port)],
+            )
+        },
+
         InMemoryMap { input, map } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
@@ -479,6 +497,7 @@ fn to_graph_rec<'a>(
                 scan_type,
                 predicate,
                 mut file_options,
+                runtime_filters,
             } = v.clone()
             else {
                 unreachable!()
@@ -541,7 +560,8 @@ fn to_graph_rec<'a>(
                                 cloud_options,
                                 file_options,
                                 first_metadata,
-                            ),
+                            )
+                            .with_runtime_filters(runtime_filters),
                         ),
                         [],
                     ),
@@ -700,8 +720,10 @@ fn to_graph_rec<'a>(
             left_on,
             right_on,
             args,
+            runtime_filters,
         } => {
             let args = args.clone();
+            let runtime_filters = runtime_filters.clone();
             let left_input_key = to_graph_rec(input_left.node, ctx)?;
             let right_input_key = to_graph_rec(input_right.node, ctx)?;
             let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
@@ -730,6 +752,7 @@ fn to_graph_rec<'a>(
                     left_key_selectors,
                     right_key_selectors,
                     args,
+                    runtime_filters,
                 )?,
                 [
                     (left_input_key, input_left.port),
@@ -4,7 +4,7 @@ use polars_error::PolarsResult;
 use polars_utils::priority::Priority;
 
 use crate::async_executor::{JoinHandle, TaskPriority, TaskScope};
-use crate::async_primitives::connector::{connector, Receiver, Sender};
+use crate::async_primitives::connector::{connector, Receiver, RecvHook, Sender};
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::linearizer::Linearizer;
 use crate::async_primitives::wait_group::WaitGroup;
@@ -21,24 +21,38 @@ pub enum PhysicalPipe {
 }
 
 pub struct SendPort<'a>(&'a mut PhysicalPipe);
-pub struct RecvPort<'a>(&'a mut PhysicalPipe);
+pub struct RecvPort<'a>(&'a mut PhysicalPipe, Option<RecvHook<Morsel>>);
 
 impl RecvPort<'_> {
+    /// Call `hook` on every morsel the receiving node gets through this port.
+    pub fn with_hook(mut self, hook: Option<RecvHook<Morsel>>) -> Self {
+        self.1 = hook;
+        self
+    }
+
+    fn new_receiver(&self) -> (Sender<Morsel>, Receiver<Morsel>) {
+        let (send, mut recv) = connector();
+        if let Some(hook) = &self.1 {
+            recv.set_hook(hook.clone());
+        }
+        (send, recv)
+    }
+
     pub fn serial(self) -> Receiver<Morsel> {
-        let PhysicalPipe::Uninit(num_pipelines) = self.0 else {
+        let PhysicalPipe::Uninit(num_pipelines) = *self.0 else {
             unreachable!()
         };
-        let (send, recv) = connector();
-        *self.0 = PhysicalPipe::SerialReceiver(*num_pipelines, send);
+        let (send, recv) = self.new_receiver();
+        *self.0 = PhysicalPipe::SerialReceiver(num_pipelines, send);
         recv
     }
 
     pub fn parallel(self) -> Vec<Receiver<Morsel>> {
-        let PhysicalPipe::Uninit(num_pipelines) = self.0 else {
+        let PhysicalPipe::Uninit(num_pipelines) = *self.0 else {
             unreachable!()
         };
         let (senders, receivers): (Vec<Sender<Morsel>>, Vec<Receiver<Morsel>>) =
-            (0..*num_pipelines).map(|_| connector()).unzip();
+            (0..num_pipelines).map(|_| self.new_receiver()).unzip();
         *self.0 = PhysicalPipe::ParallelReceiver(senders);
         receivers
     }
@@ -92,7 +106,7 @@ impl PhysicalPipe {
             matches!(self, Self::Uninit(_)),
             "PhysicalPipe::recv_port can only be called on an uninitialized pipe"
         );
-        RecvPort(self)
+        RecvPort(self, None)
     }
 
     pub fn send_port(&mut self) -> SendPort<'_> {
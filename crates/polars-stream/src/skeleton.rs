@@ -10,11 +10,40 @@ use polars_plan::prelude::AExpr;
 use polars_utils::arena::{Arena, Node};
 use slotmap::{SecondaryMap, SlotMap};
 
+use crate::metrics::NodeMetrics;
+
 pub fn run_query(
     node: Node,
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<Option<DataFrame>> {
+    run_query_impl(node, ir_arena, expr_arena, false).map(|(df, _)| df)
+}
+
+/// The physical plan of a query annotated with the metrics recorded while running it.
+pub struct QueryMetrics {
+    /// The plan as an indented tree, with the metrics below every node.
+    pub plan: String,
+    /// A row with the metrics of every node of the plan, in the order of `plan`.
+    pub nodes: DataFrame,
+}
+
+/// Run the query like [`run_query`], and record rows, morsels and bytes sent between nodes, time
+/// spent, and memory buffered per node of the physical plan.
+pub fn run_query_with_metrics(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<(Option<DataFrame>, QueryMetrics)> {
+    run_query_impl(node, ir_arena, expr_arena, true).map(|(df, metrics)| (df, metrics.unwrap()))
+}
+
+fn run_query_impl(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    with_metrics: bool,
+) -> PolarsResult<(Option<DataFrame>, Option<QueryMetrics>)> {
     if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_IR") {
         let plan = IRPlan {
             lp_top: node,
@@ -33,8 +62,16 @@ pub fn run_query(
     let (mut graph, phys_to_graph) =
         crate::physical_plan::physical_plan_to_graph(root, &phys_sm, expr_arena)?;
 
+    let graph_metrics: Option<SecondaryMap<_, _>> = with_metrics.then(|| {
+        graph
+            .nodes
+            .keys()
+            .map(|k| (k, Arc::new(NodeMetrics::default())))
+            .collect()
+    });
+
     crate::async_executor::clear_task_wait_statistics();
-    let mut results = crate::execute::execute_graph(&mut graph)?;
+    let mut results = crate::execute::execute_graph(&mut graph, graph_metrics.as_ref())?;
 
     if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
         let mut stats = crate::async_executor::get_task_wait_statistics();
@@ -45,5 +82,18 @@ pub fn run_query(
         }
     }
 
-    Ok(results.remove(phys_to_graph[root]))
+    let metrics = graph_metrics.map(|graph_metrics| {
+        let metrics = phys_to_graph
+            .iter()
+            .map(|(phys_key, graph_key)| (phys_key, graph_metrics[*graph_key].snapshot()))
+            .collect();
+        PolarsResult::Ok(QueryMetrics {
+            plan: crate::physical_plan::format_plan_with_metrics(
+                root, &phys_sm, expr_arena, &metrics,
+            ),
+            nodes: crate::physical_plan::plan_metrics_df(root, &phys_sm, expr_arena, &metrics)?,
+        })
+    });
+
+    Ok((results.remove(phys_to_graph[root]), metrics.transpose()?))
 }
@@ -407,6 +407,8 @@
 //! * `POLARS_PANIC_ON_ERR` -> panic instead of returning an Error.
 //! * `POLARS_BACKTRACE_IN_ERR` -> include a Rust backtrace in Error messages.
 //! * `POLARS_NO_CHUNKED_JOIN` -> force rechunk before joins.
+//! * `POLARS_NO_CREDENTIAL_CHAIN` -> if set, cloud connections do not fall back to AWS profiles,
+//!                                   the Azure CLI or the GCP application default credentials.
 //!
 //! ## User guide
 //!
@@ -0,0 +1,236 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use polars::prelude::*;
+use polars_io::catalog::unity::client::{CatalogClient, CatalogClientBuilder};
+
+const TABLES: &str = "/api/2.1/unity-catalog/tables/";
+const CREDENTIALS: &str = "/api/2.1/unity-catalog/temporary-table-credentials";
+
+/// A catalog server that answers requests by method and path, and records the requests it
+/// receives.
+struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    fn start(routes: Vec<(&'static str, String, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let log = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&mut stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap().to_string();
+                let target = parts.next().unwrap().to_string();
+                let path = target.split('?').next().unwrap();
+                log.lock().unwrap().push(format!("{method} {target}"));
+
+                let (status, body) = routes
+                    .iter()
+                    .find(|(m, p, _)| *m == method && p == path)
+                    .map_or(
+                        (
+                            "404 Not Found",
+                            r#"{"error_code":"TABLE_DOES_NOT_EXIST"}"#.to_string(),
+                        ),
+                        |(_, _, body)| ("200 OK", body.clone()),
+                    );
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    fn client(&self) -> PolarsResult<CatalogClient> {
+        CatalogClientBuilder::new()
+            .with_workspace_url(&self.url)
+            .with_bearer_token("token")
+            .build()
+    }
+
+    fn take_requests(&self) -> Vec<String> {
+        std::mem::take(&mut self.requests.lock().unwrap())
+    }
+}
+
+fn table_info(name: &str, format: &str, location: &Path, columns: &str) -> String {
+    format!(
+        r#"{{"name":"{name}","table_id":"{name}-id","table_type":"MANAGED","data_source_format":"{format}","storage_location":"{}","columns":{columns},"properties":null,"created_at":null,"created_by":null,"updated_at":null,"updated_by":null}}"#,
+        location.display()
+    )
+}
+
+fn column_info(name: &str, type_name: &str, position: u32) -> String {
+    format!(
+        r#"{{"name":"{name}","type_name":"{}","type_text":"{type_name}","type_json":"{{\"name\":\"{name}\",\"type\":\"{type_name}\",\"nullable\":true,\"metadata\":{{}}}}","position":{position},"comment":null,"partition_index":null}}"#,
+        type_name.to_uppercase()
+    )
+}
+
+#[test]
+fn test_catalog_scan_and_write_table() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    let items = root.join("items");
+    let events = root.join("events");
+    std::fs::create_dir_all(&events)?;
+    let mut events_df = df!("id" => [1i64, 2], "kind" => ["a", "b"])?;
+    ParquetWriter::new(std::fs::File::create(events.join("0.parquet"))?).finish(&mut events_df)?;
+
+    let credentials = r#"{"aws_temp_credentials":{"access_key_id":"key","secret_access_key":"secret","session_token":"session"},"expiration_time":1700000000000}"#;
+    let events_columns = format!(
+        "[{},{}]",
+        column_info("id", "long", 0),
+        column_info("kind", "string", 1)
+    );
+    let server = MockServer::start(vec![
+        (
+            "GET",
+            format!("{TABLES}main.default.items"),
+            table_info("items", "DELTA", &items, "null"),
+        ),
+        (
+            "GET",
+            format!("{TABLES}main.default.events"),
+            table_info("events", "PARQUET", &events, &events_columns),
+        ),
+        ("POST", CREDENTIALS.to_string(), credentials.to_string()),
+    ]);
+    let client = server.client()?;
+
+    // Writes create the Delta table at the storage location of the managed table.
+    let df = df!("x" => [1i64, 2, 3])?;
+    client.write_table(
+        df.clone().lazy(),
+        "main",
+        "default",
+        "items",
+        Default::default(),
+    )?;
+    assert_eq!(
+        server.take_requests(),
+        [
+            format!("GET {TABLES}main.default.items?full_name=main.default.items"),
+            format!("POST {CREDENTIALS}?table_id=items-id&operation=READ_WRITE"),
+        ]
+    );
+    let out = client.scan_table("main", "default", "items")?.collect()?;
+    assert_eq!(out, df);
+    assert_eq!(
+        server.take_requests()[1],
+        format!("POST {CREDENTIALS}?table_id=items-id&operation=READ")
+    );
+
+    client.write_table(
+        df.clone().lazy(),
+        "main",
+        "default",
+        "items",
+        Default::default(),
+    )?;
+    let out = client.scan_table("main", "default", "items")?.collect()?;
+    assert_eq!(out.height(), 6);
+
+    let options = DeltaWriteOptions {
+        mode: DeltaWriteMode::Overwrite,
+        ..Default::default()
+    };
+    let df = df!("x" => [4i64])?;
+    client.write_table(df.clone().lazy(), "main", "default", "items", options)?;
+    let out = client.scan_table("main", "default", "items")?.collect()?;
+    assert_eq!(out, df);
+
+    // Parquet tables are scanned with the schema of the catalog.
+    let out = client.scan_table("main", "default", "events")?.collect()?;
+    assert_eq!(out, events_df);
+    let err = client
+        .write_table(
+            events_df.lazy(),
+            "main",
+            "default",
+            "events",
+            Default::default(),
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("DELTA"));
+
+    // Errors of the server include the response body.
+    let Err(err) = client.scan_table("main", "default", "missing") else {
+        panic!("expected an error for a missing table")
+    };
+    assert!(err.to_string().contains("TABLE_DOES_NOT_EXIST"));
+    Ok(())
+}
+
+#[cfg(feature = "aws")]
+#[test]
+fn test_catalog_aws_credentials() -> PolarsResult<()> {
+    use polars_io::cloud::CloudOptions;
+    use polars_io::pl_async::get_runtime;
+
+    let location = "s3://bucket/items";
+    let credentials = r#"{"aws_temp_credentials":{"access_key_id":"key","secret_access_key":"secret","session_token":"session","access_point":"arn:aws:s3:us-east-1:123456789012:accesspoint/items"},"expiration_time":1700000000000}"#;
+    let server = MockServer::start(vec![
+        (
+            "GET",
+            format!("{TABLES}main.default.items"),
+            table_info("items", "DELTA", Path::new(location), "null"),
+        ),
+        ("POST", CREDENTIALS.to_string(), credentials.to_string()),
+    ]);
+    let client = server.client()?;
+
+    let cloud_options = get_runtime().block_on(async {
+        let table_info = client.get_table_info("main", "default", "items").await?;
+        client.get_table_cloud_options(&table_info, false).await
+    })?;
+    // The credentials are passed by a credential provider instead of the configuration, and are
+    // only requested again shortly before they expire.
+    let without_credentials =
+        CloudOptions::from_untyped_config(location, std::iter::empty::<(&str, String)>())?;
+    assert_ne!(cloud_options, without_credentials);
+    assert_eq!(
+        cloud_options.with_credential_provider(None),
+        without_credentials
+    );
+    assert_eq!(
+        server.take_requests(),
+        [
+            format!("GET {TABLES}main.default.items?full_name=main.default.items"),
+            format!("POST {CREDENTIALS}?table_id=items-id&operation=READ"),
+        ]
+    );
+    Ok(())
+}
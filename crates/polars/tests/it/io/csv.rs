@@ -1398,3 +1398,69 @@ fn test_read_io_reader() {
         .head(Some(df.height()));
     assert_eq!(&df, &expected);
 }
+
+#[test]
+fn test_multi_char_separator_and_escape_char() -> PolarsResult<()> {
+    let csv = "a||b||c\n1||\"x||\\\"y\\\"\"||2.5\n2||plain||3.5\n";
+    let file = Cursor::new(csv);
+    let mut df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_multi_char_separator("||")
+                .with_escape_char(Some(b'\\'))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(df.column("a")?.get(1)?, AnyValue::Int64(2));
+    assert_eq!(df.column("b")?.get(0)?, AnyValue::String("x||\"y\""));
+    assert_eq!(df.column("c")?.get(0)?, AnyValue::Float64(2.5));
+
+    let mut buf = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_multi_char_separator(Some("||".to_string()))
+        .with_escape_char(Some(b'\\'))
+        .finish(&mut df)?;
+    assert_eq!(std::str::from_utf8(&buf).unwrap(), csv);
+    Ok(())
+}
+
+#[test]
+fn test_multi_char_separator_multiple_chunks() -> PolarsResult<()> {
+    use std::fmt::Write;
+
+    let n_rows = 50_000;
+    let mut csv = String::from("a||b||c\n");
+    for i in 0..n_rows {
+        writeln!(csv, "{i}||\"x||{i}\"||y|z").unwrap();
+    }
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_n_threads(Some(4))
+        .with_chunk_size(1000)
+        .map_parse_options(|parse_options| parse_options.with_multi_char_separator("||"))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.shape(), (n_rows, 3));
+    assert!(df.column("a")?.n_chunks() > 1);
+    assert_eq!(
+        df.column("a")?.as_materialized_series().sum::<i64>()?,
+        (0..n_rows as i64).sum::<i64>()
+    );
+    assert_eq!(df.column("b")?.get(7)?, AnyValue::String("x||7"));
+    assert_eq!(df.column("c")?.get(n_rows - 1)?, AnyValue::String("y|z"));
+    Ok(())
+}
+
+#[test]
+fn test_empty_multi_char_separator() {
+    let file = Cursor::new("a,b\n1,2\n");
+    let err = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_multi_char_separator(""))
+        .into_reader_with_file_handle(file)
+        .finish()
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::InvalidOperation(_)));
+}
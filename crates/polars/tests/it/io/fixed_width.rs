@@ -0,0 +1,45 @@
+use std::io::Write;
+
+use polars::prelude::*;
+
+fn columns() -> Vec<FixedWidthColumn> {
+    vec![
+        FixedWidthColumn::new("id".into(), 0, 3, DataType::Int32),
+        FixedWidthColumn::new("city".into(), 3, 9, DataType::String),
+        FixedWidthColumn::new("temp".into(), 12, 5, DataType::Float64),
+    ]
+}
+
+#[test]
+fn test_scan_fixed_width() -> PolarsResult<()> {
+    let mut file = tempfile::NamedTempFile::new()?;
+    file.write_all(
+        b"id city     temp\r\n  1Amsterdam 12.5\r\n  2Utrecht   NA\r\n\r\n  3Delft    -1.0\r\n",
+    )?;
+    let path = file.into_temp_path();
+
+    let lf = LazyFixedWidthReader::new(&path, columns())
+        .with_skip_rows(1)
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+        .finish()?;
+
+    let df = lf.clone().collect()?;
+    let expected = df![
+        "id" => [1i32, 2, 3],
+        "city" => ["Amsterdam", "Utrecht", "Delft"],
+        "temp" => [Some(12.5), None, Some(-1.0)],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // Projection and slice are pushed down into the scan.
+    let df = lf
+        .clone()
+        .select([col("temp"), col("id")])
+        .limit(2)
+        .collect()?;
+    assert_eq!(df.get_column_names(), &["temp", "id"]);
+    assert_eq!(df.height(), 2);
+    let plan = lf.select([col("city")]).limit(1).explain(true)?;
+    assert!(plan.contains("PROJECT 1/3 COLUMNS"));
+    Ok(())
+}
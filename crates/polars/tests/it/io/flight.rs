@@ -0,0 +1,169 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use polars::io::flight::{FlightHandler, FlightReader, FlightServer};
+use polars::io::ipc::IpcStreamWriter;
+use polars::io::SerWriter;
+use polars::prelude::*;
+
+fn example_df() -> PolarsResult<DataFrame> {
+    let n = 1000;
+    let mut tags: ListChunked = (0..n)
+        .map(|i| (i % 5 != 1).then(|| Series::new(PlSmallStr::EMPTY, vec![i; i as usize % 3])))
+        .collect();
+    tags.rename("tags".into());
+    let df = df!(
+        "id" => (0..n).collect::<Vec<i64>>(),
+        "name" => (0..n).map(|i| (i % 7 != 0).then(|| format!("name {i}"))).collect::<Vec<_>>(),
+        "kind" => (0..n).map(|i| ["a", "b", "c"][i as usize % 3]).collect::<Vec<_>>(),
+        "score" => (0..n).map(|i| i as f64 / 8.0).collect::<Vec<_>>(),
+    )?
+    .hstack(&[tags.into_column()])?;
+    df.lazy()
+        .with_columns([
+            col("kind").cast(DataType::Categorical(None, Default::default())),
+            (col("id") * lit(1_000_000_007i64))
+                .cast(DataType::Datetime(TimeUnit::Microseconds, None))
+                .alias("at"),
+            as_struct(vec![col("id"), col("name")]).alias("point"),
+        ])
+        .collect()
+}
+
+/// Compare categoricals by their values.
+fn with_string_kind(df: DataFrame) -> PolarsResult<DataFrame> {
+    df.lazy()
+        .with_column(col("kind").cast(DataType::String))
+        .collect()
+}
+
+#[test]
+fn test_flight_scan_served_lazy_frame() -> PolarsResult<()> {
+    let df = example_df()?;
+    // Serve a result of several batches.
+    let served = df
+        .clone()
+        .lazy()
+        .filter(col("id").gt(lit(10)))
+        .collect()?
+        .vstack(&df.head(Some(3)))?;
+    let server = served.clone().lazy().serve_flight("127.0.0.1:0")?;
+
+    let out =
+        LazyFrame::scan_flight(server.location(), "any ticket", Default::default())?.collect()?;
+    assert!(with_string_kind(out)?.equals_missing(&with_string_kind(served.clone())?));
+
+    let args = ScanArgsFlight {
+        schema: Some(served.schema().clone()),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_flight(server.location(), vec![], args)?
+        .filter(col("kind").cast(DataType::String).eq(lit("b")))
+        .select([col("id"), col("point"), col("tags")])
+        .limit(5)
+        .collect()?;
+    let expected = served
+        .clone()
+        .lazy()
+        .filter(col("kind").cast(DataType::String).eq(lit("b")))
+        .select([col("id"), col("point"), col("tags")])
+        .limit(5)
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let out = FlightReader::new(server.location(), vec![])
+        .with_n_rows(Some(992))
+        .finish()?;
+    assert_eq!(out.height(), 992);
+    assert!(with_string_kind(out)?.equals_missing(&with_string_kind(served.head(Some(992)))?));
+    Ok(())
+}
+
+#[test]
+fn test_flight_server_tickets_and_errors() -> PolarsResult<()> {
+    let server = FlightServer::bind(
+        "127.0.0.1:0",
+        Arc::new(|ticket: &[u8]| match ticket {
+            b"empty" => Ok(DataFrame::empty_with_schema(&Schema::from_iter([
+                Field::new("a".into(), DataType::Int32),
+                Field::new("b".into(), DataType::String),
+            ]))),
+            b"numbers" => df!("a" => [1i32, 2, 3]),
+            _ => {
+                polars_bail!(ComputeError: "unknown ticket '{}' (100%)", String::from_utf8_lossy(ticket))
+            },
+        }),
+    )?;
+    let location = format!("grpc+tcp://{}", server.local_addr());
+
+    let out = FlightReader::new(&location, "numbers").finish()?;
+    assert_eq!(out, df!("a" => [1i32, 2, 3])?);
+
+    let reader = FlightReader::new(&location, "empty");
+    let schema = reader.schema()?;
+    assert_eq!(schema.len(), 2);
+    let out = reader.finish()?;
+    assert_eq!(out.height(), 0);
+    assert_eq!(out.schema(), &schema);
+
+    let err = FlightReader::new(&location, "tablé").finish().unwrap_err();
+    assert!(
+        err.to_string().contains("unknown ticket 'tablé' (100%)"),
+        "{err}"
+    );
+    assert!(LazyFrame::scan_flight(&location, "missing", Default::default()).is_err());
+    assert!(FlightReader::new("grpc+tls://localhost:1", "numbers")
+        .finish()
+        .is_err());
+    Ok(())
+}
+
+/// Writes batches of one row until the client cancels the stream.
+#[derive(Default)]
+struct EndlessHandler {
+    streams: AtomicUsize,
+    cancelled: AtomicBool,
+}
+
+impl FlightHandler for EndlessHandler {
+    fn schema(&self, _ticket: &[u8]) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(Schema::from_iter([Field::new(
+            "a".into(),
+            DataType::Int64,
+        )])))
+    }
+
+    fn write(&self, ticket: &[u8], writer: Box<dyn std::io::Write + Send>) -> PolarsResult<()> {
+        self.streams.fetch_add(1, Ordering::SeqCst);
+        let mut writer = IpcStreamWriter::new(writer).batched(self.schema(ticket)?.as_ref())?;
+        for i in 0..1_000_000i64 {
+            if writer.write_batch(&df!("a" => [i])?).is_err() {
+                self.cancelled.store(true, Ordering::SeqCst);
+                return Ok(());
+            }
+        }
+        writer.finish()
+    }
+}
+
+#[test]
+fn test_flight_server_streams_batches() -> PolarsResult<()> {
+    let handler = Arc::new(EndlessHandler::default());
+    let server = FlightServer::bind("127.0.0.1:0", handler.clone())?;
+
+    // The schema is served without writing the stream, and the batches are sent while the
+    // handler is still writing.
+    let out = LazyFrame::scan_flight(server.location(), "ticket", Default::default())?
+        .limit(3)
+        .collect()?;
+    assert_eq!(out, df!("a" => [0i64, 1, 2])?);
+    assert_eq!(handler.streams.load(Ordering::SeqCst), 1);
+
+    let start = Instant::now();
+    while !handler.cancelled.load(Ordering::SeqCst) {
+        assert!(start.elapsed() < Duration::from_secs(10));
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    Ok(())
+}
@@ -0,0 +1,431 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use apache_avro::{Schema as AvroSchema, Writer};
+use arrow::record_batch::RecordBatchT;
+use chrono::NaiveDate;
+use polars::prelude::*;
+use polars_parquet::write::{
+    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, ParquetType,
+    RowGroupIterator, SchemaDescriptor, StatisticsOptions, Version, WriteOptions,
+};
+
+const MANIFEST_LIST_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_file",
+    "fields": [
+        {"name": "manifest_path", "type": "string"},
+        {"name": "manifest_length", "type": "long"},
+        {"name": "partition_spec_id", "type": "int"},
+        {"name": "content", "type": "int"},
+        {"name": "sequence_number", "type": "long"},
+        {"name": "added_snapshot_id", "type": "long"},
+        {"name": "partitions", "type": ["null", {"type": "array", "items": {
+            "type": "record",
+            "name": "r508",
+            "fields": [
+                {"name": "contains_null", "type": "boolean"},
+                {"name": "lower_bound", "type": ["null", "bytes"]},
+                {"name": "upper_bound", "type": ["null", "bytes"]}
+            ]
+        }}]}
+    ]
+}"#;
+
+const MANIFEST_SCHEMA: &str = r#"{
+    "type": "record",
+    "name": "manifest_entry",
+    "fields": [
+        {"name": "status", "type": "int"},
+        {"name": "snapshot_id", "type": ["null", "long"]},
+        {"name": "sequence_number", "type": ["null", "long"]},
+        {"name": "data_file", "type": {
+            "type": "record",
+            "name": "r2",
+            "fields": [
+                {"name": "content", "type": "int"},
+                {"name": "file_path", "type": "string"},
+                {"name": "file_format", "type": "string"},
+                {"name": "partition", "type": {
+                    "type": "record",
+                    "name": "r102",
+                    "fields": [{"name": "day_month", "type": ["null", "int"]}]
+                }},
+                {"name": "record_count", "type": "long"},
+                {"name": "file_size_in_bytes", "type": "long"},
+                {"name": "lower_bounds", "type": ["null", {"type": "array", "items": {
+                    "type": "record",
+                    "name": "k126_v127",
+                    "fields": [{"name": "key", "type": "int"}, {"name": "value", "type": "bytes"}]
+                }}]},
+                {"name": "upper_bounds", "type": ["null", {"type": "array", "items": {
+                    "type": "record",
+                    "name": "k129_v130",
+                    "fields": [{"name": "key", "type": "int"}, {"name": "value", "type": "bytes"}]
+                }}]},
+                {"name": "equality_ids", "type": ["null", {"type": "array", "items": "int"}]}
+            ]
+        }}
+    ]
+}"#;
+
+const SCHEMA_V0: &str = r#"{"type":"struct","schema-id":0,"fields":[
+    {"id":1,"name":"id","required":true,"type":"long"},
+    {"id":2,"name":"label","required":false,"type":"string"},
+    {"id":3,"name":"day","required":false,"type":"date"}]}"#;
+
+const SCHEMA_V1: &str = r#"{"type":"struct","schema-id":1,"fields":[
+    {"id":1,"name":"id","required":true,"type":"long"},
+    {"id":2,"name":"name","required":false,"type":"string"},
+    {"id":3,"name":"day","required":false,"type":"date"},
+    {"id":4,"name":"score","required":false,"type":"double"}]}"#;
+
+fn write_avro(path: &Path, schema: &str, records: Vec<Value>) -> PolarsResult<()> {
+    let schema = AvroSchema::parse_str(schema).unwrap();
+    let mut writer = Writer::new(&schema, Vec::new());
+    for record in records {
+        writer.append(record).unwrap();
+    }
+    std::fs::write(path, writer.into_inner().unwrap())?;
+    Ok(())
+}
+
+fn record(fields: Vec<(&str, Value)>) -> Value {
+    Value::Record(
+        fields
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    )
+}
+
+fn nullable(value: Option<Value>) -> Value {
+    match value {
+        Some(value) => Value::Union(1, Box::new(value)),
+        None => Value::Union(0, Box::new(Value::Null)),
+    }
+}
+
+fn manifest_file(path: &Path, content: i32, sequence_number: i64, months: (i32, i32)) -> Value {
+    let summary = record(vec![
+        ("contains_null", Value::Boolean(false)),
+        (
+            "lower_bound",
+            nullable(Some(Value::Bytes(months.0.to_le_bytes().to_vec()))),
+        ),
+        (
+            "upper_bound",
+            nullable(Some(Value::Bytes(months.1.to_le_bytes().to_vec()))),
+        ),
+    ]);
+    record(vec![
+        ("manifest_path", Value::String(path.display().to_string())),
+        ("manifest_length", Value::Long(0)),
+        ("partition_spec_id", Value::Int(0)),
+        ("content", Value::Int(content)),
+        ("sequence_number", Value::Long(sequence_number)),
+        ("added_snapshot_id", Value::Long(1)),
+        ("partitions", nullable(Some(Value::Array(vec![summary])))),
+    ])
+}
+
+struct Entry<'a> {
+    status: i32,
+    content: i32,
+    path: &'a Path,
+    month: i32,
+    record_count: i64,
+    id_bounds: Option<(i64, i64)>,
+    equality_ids: Option<Vec<i32>>,
+}
+
+fn manifest_entry(entry: Entry) -> Value {
+    let bounds = |bound: Option<i64>| {
+        nullable(bound.map(|bound| {
+            Value::Array(vec![record(vec![
+                ("key", Value::Int(1)),
+                ("value", Value::Bytes(bound.to_le_bytes().to_vec())),
+            ])])
+        }))
+    };
+    let data_file = record(vec![
+        ("content", Value::Int(entry.content)),
+        ("file_path", Value::String(entry.path.display().to_string())),
+        ("file_format", Value::String("PARQUET".into())),
+        (
+            "partition",
+            record(vec![("day_month", nullable(Some(Value::Int(entry.month))))]),
+        ),
+        ("record_count", Value::Long(entry.record_count)),
+        ("file_size_in_bytes", Value::Long(0)),
+        ("lower_bounds", bounds(entry.id_bounds.map(|b| b.0))),
+        ("upper_bounds", bounds(entry.id_bounds.map(|b| b.1))),
+        (
+            "equality_ids",
+            nullable(
+                entry
+                    .equality_ids
+                    .map(|ids| Value::Array(ids.into_iter().map(Value::Int).collect())),
+            ),
+        ),
+    ]);
+    record(vec![
+        ("status", Value::Int(entry.status)),
+        ("snapshot_id", nullable(None)),
+        // Inherit the sequence number of the manifest.
+        ("sequence_number", nullable(None)),
+        ("data_file", data_file),
+    ])
+}
+
+fn write_parquet(path: &Path, mut df: DataFrame) -> PolarsResult<()> {
+    ParquetWriter::new(std::fs::File::create(path)?).finish(&mut df)?;
+    Ok(())
+}
+
+/// Write a parquet file with the given field IDs, which the polars writer does not write.
+fn write_parquet_with_field_ids(path: &Path, df: DataFrame, ids: &[i32]) -> PolarsResult<()> {
+    let schema = df.schema().to_arrow(CompatLevel::newest());
+    let parquet_schema = to_parquet_schema(&schema)?;
+    let fields = parquet_schema
+        .fields()
+        .iter()
+        .zip(ids)
+        .map(|(field, id)| {
+            let mut field = field.clone();
+            match &mut field {
+                ParquetType::PrimitiveType(primitive) => primitive.field_info.id = Some(*id),
+                ParquetType::GroupType { field_info, .. } => field_info.id = Some(*id),
+            }
+            field
+        })
+        .collect();
+    let parquet_schema = SchemaDescriptor::new(parquet_schema.name().into(), fields);
+
+    let options = WriteOptions {
+        statistics: StatisticsOptions::full(),
+        compression: CompressionOptions::Uncompressed,
+        version: Version::V2,
+        data_page_size: None,
+    };
+    let encodings = schema
+        .iter_values()
+        .map(|f| transverse(f.dtype(), |_| Encoding::Plain))
+        .collect();
+    let schema = Arc::new(schema);
+    let batches = df
+        .iter_chunks(CompatLevel::newest(), false)
+        .map(|batch| RecordBatchT::try_new(batch.len(), schema.clone(), batch.into_arrays()));
+    let row_groups = RowGroupIterator::try_new(batches, &schema, options, encodings)?;
+
+    let file = std::fs::File::create(path)?;
+    let mut writer =
+        FileWriter::new_with_parquet_schema(file, schema.as_ref().clone(), parquet_schema, options);
+    for group in row_groups {
+        writer.write(group?)?;
+    }
+    writer.end(None)?;
+    Ok(())
+}
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn dates(values: &[NaiveDate]) -> Column {
+    DateChunked::from_naive_date(PlSmallStr::from_static("day"), values.iter().copied())
+        .into_column()
+}
+
+/// Create a table that is partitioned by the month of `day`, with two snapshots:
+/// 1. appends `a.parquet`, which was written with field IDs, before column 2 was renamed from
+///    `label` to `name`.
+/// 2. appends `b.parquet`, which was written without field IDs, and deletes two rows of
+///    `a.parquet`, one by position and one by equality.
+fn create_table(root: &Path) -> PolarsResult<()> {
+    std::fs::create_dir_all(root.join("metadata"))?;
+    std::fs::create_dir_all(root.join("data"))?;
+    let data = root.join("data");
+    let metadata = root.join("metadata");
+
+    // January 2024 is month 648 since the epoch.
+    let a = data.join("a.parquet");
+    write_parquet_with_field_ids(
+        &a,
+        DataFrame::new(vec![
+            Column::new("id".into(), [1i64, 2, 3]),
+            Column::new("label".into(), ["a", "b", "c"]),
+            dates(&[date(2024, 1, 10), date(2024, 1, 20), date(2024, 1, 31)]),
+        ])?,
+        &[1, 2, 3],
+    )?;
+    let b = data.join("b.parquet");
+    write_parquet(
+        &b,
+        DataFrame::new(vec![
+            Column::new("id".into(), [4i64, 5, 6]),
+            Column::new("name".into(), ["d", "e", "f"]),
+            dates(&[date(2024, 2, 5), date(2024, 2, 10), date(2024, 2, 20)]),
+            Column::new("score".into(), [4.0, 5.0, 6.0]),
+        ])?,
+    )?;
+    let position_deletes = data.join("position-deletes.parquet");
+    write_parquet(
+        &position_deletes,
+        df!(
+            "file_path" => [a.display().to_string()],
+            "pos" => [1i64],
+        )?,
+    )?;
+    let equality_deletes = data.join("equality-deletes.parquet");
+    write_parquet_with_field_ids(&equality_deletes, df!("id" => [3i64])?, &[1])?;
+
+    let entry = |status, content, path, month, id_bounds, equality_ids| {
+        manifest_entry(Entry {
+            status,
+            content,
+            path,
+            month,
+            record_count: 3,
+            id_bounds,
+            equality_ids,
+        })
+    };
+    let manifest_a = metadata.join("manifest-a.avro");
+    write_avro(
+        &manifest_a,
+        MANIFEST_SCHEMA,
+        vec![entry(1, 0, &a, 648, Some((1, 3)), None)],
+    )?;
+    let manifest_b = metadata.join("manifest-b.avro");
+    let missing = data.join("missing.parquet");
+    write_avro(
+        &manifest_b,
+        MANIFEST_SCHEMA,
+        vec![
+            entry(1, 0, &b, 649, Some((4, 6)), None),
+            // Deleted entries are not read.
+            entry(2, 0, &missing, 649, None, None),
+        ],
+    )?;
+    let manifest_deletes = metadata.join("manifest-deletes.avro");
+    write_avro(
+        &manifest_deletes,
+        MANIFEST_SCHEMA,
+        vec![
+            entry(1, 1, &position_deletes, 648, None, None),
+            entry(1, 2, &equality_deletes, 648, None, Some(vec![1])),
+        ],
+    )?;
+
+    let snap_1 = metadata.join("snap-1.avro");
+    write_avro(
+        &snap_1,
+        MANIFEST_LIST_SCHEMA,
+        vec![manifest_file(&manifest_a, 0, 1, (648, 648))],
+    )?;
+    let snap_2 = metadata.join("snap-2.avro");
+    write_avro(
+        &snap_2,
+        MANIFEST_LIST_SCHEMA,
+        vec![
+            manifest_file(&manifest_a, 0, 1, (648, 648)),
+            manifest_file(&manifest_b, 0, 2, (649, 649)),
+            manifest_file(&manifest_deletes, 1, 2, (648, 648)),
+        ],
+    )?;
+
+    let name_mapping = r#"[{\"field-id\":1,\"names\":[\"id\"]},{\"field-id\":2,\"names\":[\"label\",\"name\"]},{\"field-id\":3,\"names\":[\"day\"]},{\"field-id\":4,\"names\":[\"score\"]}]"#;
+    let table_metadata = format!(
+        r#"{{
+            "format-version": 2,
+            "table-uuid": "00000000-0000-0000-0000-000000000000",
+            "location": "{root}",
+            "last-sequence-number": 2,
+            "last-updated-ms": 0,
+            "last-column-id": 4,
+            "current-schema-id": 1,
+            "schemas": [{SCHEMA_V0}, {SCHEMA_V1}],
+            "default-spec-id": 0,
+            "partition-specs": [{{"spec-id": 0, "fields": [
+                {{"name": "day_month", "transform": "month", "source-id": 3, "field-id": 1000}}
+            ]}}],
+            "last-partition-id": 1000,
+            "properties": {{"schema.name-mapping.default": "{name_mapping}"}},
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {{"snapshot-id": 1, "sequence-number": 1, "timestamp-ms": 0,
+                  "manifest-list": "{snap_1}", "schema-id": 0}},
+                {{"snapshot-id": 2, "parent-snapshot-id": 1, "sequence-number": 2,
+                  "timestamp-ms": 0, "manifest-list": "{snap_2}", "schema-id": 1}}
+            ]
+        }}"#,
+        root = root.display(),
+        snap_1 = snap_1.display(),
+        snap_2 = snap_2.display(),
+    );
+    std::fs::write(metadata.join("v2.metadata.json"), table_metadata)?;
+    std::fs::write(metadata.join("version-hint.text"), "2")?;
+    Ok(())
+}
+
+fn scan(root: &Path, args: ScanArgsIceberg) -> PolarsResult<LazyFrame> {
+    LazyFrame::scan_iceberg(root.to_str().unwrap(), args)
+}
+
+#[test]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    create_table(root)?;
+
+    let out = scan(root, Default::default())?
+        .sort(["id"], Default::default())
+        .collect()?;
+    let expected = DataFrame::new(vec![
+        Column::new("id".into(), [1i64, 4, 5, 6]),
+        Column::new("name".into(), ["a", "d", "e", "f"]),
+        dates(&[
+            date(2024, 1, 10),
+            date(2024, 2, 5),
+            date(2024, 2, 10),
+            date(2024, 2, 20),
+        ]),
+        Column::new("score".into(), [None, Some(4.0), Some(5.0), Some(6.0)]),
+    ])?;
+    assert!(out.equals_missing(&expected));
+
+    // Time travel to the first snapshot, which has the schema before the rename.
+    let args = ScanArgsIceberg {
+        snapshot_id: Some(1),
+        ..Default::default()
+    };
+    let out = scan(root, args)?.collect()?;
+    let expected = DataFrame::new(vec![
+        Column::new("id".into(), [1i64, 2, 3]),
+        Column::new("label".into(), ["a", "b", "c"]),
+        dates(&[date(2024, 1, 10), date(2024, 1, 20), date(2024, 1, 31)]),
+    ])?;
+    assert!(out.equals_missing(&expected));
+
+    // Without `a.parquet`, the scans only succeed if it is pruned.
+    std::fs::remove_file(root.join("data/a.parquet"))?;
+    // Pruned by the partition summaries of the manifest, 2024-02-06 is day 19759 since the epoch.
+    let out = scan(root, Default::default())?
+        .filter(col("day").gt_eq(Expr::Literal(LiteralValue::Date(19759))))
+        .select([col("id")])
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out, df!("id" => [5i64, 6])?);
+    // Pruned by the column bounds of the data file.
+    let out = scan(root, Default::default())?
+        .filter(col("id").gt(lit(4i64)))
+        .select([col("id"), col("score")])
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out, df!("id" => [5i64, 6], "score" => [5.0, 6.0])?);
+    // Unfiltered scans read all files.
+    assert!(scan(root, Default::default())?.collect().is_err());
+    Ok(())
+}
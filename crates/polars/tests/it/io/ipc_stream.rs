@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use std::io::Cursor;
+    use std::io::{Cursor, Seek};
 
     use polars_core::prelude::*;
     use polars_core::{assert_df_eq, df};
@@ -156,4 +156,100 @@ mod test {
         let actual = IpcStreamReader::new(reader).finish().unwrap();
         assert_df_eq!(df(), actual);
     }
+
+    #[test]
+    #[cfg(feature = "lazy")]
+    fn test_scan_and_sink_ipc_stream() -> PolarsResult<()> {
+        use polars_lazy::prelude::*;
+
+        let lf = LazyFrame::scan_ipc_stream(create_ipc_stream(create_df()), Default::default())?;
+        let out = lf.select([col("temp")]).limit(2).collect()?;
+        assert_df_eq!(out, create_df().select(["temp"])?.head(Some(2)));
+
+        let mut file = tempfile::tempfile()?;
+        create_df()
+            .lazy()
+            .sink_ipc_stream(file.try_clone()?, Default::default())?;
+        file.rewind()?;
+        assert_df_eq!(IpcStreamReader::new(file).finish()?, create_df());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "lazy", feature = "streaming", unix))]
+    fn test_scan_and_sink_ipc_stream_forwards_batches() -> PolarsResult<()> {
+        use std::os::unix::net::UnixStream;
+        use std::sync::mpsc;
+        use std::time::Duration;
+
+        use polars_lazy::prelude::*;
+
+        let (producer, input) = UnixStream::pair()?;
+        let (output, consumer) = UnixStream::pair()?;
+        let (ack, acked) = mpsc::channel();
+
+        // Only send the next batch once the previous one came out of the query.
+        let producer = std::thread::spawn(move || -> PolarsResult<()> {
+            let df = create_df();
+            let mut writer = IpcStreamWriter::new(producer).batched(df.schema())?;
+            for i in 0..3 {
+                writer.write_batch(&df.slice(i, 1))?;
+                acked
+                    .recv_timeout(Duration::from_secs(10))
+                    .map_err(|_| polars_err!(ComputeError: "batch {i} was not forwarded"))?;
+            }
+            writer.finish()
+        });
+        let query = std::thread::spawn(move || -> PolarsResult<()> {
+            LazyFrame::scan_ipc_stream(input, Default::default())?
+                .with_column((col("days") * lit(2)).alias("days2"))
+                .sink_ipc_stream(output, Default::default())
+        });
+
+        let mut reader = IpcStreamReader::new(consumer).batched()?;
+        let mut n_batches = 0;
+        while let Some(batch) = reader.next_batch()? {
+            assert_eq!(batch.height(), 1);
+            n_batches += 1;
+            let _ = ack.send(());
+        }
+        producer.join().unwrap()?;
+        query.join().unwrap()?;
+        assert_eq!(n_batches, 3);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(feature = "lazy", unix))]
+    fn test_map_ipc_stream_over_socket() -> PolarsResult<()> {
+        use std::os::unix::net::UnixStream;
+
+        use polars_lazy::prelude::*;
+
+        let (producer, consumer) = UnixStream::pair()?;
+        let handle = std::thread::spawn(move || -> PolarsResult<()> {
+            let df = create_df();
+            let mut writer = IpcStreamWriter::new(producer).batched(df.schema())?;
+            for i in 0..3 {
+                writer.write_batch(&df.slice(i, 1))?;
+            }
+            writer.finish()
+        });
+
+        let mut out = Cursor::new(Vec::new());
+        map_ipc_stream(consumer, &mut out, Default::default(), |lf| {
+            lf.with_column((col("days") * lit(2)).alias("days2"))
+        })?;
+        handle.join().unwrap()?;
+
+        out.set_position(0);
+        let mut reader = IpcStreamReader::new(out).batched()?;
+        let mut n_batches = 0;
+        while let Some(batch) = reader.next_batch()? {
+            assert_eq!(batch.height(), 1);
+            n_batches += 1;
+        }
+        assert_eq!(n_batches, 3);
+        Ok(())
+    }
 }
@@ -1,10 +1,26 @@
+#[cfg(feature = "catalog")]
+mod catalog;
 mod csv;
 
+#[cfg(feature = "delta")]
+mod delta;
+#[cfg(feature = "fixed_width")]
+mod fixed_width;
+#[cfg(feature = "flight")]
+mod flight;
+#[cfg(feature = "iceberg")]
+mod iceberg;
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "orc")]
+mod orc;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(feature = "spreadsheet")]
+mod spreadsheet;
+#[cfg(feature = "sqlite")]
+mod sqlite;
 
 #[cfg(feature = "avro")]
 mod avro;
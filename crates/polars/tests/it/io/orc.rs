@@ -0,0 +1,153 @@
+use std::fs::File;
+
+use polars::prelude::*;
+
+fn nested_df() -> PolarsResult<DataFrame> {
+    let n = 10;
+    let amount = Int128Chunked::from_iter_options(
+        "amount".into(),
+        (0..n).map(|i| (i != 5).then_some(i as i128 * 1234 - 5000)),
+    )
+    .into_decimal_unchecked(Some(10), 2);
+    let at = Int64Chunked::new(
+        "at".into(),
+        (0..n)
+            .map(|i| (i - 3) * 1_500_000_123)
+            .collect::<Vec<i64>>(),
+    )
+    .into_datetime(TimeUnit::Nanoseconds, None);
+    let mut tags: ListChunked = (0..n)
+        .map(|i| {
+            (i % 3 != 1).then(|| {
+                Series::new(PlSmallStr::EMPTY, [Some("a"), None, Some("bc")])
+                    .slice(0, i as usize % 4)
+            })
+        })
+        .collect();
+    tags.rename("tags".into());
+    let mut ys: ListChunked = (0..n)
+        .map(|i| Some(Series::new(PlSmallStr::EMPTY, vec![i; i as usize % 3])))
+        .collect();
+    ys.rename("ys".into());
+
+    let df = df!(
+        "id" => (0..n).collect::<Vec<i64>>(),
+        "small" => (0..n).map(|i| (i % 4 != 0).then_some(i as i8 - 5)).collect::<Vec<_>>(),
+        "flag" => (0..n).map(|i| (i != 2).then_some(i % 3 == 0)).collect::<Vec<_>>(),
+        "score" => (0..n).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+        "name" => (0..n).map(|i| (i != 7).then(|| format!("name {i}"))).collect::<Vec<_>>(),
+        "unsigned" => (0..n).map(|i| i as u32 * 1000).collect::<Vec<_>>(),
+        "x" => (0..n).map(|i| i as i32).collect::<Vec<_>>(),
+    )?
+    .hstack(&[
+        amount.into_column(),
+        at.into_column(),
+        tags.into_column(),
+        ys.into_column(),
+    ])?;
+    df.lazy()
+        .with_columns([
+            col("at").cast(DataType::Date).alias("day"),
+            when(col("id").eq(lit(4)))
+                .then(lit(NULL))
+                .otherwise(as_struct(vec![col("x"), col("ys")]))
+                .alias("point"),
+        ])
+        .drop(["x", "ys"])
+        .collect()
+}
+
+#[test]
+fn test_orc_round_trip() -> PolarsResult<()> {
+    let df = nested_df()?;
+    // ORC has no unsigned integers.
+    let expected = df
+        .clone()
+        .lazy()
+        .with_column(col("unsigned").cast(DataType::Int64))
+        .collect()?;
+
+    let dir = tempfile::tempdir()?;
+    for compression in [
+        OrcCompression::Uncompressed,
+        OrcCompression::Zlib,
+        OrcCompression::Snappy,
+        OrcCompression::Lz4,
+        OrcCompression::Zstd,
+    ] {
+        let path = dir.path().join(format!("round_trip_{compression:?}.orc"));
+        let options = OrcWriterOptions {
+            compression,
+            stripe_size: 4,
+            ..Default::default()
+        };
+        OrcWriter::new(File::create(&path)?)
+            .with_options(options)
+            .finish(&mut df.clone())?;
+
+        let out = OrcReader::new(File::open(&path)?).finish()?;
+        assert!(out.equals_missing(&expected), "{compression:?}: {out}");
+
+        let out = OrcReader::new(File::open(&path)?)
+            .with_columns(Some(["point".into(), "amount".into()].into()))
+            .with_n_rows(Some(6))
+            .finish()?;
+        assert!(out.equals_missing(&expected.select(["point", "amount"])?.head(Some(6))));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_orc_scan_stripe_pruning() -> PolarsResult<()> {
+    let df = nested_df()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("stripe_pruning.orc");
+    let options = OrcWriterOptions {
+        stripe_size: 4,
+        ..Default::default()
+    };
+    df.clone().lazy().sink_orc(&path, options)?;
+
+    let mut reader = OrcReader::new(File::open(&path)?);
+    let metadata = reader.metadata()?;
+    assert_eq!(metadata.num_stripes(), 3);
+    let stats = metadata.stripe_statistics()?;
+    let expected = df!(
+        "len" => [4 as IdxSize, 4, 2],
+        "id_min" => [0i64, 4, 8],
+        "id_max" => [3i64, 7, 9],
+        "id_nc" => [0 as IdxSize, 0, 0],
+        "name_min" => ["name 0", "name 4", "name 8"],
+        "name_nc" => [0 as IdxSize, 1, 0],
+    )?;
+    assert_eq!(
+        stats.select(["len", "id_min", "id_max", "id_nc", "name_min", "name_nc"])?,
+        expected
+    );
+
+    let out = LazyFrame::scan_orc(&path, Default::default())?
+        .filter(col("id").gt(lit(5)).and(col("small").is_not_null()))
+        .select([col("id"), col("tags")])
+        .collect()?;
+    let expected = df
+        .clone()
+        .lazy()
+        .filter(col("id").gt(lit(5)).and(col("small").is_not_null()))
+        .select([col("id"), col("tags")])
+        .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    // Corrupt the first stream of the first stripe, which starts after the magic bytes.
+    let mut bytes = std::fs::read(&path)?;
+    bytes[3..6].fill(0xff);
+    std::fs::write(&path, bytes)?;
+    let out = LazyFrame::scan_orc(&path, Default::default())?
+        .filter(col("id").gt_eq(lit(4)))
+        .select([col("id")])
+        .collect()?;
+    assert_eq!(out, df!("id" => (4..10).collect::<Vec<i64>>())?);
+    assert!(LazyFrame::scan_orc(&path, Default::default())?
+        .collect()
+        .is_err());
+    Ok(())
+}
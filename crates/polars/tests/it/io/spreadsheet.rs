@@ -0,0 +1,172 @@
+use std::io::Write;
+use std::path::Path;
+
+use chrono::{NaiveDate, NaiveTime};
+use polars::prelude::*;
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook};
+
+/// A workbook with a sheet `notes`, and a sheet `data` with a header in row 3 and a table
+/// of typed cells below it.
+fn write_xlsx(path: &Path) {
+    let mut workbook = Workbook::new();
+    let date = Format::new().set_num_format("yyyy-mm-dd");
+    let datetime = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+    let time = Format::new().set_num_format("hh:mm:ss");
+
+    let notes = workbook.add_worksheet();
+    notes.set_name("notes").unwrap();
+    notes.write_string(0, 0, "not data").unwrap();
+
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("data").unwrap();
+    sheet.write_string(0, 0, "a title").unwrap();
+    for (col, name) in ["id", "name", "score", "flag", "day", "at", "time", "id"]
+        .iter()
+        .enumerate()
+    {
+        sheet.write_string(2, col as u16, *name).unwrap();
+    }
+    for i in 0..3u32 {
+        let row = 3 + i;
+        sheet.write_number(row, 0, (i + 1) as f64).unwrap();
+        if i != 1 {
+            sheet.write_string(row, 1, format!("n{i}")).unwrap();
+        }
+        sheet.write_number(row, 2, 0.5 + i as f64).unwrap();
+        sheet.write_boolean(row, 3, i % 2 == 0).unwrap();
+        let day = ExcelDateTime::from_ymd(2024, 1, 1 + i as u8).unwrap();
+        sheet
+            .write_datetime_with_format(row, 4, &day, &date)
+            .unwrap();
+        let at = ExcelDateTime::from_ymd(2024, 1, 1)
+            .unwrap()
+            .and_hms(12, 30, i as u8)
+            .unwrap();
+        sheet
+            .write_datetime_with_format(row, 5, &at, &datetime)
+            .unwrap();
+        let t = ExcelDateTime::from_hms(8, i as u8, 0).unwrap();
+        sheet.write_datetime_with_format(row, 6, &t, &time).unwrap();
+        // Text cells are inferred as in CSV files.
+        sheet.write_string(row, 7, (10 * i).to_string()).unwrap();
+    }
+    workbook.save(path).unwrap();
+}
+
+fn write_ods(path: &Path) {
+    let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2">
+<office:body><office:spreadsheet><table:table table:name="Sheet1">
+<table:table-row><table:table-cell office:value-type="string"><text:p>name</text:p></table:table-cell><table:table-cell office:value-type="string"><text:p>value</text:p></table:table-cell><table:table-cell office:value-type="string"><text:p>day</text:p></table:table-cell></table:table-row>
+<table:table-row><table:table-cell office:value-type="string"><text:p>a</text:p></table:table-cell><table:table-cell office:value-type="float" office:value="1.5"><text:p>1.5</text:p></table:table-cell><table:table-cell office:value-type="date" office:date-value="2024-01-31"><text:p>31/01/24</text:p></table:table-cell></table:table-row>
+<table:table-row><table:table-cell office:value-type="string"><text:p>b</text:p></table:table-cell><table:table-cell office:value-type="float" office:value="2"><text:p>2</text:p></table:table-cell><table:table-cell/></table:table-row>
+</table:table></office:spreadsheet></office:body></office:document-content>"#;
+    let manifest = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2">
+<manifest:file-entry manifest:full-path="/" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>"#;
+
+    let mut zip = ::zip::ZipWriter::new(std::fs::File::create(path).unwrap());
+    let options = ::zip::write::SimpleFileOptions::default()
+        .compression_method(::zip::CompressionMethod::Stored);
+    for (name, data) in [
+        ("mimetype", "application/vnd.oasis.opendocument.spreadsheet"),
+        ("META-INF/manifest.xml", manifest),
+        ("content.xml", content),
+    ] {
+        zip.start_file(name, options).unwrap();
+        zip.write_all(data.as_bytes()).unwrap();
+    }
+    zip.finish().unwrap();
+}
+
+#[test]
+fn test_read_xlsx() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("spreadsheet.xlsx");
+    write_xlsx(&path);
+    assert_eq!(sheet_names(&path)?, ["notes", "data"]);
+
+    let options = SpreadsheetReadOptions {
+        sheet: SheetSelector::Name("data".into()),
+        range: Some("A3:H".into()),
+        ..Default::default()
+    };
+    let df = SpreadsheetReader::new(&path)
+        .with_options(options.clone())
+        .finish()?;
+    let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+    let mut expected = df!(
+        "id" => [1i64, 2, 3],
+        "name" => [Some("n0"), None, Some("n2")],
+        "score" => [0.5, 1.5, 2.5],
+        "flag" => [true, false, true],
+        "day" => [day(1), day(2), day(3)],
+        "at" => (0..3).map(|s| day(1).and_hms_opt(12, 30, s).unwrap()).collect::<Vec<_>>(),
+        "time" => (0..3).map(|m| NaiveTime::from_hms_opt(8, m, 0).unwrap()).collect::<Vec<_>>(),
+        "id_duplicated_0" => [0i64, 10, 20],
+    )?;
+    expected.apply("at", |c| {
+        c.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap()
+    })?;
+    assert_eq!(df, expected);
+
+    // Columns and rows are pushed down into the scan.
+    let args = ScanArgsSpreadsheet {
+        read_options: options.clone(),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_spreadsheet(&path, args)?
+        .select([col("name"), col("score")])
+        .limit(2)
+        .collect()?;
+    assert_eq!(out, expected.select(["name", "score"])?.head(Some(2)));
+
+    // Without a header, the header is read as a row and the columns are strings.
+    let options = SpreadsheetReadOptions {
+        range: Some("B3:C4".into()),
+        has_header: Some(false),
+        schema_overwrite: Some(Arc::new(Schema::from_iter([Field::new(
+            "column_2".into(),
+            DataType::String,
+        )]))),
+        ..options
+    };
+    let out = SpreadsheetReader::new(&path)
+        .with_options(options)
+        .finish()?;
+    let expected = df!("column_1" => ["name", "n0"], "column_2" => ["score", "0.5"])?;
+    assert_eq!(out, expected);
+
+    let options = SpreadsheetReadOptions {
+        sheet: SheetSelector::Index(5),
+        ..Default::default()
+    };
+    let err = SpreadsheetReader::new(&path)
+        .with_options(options)
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("no sheet 5"));
+    Ok(())
+}
+
+#[test]
+fn test_read_ods() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("spreadsheet.ods");
+    write_ods(&path);
+
+    let out = LazyFrame::scan_spreadsheet(&path, Default::default())?
+        .with_row_index("index", None)
+        .collect()?;
+    let expected = df!(
+        "index" => [0 as IdxSize, 1],
+        "name" => ["a", "b"],
+        "value" => [1.5, 2.0],
+        "day" => [NaiveDate::from_ymd_opt(2024, 1, 31), None],
+    )?;
+    assert_eq!(out, expected);
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use chrono::{NaiveDate, NaiveTime};
+use polars::prelude::*;
+use polars_io::sqlite::SqliteReader;
+
+fn test_df() -> PolarsResult<DataFrame> {
+    let datetime = NaiveDate::from_ymd_opt(2024, 1, 31)
+        .unwrap()
+        .and_hms_micro_opt(12, 30, 0, 500)
+        .unwrap();
+    let mut df = df!(
+        "id" => [1i64, 2, 3, 4],
+        "name" => [Some("a"), Some("b'c"), None, Some("d")],
+        "score" => [Some(1.5), None, Some(-2.0), Some(4.25)],
+        "flag" => [Some(true), Some(false), None, Some(true)],
+        "small" => [1u32, 2, 3, 4],
+        "bytes" => [b"x".as_ref(), b"", b"yz", b"\0"],
+        "date" => [NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(); 4],
+        "datetime" => [datetime; 4],
+        "time" => [NaiveTime::from_hms_nano_opt(23, 59, 59, 1).unwrap(); 4],
+    )?;
+    df.apply("time", |c| c.cast(&DataType::Time).unwrap())?;
+    Ok(df)
+}
+
+/// The frame as it is read back, with the types that SQLite can store.
+fn read_back(df: &DataFrame) -> PolarsResult<DataFrame> {
+    let mut df = df.clone();
+    df.apply("small", |c| c.cast(&DataType::Int64).unwrap())?;
+    df.apply("datetime", |c| {
+        c.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap()
+    })?;
+    Ok(df)
+}
+
+#[test]
+fn test_sqlite_round_trip() -> PolarsResult<()> {
+    let df = test_df()?;
+    let dir = tempfile::tempdir()?;
+    for (name, temporal_format) in [
+        ("sqlite_text", SqliteTemporalFormat::Text),
+        ("sqlite_integer", SqliteTemporalFormat::Integer),
+    ] {
+        let path = dir.path().join(format!("{name}.sqlite"));
+        let options = SqliteWriteOptions {
+            temporal_format,
+            ..Default::default()
+        };
+        df.clone().lazy().sink_sqlite(&path, "items", options)?;
+        let out = LazyFrame::scan_sqlite(&path, "items", Default::default())?.collect()?;
+        assert_eq!(out, read_back(&df)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_if_table_exists() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("sqlite_if_table_exists.sqlite");
+    let df = df!("x" => [1i64, 2])?;
+    let scan = || LazyFrame::scan_sqlite(&path, "t", Default::default())?.collect();
+
+    df.clone()
+        .lazy()
+        .sink_sqlite(&path, "t", Default::default())?;
+    df.clone()
+        .lazy()
+        .sink_sqlite(&path, "t", Default::default())?;
+    assert_eq!(scan()?.height(), 4);
+
+    let replace = SqliteWriteOptions {
+        if_table_exists: SqliteIfTableExists::Replace,
+        ..Default::default()
+    };
+    let new = df!("y" => ["a"])?;
+    new.clone().lazy().sink_sqlite(&path, "t", replace)?;
+    assert_eq!(scan()?, new);
+
+    let fail = SqliteWriteOptions {
+        if_table_exists: SqliteIfTableExists::Fail,
+        ..Default::default()
+    };
+    let err = new.lazy().sink_sqlite(&path, "t", fail).unwrap_err();
+    assert!(err.to_string().contains("already exists"));
+
+    // Nested types cannot be stored.
+    let list = df!("l" => [Series::new("".into(), [1i64])])?;
+    assert!(list
+        .lazy()
+        .sink_sqlite(&path, "l", Default::default())
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_out_of_range() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("sqlite_out_of_range.sqlite");
+    let datetime = Column::new("datetime".into(), [i64::MAX])
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+    let df = DataFrame::new(vec![datetime])?;
+
+    // The datetime cannot be written as text, but it can be written as an integer.
+    let err = df
+        .clone()
+        .lazy()
+        .sink_sqlite(&path, "t", Default::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("out of the range"));
+    let options = SqliteWriteOptions {
+        temporal_format: SqliteTemporalFormat::Integer,
+        ..Default::default()
+    };
+    df.clone().lazy().sink_sqlite(&path, "t", options)?;
+    let out = LazyFrame::scan_sqlite(&path, "t", Default::default())?.collect()?;
+    assert_eq!(out, df);
+
+    let big = df!("x" => [u64::MAX])?;
+    assert!(big
+        .lazy()
+        .sink_sqlite(&path, "big", Default::default())
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_query() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("sqlite_query.sqlite");
+    test_df()?
+        .lazy()
+        .sink_sqlite(&path, "items", Default::default())?;
+
+    // Columns of expressions get the type of their values.
+    let query = "SELECT id * 2 AS double, name || '!' AS name, NULL AS empty FROM items;";
+    let out = LazyFrame::scan_sqlite(&path, query, Default::default())?.collect()?;
+    let mut expected = df!(
+        "double" => [2i64, 4, 6, 8],
+        "name" => [Some("a!"), Some("b'c!"), None, Some("d!")],
+        "empty" => [None::<i32>; 4],
+    )?;
+    expected.apply("empty", |c| c.cast(&DataType::Null).unwrap())?;
+    assert_eq!(out, expected);
+
+    let out = SqliteReader::new(&path, "items")
+        .with_columns(Some(["name".into()].into()))
+        .with_filter(Some("id > 2".into()))
+        .finish()?;
+    assert_eq!(out, df!("name" => [None, Some("d")])?);
+    Ok(())
+}
+
+#[test]
+fn test_sqlite_pushdown() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("sqlite_pushdown.sqlite");
+    test_df()?
+        .lazy()
+        .sink_sqlite(&path, "items", Default::default())?;
+    let scan = || LazyFrame::scan_sqlite(&path, "items", Default::default());
+
+    let out = scan()?
+        .filter(col("id").gt(lit(1)).and(col("name").neq(lit("d"))))
+        .select([col("name")])
+        .collect()?;
+    assert_eq!(out, df!("name" => ["b'c"])?);
+
+    let out = scan()?
+        .filter(lit(1.0).lt(col("score")).or(col("flag").is_null()))
+        .select([col("id")])
+        .collect()?;
+    assert_eq!(out, df!("id" => [1i64, 3, 4])?);
+
+    // Casts that change the literal are evaluated by Polars.
+    let out = scan()?
+        .filter(col("id").eq(lit(2.5).cast(DataType::Int64)))
+        .select([col("id")])
+        .collect()?;
+    assert_eq!(out, df!("id" => [2i64])?);
+
+    // Predicates that cannot be translated to SQL are evaluated before the slice.
+    let out = scan()?
+        .filter(col("flag").not().and((col("id") % lit(2)).eq(lit(0))))
+        .select([col("id")])
+        .limit(1)
+        .collect()?;
+    assert_eq!(out, df!("id" => [2i64])?);
+
+    let out = scan()?
+        .filter((col("score") * lit(2)).gt(lit(3)))
+        .select([col("id")])
+        .collect()?;
+    assert_eq!(out, df!("id" => [4i64])?);
+
+    let out = scan()?
+        .with_row_index("index", None)
+        .slice(1, 2)
+        .select([col("index"), col("id")])
+        .collect()?;
+    assert_eq!(out, df!("index" => [1 as IdxSize, 2], "id" => [2i64, 3])?);
+
+    let out = scan()?.select([len()]).collect()?;
+    assert_eq!(out, df!("len" => [4 as IdxSize])?);
+    Ok(())
+}